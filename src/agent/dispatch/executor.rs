@@ -6,7 +6,7 @@ use crate::agent::config::AgentProfile;
 use crate::agent::prompt::{TemplateError, render_template};
 use crate::context::WorkflowContext;
 use crate::error::{BurlError, Result};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::io::Write;
 use std::path::PathBuf;
 use std::process::{Child, Command, Stdio};
@@ -46,6 +46,8 @@ impl AgentResult {
 /// * `variables` - Template variables for command substitution
 /// * `worktree` - Path to the task worktree (working directory for the command)
 /// * `timeout_seconds` - Maximum execution time before killing the process
+/// * `base_env` - Environment set before the agent's own `environment`
+///   (the config `worktree_setup.env`)
 ///
 /// # Returns
 ///
@@ -57,6 +59,7 @@ pub fn execute_agent(
     variables: &HashMap<String, String>,
     worktree: &str,
     timeout_seconds: u64,
+    base_env: &BTreeMap<String, String>,
 ) -> Result<AgentResult> {
     // Render the command template
     let command_str = render_template(&agent.command, variables).map_err(|e| match e {
@@ -135,7 +138,8 @@ pub fn execute_agent(
         .stdout(Stdio::from(stdout_file))
         .stderr(Stdio::from(stderr_file));
 
-    // Merge environment variables
+    // Merge environment variables (agent settings win over the shared ones)
+    command.envs(base_env);
     for (key, value) in &agent.environment {
         command.env(key, value);
     }
@@ -294,6 +298,7 @@ mod tests {
             &vars,
             worktree.to_str().unwrap(),
             10,
+            &BTreeMap::new(),
         )
         .unwrap();
 
@@ -327,6 +332,7 @@ mod tests {
             &vars,
             worktree.to_str().unwrap(),
             10,
+            &BTreeMap::new(),
         )
         .unwrap();
 
@@ -359,6 +365,7 @@ mod tests {
             &vars,
             worktree.to_str().unwrap(),
             10,
+            &BTreeMap::new(),
         )
         .unwrap();
 
@@ -391,6 +398,7 @@ mod tests {
             &vars,
             worktree.to_str().unwrap(),
             1,
+            &BTreeMap::new(),
         )
         .unwrap();
 
@@ -416,6 +424,7 @@ mod tests {
             &vars,
             worktree.to_str().unwrap(),
             10,
+            &BTreeMap::new(),
         );
 
         assert!(result.is_err());
@@ -442,6 +451,7 @@ mod tests {
             &vars,
             worktree.to_str().unwrap(),
             10,
+            &BTreeMap::new(),
         );
 
         assert!(result.is_err());
@@ -467,6 +477,7 @@ mod tests {
             &vars,
             worktree.to_str().unwrap(),
             10,
+            &BTreeMap::new(),
         );
 
         assert!(result.is_err());
@@ -500,6 +511,7 @@ mod tests {
             &vars,
             worktree.to_str().unwrap(),
             10,
+            &BTreeMap::new(),
         )
         .unwrap();
 
@@ -536,6 +548,7 @@ mod tests {
             &vars,
             worktree.to_str().unwrap(),
             10,
+            &BTreeMap::new(),
         )
         .unwrap();
 
@@ -173,7 +173,7 @@ impl TaskContext {
 ///
 /// Recognizes headings like `## Section Name` or `### Section Name`.
 /// Returns a map of lowercase section names to their content.
-fn extract_sections(body: &str) -> HashMap<String, String> {
+pub fn extract_sections(body: &str) -> HashMap<String, String> {
     let mut sections = HashMap::new();
     let mut current_section: Option<String> = None;
     let mut current_content = String::new();
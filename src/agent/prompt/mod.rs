@@ -26,6 +26,6 @@ mod context;
 mod generator;
 mod template;
 
-pub use context::TaskContext;
+pub use context::{TaskContext, extract_sections};
 pub use generator::{GeneratedPrompt, generate_and_write_prompt, generate_prompt, write_prompt};
 pub use template::{TemplateError, render_template};
@@ -4,7 +4,7 @@
 //! This module defines the command structure; actual implementations
 //! are in the `commands` module.
 
-use clap::{ArgAction, Parser, Subcommand};
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
 
 /// Burl: Minimal file-based workflow orchestrator for agentic coding pipelines.
 ///
@@ -17,10 +17,27 @@ use clap::{ArgAction, Parser, Subcommand};
 #[command(author, version, about, long_about = None)]
 #[command(propagate_version = true)]
 pub struct Cli {
+    /// Output format for lifecycle commands.
+    ///
+    /// With `json`, the command prints one JSON result object to stdout and
+    /// human-readable text goes to stderr.
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Text)]
+    pub output: OutputFormat,
+
     #[command(subcommand)]
     pub command: Command,
 }
 
+/// Output format selected with `--output`.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable text.
+    #[default]
+    Text,
+    /// One versioned JSON result object on stdout.
+    Json,
+}
+
 /// Available commands for burl.
 #[derive(Subcommand, Debug)]
 pub enum Command {
@@ -35,6 +52,20 @@ pub enum Command {
     /// Creates a task file in the READY bucket with the specified metadata.
     Add(AddArgs),
 
+    /// Import many tasks from a plan file.
+    ///
+    /// Reads a YAML plan (`tasks:` list) or a markdown plan (one `##`
+    /// section per task), resolves symbolic dependencies between entries,
+    /// and creates all tasks in READY with a single workflow commit.
+    Import(ImportArgs),
+
+    /// Export workflow state as a single document.
+    ///
+    /// Emits every task's frontmatter, body sections, and bucket (and
+    /// optionally its event history) as JSON, YAML, or CSV. JSON and YAML
+    /// exports can be re-imported with `burl import`.
+    Export(ExportArgs),
+
     /// Show workflow status summary.
     ///
     /// Displays counts per bucket and highlights locked or stalled tasks.
@@ -45,12 +76,48 @@ pub enum Command {
     /// Renders the task markdown and key metadata.
     Show(ShowArgs),
 
+    /// List tasks with optional filters.
+    ///
+    /// Prints a table by default; `--format json|ndjson|csv` emits
+    /// machine-readable records for scripts and dashboards.
+    List(ListArgs),
+
+    /// Edit task frontmatter.
+    ///
+    /// Applies typed changes to a task's metadata (priority, scope, tags,
+    /// dependencies, agent, validation profile) and commits workflow state.
+    Edit(Box<EditArgs>),
+
+    /// Search tasks with a query.
+    ///
+    /// Matches frontmatter fields (`tag:net`, `completed>2026-09-01`,
+    /// `field.name:value`) and full text across all buckets.
+    Search(SearchArgs),
+
+    /// Show the task dependency graph.
+    ///
+    /// Renders `depends_on` links as text, Graphviz DOT, or Mermaid, colored
+    /// by bucket, and highlights cycles and dependencies on missing tasks.
+    Graph(GraphArgs),
+
+    /// Analyze remaining work in the dependency graph.
+    ///
+    /// Shows the tasks claimable now, the critical path of open tasks, and
+    /// how many open tasks transitively depend on each task.
+    Plan(PlanArgs),
+
     /// Claim a task for work.
     ///
     /// Creates a branch and worktree for the task, sets base_sha,
     /// and moves the task from READY to DOING.
     Claim(ClaimArgs),
 
+    /// Release a claimed task back to READY.
+    ///
+    /// The inverse of `claim`: clears the assignment, removes the task
+    /// worktree (keeping the branch by default), and moves DOING to READY.
+    Release(ReleaseArgs),
+
     /// Submit a task for QA review.
     ///
     /// Runs scope and stub validation checks, then moves the task
@@ -75,11 +142,41 @@ pub enum Command {
     /// and preserves the branch/worktree for rework.
     Reject(RejectArgs),
 
+    /// Move a task to BLOCKED with a reason.
+    ///
+    /// Models tasks waiting on a human or an external dependency.
+    /// Allowed from READY, DOING, or QA.
+    Block(BlockArgs),
+
+    /// Move a BLOCKED task back to READY (or DOING).
+    ///
+    /// Clears the blocked reason and optionally resets QA attempts.
+    Unblock(UnblockArgs),
+
+    /// Move a task along a configured state transition.
+    ///
+    /// Takes an edge declared under `states.transitions` in config.yaml
+    /// (e.g. QA -> REVIEW), running the gates configured for that edge.
+    Move(MoveArgs),
+
+    /// Split a task into smaller child tasks.
+    ///
+    /// Partitions the task's scope across new READY tasks, copies its body
+    /// sections, and marks the original as superseded (moved to DONE).
+    Split(SplitArgs),
+
     /// Show the recorded worktree path for a task.
     ///
-    /// Prints the recorded worktree path for a task.
+    /// Prints the recorded worktree path for a task. `worktree widen` adds
+    /// paths to a sparse task worktree.
     Worktree(WorktreeArgs),
 
+    /// Show saved agent or validation logs for a task.
+    ///
+    /// Prints the agent's stdout/stderr logs, or with `--validation` the
+    /// full output of each step of a validation run.
+    Logs(LogsArgs),
+
     /// Lock management commands.
     ///
     /// List or clear workflow and task locks.
@@ -95,6 +192,12 @@ pub enum Command {
     /// Removes worktrees for completed tasks and cleans orphan artifacts.
     Clean(CleanArgs),
 
+    /// Move old DONE tasks into the archive.
+    ///
+    /// Archived tasks live in `ARCHIVE/YYYY-MM/` (by completion month); they
+    /// still satisfy dependencies and keep their task numbers reserved.
+    Archive(ArchiveArgs),
+
     /// Automation loop for claiming and QA processing.
     ///
     /// By default, `watch` will:
@@ -117,6 +220,64 @@ pub enum Command {
     Agent(AgentCommand),
 }
 
+impl Command {
+    /// The subcommand name as typed on the command line.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Command::Init => "init",
+            Command::Add(_) => "add",
+            Command::Import(_) => "import",
+            Command::Export(_) => "export",
+            Command::Status => "status",
+            Command::Show(_) => "show",
+            Command::List(_) => "list",
+            Command::Search(_) => "search",
+            Command::Edit(_) => "edit",
+            Command::Graph(_) => "graph",
+            Command::Plan(_) => "plan",
+            Command::Claim(_) => "claim",
+            Command::Release(_) => "release",
+            Command::Submit(_) => "submit",
+            Command::Validate(_) => "validate",
+            Command::Approve(_) => "approve",
+            Command::Reject(_) => "reject",
+            Command::Block(_) => "block",
+            Command::Unblock(_) => "unblock",
+            Command::Move(_) => "move",
+            Command::Split(_) => "split",
+            Command::Worktree(_) => "worktree",
+            Command::Logs(_) => "logs",
+            Command::Lock(_) => "lock",
+            Command::Doctor(_) => "doctor",
+            Command::Clean(_) => "clean",
+            Command::Archive(_) => "archive",
+            Command::Watch(_) => "watch",
+            Command::Monitor(_) => "monitor",
+            Command::Agent(_) => "agent",
+        }
+    }
+
+    /// Whether the command records a result for `--output json`.
+    pub fn supports_json_output(&self) -> bool {
+        matches!(
+            self,
+            Command::Claim(_)
+                | Command::Release(_)
+                | Command::Submit(_)
+                | Command::Validate(_)
+                | Command::Approve(_)
+                | Command::Reject(_)
+                | Command::Block(_)
+                | Command::Unblock(_)
+                | Command::Move(_)
+                | Command::Split(_)
+                | Command::Doctor(_)
+                | Command::Clean(_)
+                | Command::Archive(_)
+        )
+    }
+}
+
 /// Arguments for the `add` command.
 #[derive(Parser, Debug)]
 pub struct AddArgs {
@@ -146,6 +307,18 @@ pub struct AddArgs {
     /// Tags for categorization.
     #[arg(long, value_delimiter = ',')]
     pub tags: Vec<String>,
+
+    /// Epic (parent task) this task belongs to.
+    #[arg(long)]
+    pub parent: Option<String>,
+
+    /// Task template from `.burl/.workflow/task-templates/<name>.md`.
+    #[arg(long)]
+    pub template: Option<String>,
+
+    /// Task kind: one of the configured `id_prefixes` (e.g. `bug` for BUG-042).
+    #[arg(long)]
+    pub kind: Option<String>,
 }
 
 /// Arguments for the `show` command.
@@ -155,6 +328,186 @@ pub struct ShowArgs {
     pub task_id: String,
 }
 
+/// Arguments for the `import` command.
+#[derive(Parser, Debug)]
+pub struct ImportArgs {
+    /// Path to the plan file (`.yaml`/`.yml` or `.md`).
+    pub file: String,
+
+    /// Plan format (detected from the file extension by default).
+    #[arg(long, value_parser = ["yaml", "markdown"])]
+    pub format: Option<String>,
+}
+
+/// Arguments for the `export` command.
+#[derive(Parser, Debug)]
+pub struct ExportArgs {
+    /// Document format.
+    #[arg(long, default_value = "json", value_parser = ["json", "yaml", "csv"])]
+    pub format: String,
+
+    /// Only export tasks in these buckets (repeatable or comma-separated).
+    #[arg(long, value_delimiter = ',')]
+    pub bucket: Vec<String>,
+
+    /// Include each task's event history from `events.ndjson`.
+    #[arg(long)]
+    pub include_events: bool,
+}
+
+/// Arguments for the `list` command.
+#[derive(Parser, Debug)]
+pub struct ListArgs {
+    /// Only list tasks in these buckets (repeatable or comma-separated).
+    #[arg(long, value_delimiter = ',')]
+    pub bucket: Vec<String>,
+
+    /// Only list tasks with all of these tags (repeatable or comma-separated).
+    #[arg(long, value_delimiter = ',')]
+    pub tag: Vec<String>,
+
+    /// Only list tasks with this priority (high, medium, low).
+    #[arg(long)]
+    pub priority: Option<String>,
+
+    /// Only list tasks assigned to this agent profile.
+    #[arg(long)]
+    pub agent: Option<String>,
+
+    /// Only list tasks assigned to this user (`user`, `user@host`, or `me`).
+    #[arg(long)]
+    pub assigned_to: Option<String>,
+
+    /// Only list DOING/QA tasks idle for longer than this (e.g. 24h, 2d).
+    #[arg(long)]
+    pub stalled: Option<String>,
+
+    /// Only list tasks matching this query (same syntax as `burl search`).
+    #[arg(long)]
+    pub query: Option<String>,
+
+    /// Output format.
+    #[arg(long, default_value = "table", value_parser = ["table", "json", "ndjson", "csv"])]
+    pub format: String,
+}
+
+/// Arguments for the `search` command.
+#[derive(Parser, Debug)]
+pub struct SearchArgs {
+    /// Query terms, e.g. `'tag:net priority:high bucket:QA "rollback"'`.
+    ///
+    /// Quote the query (or put it after `--`) when it has negated `-` terms.
+    #[arg(required = true, num_args = 1..)]
+    pub query: Vec<String>,
+
+    /// Output format.
+    #[arg(long, default_value = "table", value_parser = ["table", "json", "ndjson", "csv"])]
+    pub format: String,
+}
+
+/// Arguments for the `graph` command.
+#[derive(Parser, Debug)]
+pub struct GraphArgs {
+    /// Output format.
+    #[arg(long, default_value = "text", value_parser = ["text", "dot", "mermaid"])]
+    pub format: String,
+
+    /// Only include tasks in these buckets (repeatable or comma-separated).
+    #[arg(long, value_delimiter = ',')]
+    pub bucket: Vec<String>,
+}
+
+/// Arguments for the `plan` command.
+#[derive(Parser, Debug)]
+pub struct PlanArgs {
+    /// Output format.
+    #[arg(long, default_value = "text", value_parser = ["text", "json"])]
+    pub format: String,
+}
+
+/// Arguments for the `edit` command.
+#[derive(Parser, Debug, Default)]
+pub struct EditArgs {
+    /// Task ID to edit (e.g., TASK-001).
+    pub task_id: String,
+
+    /// New title.
+    #[arg(long)]
+    pub title: Option<String>,
+
+    /// New priority level (high, medium, low).
+    #[arg(short, long)]
+    pub priority: Option<String>,
+
+    /// Files or paths to add to `affects`.
+    #[arg(long, value_delimiter = ',')]
+    pub add_affects: Vec<String>,
+
+    /// Files or paths to remove from `affects`.
+    #[arg(long, value_delimiter = ',')]
+    pub remove_affects: Vec<String>,
+
+    /// Glob patterns to add to `affects_globs`.
+    #[arg(long, value_delimiter = ',')]
+    pub add_affects_globs: Vec<String>,
+
+    /// Glob patterns to remove from `affects_globs`.
+    #[arg(long, value_delimiter = ',')]
+    pub remove_affects_globs: Vec<String>,
+
+    /// Paths to add to `must_not_touch`.
+    #[arg(long, value_delimiter = ',')]
+    pub add_must_not_touch: Vec<String>,
+
+    /// Paths to remove from `must_not_touch`.
+    #[arg(long, value_delimiter = ',')]
+    pub remove_must_not_touch: Vec<String>,
+
+    /// Task IDs to add to `depends_on`.
+    #[arg(long, value_delimiter = ',')]
+    pub add_depends_on: Vec<String>,
+
+    /// Task IDs to remove from `depends_on`.
+    #[arg(long, value_delimiter = ',')]
+    pub remove_depends_on: Vec<String>,
+
+    /// Epic (parent task) to move this task under.
+    #[arg(long, conflicts_with = "clear_parent")]
+    pub parent: Option<String>,
+
+    /// Detach the task from its epic.
+    #[arg(long)]
+    pub clear_parent: bool,
+
+    /// Tags to add.
+    #[arg(long, value_delimiter = ',')]
+    pub add_tag: Vec<String>,
+
+    /// Tags to remove.
+    #[arg(long, value_delimiter = ',')]
+    pub remove_tag: Vec<String>,
+
+    /// Agent profile to assign.
+    #[arg(long, conflicts_with = "clear_agent")]
+    pub agent: Option<String>,
+
+    /// Clear the agent assignment (use the default agent).
+    #[arg(long)]
+    pub clear_agent: bool,
+
+    /// Validation profile to use.
+    #[arg(long, conflicts_with = "clear_validation_profile")]
+    pub validation_profile: Option<String>,
+
+    /// Clear the validation profile (use the config default).
+    #[arg(long)]
+    pub clear_validation_profile: bool,
+
+    /// Replace the recorded base SHA (only allowed in READY/BLOCKED).
+    #[arg(long)]
+    pub base_sha: Option<String>,
+}
+
 /// Arguments for the `claim` command.
 #[derive(Parser, Debug)]
 pub struct ClaimArgs {
@@ -162,6 +515,39 @@ pub struct ClaimArgs {
     pub task_id: Option<String>,
 }
 
+/// Arguments for the `release` command.
+#[derive(Parser, Debug)]
+pub struct ReleaseArgs {
+    /// Task ID to release (e.g., TASK-001).
+    pub task_id: String,
+
+    /// Keep the task worktree in place (default removes it, keeping the branch).
+    #[arg(long, conflicts_with = "drop_branch")]
+    pub keep_worktree: bool,
+
+    /// Also delete the task branch and clear recorded git metadata.
+    #[arg(long)]
+    pub drop_branch: bool,
+}
+
+/// Arguments for the `split` command.
+#[derive(Parser, Debug)]
+pub struct SplitArgs {
+    /// Task ID to split (e.g., TASK-001).
+    pub task_id: String,
+
+    /// Child task as "title:scope1,scope2" (repeatable, at least two).
+    ///
+    /// Scope entries are paths (`affects`) or globs (`affects_globs`) and
+    /// must partition the original task's scope.
+    #[arg(long = "into", value_name = "TITLE:SCOPE", required = true)]
+    pub into: Vec<String>,
+
+    /// Make each child depend on the previous one.
+    #[arg(long)]
+    pub sequential: bool,
+}
+
 /// Arguments for the `submit` command.
 #[derive(Parser, Debug)]
 pub struct SubmitArgs {
@@ -174,6 +560,18 @@ pub struct SubmitArgs {
 pub struct ValidateArgs {
     /// Task ID to validate.
     pub task_id: String,
+
+    /// Rerun every step instead of reusing passes cached for the same tree.
+    #[arg(long)]
+    pub no_cache: bool,
+
+    /// Write each validation gate as a JUnit XML test case to this file.
+    #[arg(long, value_name = "PATH")]
+    pub report_junit: Option<String>,
+
+    /// Write scope and stub violations as a SARIF 2.1.0 log to this file.
+    #[arg(long, value_name = "PATH")]
+    pub report_sarif: Option<String>,
 }
 
 /// Arguments for the `approve` command.
@@ -181,6 +579,10 @@ pub struct ValidateArgs {
 pub struct ApproveArgs {
     /// Task ID to approve.
     pub task_id: String,
+
+    /// Rerun every step instead of reusing passes cached for the same tree.
+    #[arg(long)]
+    pub no_cache: bool,
 }
 
 /// Arguments for the `reject` command.
@@ -194,11 +596,95 @@ pub struct RejectArgs {
     pub reason: String,
 }
 
+/// Arguments for the `block` command.
+#[derive(Parser, Debug)]
+pub struct BlockArgs {
+    /// Task ID to block.
+    pub task_id: String,
+
+    /// Why the task is blocked (required).
+    #[arg(short, long)]
+    pub reason: String,
+}
+
+/// Arguments for the `unblock` command.
+#[derive(Parser, Debug)]
+pub struct UnblockArgs {
+    /// Task ID to unblock.
+    pub task_id: String,
+
+    /// Destination bucket (READY or DOING).
+    #[arg(long, default_value = "READY", value_parser = ["READY", "DOING"], ignore_case = true)]
+    pub to: String,
+
+    /// Reset qa_attempts to 0.
+    #[arg(long)]
+    pub reset_attempts: bool,
+}
+
+/// Arguments for the `move` command.
+#[derive(Parser, Debug)]
+pub struct MoveArgs {
+    /// Task ID to move.
+    pub task_id: String,
+
+    /// Destination bucket (e.g., REVIEW).
+    pub state: String,
+
+    /// Confirm a transition that has an `approval` gate.
+    #[arg(long)]
+    pub approve: bool,
+}
+
 /// Arguments for the `worktree` command.
 #[derive(Parser, Debug)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 pub struct WorktreeArgs {
     /// Task ID to get worktree path for (e.g., TASK-001).
+    #[arg(required = true)]
+    pub task_id: Option<String>,
+
+    #[command(subcommand)]
+    pub action: Option<WorktreeAction>,
+}
+
+/// Worktree subcommands.
+#[derive(Subcommand, Debug)]
+pub enum WorktreeAction {
+    /// Add paths to a sparse task worktree.
+    ///
+    /// Extends the sparse checkout created with `worktree_mode: sparse`.
+    Widen(WorktreeWidenArgs),
+}
+
+/// Arguments for the `worktree widen` command.
+#[derive(Parser, Debug)]
+pub struct WorktreeWidenArgs {
+    /// Task ID whose worktree to widen (e.g., TASK-001).
+    pub task_id: String,
+
+    /// Paths or globs to check out (e.g., `docs/`, `tests/**/*.rs`).
+    #[arg(required = true)]
+    pub globs: Vec<String>,
+}
+
+/// Arguments for the `logs` command.
+#[derive(Parser, Debug)]
+pub struct LogsArgs {
+    /// Task ID (e.g., TASK-001).
     pub task_id: String,
+
+    /// Show validation logs instead of agent logs.
+    #[arg(long)]
+    pub validation: bool,
+
+    /// Validation attempt to show (default: the latest).
+    #[arg(long, requires = "validation")]
+    pub attempt: Option<u32>,
+
+    /// Only show the log of this validation step.
+    #[arg(long, requires = "validation")]
+    pub step: Option<String>,
 }
 
 /// Lock subcommands.
@@ -261,6 +747,18 @@ pub struct CleanArgs {
     pub yes: bool,
 }
 
+/// Arguments for the `archive` command.
+#[derive(Parser, Debug)]
+pub struct ArchiveArgs {
+    /// Only archive tasks completed at least this long ago (e.g. 30d, 12w).
+    #[arg(long, value_name = "DURATION", default_value = "30d")]
+    pub older_than: String,
+
+    /// List the tasks that would be archived without moving them.
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
 /// Arguments for the `watch` command.
 #[derive(Parser, Debug)]
 pub struct WatchArgs {
@@ -287,6 +785,10 @@ pub struct WatchArgs {
     /// Run a single iteration and exit.
     #[arg(long)]
     pub once: bool,
+
+    /// Only claim, dispatch and validate tasks matching this query (see `burl search`).
+    #[arg(long)]
+    pub query: Option<String>,
 }
 
 /// Arguments for the `monitor` (visualizer) command.
@@ -311,6 +813,10 @@ pub struct MonitorArgs {
     /// Show the last N events from the audit log (0 disables).
     #[arg(long, default_value_t = 10)]
     pub tail: usize,
+
+    /// Only count and show tasks matching this query (see `burl search`).
+    #[arg(long)]
+    pub query: Option<String>,
 }
 
 /// Agent subcommands.
@@ -400,10 +906,13 @@ mod tests {
             "src/tests/**",
             "--tags",
             "feature,v1",
+            "--kind",
+            "bug",
         ])
         .unwrap();
         if let Command::Add(args) = cli.command {
             assert_eq!(args.title, "Implement feature");
+            assert_eq!(args.kind.as_deref(), Some("bug"));
             assert_eq!(args.priority, "high");
             assert_eq!(args.affects, vec!["src/lib.rs", "src/main.rs"]);
             assert_eq!(args.must_not_touch, vec!["src/tests/**"]);
@@ -413,6 +922,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_global_output_flag() {
+        let cli = Cli::try_parse_from(["burl", "status"]).unwrap();
+        assert_eq!(cli.output, OutputFormat::Text);
+
+        let cli = Cli::try_parse_from(["burl", "claim", "--output", "json"]).unwrap();
+        assert_eq!(cli.output, OutputFormat::Json);
+        assert_eq!(cli.command.name(), "claim");
+        assert!(cli.command.supports_json_output());
+
+        let cli = Cli::try_parse_from(["burl", "--output", "json", "status"]).unwrap();
+        assert_eq!(cli.output, OutputFormat::Json);
+        assert!(!cli.command.supports_json_output());
+
+        assert!(Cli::try_parse_from(["burl", "--output", "xml", "status"]).is_err());
+    }
+
     #[test]
     fn parse_status() {
         let cli = Cli::try_parse_from(["burl", "status"]).unwrap();
@@ -429,6 +955,230 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_import() {
+        let cli = Cli::try_parse_from(["burl", "import", "plan.md"]).unwrap();
+        if let Command::Import(args) = cli.command {
+            assert_eq!(args.file, "plan.md");
+            assert!(args.format.is_none());
+        } else {
+            panic!("Expected Import command");
+        }
+
+        let cli =
+            Cli::try_parse_from(["burl", "import", "plan.txt", "--format", "markdown"]).unwrap();
+        if let Command::Import(args) = cli.command {
+            assert_eq!(args.format.as_deref(), Some("markdown"));
+        } else {
+            panic!("Expected Import command");
+        }
+
+        assert!(Cli::try_parse_from(["burl", "import", "p", "--format", "toml"]).is_err());
+    }
+
+    #[test]
+    fn parse_export() {
+        let cli = Cli::try_parse_from(["burl", "export"]).unwrap();
+        if let Command::Export(args) = cli.command {
+            assert_eq!(args.format, "json");
+            assert!(args.bucket.is_empty());
+            assert!(!args.include_events);
+        } else {
+            panic!("Expected Export command");
+        }
+
+        let cli = Cli::try_parse_from([
+            "burl",
+            "export",
+            "--format",
+            "csv",
+            "--bucket",
+            "DONE,QA",
+            "--include-events",
+        ])
+        .unwrap();
+        if let Command::Export(args) = cli.command {
+            assert_eq!(args.format, "csv");
+            assert_eq!(args.bucket, vec!["DONE", "QA"]);
+            assert!(args.include_events);
+        } else {
+            panic!("Expected Export command");
+        }
+    }
+
+    #[test]
+    fn parse_graph() {
+        let cli = Cli::try_parse_from(["burl", "graph"]).unwrap();
+        if let Command::Graph(args) = cli.command {
+            assert_eq!(args.format, "text");
+            assert!(args.bucket.is_empty());
+        } else {
+            panic!("Expected Graph command");
+        }
+
+        let cli = Cli::try_parse_from([
+            "burl",
+            "graph",
+            "--format",
+            "dot",
+            "--bucket",
+            "READY,DOING",
+        ])
+        .unwrap();
+        if let Command::Graph(args) = cli.command {
+            assert_eq!(args.format, "dot");
+            assert_eq!(args.bucket, vec!["READY", "DOING"]);
+        } else {
+            panic!("Expected Graph command");
+        }
+
+        assert!(Cli::try_parse_from(["burl", "graph", "--format", "svg"]).is_err());
+    }
+
+    #[test]
+    fn parse_plan() {
+        let cli = Cli::try_parse_from(["burl", "plan"]).unwrap();
+        if let Command::Plan(args) = cli.command {
+            assert_eq!(args.format, "text");
+        } else {
+            panic!("Expected Plan command");
+        }
+
+        let cli = Cli::try_parse_from(["burl", "plan", "--format", "json"]).unwrap();
+        if let Command::Plan(args) = cli.command {
+            assert_eq!(args.format, "json");
+        } else {
+            panic!("Expected Plan command");
+        }
+
+        assert!(Cli::try_parse_from(["burl", "plan", "--format", "csv"]).is_err());
+    }
+
+    #[test]
+    fn parse_list() {
+        let cli = Cli::try_parse_from(["burl", "list"]).unwrap();
+        if let Command::List(args) = cli.command {
+            assert!(args.bucket.is_empty());
+            assert_eq!(args.format, "table");
+        } else {
+            panic!("Expected List command");
+        }
+
+        let cli = Cli::try_parse_from([
+            "burl",
+            "list",
+            "--bucket",
+            "QA,DOING",
+            "--tag",
+            "net",
+            "--priority",
+            "high",
+            "--assigned-to",
+            "me",
+            "--stalled",
+            "24h",
+            "--format",
+            "ndjson",
+        ])
+        .unwrap();
+        if let Command::List(args) = cli.command {
+            assert_eq!(args.bucket, vec!["QA", "DOING"]);
+            assert_eq!(args.tag, vec!["net"]);
+            assert_eq!(args.priority.as_deref(), Some("high"));
+            assert_eq!(args.assigned_to.as_deref(), Some("me"));
+            assert_eq!(args.stalled.as_deref(), Some("24h"));
+            assert_eq!(args.format, "ndjson");
+        } else {
+            panic!("Expected List command");
+        }
+
+        assert!(Cli::try_parse_from(["burl", "list", "--format", "xml"]).is_err());
+    }
+
+    #[test]
+    fn parse_search() {
+        let cli = Cli::try_parse_from([
+            "burl",
+            "search",
+            "tag:net -tag:wip",
+            "rollback",
+            "--format",
+            "json",
+        ])
+        .unwrap();
+        if let Command::Search(args) = cli.command {
+            assert_eq!(args.query, vec!["tag:net -tag:wip", "rollback"]);
+            assert_eq!(args.format, "json");
+        } else {
+            panic!("Expected Search command");
+        }
+
+        assert!(Cli::try_parse_from(["burl", "search"]).is_err());
+    }
+
+    #[test]
+    fn parse_edit() {
+        let cli = Cli::try_parse_from([
+            "burl",
+            "edit",
+            "TASK-001",
+            "--priority",
+            "high",
+            "--add-affects-globs",
+            "src/net/**",
+            "--remove-tag",
+            "wip",
+            "--agent",
+            "codex",
+        ])
+        .unwrap();
+        if let Command::Edit(args) = cli.command {
+            assert_eq!(args.task_id, "TASK-001");
+            assert_eq!(args.priority, Some("high".to_string()));
+            assert_eq!(args.add_affects_globs, vec!["src/net/**"]);
+            assert_eq!(args.remove_tag, vec!["wip"]);
+            assert_eq!(args.agent, Some("codex".to_string()));
+            assert!(!args.clear_agent);
+        } else {
+            panic!("Expected Edit command");
+        }
+    }
+
+    #[test]
+    fn parse_edit_agent_conflicts_with_clear() {
+        let result =
+            Cli::try_parse_from(["burl", "edit", "TASK-001", "--agent", "x", "--clear-agent"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse_add_and_edit_parent() {
+        let cli = Cli::try_parse_from(["burl", "add", "Child", "--parent", "TASK-010"]).unwrap();
+        if let Command::Add(args) = cli.command {
+            assert_eq!(args.parent, Some("TASK-010".to_string()));
+        } else {
+            panic!("Expected Add command");
+        }
+
+        let cli = Cli::try_parse_from(["burl", "edit", "TASK-011", "--clear-parent"]).unwrap();
+        if let Command::Edit(args) = cli.command {
+            assert!(args.clear_parent);
+            assert_eq!(args.parent, None);
+        } else {
+            panic!("Expected Edit command");
+        }
+
+        let result = Cli::try_parse_from([
+            "burl",
+            "edit",
+            "TASK-011",
+            "--parent",
+            "TASK-010",
+            "--clear-parent",
+        ]);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn parse_claim_with_id() {
         let cli = Cli::try_parse_from(["burl", "claim", "TASK-001"]).unwrap();
@@ -449,6 +1199,54 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_split() {
+        let cli = Cli::try_parse_from([
+            "burl",
+            "split",
+            "TASK-001",
+            "--into",
+            "Parser:src/parse/**",
+            "--into",
+            "Printer:src/print.rs",
+            "--sequential",
+        ])
+        .unwrap();
+        if let Command::Split(args) = cli.command {
+            assert_eq!(args.task_id, "TASK-001");
+            assert_eq!(
+                args.into,
+                vec!["Parser:src/parse/**", "Printer:src/print.rs"]
+            );
+            assert!(args.sequential);
+        } else {
+            panic!("Expected Split command");
+        }
+
+        assert!(Cli::try_parse_from(["burl", "split", "TASK-001"]).is_err());
+    }
+
+    #[test]
+    fn parse_release() {
+        let cli = Cli::try_parse_from(["burl", "release", "TASK-001", "--drop-branch"]).unwrap();
+        if let Command::Release(args) = cli.command {
+            assert_eq!(args.task_id, "TASK-001");
+            assert!(args.drop_branch);
+            assert!(!args.keep_worktree);
+        } else {
+            panic!("Expected Release command");
+        }
+
+        let result = Cli::try_parse_from([
+            "burl",
+            "release",
+            "TASK-001",
+            "--keep-worktree",
+            "--drop-branch",
+        ]);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn parse_submit_with_id() {
         let cli = Cli::try_parse_from(["burl", "submit", "TASK-001"]).unwrap();
@@ -464,6 +1262,26 @@ mod tests {
         let cli = Cli::try_parse_from(["burl", "validate", "TASK-001"]).unwrap();
         if let Command::Validate(args) = cli.command {
             assert_eq!(args.task_id, "TASK-001");
+            assert!(!args.no_cache);
+        } else {
+            panic!("Expected Validate command");
+        }
+
+        let cli = Cli::try_parse_from([
+            "burl",
+            "validate",
+            "TASK-001",
+            "--no-cache",
+            "--report-junit",
+            "out.xml",
+            "--report-sarif",
+            "out.sarif",
+        ])
+        .unwrap();
+        if let Command::Validate(args) = cli.command {
+            assert!(args.no_cache);
+            assert_eq!(args.report_junit.as_deref(), Some("out.xml"));
+            assert_eq!(args.report_sarif.as_deref(), Some("out.sarif"));
         } else {
             panic!("Expected Validate command");
         }
@@ -471,9 +1289,10 @@ mod tests {
 
     #[test]
     fn parse_approve() {
-        let cli = Cli::try_parse_from(["burl", "approve", "TASK-001"]).unwrap();
+        let cli = Cli::try_parse_from(["burl", "approve", "TASK-001", "--no-cache"]).unwrap();
         if let Command::Approve(args) = cli.command {
             assert_eq!(args.task_id, "TASK-001");
+            assert!(args.no_cache);
         } else {
             panic!("Expected Approve command");
         }
@@ -491,14 +1310,115 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_block() {
+        let cli = Cli::try_parse_from(["burl", "block", "TASK-001", "--reason", "waiting on API"])
+            .unwrap();
+        if let Command::Block(args) = cli.command {
+            assert_eq!(args.task_id, "TASK-001");
+            assert_eq!(args.reason, "waiting on API");
+        } else {
+            panic!("Expected Block command");
+        }
+    }
+
+    #[test]
+    fn parse_unblock() {
+        let cli = Cli::try_parse_from(["burl", "unblock", "TASK-001"]).unwrap();
+        if let Command::Unblock(args) = cli.command {
+            assert_eq!(args.to, "READY");
+            assert!(!args.reset_attempts);
+        } else {
+            panic!("Expected Unblock command");
+        }
+
+        let cli = Cli::try_parse_from([
+            "burl",
+            "unblock",
+            "TASK-001",
+            "--to",
+            "doing",
+            "--reset-attempts",
+        ])
+        .unwrap();
+        if let Command::Unblock(args) = cli.command {
+            assert_eq!(args.to.to_uppercase(), "DOING");
+            assert!(args.reset_attempts);
+        } else {
+            panic!("Expected Unblock command");
+        }
+
+        assert!(Cli::try_parse_from(["burl", "unblock", "TASK-001", "--to", "QA"]).is_err());
+    }
+
+    #[test]
+    fn parse_move() {
+        let cli = Cli::try_parse_from(["burl", "move", "TASK-001", "REVIEW", "--approve"]).unwrap();
+        if let Command::Move(args) = cli.command {
+            assert_eq!(args.task_id, "TASK-001");
+            assert_eq!(args.state, "REVIEW");
+            assert!(args.approve);
+        } else {
+            panic!("Expected Move command");
+        }
+
+        assert!(Cli::try_parse_from(["burl", "move", "TASK-001"]).is_err());
+    }
+
     #[test]
     fn parse_worktree() {
         let cli = Cli::try_parse_from(["burl", "worktree", "TASK-001"]).unwrap();
         if let Command::Worktree(args) = cli.command {
+            assert_eq!(args.task_id.as_deref(), Some("TASK-001"));
+            assert!(args.action.is_none());
+        } else {
+            panic!("Expected Worktree command");
+        }
+
+        assert!(Cli::try_parse_from(["burl", "worktree"]).is_err());
+    }
+
+    #[test]
+    fn parse_logs() {
+        let cli = Cli::try_parse_from([
+            "burl",
+            "logs",
+            "TASK-001",
+            "--validation",
+            "--attempt",
+            "2",
+            "--step",
+            "test",
+        ])
+        .unwrap();
+        if let Command::Logs(args) = cli.command {
             assert_eq!(args.task_id, "TASK-001");
+            assert!(args.validation);
+            assert_eq!(args.attempt, Some(2));
+            assert_eq!(args.step.as_deref(), Some("test"));
+        } else {
+            panic!("Expected Logs command");
+        }
+
+        assert!(Cli::try_parse_from(["burl", "logs", "TASK-001", "--attempt", "2"]).is_err());
+    }
+
+    #[test]
+    fn parse_worktree_widen() {
+        let cli =
+            Cli::try_parse_from(["burl", "worktree", "widen", "TASK-001", "docs/", "tests/**"])
+                .unwrap();
+        if let Command::Worktree(args) = cli.command {
+            let Some(WorktreeAction::Widen(widen)) = args.action else {
+                panic!("Expected widen action");
+            };
+            assert_eq!(widen.task_id, "TASK-001");
+            assert_eq!(widen.globs, vec!["docs/", "tests/**"]);
         } else {
             panic!("Expected Worktree command");
         }
+
+        assert!(Cli::try_parse_from(["burl", "worktree", "widen", "TASK-001"]).is_err());
     }
 
     #[test]
@@ -561,6 +1481,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_archive() {
+        let cli = Cli::try_parse_from(["burl", "archive"]).unwrap();
+        if let Command::Archive(args) = cli.command {
+            assert_eq!(args.older_than, "30d");
+            assert!(!args.dry_run);
+        } else {
+            panic!("Expected Archive command");
+        }
+
+        let cli =
+            Cli::try_parse_from(["burl", "archive", "--older-than", "12w", "--dry-run"]).unwrap();
+        if let Command::Archive(args) = cli.command {
+            assert_eq!(args.older_than, "12w");
+            assert!(args.dry_run);
+        } else {
+            panic!("Expected Archive command");
+        }
+    }
+
     #[test]
     fn parse_watch_defaults() {
         let cli = Cli::try_parse_from(["burl", "watch"]).unwrap();
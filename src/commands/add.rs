@@ -1,6 +1,8 @@
 //! Implementation of the `burl add` command.
 //!
 //! Creates a new task file in the READY bucket with the specified metadata.
+//! With `--template`, default fields and the body come from a task template
+//! (see [`crate::task::TaskTemplate`]).
 
 use crate::cli::AddArgs;
 use crate::config::Config;
@@ -9,13 +11,15 @@ use crate::error::{BurlError, Result};
 use crate::events::{Event, EventAction, append_event};
 use crate::fs::atomic_write_file;
 use crate::git::run_git;
+use crate::graph::{DependencyGraph, format_path};
 use crate::locks;
-use crate::task::{TaskFile, TaskFrontmatter};
+use crate::task::{TaskFile, TaskFrontmatter, TaskTemplate};
 use crate::workflow::{
-    TaskIndex, generate_task_filename, generate_task_id, validate_filename_safe,
+    TaskIndex, generate_task_filename, generate_task_id, validate_filename_safe, validate_task_id,
 };
 use chrono::Utc;
 use serde_json::json;
+use std::collections::HashMap;
 
 /// Default task body template.
 const TASK_BODY_TEMPLATE: &str = r#"
@@ -37,26 +41,130 @@ const TASK_BODY_TEMPLATE: &str = r#"
 <!-- Validator fills (tool can append) -->
 "#;
 
+/// Acceptance criteria placeholder scaffolded by [`TASK_BODY_TEMPLATE`].
+pub(crate) const ACCEPTANCE_CRITERIA_PLACEHOLDER: &str =
+    "- [ ] Criterion 1 (specific, verifiable)\n- [ ] Criterion 2\n- [ ] Criterion 3";
+
+/// Content for the body sections scaffolded by [`TASK_BODY_TEMPLATE`].
+///
+/// Sections left empty keep their template placeholder comment.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct TaskBodySections {
+    pub objective: Option<String>,
+    pub acceptance_criteria: Vec<String>,
+    pub context: Option<String>,
+    pub implementation_notes: Option<String>,
+}
+
+/// Render a task body with the standard sections, filling in any provided content.
+///
+/// Acceptance criteria become checklist items; items that already carry a
+/// checkbox (`[ ]`/`[x]`) keep it. With no content this is [`TASK_BODY_TEMPLATE`].
+pub(crate) fn render_task_body(sections: &TaskBodySections) -> String {
+    fn section(out: &mut String, heading: &str, content: Option<&str>, placeholder: &str) {
+        out.push_str(&format!("\n## {}\n", heading));
+        match content.map(str::trim).filter(|c| !c.is_empty()) {
+            Some(content) => out.push_str(&format!("{}\n", content)),
+            None => out.push_str(&format!("{}\n", placeholder)),
+        }
+    }
+
+    let criteria = if sections.acceptance_criteria.is_empty() {
+        None
+    } else {
+        Some(
+            sections
+                .acceptance_criteria
+                .iter()
+                .map(|item| {
+                    let item = item.trim();
+                    if item.starts_with("[ ]") || item.starts_with("[x]") || item.starts_with("[X]")
+                    {
+                        format!("- {}", item)
+                    } else {
+                        format!("- [ ] {}", item)
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    };
+
+    let mut body = String::new();
+    section(
+        &mut body,
+        "Objective",
+        sections.objective.as_deref(),
+        "<!-- Single sentence describing what \"done\" looks like -->",
+    );
+    section(
+        &mut body,
+        "Acceptance Criteria",
+        criteria.as_deref(),
+        ACCEPTANCE_CRITERIA_PLACEHOLDER,
+    );
+    section(
+        &mut body,
+        "Context",
+        sections.context.as_deref(),
+        "<!-- Relevant notes/links, constraints, file references -->",
+    );
+    section(
+        &mut body,
+        "Implementation Notes",
+        sections.implementation_notes.as_deref(),
+        "<!-- Worker fills -->",
+    );
+    section(
+        &mut body,
+        "QA Report",
+        None,
+        "<!-- Validator fills (tool can append) -->",
+    );
+    body
+}
+
 /// Execute the `burl add` command.
 ///
 /// Creates a new task file in the READY bucket with:
-/// - Auto-generated numeric ID (monotonic, scanning all buckets)
+/// - Auto-generated ID: the `--kind` (or default) prefix and the next number
+///   for that prefix (monotonic, scanning all buckets and the archive)
 /// - Slugified title for filename
 /// - YAML frontmatter with provided metadata
-/// - Standard body template
+/// - Standard body template, or the body and defaults of `--template`
 pub fn cmd_add(args: AddArgs) -> Result<()> {
     let ctx = require_initialized_workflow()?;
 
-    // Validate priority
+    let config = Config::load(ctx.config_path()).unwrap_or_default();
+
+    // Validate priority and resolve the ID prefix from --kind
     let priority = validate_priority(&args.priority)?;
+    let prefix = config.id_prefix(args.kind.as_deref())?;
+
+    // Load the template before taking the lock so a typo fails fast
+    let template = match &args.template {
+        Some(name) => Some(TaskTemplate::load(&ctx.task_templates_dir(), name)?),
+        None => None,
+    };
 
     // Acquire workflow lock
     let _workflow_lock = locks::acquire_workflow_lock(&ctx, "add")?;
 
     // Build task index to find the next available ID
     let index = TaskIndex::build(&ctx)?;
-    let task_number = index.next_number();
-    let task_id = generate_task_id(task_number);
+    let task_number = index.next_number(&prefix)?;
+    let task_id = generate_task_id(&prefix, task_number, config.id_width);
+
+    // Dependencies must exist and must not close a cycle
+    let depends_on = validate_dependencies(&index, &task_id, &args.depends_on)?;
+    let parent = match &args.parent {
+        Some(parent) => Some(validate_parent(
+            &DependencyGraph::build(&index)?,
+            &task_id,
+            parent,
+        )?),
+        None => None,
+    };
 
     // Generate filename
     let filename = generate_task_filename(&task_id, &args.title);
@@ -75,6 +183,18 @@ pub fn cmd_add(args: AddArgs) -> Result<()> {
         )));
     }
 
+    // Template defaults come first; command-line values are added after them
+    let defaults = template
+        .as_ref()
+        .map(|t| t.defaults.clone())
+        .unwrap_or_default();
+    let body = match &template {
+        Some(template) => {
+            template.render_body(&template_variables(&task_id, &args.title, &priority))?
+        }
+        None => TASK_BODY_TEMPLATE.to_string(),
+    };
+
     // Create the task frontmatter
     let frontmatter = TaskFrontmatter {
         id: task_id.clone(),
@@ -83,41 +203,41 @@ pub fn cmd_add(args: AddArgs) -> Result<()> {
         created: Some(Utc::now()),
         assigned_to: None,
         qa_attempts: 0,
+        blocked_reason: None,
+        blocked_at: None,
         started_at: None,
         submitted_at: None,
         completed_at: None,
         worktree: None,
         branch: None,
         base_sha: None,
-        affects: args.affects,
-        affects_globs: args.affects_globs,
-        must_not_touch: args.must_not_touch,
-        depends_on: args.depends_on,
-        tags: args.tags,
-        agent: None,
-        validation_profile: None,
+        affects: merge_values(defaults.affects, args.affects),
+        affects_globs: merge_values(defaults.affects_globs, args.affects_globs),
+        must_not_touch: merge_values(defaults.must_not_touch, args.must_not_touch),
+        depends_on,
+        parent,
+        superseded_by: Vec::new(),
+        tags: merge_values(defaults.tags, args.tags),
+        agent: defaults.agent,
+        validation_profile: defaults.validation_profile,
         extra: Default::default(),
     };
 
     // Create the task file
-    let task = TaskFile {
-        frontmatter,
-        body: TASK_BODY_TEMPLATE.to_string(),
-    };
+    let task = TaskFile { frontmatter, body };
 
     // Write the task file atomically
     let content = task.to_string()?;
     atomic_write_file(&task_path, &content)?;
 
-    // Load config to check if auto-commit is enabled
-    let config = Config::load(ctx.config_path()).unwrap_or_default();
-
     // Append event
     let event = Event::new(EventAction::Add)
         .with_task(&task_id)
         .with_details(json!({
             "title": args.title,
             "priority": task.frontmatter.priority,
+            "parent": task.frontmatter.parent,
+            "template": args.template,
             "filename": filename,
             "path": task_path.display().to_string()
         }));
@@ -138,6 +258,12 @@ pub fn cmd_add(args: AddArgs) -> Result<()> {
     println!();
     println!("  Title:    {}", args.title);
     println!("  Priority: {}", task.frontmatter.priority);
+    if let Some(parent) = &task.frontmatter.parent {
+        println!("  Parent:   {}", parent);
+    }
+    if let Some(template) = &args.template {
+        println!("  Template: {}", template);
+    }
     println!("  Path:     {}", task_path.display());
     println!();
     println!("Next steps:");
@@ -147,8 +273,31 @@ pub fn cmd_add(args: AddArgs) -> Result<()> {
     Ok(())
 }
 
+/// Placeholders available in task template bodies.
+fn template_variables(task_id: &str, title: &str, priority: &str) -> HashMap<String, String> {
+    HashMap::from([
+        ("id".to_string(), task_id.to_string()),
+        ("title".to_string(), title.to_string()),
+        ("priority".to_string(), priority.to_string()),
+        (
+            "date".to_string(),
+            Utc::now().format("%Y-%m-%d").to_string(),
+        ),
+    ])
+}
+
+/// Append `extra` to `base`, skipping values already present.
+fn merge_values(mut base: Vec<String>, extra: Vec<String>) -> Vec<String> {
+    for value in extra {
+        if !base.contains(&value) {
+            base.push(value);
+        }
+    }
+    base
+}
+
 /// Validate and normalize priority value.
-fn validate_priority(priority: &str) -> Result<String> {
+pub(crate) fn validate_priority(priority: &str) -> Result<String> {
     let normalized = priority.to_lowercase();
     match normalized.as_str() {
         "high" | "medium" | "low" => Ok(normalized),
@@ -159,6 +308,77 @@ fn validate_priority(priority: &str) -> Result<String> {
     }
 }
 
+/// Normalize `--depends-on` IDs and check them against the dependency graph.
+///
+/// Every dependency must name an existing task. A brand-new task can only
+/// close a cycle if an existing task already (danglingly) depends on its ID.
+fn validate_dependencies(index: &TaskIndex, task_id: &str, deps: &[String]) -> Result<Vec<String>> {
+    let graph = DependencyGraph::build(index)?;
+    let mut resolved: Vec<String> = Vec::new();
+
+    for dep in deps {
+        let dep = validate_task_id(dep)?;
+        if index.find(&dep).is_none() {
+            return Err(BurlError::UserError(format!(
+                "cannot depend on '{}': task not found.\n\n\
+                 Use `burl status` to see available tasks.",
+                dep
+            )));
+        }
+        if let Some(cycle) = graph.cycle_if_added(task_id, &dep) {
+            return Err(BurlError::UserError(format!(
+                "dependency on '{}' would create a cycle: {}",
+                dep,
+                format_path(&cycle)
+            )));
+        }
+        if !resolved.contains(&dep) {
+            resolved.push(dep);
+        }
+    }
+
+    Ok(resolved)
+}
+
+/// Normalize a `parent` epic ID and check it against the task graph.
+///
+/// The parent must be an existing task other than `task_id` that is not yet
+/// DONE, and following parents upwards must not lead back to `task_id`.
+pub(crate) fn validate_parent(
+    graph: &DependencyGraph,
+    task_id: &str,
+    parent: &str,
+) -> Result<String> {
+    let parent = validate_task_id(parent)?;
+    if parent == task_id {
+        return Err(BurlError::UserError(format!(
+            "task '{}' cannot be its own parent.",
+            task_id
+        )));
+    }
+    let Some(node) = graph.get(&parent) else {
+        return Err(BurlError::UserError(format!(
+            "parent '{}' not found.\n\n\
+             Use `burl status` to see available tasks.",
+            parent
+        )));
+    };
+    if node.bucket == "DONE" {
+        return Err(BurlError::UserError(format!(
+            "parent '{}' is already DONE; completed epics cannot gain children.",
+            parent
+        )));
+    }
+    if let Some(cycle) = graph.parent_cycle_if_set(task_id, &parent) {
+        return Err(BurlError::UserError(format!(
+            "parent '{}' would create a parent cycle: {}",
+            parent,
+            format_path(&cycle)
+        )));
+    }
+    Ok(parent)
+}
+
 /// Commit the task addition to the workflow branch.
 fn commit_task_addition(
     ctx: &crate::context::WorkflowContext,
@@ -211,6 +431,23 @@ mod tests {
         assert_eq!(validate_priority("LOW").unwrap(), "low");
     }
 
+    #[test]
+    fn test_render_task_body_defaults_to_template() {
+        assert_eq!(
+            render_task_body(&TaskBodySections::default()),
+            TASK_BODY_TEMPLATE
+        );
+
+        let body = render_task_body(&TaskBodySections {
+            objective: Some("Player can jump".to_string()),
+            acceptance_criteria: vec!["Space jumps".to_string(), "[x] Has tests".to_string()],
+            ..Default::default()
+        });
+        assert!(body.contains("## Objective\nPlayer can jump\n"));
+        assert!(body.contains("- [ ] Space jumps\n- [x] Has tests\n"));
+        assert!(body.contains("<!-- Relevant notes/links"));
+    }
+
     #[test]
     fn test_validate_priority_invalid() {
         assert!(validate_priority("urgent").is_err());
@@ -236,6 +473,9 @@ mod tests {
             must_not_touch: vec!["vendor/".to_string()],
             depends_on: vec![],
             tags: vec!["test".to_string()],
+            parent: None,
+            template: None,
+            kind: None,
         };
         cmd_add(args).unwrap();
 
@@ -260,6 +500,32 @@ mod tests {
         assert_eq!(task.frontmatter.tags, vec!["test"]);
     }
 
+    #[test]
+    #[serial]
+    fn test_add_rejects_unknown_dependency() {
+        let temp_dir = create_test_repo();
+        let _guard = DirGuard::new(temp_dir.path());
+        cmd_init().unwrap();
+
+        let err = cmd_add(AddArgs {
+            title: "Orphan".to_string(),
+            priority: "medium".to_string(),
+            affects: vec![],
+            affects_globs: vec![],
+            must_not_touch: vec![],
+            depends_on: vec!["TASK-042".to_string()],
+            tags: vec![],
+            parent: None,
+            template: None,
+            kind: None,
+        })
+        .unwrap_err();
+        assert!(err.to_string().contains("task not found"));
+
+        let ctx = require_initialized_workflow().unwrap();
+        assert!(TaskIndex::build(&ctx).unwrap().find("TASK-001").is_none());
+    }
+
     #[test]
     #[serial]
     fn test_add_increments_task_id() {
@@ -278,6 +544,9 @@ mod tests {
             must_not_touch: vec![],
             depends_on: vec![],
             tags: vec![],
+            parent: None,
+            template: None,
+            kind: None,
         };
         cmd_add(args1).unwrap();
 
@@ -290,6 +559,9 @@ mod tests {
             must_not_touch: vec![],
             depends_on: vec![],
             tags: vec![],
+            parent: None,
+            template: None,
+            kind: None,
         };
         cmd_add(args2).unwrap();
 
@@ -308,4 +580,132 @@ mod tests {
         assert_eq!(task1.frontmatter.id, "TASK-001");
         assert_eq!(task2.frontmatter.id, "TASK-002");
     }
+
+    #[test]
+    #[serial]
+    fn test_add_with_parent_validates_epic() {
+        let temp_dir = create_test_repo();
+        let _guard = DirGuard::new(temp_dir.path());
+        cmd_init().unwrap();
+
+        let args = |title: &str, parent: Option<&str>| AddArgs {
+            title: title.to_string(),
+            priority: "medium".to_string(),
+            affects: vec![],
+            affects_globs: vec![],
+            must_not_touch: vec![],
+            depends_on: vec![],
+            tags: vec![],
+            parent: parent.map(str::to_string),
+            template: None,
+            kind: None,
+        };
+        cmd_add(args("Epic", None)).unwrap();
+        cmd_add(args("Child", Some("task-001"))).unwrap();
+
+        let ctx = require_initialized_workflow().unwrap();
+        let child = TaskFile::load(ctx.bucket_path("READY").join("TASK-002-child.md")).unwrap();
+        assert_eq!(child.frontmatter.parent.as_deref(), Some("TASK-001"));
+
+        let err = cmd_add(args("Orphan", Some("TASK-009"))).unwrap_err();
+        assert!(err.to_string().contains("parent 'TASK-009' not found"));
+
+        let graph = DependencyGraph::load(&ctx).unwrap();
+        let err = validate_parent(&graph, "TASK-001", "TASK-002").unwrap_err();
+        assert!(err.to_string().contains("TASK-001 -> TASK-002 -> TASK-001"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_add_with_kind_numbers_each_prefix() {
+        let temp_dir = create_test_repo();
+        let _guard = DirGuard::new(temp_dir.path());
+        cmd_init().unwrap();
+
+        let ctx = require_initialized_workflow().unwrap();
+        let mut config = Config::load(ctx.config_path()).unwrap();
+        config.id_prefixes = vec!["TASK".to_string(), "BUG".to_string()];
+        config.id_width = 4;
+        std::fs::write(ctx.config_path(), config.to_yaml().unwrap()).unwrap();
+
+        let args = |title: &str, kind: Option<&str>| AddArgs {
+            title: title.to_string(),
+            priority: "medium".to_string(),
+            affects: vec![],
+            affects_globs: vec![],
+            must_not_touch: vec![],
+            depends_on: vec![],
+            tags: vec![],
+            parent: None,
+            template: None,
+            kind: kind.map(str::to_string),
+        };
+        cmd_add(args("Feature", None)).unwrap();
+        cmd_add(args("Crash", Some("bug"))).unwrap();
+        cmd_add(args("Leak", Some("BUG"))).unwrap();
+
+        let ready = ctx.bucket_path("READY");
+        assert!(ready.join("TASK-0001-feature.md").exists());
+        assert!(ready.join("BUG-0001-crash.md").exists());
+        assert!(ready.join("BUG-0002-leak.md").exists());
+
+        let index = TaskIndex::build(&ctx).unwrap();
+        assert_eq!(index.next_number("TASK").unwrap(), 2);
+        assert_eq!(index.next_number("BUG").unwrap(), 3);
+        assert_eq!(index.find("BUG-0002").unwrap().bucket, "READY");
+
+        let err = cmd_add(args("Flaky", Some("net"))).unwrap_err();
+        assert!(err.to_string().contains("unknown task kind 'net'"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_add_with_template() {
+        let temp_dir = create_test_repo();
+        let _guard = DirGuard::new(temp_dir.path());
+        cmd_init().unwrap();
+
+        let ctx = require_initialized_workflow().unwrap();
+        std::fs::write(
+            ctx.task_templates_dir().join("bugfix.md"),
+            "---\ntags: [bug]\nagent: codex\nmust_not_touch: [migrations/**]\n---\n\n## Objective\nFix {title} ({priority})\n",
+        )
+        .unwrap();
+
+        cmd_add(AddArgs {
+            title: "Crash on start".to_string(),
+            priority: "high".to_string(),
+            affects: vec![],
+            affects_globs: vec![],
+            must_not_touch: vec![],
+            depends_on: vec![],
+            tags: vec!["urgent".to_string(), "bug".to_string()],
+            parent: None,
+            template: Some("bugfix".to_string()),
+            kind: None,
+        })
+        .unwrap();
+
+        let task =
+            TaskFile::load(ctx.bucket_path("READY").join("TASK-001-crash-on-start.md")).unwrap();
+        assert_eq!(task.frontmatter.tags, vec!["bug", "urgent"]);
+        assert_eq!(task.frontmatter.agent.as_deref(), Some("codex"));
+        assert_eq!(task.frontmatter.must_not_touch, vec!["migrations/**"]);
+        assert_eq!(task.body, "\n## Objective\nFix Crash on start (high)\n");
+
+        let err = cmd_add(AddArgs {
+            title: "Other".to_string(),
+            priority: "medium".to_string(),
+            affects: vec![],
+            affects_globs: vec![],
+            must_not_touch: vec![],
+            depends_on: vec![],
+            tags: vec![],
+            parent: None,
+            template: Some("feature".to_string()),
+            kind: None,
+        })
+        .unwrap_err();
+        assert!(err.to_string().contains("Available templates: bugfix"));
+    }
 }
@@ -27,7 +27,7 @@ use std::path::{Path, PathBuf};
 /// 5. Returns agent's exit code
 pub fn cmd_agent_run(args: AgentRunArgs) -> Result<()> {
     let ctx = require_initialized_workflow()?;
-    let _config = Config::load(ctx.config_path()).unwrap_or_default();
+    let config = Config::load(ctx.config_path()).unwrap_or_default();
 
     // Load agents config
     let agents_config = AgentsConfig::load(ctx.agents_config_path())?.ok_or_else(|| {
@@ -180,6 +180,7 @@ pub fn cmd_agent_run(args: AgentRunArgs) -> Result<()> {
         &vars,
         &worktree_str,
         timeout,
+        &config.worktree_setup.resolved_env(&ctx.repo_root),
     )?;
 
     // Log agent complete event
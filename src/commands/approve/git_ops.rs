@@ -7,11 +7,15 @@ use crate::error::{BurlError, Result};
 use crate::events::{Event, EventAction, append_event};
 use crate::git::run_git;
 use crate::git_worktree::cleanup_task_worktree;
+use crate::graph::DependencyGraph;
+use crate::hooks::{HookTask, run_post_hook};
 use crate::locks::acquire_workflow_lock;
+use crate::output::{self, textln};
 use crate::task::TaskFile;
+use crate::workflow::TaskIndex;
 use chrono::Utc;
 use serde_json::json;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Merge the task branch into local main using --ff-only.
 pub fn merge_ff_only(
@@ -145,9 +149,10 @@ pub fn reject_task(
 
     crate::fs::move_file(task_path, &ready_path).map_err(|e| {
         BurlError::UserError(format!(
-            "failed to move task from QA to READY: {}\n\n\
+            "failed to move task from {} to READY: {}\n\n\
              Task file: {}\n\
              Destination: {}",
+            source_bucket(task_path),
             e,
             task_path.display(),
             ready_path.display()
@@ -174,15 +179,25 @@ pub fn reject_task(
         }
     }
 
-    println!();
-    println!("Rejected task: {}", task_id);
-    println!("  Title:       {}", task_file.frontmatter.title);
-    println!("  Reason:      {}", reason);
-    println!("  From:        QA");
-    println!("  To:          READY");
-    println!("  QA Attempts: {}", task_file.frontmatter.qa_attempts);
-    println!();
-    println!("The task branch and worktree have been preserved for rework.");
+    output::update(|r| {
+        r.to_bucket = Some("READY".to_string());
+        r.details = json!({
+            "title": task_file.frontmatter.title,
+            "rejected": true,
+            "reason": reason,
+            "qa_attempts": task_file.frontmatter.qa_attempts,
+        });
+    });
+
+    textln!();
+    textln!("Rejected task: {}", task_id);
+    textln!("  Title:       {}", task_file.frontmatter.title);
+    textln!("  Reason:      {}", reason);
+    textln!("  From:        {}", source_bucket(task_path));
+    textln!("  To:          READY");
+    textln!("  QA Attempts: {}", task_file.frontmatter.qa_attempts);
+    textln!();
+    textln!("The task branch and worktree have been preserved for rework.");
 
     // Return an error to signal that approval failed
     Err(BurlError::GitError(format!(
@@ -204,7 +219,7 @@ pub fn complete_approval(
     ctx.ensure_workflow_clean()?;
 
     // Acquire workflow lock
-    let _workflow_lock = acquire_workflow_lock(ctx, "approve")?;
+    let workflow_lock = acquire_workflow_lock(ctx, "approve")?;
 
     // Set completed_at
     let now = Utc::now();
@@ -228,9 +243,10 @@ pub fn complete_approval(
 
     crate::fs::move_file(task_path, &done_path).map_err(|e| {
         BurlError::UserError(format!(
-            "failed to move task from QA to DONE: {}\n\n\
+            "failed to move task from {} to DONE: {}\n\n\
              Task file: {}\n\
              Destination: {}",
+            source_bucket(task_path),
             e,
             task_path.display(),
             done_path.display()
@@ -247,6 +263,9 @@ pub fn complete_approval(
         }));
     append_event(ctx, &event)?;
 
+    // Roll completion up to epics whose children are now all DONE
+    complete_finished_epics(ctx, task_file.frontmatter.parent.as_deref())?;
+
     // Commit workflow state if auto-commit enabled
     if config.workflow_auto_commit {
         commit_approve(ctx, task_id, &task_file.frontmatter.title)?;
@@ -256,6 +275,77 @@ pub fn complete_approval(
         }
     }
 
+    drop(workflow_lock);
+    run_post_hook(
+        ctx,
+        config,
+        "approve",
+        &HookTask::new(task_id, "DONE", &done_path, &task_file.frontmatter),
+    );
+
+    Ok(())
+}
+
+/// Complete `parent` (and then its own ancestors) once all children are DONE.
+///
+/// Only epics waiting in READY or BLOCKED are completed; an epic that was
+/// claimed before it gained children is left for a human to resolve. Must be
+/// called while holding the workflow lock.
+fn complete_finished_epics(
+    ctx: &crate::context::WorkflowContext,
+    parent: Option<&str>,
+) -> Result<()> {
+    let mut current = parent.map(str::to_string);
+
+    while let Some(epic_id) = current.take() {
+        let index = TaskIndex::build(ctx)?;
+        let graph = DependencyGraph::build(&index)?;
+        let (Some(info), Some(rollup)) = (index.find(&epic_id), graph.rollup(&epic_id)) else {
+            break;
+        };
+        if !rollup.is_complete() || !matches!(info.bucket.as_str(), "READY" | "BLOCKED") {
+            break;
+        }
+
+        let mut epic = TaskFile::load(&info.path)?;
+        epic.set_completed(Utc::now());
+        epic.save(&info.path)?;
+
+        let filename = info
+            .path
+            .file_name()
+            .ok_or_else(|| BurlError::UserError("invalid task file path".to_string()))?;
+        let done_path = ctx.bucket_path("DONE").join(filename);
+        crate::fs::move_file(&info.path, &done_path).map_err(|e| {
+            BurlError::UserError(format!(
+                "failed to move epic {} from {} to DONE: {}",
+                epic_id, info.bucket, e
+            ))
+        })?;
+
+        let children: Vec<&str> = graph
+            .children(&epic_id)
+            .iter()
+            .map(|c| c.id.as_str())
+            .collect();
+        let event = Event::new(EventAction::EpicComplete)
+            .with_task(&epic_id)
+            .with_details(json!({
+                "title": epic.frontmatter.title,
+                "from": info.bucket,
+                "children": children,
+            }));
+        append_event(ctx, &event)?;
+
+        textln!(
+            "Completed epic {} ({}): all {} child task(s) are DONE",
+            epic_id,
+            epic.frontmatter.title,
+            rollup.total
+        );
+        current = epic.frontmatter.parent;
+    }
+
     Ok(())
 }
 
@@ -312,3 +402,13 @@ fn push_workflow_branch(ctx: &crate::context::WorkflowContext, config: &Config)
 
     Ok(())
 }
+
+/// Bucket a task is being approved from: the directory holding its file
+/// (QA, or a custom review bucket from `states.approve_from`).
+pub fn source_bucket(task_path: &Path) -> String {
+    task_path
+        .parent()
+        .and_then(|dir| dir.file_name())
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| "QA".to_string())
+}
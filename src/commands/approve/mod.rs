@@ -1,6 +1,7 @@
 //! Implementation of the `burl approve` command.
 //!
-//! This module implements the QA -> DONE transition with rebase, validation, and merge:
+//! This module implements the QA -> DONE transition with rebase, validation, and merge
+//! (or from the review buckets configured in `states.approve_from`):
 //! - Fetch and rebase onto origin/main
 //! - Run validation against rebased base (origin/main..HEAD)
 //! - Fast-forward merge into local main
@@ -9,7 +10,8 @@
 //! # Transaction Steps (rebase_ff_only strategy)
 //!
 //! 1. Acquire per-task lock (`TASK-XXX.lock`)
-//! 2. Verify task is in QA with valid worktree/branch
+//! 2. Verify task is in QA with valid worktree/branch, then run the
+//!    `pre_approve` hook (see [`crate::hooks`])
 //! 3. Fetch origin/main
 //! 4. Rebase task branch onto origin/main (conflict -> reject, move QA -> READY)
 //! 5. Run validation against rebased base (origin/main..HEAD)
@@ -18,8 +20,9 @@
 //! 8. Cleanup worktree and branch (best-effort)
 //! 9. Acquire `workflow.lock` for workflow-state mutation
 //! 10. Set completed_at, move QA -> DONE
-//! 11. Append approve event and commit workflow branch
-//! 12. Release locks
+//! 11. Append approve event and run the `post_approve` hook; complete the
+//!     parent epic (and its ancestors) if all of its children are now DONE
+//! 12. Commit workflow branch and release locks
 
 mod git_ops;
 mod strategies;
@@ -32,7 +35,9 @@ use crate::cli::ApproveArgs;
 use crate::config::{Config, MergeStrategy};
 use crate::context::require_initialized_workflow;
 use crate::error::{BurlError, Result};
+use crate::hooks::{HookTask, run_pre_hook};
 use crate::locks::acquire_task_lock;
+use crate::output::{self, CommandResult};
 use crate::task::TaskFile;
 use crate::workflow::{TaskIndex, validate_task_id};
 
@@ -70,15 +75,24 @@ pub fn cmd_approve(args: ApproveArgs) -> Result<()> {
         ))
     })?;
 
-    // Verify task is in QA bucket
-    if task_info.bucket != "QA" {
+    // Verify task is in a bucket approve accepts (QA by default)
+    if !config.states.can_approve_from(&task_info.bucket) {
         return Err(BurlError::UserError(format!(
-            "task '{}' is not in QA (currently in {}).\n\n\
-             Only tasks in QA can be approved.",
-            task_info.id, task_info.bucket
+            "task '{}' is not in {} (currently in {}).\n\n\
+             Only tasks in {} can be approved (see `states.approve_from`).",
+            task_info.id,
+            config.states.approve_from.join("/"),
+            task_info.bucket,
+            config.states.approve_from.join(", ")
         )));
     }
 
+    output::record(
+        CommandResult::new("approve")
+            .with_task(&task_info.id)
+            .with_from_bucket(&task_info.bucket),
+    );
+
     // ========================================================================
     // Phase 2: Acquire per-task lock and load task file
     // ========================================================================
@@ -101,6 +115,11 @@ pub fn cmd_approve(args: ApproveArgs) -> Result<()> {
     let expected_branch = refs.branch;
     let worktree_path = refs.worktree_path;
 
+    output::update(|r| {
+        r.branch = task_file.frontmatter.branch.clone();
+        r.worktree = task_file.frontmatter.worktree.clone();
+    });
+
     if !worktree_path.exists() {
         return Err(BurlError::UserError(format!(
             "task worktree does not exist at '{}'.\n\n\
@@ -122,6 +141,18 @@ pub fn cmd_approve(args: ApproveArgs) -> Result<()> {
         )));
     }
 
+    run_pre_hook(
+        &ctx,
+        &config,
+        "approve",
+        &HookTask::new(
+            &task_info.id,
+            &task_info.bucket,
+            &task_info.path,
+            &task_file.frontmatter,
+        ),
+    )?;
+
     // ========================================================================
     // Phase 4: Strategy-based git operations
     // ========================================================================
@@ -135,6 +166,7 @@ pub fn cmd_approve(args: ApproveArgs) -> Result<()> {
             &mut task_file,
             &worktree_path,
             &expected_branch,
+            !args.no_cache,
         ),
         MergeStrategy::FfOnly => approve_ff_only(
             &ctx,
@@ -144,6 +176,7 @@ pub fn cmd_approve(args: ApproveArgs) -> Result<()> {
             &mut task_file,
             &worktree_path,
             &expected_branch,
+            !args.no_cache,
         ),
         MergeStrategy::Manual => Err(BurlError::UserError(
             "merge_strategy 'manual' is not implemented in V1.\n\n\
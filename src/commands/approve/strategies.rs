@@ -7,13 +7,18 @@
 use crate::config::Config;
 use crate::error::{BurlError, Result};
 use crate::git::run_git;
+use crate::output::{self, textln};
 use crate::task::TaskFile;
 use std::path::PathBuf;
 
-use super::git_ops::{cleanup_worktree, complete_approval, merge_ff_only, push_main, reject_task};
-use super::validation::{format_validation_summary, run_validation};
+use super::git_ops::{
+    cleanup_worktree, complete_approval, merge_ff_only, push_main, reject_task, source_bucket,
+};
+use super::validation::{ValidationResult, format_validation_summary, run_validation};
+use serde_json::json;
 
 /// Approve using rebase_ff_only strategy (default).
+#[allow(clippy::too_many_arguments)]
 pub fn approve_rebase_ff_only(
     ctx: &crate::context::WorkflowContext,
     config: &Config,
@@ -22,11 +27,12 @@ pub fn approve_rebase_ff_only(
     task_file: &mut TaskFile,
     worktree_path: &PathBuf,
     branch: &str,
+    use_cache: bool,
 ) -> Result<()> {
     let remote_main = format!("{}/{}", config.remote, config.main_branch);
 
     // Step 1: Fetch origin/main
-    println!("Fetching {}/{}...", config.remote, config.main_branch);
+    textln!("Fetching {}/{}...", config.remote, config.main_branch);
     run_git(
         &ctx.repo_root,
         &["fetch", &config.remote, &config.main_branch],
@@ -39,7 +45,7 @@ pub fn approve_rebase_ff_only(
     })?;
 
     // Step 2: Rebase task branch onto origin/main in worktree
-    println!("Rebasing {} onto {}...", branch, remote_main);
+    textln!("Rebasing {} onto {}...", branch, remote_main);
     let rebase_result = run_git(worktree_path, &["rebase", &remote_main]);
 
     if let Err(e) = rebase_result {
@@ -57,13 +63,24 @@ pub fn approve_rebase_ff_only(
         );
     }
 
-    // Step 3: Run validation against rebased base (origin/main..HEAD)
-    println!("Running validation...");
-    let validation_result = run_validation(ctx, config, task_file, worktree_path, &remote_main)?;
+    // Step 3: Run validation against rebased base (origin/main..HEAD). If the
+    // rebase brought in upstream changes, the new tree misses the validation
+    // cache and every step reruns.
+    textln!("Running validation...");
+    let validation_result = run_validation(
+        ctx,
+        config,
+        task_file,
+        worktree_path,
+        &remote_main,
+        use_cache,
+    )?;
+    record_validation(&validation_result);
 
     if !validation_result.all_passed {
         // Append validation report before rejecting
-        let summary = format_validation_summary(&validation_result.results, false);
+        let mut summary = format_validation_summary(&validation_result.results, false);
+        summary.push_str(&validation_result.logs_link);
         task_file.append_to_qa_report(&summary);
 
         return reject_task(
@@ -77,37 +94,40 @@ pub fn approve_rebase_ff_only(
     }
 
     // Step 4: Merge into local main using --ff-only
-    println!("Merging {} into local main...", branch);
+    textln!("Merging {} into local main...", branch);
     merge_ff_only(ctx, config, task_id, task_path, task_file, branch)?;
 
     // Step 5: Optional push
     if config.push_main_on_approve {
-        println!("Pushing main to {}...", config.remote);
+        textln!("Pushing main to {}...", config.remote);
         push_main(ctx, config)?;
     }
 
     // Step 6: Cleanup worktree and branch (best-effort)
-    println!("Cleaning up worktree and branch...");
+    textln!("Cleaning up worktree and branch...");
     let cleanup_failed = cleanup_worktree(ctx, branch, worktree_path)?;
 
     // Step 7: Workflow state mutation
     complete_approval(ctx, config, task_id, task_path, task_file, cleanup_failed)?;
+    record_approval(task_file, config, branch, cleanup_failed);
 
-    println!();
-    println!("Approved task: {}", task_id);
-    println!("  Title:     {}", task_file.frontmatter.title);
-    println!("  From:      QA");
-    println!("  To:        DONE");
-    println!("  Branch:    {} (merged to {})", branch, config.main_branch);
+    textln!();
+    textln!("Approved task: {}", task_id);
+    textln!("  Title:     {}", task_file.frontmatter.title);
+    textln!("  From:      {}", source_bucket(task_path));
+    textln!("  To:        DONE");
+    textln!("  Branch:    {} (merged to {})", branch, config.main_branch);
     if cleanup_failed {
-        println!("  Cleanup:   Failed (run `burl clean` to remove leftovers)");
+        textln!("  Cleanup:   Failed (run `burl clean` to remove leftovers)");
     } else {
-        println!("  Cleanup:   Complete");
+        textln!("  Cleanup:   Complete");
     }
     if config.push_main_on_approve {
-        println!(
+        textln!(
             "  Pushed:    {} -> {}/{}",
-            config.main_branch, config.remote, config.main_branch
+            config.main_branch,
+            config.remote,
+            config.main_branch
         );
     }
 
@@ -115,6 +135,7 @@ pub fn approve_rebase_ff_only(
 }
 
 /// Approve using ff_only strategy (skip rebase).
+#[allow(clippy::too_many_arguments)]
 pub fn approve_ff_only(
     ctx: &crate::context::WorkflowContext,
     config: &Config,
@@ -123,11 +144,12 @@ pub fn approve_ff_only(
     task_file: &mut TaskFile,
     worktree_path: &PathBuf,
     branch: &str,
+    use_cache: bool,
 ) -> Result<()> {
     let remote_main = format!("{}/{}", config.remote, config.main_branch);
 
     // Step 1: Fetch origin/main
-    println!("Fetching {}/{}...", config.remote, config.main_branch);
+    textln!("Fetching {}/{}...", config.remote, config.main_branch);
     run_git(
         &ctx.repo_root,
         &["fetch", &config.remote, &config.main_branch],
@@ -140,7 +162,7 @@ pub fn approve_ff_only(
     })?;
 
     // Step 2: Verify task branch is descendant of origin/main
-    println!("Verifying branch is up-to-date with {}...", remote_main);
+    textln!("Verifying branch is up-to-date with {}...", remote_main);
     let is_ancestor = run_git(
         worktree_path,
         &["merge-base", "--is-ancestor", &remote_main, "HEAD"],
@@ -158,11 +180,20 @@ pub fn approve_ff_only(
     }
 
     // Step 3: Run validation against origin/main..HEAD
-    println!("Running validation...");
-    let validation_result = run_validation(ctx, config, task_file, worktree_path, &remote_main)?;
+    textln!("Running validation...");
+    let validation_result = run_validation(
+        ctx,
+        config,
+        task_file,
+        worktree_path,
+        &remote_main,
+        use_cache,
+    )?;
+    record_validation(&validation_result);
 
     if !validation_result.all_passed {
-        let summary = format_validation_summary(&validation_result.results, false);
+        let mut summary = format_validation_summary(&validation_result.results, false);
+        summary.push_str(&validation_result.logs_link);
         task_file.append_to_qa_report(&summary);
 
         return reject_task(
@@ -187,39 +218,65 @@ pub fn approve_ff_only(
     );
 
     // Step 5: Merge into local main using --ff-only
-    println!("Merging {} into local main...", branch);
+    textln!("Merging {} into local main...", branch);
     merge_ff_only(ctx, config, task_id, task_path, task_file, branch)?;
 
     // Step 6: Optional push
     if config.push_main_on_approve {
-        println!("Pushing main to {}...", config.remote);
+        textln!("Pushing main to {}...", config.remote);
         push_main(ctx, config)?;
     }
 
     // Step 7: Cleanup worktree and branch (best-effort)
-    println!("Cleaning up worktree and branch...");
+    textln!("Cleaning up worktree and branch...");
     let cleanup_failed = cleanup_worktree(ctx, branch, worktree_path)?;
 
     // Step 8: Workflow state mutation
     complete_approval(ctx, config, task_id, task_path, task_file, cleanup_failed)?;
+    record_approval(task_file, config, branch, cleanup_failed);
 
-    println!();
-    println!("Approved task: {}", task_id);
-    println!("  Title:     {}", task_file.frontmatter.title);
-    println!("  From:      QA");
-    println!("  To:        DONE");
-    println!("  Branch:    {} (merged to {})", branch, config.main_branch);
+    textln!();
+    textln!("Approved task: {}", task_id);
+    textln!("  Title:     {}", task_file.frontmatter.title);
+    textln!("  From:      {}", source_bucket(task_path));
+    textln!("  To:        DONE");
+    textln!("  Branch:    {} (merged to {})", branch, config.main_branch);
     if cleanup_failed {
-        println!("  Cleanup:   Failed (run `burl clean` to remove leftovers)");
+        textln!("  Cleanup:   Failed (run `burl clean` to remove leftovers)");
     } else {
-        println!("  Cleanup:   Complete");
+        textln!("  Cleanup:   Complete");
     }
     if config.push_main_on_approve {
-        println!(
+        textln!(
             "  Pushed:    {} -> {}/{}",
-            config.main_branch, config.remote, config.main_branch
+            config.main_branch,
+            config.remote,
+            config.main_branch
         );
     }
 
     Ok(())
 }
+
+/// Record validation step results and violations for `--output json`.
+fn record_validation(validation_result: &ValidationResult) {
+    output::update(|r| {
+        r.validation = validation_result.results.clone();
+        r.scope_violations = validation_result.scope_violations.clone();
+        r.stub_violations = validation_result.stub_violations.clone();
+    });
+}
+
+/// Record a successful approval for `--output json`.
+fn record_approval(task_file: &TaskFile, config: &Config, branch: &str, cleanup_failed: bool) {
+    output::update(|r| {
+        r.to_bucket = Some("DONE".to_string());
+        r.details = json!({
+            "title": task_file.frontmatter.title,
+            "merged_into": config.main_branch,
+            "branch": branch,
+            "cleanup_failed": cleanup_failed,
+            "pushed_main": config.push_main_on_approve,
+        });
+    });
+}
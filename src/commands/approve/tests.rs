@@ -26,6 +26,9 @@ fn setup_task_in_qa(temp_dir: &TempDir) -> PathBuf {
         must_not_touch: vec![],
         depends_on: vec![],
         tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
     })
     .unwrap();
 
@@ -82,12 +85,16 @@ fn test_approve_task_not_in_qa_fails() {
         must_not_touch: vec![],
         depends_on: vec![],
         tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
     })
     .unwrap();
 
     // Try to approve task in READY - should fail
     let result = cmd_approve(ApproveArgs {
         task_id: "TASK-001".to_string(),
+        no_cache: false,
     });
 
     assert!(result.is_err());
@@ -108,6 +115,7 @@ fn test_approve_nonexistent_task_fails() {
     // Try to approve a task that doesn't exist
     let result = cmd_approve(ApproveArgs {
         task_id: "TASK-999".to_string(),
+        no_cache: false,
     });
 
     assert!(result.is_err());
@@ -135,6 +143,7 @@ fn test_approve_happy_path() {
     // Approve the task
     let result = cmd_approve(ApproveArgs {
         task_id: "TASK-001".to_string(),
+        no_cache: false,
     });
 
     assert!(result.is_ok(), "Approve should succeed: {:?}", result);
@@ -191,6 +200,7 @@ fn test_approve_skips_cleanup_when_worktree_dirty() {
 
     let result = cmd_approve(ApproveArgs {
         task_id: "TASK-001".to_string(),
+        no_cache: false,
     });
     assert!(result.is_ok(), "Approve should succeed: {:?}", result);
 
@@ -251,6 +261,7 @@ fn test_approve_with_rebase_conflict_rejects() {
     // Try to approve - should fail with rebase conflict
     let result = cmd_approve(ApproveArgs {
         task_id: "TASK-001".to_string(),
+        no_cache: false,
     });
 
     assert!(result.is_err());
@@ -287,3 +298,62 @@ fn test_approve_with_rebase_conflict_rejects() {
     let task = TaskFile::load(&ready_path).unwrap();
     assert_eq!(task.frontmatter.qa_attempts, 1);
 }
+
+#[test]
+#[serial]
+fn test_approve_last_child_completes_epic() {
+    let temp_dir = create_test_repo_with_remote();
+    let _guard = DirGuard::new(temp_dir.path());
+
+    cmd_init().unwrap();
+    let config_path = temp_dir.path().join(".burl/.workflow/config.yaml");
+    std::fs::write(&config_path, "build_command: \"\"\n").unwrap();
+
+    setup_task_in_qa(&temp_dir);
+
+    // TASK-002 becomes an epic whose only child is TASK-001.
+    cmd_add(AddArgs {
+        title: "Epic".to_string(),
+        priority: "medium".to_string(),
+        affects: vec![],
+        affects_globs: vec![],
+        must_not_touch: vec![],
+        depends_on: vec![],
+        tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
+    })
+    .unwrap();
+    crate::commands::edit::cmd_edit(crate::cli::EditArgs {
+        task_id: "TASK-001".to_string(),
+        parent: Some("TASK-002".to_string()),
+        ..Default::default()
+    })
+    .unwrap();
+
+    cmd_approve(ApproveArgs {
+        task_id: "TASK-001".to_string(),
+        no_cache: false,
+    })
+    .unwrap();
+
+    let epic_path = temp_dir
+        .path()
+        .join(".burl/.workflow/DONE/TASK-002-epic.md");
+    assert!(epic_path.exists(), "Epic should be completed into DONE");
+    let epic = TaskFile::load(&epic_path).unwrap();
+    assert!(epic.frontmatter.completed_at.is_some());
+
+    let ctx = require_initialized_workflow().unwrap();
+    let events = crate::events::read_events(&ctx).unwrap();
+    let completed = events
+        .iter()
+        .find(|e| e.action == crate::events::EventAction::EpicComplete)
+        .expect("epic_complete event");
+    assert_eq!(completed.task.as_deref(), Some("TASK-002"));
+    assert_eq!(
+        completed.details["children"],
+        serde_json::json!(["TASK-001"])
+    );
+}
@@ -7,24 +7,32 @@ use crate::config::ValidationCommandStep;
 use crate::diff::{added_lines, changed_files};
 use crate::error::Result;
 use crate::task::TaskFile;
-use crate::validate::{ValidationStepResult, ValidationStepStatus, run_command_steps};
+use crate::validate::{BUILD_COMMAND_PROFILE, StepCache, ValidationStepResult, run_command_steps};
+use crate::validate::{ScopeViolation, StubViolation};
 use crate::validate::{validate_scope, validate_stubs_with_config};
+use crate::validation_logs::save_and_link;
 use chrono::Utc;
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 /// Validation result with all step results.
 pub struct ValidationResult {
     pub all_passed: bool,
     pub results: Vec<ValidationStepResult>,
+    pub scope_violations: Vec<ScopeViolation>,
+    pub stub_violations: Vec<StubViolation>,
+    /// QA report line pointing at the run's full logs (may be empty).
+    pub logs_link: String,
 }
 
 /// Run all validation checks against the given diff base.
 pub fn run_validation(
-    _ctx: &crate::context::WorkflowContext,
+    ctx: &crate::context::WorkflowContext,
     config: &Config,
     task_file: &TaskFile,
     worktree_path: &PathBuf,
     diff_base: &str,
+    use_cache: bool,
 ) -> Result<ValidationResult> {
     let mut results: Vec<ValidationStepResult> = Vec::new();
     let mut all_passed = true;
@@ -54,7 +62,10 @@ pub fn run_validation(
     }
 
     // --- Command validation pipeline ---
-    let pipeline_results = run_validation_pipeline(config, task_file, &changed, worktree_path);
+    let env = config.worktree_setup.resolved_env(&ctx.repo_root);
+    let cache_path = use_cache.then(|| ctx.validation_cache_path());
+    let pipeline_results =
+        run_validation_pipeline(config, task_file, &changed, worktree_path, &env, cache_path);
     for result in pipeline_results {
         if !result.is_success() {
             all_passed = false;
@@ -62,9 +73,14 @@ pub fn run_validation(
         results.push(result);
     }
 
+    let logs_link = save_and_link(ctx, &task_file.frontmatter.id, &results);
+
     Ok(ValidationResult {
         all_passed,
+        logs_link,
         results,
+        scope_violations: scope_result.violations,
+        stub_violations: stub_result.violations,
     })
 }
 
@@ -78,11 +94,7 @@ pub fn format_validation_summary(results: &[ValidationStepResult], all_passed: b
     );
 
     for result in results {
-        let status = match result.status {
-            ValidationStepStatus::Pass => "PASS",
-            ValidationStepStatus::Fail => "FAIL",
-            ValidationStepStatus::Skip => "SKIP",
-        };
+        let status = result.label();
         summary.push_str(&format!("- **{}**: {}\n", result.name, status));
 
         if let Some(msg) = &result.message {
@@ -100,6 +112,8 @@ fn run_validation_pipeline(
     task_file: &TaskFile,
     changed_files: &[String],
     worktree_path: &Path,
+    env: &BTreeMap<String, String>,
+    cache_path: Option<PathBuf>,
 ) -> Vec<ValidationStepResult> {
     let profile_name = task_file
         .frontmatter
@@ -108,7 +122,7 @@ fn run_validation_pipeline(
         .or(config.default_validation_profile.as_deref());
 
     let Some(profile_name) = profile_name else {
-        return run_legacy_build_command(config, worktree_path);
+        return run_legacy_build_command(config, worktree_path, env, cache_path);
     };
 
     let Some(profile) = config.validation_profiles.get(profile_name) else {
@@ -129,10 +143,28 @@ fn run_validation_pipeline(
         )];
     }
 
-    run_command_steps(&profile.steps, changed_files, worktree_path)
+    let mut cache =
+        cache_path.and_then(|path| StepCache::open(path, profile_name, worktree_path, env));
+    let results = run_command_steps(
+        &profile.steps,
+        changed_files,
+        worktree_path,
+        env,
+        profile.parallelism(),
+        cache.as_mut(),
+    );
+    if let Some(cache) = cache {
+        cache.save();
+    }
+    results
 }
 
-fn run_legacy_build_command(config: &Config, worktree_path: &Path) -> Vec<ValidationStepResult> {
+fn run_legacy_build_command(
+    config: &Config,
+    worktree_path: &Path,
+    env: &BTreeMap<String, String>,
+    cache_path: Option<PathBuf>,
+) -> Vec<ValidationStepResult> {
     if config.build_command.trim().is_empty() {
         return Vec::new();
     }
@@ -143,13 +175,27 @@ fn run_legacy_build_command(config: &Config, worktree_path: &Path) -> Vec<Valida
         ..Default::default()
     };
 
-    run_command_steps(std::slice::from_ref(&step), &[], worktree_path)
+    let mut cache = cache_path
+        .and_then(|path| StepCache::open(path, BUILD_COMMAND_PROFILE, worktree_path, env));
+    let results = run_command_steps(
+        std::slice::from_ref(&step),
+        &[],
+        worktree_path,
+        env,
+        1,
+        cache.as_mut(),
+    );
+    if let Some(cache) = cache {
+        cache.save();
+    }
+    results
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::task::TaskFrontmatter;
+    use crate::validate::ValidationStepStatus;
     use tempfile::TempDir;
 
     #[test]
@@ -208,7 +254,14 @@ validation_profiles:
 
         let task = make_task(None);
         let worktree = TempDir::new().unwrap();
-        let results = super::run_validation_pipeline(&config, &task, &[], worktree.path());
+        let results = super::run_validation_pipeline(
+            &config,
+            &task,
+            &[],
+            worktree.path(),
+            &BTreeMap::new(),
+            None,
+        );
 
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].name, "git-version");
@@ -219,12 +272,26 @@ validation_profiles:
     fn test_run_validation_pipeline_no_profile_uses_legacy_build_command() {
         let config = Config::from_yaml("build_command: \"git --version\"").unwrap();
         let task = make_task(None);
-        let worktree = TempDir::new().unwrap();
-
-        let results = super::run_validation_pipeline(&config, &task, &[], worktree.path());
+        let worktree = crate::test_support::create_test_repo();
+        let cache_path = worktree.path().join(".git/validation.cache.json");
+        let run = || {
+            super::run_validation_pipeline(
+                &config,
+                &task,
+                &[],
+                worktree.path(),
+                &BTreeMap::new(),
+                Some(cache_path.clone()),
+            )
+        };
 
+        let results = run();
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].name, "build/test");
         assert_eq!(results[0].status, ValidationStepStatus::Pass);
+
+        // A build that already passed on this tree (e.g. in `burl validate`)
+        // isn't run again.
+        assert_eq!(run()[0].status, ValidationStepStatus::CachedPass);
     }
 }
@@ -0,0 +1,437 @@
+//! Implementation of the `burl archive` command.
+//!
+//! Moves DONE tasks completed longer ago than `--older-than` (default 30d)
+//! into `.workflow/ARCHIVE/YYYY-MM/`, keyed by the month of `completed_at`.
+//! Archived tasks drop out of bucket listings but `TaskIndex` still finds
+//! them on demand, so they keep satisfying `depends_on` and their task
+//! numbers are never reused.
+//!
+//! Tasks are skipped (and reported) when they have no `completed_at`, when
+//! their parent epic is still open (so its rollup keeps counting them), or
+//! when their task lock is held.
+//!
+//! # Transaction Steps
+//!
+//! 1. Verify workflow worktree has no unexpected tracked modifications
+//! 2. Acquire `workflow.lock`
+//! 3. Select candidates and acquire each candidate's task lock
+//! 4. Move the task files into the archive
+//! 5. Append an `archive` event, commit workflow branch once
+//! 6. Release locks
+//!
+//! # Rollback
+//!
+//! If a move fails, the files already moved are put back in DONE.
+
+use crate::cli::ArchiveArgs;
+use crate::config::Config;
+use crate::context::{WorkflowContext, require_initialized_workflow};
+use crate::duration::parse_duration;
+use crate::error::{BurlError, Result};
+use crate::events::{Event, EventAction, append_event};
+use crate::git::run_git;
+use crate::graph::DependencyGraph;
+use crate::locks::{LockGuard, acquire_task_lock, acquire_workflow_lock};
+use crate::output::{self, CommandResult, textln};
+use crate::task::TaskFile;
+use crate::workflow::{TaskIndex, task_key};
+use chrono::{DateTime, Utc};
+use serde_json::json;
+use std::path::PathBuf;
+
+/// A DONE task selected for archiving.
+#[derive(Debug)]
+struct ArchiveCandidate {
+    id: String,
+    title: String,
+    /// Archive month (`YYYY-MM`) from `completed_at`.
+    month: String,
+    source: PathBuf,
+    destination: PathBuf,
+}
+
+/// Tasks to archive and tasks passed over.
+#[derive(Debug, Default)]
+struct ArchivePlan {
+    candidates: Vec<ArchiveCandidate>,
+    /// `(task ID, reason)` for old enough tasks that are kept in DONE.
+    skipped: Vec<(String, String)>,
+}
+
+/// Execute the `burl archive` command.
+pub fn cmd_archive(args: ArchiveArgs) -> Result<()> {
+    let ctx = require_initialized_workflow()?;
+    let config = Config::load(ctx.config_path()).unwrap_or_default();
+
+    let older_than = parse_duration(&args.older_than)?;
+    let cutoff = Utc::now() - older_than;
+
+    // ========================================================================
+    // Phase 1: Select candidates (under workflow lock unless dry-run)
+    // ========================================================================
+
+    let _workflow_lock = if args.dry_run {
+        None
+    } else {
+        ctx.ensure_workflow_clean()?;
+        Some(acquire_workflow_lock(&ctx, "archive")?)
+    };
+
+    let index = TaskIndex::build(&ctx)?;
+    let mut plan = plan_archive(&ctx, &index, cutoff)?;
+
+    let mut task_locks: Vec<LockGuard> = Vec::new();
+    if !args.dry_run {
+        let mut locked = Vec::new();
+        for candidate in std::mem::take(&mut plan.candidates) {
+            match acquire_task_lock(&ctx, &candidate.id, "archive") {
+                Ok(lock) => {
+                    task_locks.push(lock);
+                    locked.push(candidate);
+                }
+                Err(_) => plan
+                    .skipped
+                    .push((candidate.id, "task lock is held".to_string())),
+            }
+        }
+        plan.candidates = locked;
+    }
+
+    output::record(CommandResult::new("archive").with_details(plan_details(&plan, &args)));
+
+    for candidate in &plan.candidates {
+        textln!(
+            "{} {}  ->  ARCHIVE/{}/  {}",
+            if args.dry_run {
+                "Would archive"
+            } else {
+                "Archive"
+            },
+            candidate.id,
+            candidate.month,
+            candidate.title
+        );
+    }
+    for (id, reason) in &plan.skipped {
+        textln!("Skipped {}: {}", id, reason);
+    }
+
+    if plan.candidates.is_empty() {
+        textln!(
+            "No DONE tasks completed more than {} ago to archive.",
+            args.older_than
+        );
+        return Ok(());
+    }
+
+    if args.dry_run {
+        textln!();
+        textln!("Dry-run mode: no changes made.");
+        return Ok(());
+    }
+
+    // ========================================================================
+    // Phase 2: Move task files into the archive
+    // ========================================================================
+
+    move_to_archive(&plan.candidates)?;
+
+    // ========================================================================
+    // Phase 3: Event Logging and Commit
+    // ========================================================================
+
+    let ids: Vec<&str> = plan.candidates.iter().map(|c| c.id.as_str()).collect();
+    let mut months: Vec<&str> = plan.candidates.iter().map(|c| c.month.as_str()).collect();
+    months.sort();
+    months.dedup();
+
+    let event = Event::new(EventAction::Archive).with_details(json!({
+        "older_than": args.older_than,
+        "count": ids.len(),
+        "tasks": ids,
+        "months": months,
+    }));
+    append_event(&ctx, &event)?;
+
+    if config.workflow_auto_commit {
+        commit_archive(&ctx, ids.len())?;
+
+        if config.workflow_auto_push {
+            push_workflow_branch(&ctx, &config)?;
+        }
+    }
+
+    drop(task_locks);
+
+    // ========================================================================
+    // Phase 4: Output
+    // ========================================================================
+
+    textln!();
+    textln!(
+        "Archived {} task(s) into {}",
+        ids.len(),
+        months
+            .iter()
+            .map(|m| format!("ARCHIVE/{}/", m))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    Ok(())
+}
+
+/// Select the DONE tasks completed before `cutoff`, in ID order.
+fn plan_archive(
+    ctx: &WorkflowContext,
+    index: &TaskIndex,
+    cutoff: DateTime<Utc>,
+) -> Result<ArchivePlan> {
+    let graph = DependencyGraph::build(index)?;
+    let mut done = index.tasks_in_bucket("DONE");
+    done.sort_by_key(|info| task_key(&info.id));
+
+    let mut plan = ArchivePlan::default();
+    for info in done {
+        let task = match TaskFile::load(&info.path) {
+            Ok(task) => task,
+            Err(e) => {
+                plan.skipped.push((info.id.clone(), e.to_string()));
+                continue;
+            }
+        };
+
+        let Some(completed_at) = task.frontmatter.completed_at else {
+            plan.skipped
+                .push((info.id.clone(), "no completed_at recorded".to_string()));
+            continue;
+        };
+        if completed_at > cutoff {
+            continue;
+        }
+
+        if let Some(parent) = &task.frontmatter.parent
+            && let Some(node) = graph.get(parent)
+            && node.bucket != "DONE"
+        {
+            plan.skipped.push((
+                info.id.clone(),
+                format!("parent epic {} is still in {}", parent, node.bucket),
+            ));
+            continue;
+        }
+
+        let Some(filename) = info.path.file_name() else {
+            continue;
+        };
+        let month = completed_at.format("%Y-%m").to_string();
+        plan.candidates.push(ArchiveCandidate {
+            id: info.id.clone(),
+            title: task.frontmatter.title,
+            destination: ctx.archive_dir().join(&month).join(filename),
+            month,
+            source: info.path.clone(),
+        });
+    }
+
+    Ok(plan)
+}
+
+/// Move every candidate into the archive, undoing earlier moves on failure.
+fn move_to_archive(candidates: &[ArchiveCandidate]) -> Result<()> {
+    for (i, candidate) in candidates.iter().enumerate() {
+        if let Err(e) = crate::fs::move_file(&candidate.source, &candidate.destination) {
+            for moved in &candidates[..i] {
+                let _ = crate::fs::move_file(&moved.destination, &moved.source);
+            }
+            return Err(e);
+        }
+    }
+    Ok(())
+}
+
+/// Build the `--output json` details for an archive plan.
+fn plan_details(plan: &ArchivePlan, args: &ArchiveArgs) -> serde_json::Value {
+    json!({
+        "dry_run": args.dry_run,
+        "older_than": args.older_than,
+        "archived": plan.candidates.iter().map(|c| json!({
+            "id": c.id,
+            "title": c.title,
+            "month": c.month,
+        })).collect::<Vec<_>>(),
+        "skipped": plan.skipped.iter().map(|(id, reason)| json!({
+            "id": id,
+            "reason": reason,
+        })).collect::<Vec<_>>(),
+    })
+}
+
+/// Commit the archive to the workflow branch.
+fn commit_archive(ctx: &WorkflowContext, count: usize) -> Result<()> {
+    run_git(&ctx.workflow_worktree, &["add", "."])
+        .map_err(|e| BurlError::GitError(format!("failed to stage archive changes: {}", e)))?;
+
+    let staged = run_git(&ctx.workflow_worktree, &["diff", "--cached", "--name-only"])?;
+    if staged.stdout.is_empty() {
+        return Ok(());
+    }
+
+    let commit_msg = format!("Archive {} DONE task(s)", count);
+
+    run_git(&ctx.workflow_worktree, &["commit", "-m", &commit_msg])
+        .map_err(|e| BurlError::GitError(format!("failed to commit archive: {}", e)))?;
+
+    Ok(())
+}
+
+/// Push the workflow branch to the remote.
+fn push_workflow_branch(ctx: &WorkflowContext, config: &Config) -> Result<()> {
+    run_git(
+        &ctx.workflow_worktree,
+        &["push", &config.remote, &config.workflow_branch],
+    )
+    .map_err(|e| BurlError::GitError(format!("failed to push workflow branch: {}", e)))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::AddArgs;
+    use crate::commands::add::cmd_add;
+    use crate::commands::claim::selection::check_dependencies_satisfied;
+    use crate::commands::init::cmd_init;
+    use crate::events::read_events;
+    use crate::test_support::{DirGuard, create_test_repo};
+    use chrono::TimeZone;
+    use serial_test::serial;
+
+    fn add(title: &str, depends_on: &[&str]) {
+        cmd_add(AddArgs {
+            title: title.to_string(),
+            priority: "medium".to_string(),
+            affects: vec![],
+            affects_globs: vec![],
+            must_not_touch: vec![],
+            depends_on: depends_on.iter().map(|s| s.to_string()).collect(),
+            tags: vec![],
+            parent: None,
+            template: None,
+            kind: None,
+        })
+        .unwrap();
+    }
+
+    /// Move a task to DONE with the given completion time and commit it.
+    fn complete(ctx: &WorkflowContext, task_id: &str, completed_at: DateTime<Utc>) {
+        let index = TaskIndex::build(ctx).unwrap();
+        let info = index.find(task_id).unwrap();
+        let mut task = TaskFile::load(&info.path).unwrap();
+        task.frontmatter.completed_at = Some(completed_at);
+        task.save(&info.path).unwrap();
+        let filename = info.path.file_name().unwrap();
+        crate::fs::move_file(&info.path, ctx.bucket_path("DONE").join(filename)).unwrap();
+        run_git(&ctx.workflow_worktree, &["add", "."]).unwrap();
+        run_git(&ctx.workflow_worktree, &["commit", "-m", "complete"]).unwrap();
+    }
+
+    #[test]
+    #[serial]
+    fn test_archive_moves_old_done_tasks() {
+        let temp_dir = create_test_repo();
+        let _guard = DirGuard::new(temp_dir.path());
+
+        cmd_init().unwrap();
+        add("Old work", &[]);
+        add("Follow-up", &["TASK-001"]);
+        add("Recent work", &[]);
+        add("Last old work", &[]);
+
+        let ctx = require_initialized_workflow().unwrap();
+        let old = Utc.with_ymd_and_hms(2026, 1, 15, 12, 0, 0).unwrap();
+        complete(&ctx, "TASK-001", old);
+        complete(&ctx, "TASK-003", Utc::now());
+        complete(&ctx, "TASK-004", old);
+
+        cmd_archive(ArchiveArgs {
+            older_than: "30d".to_string(),
+            dry_run: false,
+        })
+        .unwrap();
+
+        let index = TaskIndex::build(&ctx).unwrap();
+        let done: Vec<&str> = index
+            .tasks_in_bucket("DONE")
+            .iter()
+            .map(|t| t.id.as_str())
+            .collect();
+        assert_eq!(done, vec!["TASK-003"]);
+
+        let archived = index.find("TASK-001").unwrap();
+        assert!(archived.archived);
+        assert_eq!(archived.bucket, "DONE");
+        assert_eq!(
+            archived.path,
+            ctx.archive_dir()
+                .join("2026-01")
+                .join("TASK-001-old-work.md")
+        );
+
+        // Archived tasks satisfy dependencies and keep their number.
+        let follow_up = TaskFile::load(&index.find("TASK-002").unwrap().path).unwrap();
+        assert!(check_dependencies_satisfied(&follow_up.frontmatter.depends_on, &index).is_ok());
+        assert!(
+            DependencyGraph::build(&index)
+                .unwrap()
+                .dangling()
+                .is_empty()
+        );
+        assert_eq!(index.next_number("TASK").unwrap(), 5);
+
+        let events = read_events(&ctx).unwrap();
+        let event = events.last().unwrap();
+        assert_eq!(event.action, EventAction::Archive);
+        assert_eq!(event.details["tasks"], json!(["TASK-001", "TASK-004"]));
+        assert_eq!(event.details["months"], json!(["2026-01"]));
+
+        let status = run_git(&ctx.workflow_worktree, &["status", "--porcelain"]).unwrap();
+        assert!(status.stdout.is_empty());
+    }
+
+    #[test]
+    #[serial]
+    fn test_unreadable_archive_blocks_id_allocation() {
+        let temp_dir = create_test_repo();
+        let _guard = DirGuard::new(temp_dir.path());
+
+        cmd_init().unwrap();
+        add("Only task", &[]);
+
+        // A file where the archive directory should be can't be scanned.
+        let ctx = require_initialized_workflow().unwrap();
+        std::fs::write(ctx.archive_dir(), "not a directory\n").unwrap();
+
+        let index = TaskIndex::build(&ctx).unwrap();
+        assert!(index.find("TASK-001").is_some());
+        assert_eq!(index.archived_tasks().count(), 0);
+        let err = index.next_number("TASK").unwrap_err();
+        assert!(err.to_string().contains("failed to read archive directory"));
+
+        let err = cmd_add(AddArgs {
+            title: "Second task".to_string(),
+            priority: "medium".to_string(),
+            affects: vec![],
+            affects_globs: vec![],
+            must_not_touch: vec![],
+            depends_on: vec![],
+            tags: vec![],
+            parent: None,
+            template: None,
+            kind: None,
+        })
+        .unwrap_err();
+        assert!(err.to_string().contains("failed to read archive directory"));
+    }
+}
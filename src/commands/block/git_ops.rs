@@ -0,0 +1,41 @@
+//! Git operations for the block/unblock commands.
+//!
+//! This module contains git-related helpers for committing and pushing
+//! the block/unblock workflow state changes.
+
+use crate::config::Config;
+use crate::error::{BurlError, Result};
+use crate::git::run_git;
+
+/// Commit a block/unblock transition to the workflow branch.
+pub(super) fn commit_transition(
+    ctx: &crate::context::WorkflowContext,
+    commit_msg: &str,
+) -> Result<()> {
+    run_git(&ctx.workflow_worktree, &["add", "."])
+        .map_err(|e| BurlError::GitError(format!("failed to stage changes: {}", e)))?;
+
+    let staged = run_git(&ctx.workflow_worktree, &["diff", "--cached", "--name-only"])?;
+    if staged.stdout.is_empty() {
+        return Ok(());
+    }
+
+    run_git(&ctx.workflow_worktree, &["commit", "-m", commit_msg])
+        .map_err(|e| BurlError::GitError(format!("failed to commit: {}", e)))?;
+
+    Ok(())
+}
+
+/// Push the workflow branch to the remote.
+pub(super) fn push_workflow_branch(
+    ctx: &crate::context::WorkflowContext,
+    config: &Config,
+) -> Result<()> {
+    run_git(
+        &ctx.workflow_worktree,
+        &["push", &config.remote, &config.workflow_branch],
+    )
+    .map_err(|e| BurlError::GitError(format!("failed to push workflow branch: {}", e)))?;
+
+    Ok(())
+}
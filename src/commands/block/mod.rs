@@ -0,0 +1,331 @@
+//! Implementation of the `burl block` and `burl unblock` commands.
+//!
+//! These commands model tasks that are waiting on a human or an external
+//! dependency by moving them explicitly into (and out of) the BLOCKED bucket:
+//!
+//! - `block`: READY/DOING/QA -> BLOCKED, records `blocked_reason`/`blocked_at`
+//! - `unblock`: BLOCKED -> READY (default) or DOING, clears the blocked fields
+//!
+//! Both commands append an entry to the task's `## History` section, take the
+//! per-task lock plus `workflow.lock`, append an event, and commit the
+//! workflow branch. Branches and worktrees are always preserved.
+
+mod git_ops;
+#[cfg(test)]
+mod tests;
+
+use crate::cli::{BlockArgs, UnblockArgs};
+use crate::config::Config;
+use crate::context::{WorkflowContext, require_initialized_workflow};
+use crate::error::{BurlError, Result};
+use crate::events::{Event, EventAction, append_event, get_actor_string};
+use crate::locks::{acquire_task_lock, acquire_workflow_lock};
+use crate::output::{self, CommandResult, textln};
+use crate::task::TaskFile;
+use crate::workflow::{TaskIndex, TaskInfo, validate_task_id};
+use chrono::Utc;
+use serde_json::json;
+
+use git_ops::{commit_transition, push_workflow_branch};
+
+/// Buckets from which a task can be blocked.
+const BLOCKABLE_BUCKETS: &[&str] = &["READY", "DOING", "QA"];
+
+/// Execute the `burl block` command.
+///
+/// Moves a READY/DOING/QA task to BLOCKED with a required reason.
+/// Blocking from QA clears `submitted_at` so the task can be reworked later.
+///
+/// # Exit Codes
+///
+/// - 0: Success
+/// - 1: User error (empty reason, task not blockable)
+/// - 4: Lock contention
+pub fn cmd_block(args: BlockArgs) -> Result<()> {
+    let ctx = require_initialized_workflow()?;
+    let config = Config::load(ctx.config_path()).unwrap_or_default();
+
+    let reason = args.reason.trim();
+    if reason.is_empty() {
+        return Err(BurlError::UserError(
+            "block reason cannot be empty.\n\n\
+             Usage: burl block TASK-ID --reason \"what the task is waiting on\""
+                .to_string(),
+        ));
+    }
+
+    let index = TaskIndex::build(&ctx)?;
+    let task_info = find_task(&index, &args.task_id)?;
+
+    if !BLOCKABLE_BUCKETS.contains(&task_info.bucket.as_str()) {
+        return Err(BurlError::UserError(format!(
+            "task '{}' cannot be blocked from {}.\n\n\
+             Only tasks in {} can be blocked.",
+            task_info.id,
+            task_info.bucket,
+            BLOCKABLE_BUCKETS.join(", ")
+        )));
+    }
+
+    output::record(
+        CommandResult::new("block")
+            .with_task(&task_info.id)
+            .with_from_bucket(&task_info.bucket),
+    );
+
+    let _task_lock = acquire_task_lock(&ctx, &task_info.id, "block")?;
+    let mut task_file = TaskFile::load(&task_info.path)?;
+
+    ctx.ensure_workflow_clean()?;
+    let _workflow_lock = acquire_workflow_lock(&ctx, "block")?;
+
+    let actor = get_actor_string();
+    let now = Utc::now();
+    let from_bucket = task_info.bucket.as_str();
+
+    task_file.set_blocked(reason, now);
+    if from_bucket == "QA" {
+        task_file.frontmatter.submitted_at = None;
+    }
+    task_file.append_to_history(&format!(
+        "### Blocked: {}\n\n\
+         **Actor:** {}\n\
+         **From:** {}\n\
+         **Reason:** {}\n",
+        now.format("%Y-%m-%d %H:%M:%S UTC"),
+        actor,
+        from_bucket,
+        reason
+    ));
+
+    move_task(&ctx, &task_file, task_info, "BLOCKED")?;
+
+    let event = Event::new(EventAction::Block)
+        .with_task(&task_info.id)
+        .with_details(json!({
+            "title": task_file.frontmatter.title,
+            "reason": reason,
+            "from": from_bucket,
+        }));
+    append_event(&ctx, &event)?;
+
+    if config.workflow_auto_commit {
+        commit_transition(
+            &ctx,
+            &format!("Block task {} ({} -> BLOCKED)", task_info.id, from_bucket),
+        )?;
+
+        if config.workflow_auto_push {
+            push_workflow_branch(&ctx, &config)?;
+        }
+    }
+
+    output::record(
+        CommandResult::new("block")
+            .with_task(&task_info.id)
+            .with_from_bucket(from_bucket)
+            .with_to_bucket("BLOCKED")
+            .with_git(
+                task_file.frontmatter.branch.as_deref(),
+                task_file.frontmatter.worktree.as_deref(),
+            )
+            .with_details(json!({
+                "title": task_file.frontmatter.title,
+                "reason": reason,
+            })),
+    );
+
+    textln!();
+    textln!("Blocked task: {}", task_info.id);
+    textln!("  Title:       {}", task_file.frontmatter.title);
+    textln!("  Reason:      {}", reason);
+    textln!("  From:        {}", from_bucket);
+    textln!("  To:          BLOCKED");
+    textln!();
+    textln!(
+        "Run `burl unblock {}` once the blocker is resolved.",
+        task_info.id
+    );
+
+    Ok(())
+}
+
+/// Execute the `burl unblock` command.
+///
+/// Moves a BLOCKED task back to READY (default) or DOING. Returning to DOING
+/// requires an existing task worktree from a previous claim.
+///
+/// # Exit Codes
+///
+/// - 0: Success
+/// - 1: User error (task not in BLOCKED, no worktree for `--to DOING`)
+/// - 4: Lock contention
+pub fn cmd_unblock(args: UnblockArgs) -> Result<()> {
+    let ctx = require_initialized_workflow()?;
+    let config = Config::load(ctx.config_path()).unwrap_or_default();
+
+    let destination = args.to.to_uppercase();
+
+    let index = TaskIndex::build(&ctx)?;
+    let task_info = find_task(&index, &args.task_id)?;
+
+    if task_info.bucket != "BLOCKED" {
+        return Err(BurlError::UserError(format!(
+            "task '{}' is not in BLOCKED (currently in {}).\n\n\
+             Only tasks in BLOCKED can be unblocked.",
+            task_info.id, task_info.bucket
+        )));
+    }
+
+    output::record(
+        CommandResult::new("unblock")
+            .with_task(&task_info.id)
+            .with_from_bucket("BLOCKED"),
+    );
+
+    let _task_lock = acquire_task_lock(&ctx, &task_info.id, "unblock")?;
+    let mut task_file = TaskFile::load(&task_info.path)?;
+
+    if destination == "DOING" {
+        let refs = crate::task_git::validate_task_git_refs_if_present(
+            &ctx,
+            &task_info.id,
+            task_file.frontmatter.branch.as_deref(),
+            task_file.frontmatter.worktree.as_deref(),
+        )?;
+        let has_worktree = refs.as_ref().is_some_and(|r| r.worktree_path.exists());
+        if !has_worktree || task_file.frontmatter.base_sha.is_none() {
+            return Err(BurlError::UserError(format!(
+                "task '{}' has no existing worktree to resume in DOING.\n\n\
+                 Unblock to READY instead and claim it:\n  \
+                 burl unblock {} && burl claim {}",
+                task_info.id, task_info.id, task_info.id
+            )));
+        }
+    }
+
+    ctx.ensure_workflow_clean()?;
+    let _workflow_lock = acquire_workflow_lock(&ctx, "unblock")?;
+
+    let actor = get_actor_string();
+    let now = Utc::now();
+    let previous_reason = task_file.frontmatter.blocked_reason.clone();
+    let previous_attempts = task_file.frontmatter.qa_attempts;
+
+    task_file.clear_blocked();
+    if destination == "READY" {
+        task_file.clear_claim();
+    }
+    if args.reset_attempts {
+        task_file.frontmatter.qa_attempts = 0;
+    }
+    task_file.append_to_history(&format!(
+        "### Unblocked: {}\n\n\
+         **Actor:** {}\n\
+         **To:** {}\n",
+        now.format("%Y-%m-%d %H:%M:%S UTC"),
+        actor,
+        destination
+    ));
+
+    move_task(&ctx, &task_file, task_info, &destination)?;
+
+    let event = Event::new(EventAction::Unblock)
+        .with_task(&task_info.id)
+        .with_details(json!({
+            "title": task_file.frontmatter.title,
+            "to": destination,
+            "blocked_reason": previous_reason,
+            "reset_attempts": args.reset_attempts,
+            "qa_attempts": task_file.frontmatter.qa_attempts,
+        }));
+    append_event(&ctx, &event)?;
+
+    if config.workflow_auto_commit {
+        commit_transition(
+            &ctx,
+            &format!("Unblock task {} (BLOCKED -> {})", task_info.id, destination),
+        )?;
+
+        if config.workflow_auto_push {
+            push_workflow_branch(&ctx, &config)?;
+        }
+    }
+
+    output::record(
+        CommandResult::new("unblock")
+            .with_task(&task_info.id)
+            .with_from_bucket("BLOCKED")
+            .with_to_bucket(&destination)
+            .with_git(
+                task_file.frontmatter.branch.as_deref(),
+                task_file.frontmatter.worktree.as_deref(),
+            )
+            .with_details(json!({
+                "title": task_file.frontmatter.title,
+                "blocked_reason": previous_reason,
+                "reset_attempts": args.reset_attempts,
+                "qa_attempts": task_file.frontmatter.qa_attempts,
+            })),
+    );
+
+    textln!();
+    textln!("Unblocked task: {}", task_info.id);
+    textln!("  Title:       {}", task_file.frontmatter.title);
+    textln!("  From:        BLOCKED");
+    textln!("  To:          {}", destination);
+    if args.reset_attempts {
+        textln!("  QA Attempts: {} -> 0 (reset)", previous_attempts);
+    } else if previous_attempts >= config.qa_max_attempts {
+        textln!();
+        textln!(
+            "Note: qa_attempts is {}/{}; the next rejection will block the task again.\n\
+             Use --reset-attempts to start over.",
+            previous_attempts,
+            config.qa_max_attempts
+        );
+    }
+
+    Ok(())
+}
+
+/// Validate the task ID and find it in the index.
+fn find_task<'a>(index: &'a TaskIndex, task_id: &str) -> Result<&'a TaskInfo> {
+    let task_id = validate_task_id(task_id)?;
+    index.find(&task_id).ok_or_else(|| {
+        BurlError::UserError(format!(
+            "task '{}' not found.\n\n\
+             Use `burl status` to see available tasks.",
+            task_id
+        ))
+    })
+}
+
+/// Save the task file and move it into the destination bucket.
+fn move_task(
+    ctx: &WorkflowContext,
+    task_file: &TaskFile,
+    task_info: &TaskInfo,
+    destination: &str,
+) -> Result<()> {
+    task_file.save(&task_info.path)?;
+
+    let filename = task_info
+        .path
+        .file_name()
+        .ok_or_else(|| BurlError::UserError("invalid task file path".to_string()))?;
+    let destination_path = ctx.bucket_path(destination).join(filename);
+
+    crate::fs::move_file(&task_info.path, &destination_path).map_err(|e| {
+        BurlError::UserError(format!(
+            "failed to move task from {} to {}: {}\n\n\
+             Task file: {}\n\
+             Destination: {}",
+            task_info.bucket,
+            destination,
+            e,
+            task_info.path.display(),
+            destination_path.display()
+        ))
+    })
+}
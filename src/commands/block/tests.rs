@@ -0,0 +1,223 @@
+//! Tests for the block and unblock commands.
+
+use super::*;
+use crate::cli::{AddArgs, ClaimArgs};
+use crate::commands::add::cmd_add;
+use crate::commands::claim::cmd_claim;
+use crate::commands::init::cmd_init;
+use crate::events::Event;
+use crate::exit_codes;
+use crate::test_support::{DirGuard, create_test_repo, create_test_repo_with_remote};
+use serial_test::serial;
+use std::path::PathBuf;
+
+fn add_task() {
+    cmd_add(AddArgs {
+        title: "Test block".to_string(),
+        priority: "medium".to_string(),
+        affects: vec![],
+        affects_globs: vec!["src/**".to_string()],
+        must_not_touch: vec![],
+        depends_on: vec![],
+        tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
+    })
+    .unwrap();
+}
+
+fn block_args(reason: &str) -> BlockArgs {
+    BlockArgs {
+        task_id: "TASK-001".to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+fn unblock_args(to: &str, reset_attempts: bool) -> UnblockArgs {
+    UnblockArgs {
+        task_id: "TASK-001".to_string(),
+        to: to.to_string(),
+        reset_attempts,
+    }
+}
+
+fn bucket_file(temp_dir: &tempfile::TempDir, bucket: &str) -> PathBuf {
+    temp_dir
+        .path()
+        .join(".burl/.workflow")
+        .join(bucket)
+        .join("TASK-001-test-block.md")
+}
+
+fn last_event() -> Event {
+    let ctx = crate::context::WorkflowContext::resolve().unwrap();
+    let events = std::fs::read_to_string(ctx.events_file()).unwrap();
+    serde_json::from_str(events.lines().last().unwrap()).unwrap()
+}
+
+#[test]
+#[serial]
+fn test_block_and_unblock_ready_task() {
+    let temp_dir = create_test_repo();
+    let _guard = DirGuard::new(temp_dir.path());
+
+    cmd_init().unwrap();
+    add_task();
+
+    cmd_block(block_args("waiting on API keys")).unwrap();
+
+    let blocked_path = bucket_file(&temp_dir, "BLOCKED");
+    assert!(blocked_path.exists(), "Task should be in BLOCKED");
+    let task = TaskFile::load(&blocked_path).unwrap();
+    assert_eq!(
+        task.frontmatter.blocked_reason.as_deref(),
+        Some("waiting on API keys")
+    );
+    assert!(task.frontmatter.blocked_at.is_some());
+    assert!(task.body.contains("### Blocked:"));
+    assert!(task.body.contains("**Reason:** waiting on API keys"));
+
+    let event = last_event();
+    assert_eq!(event.action, EventAction::Block);
+    assert_eq!(event.details["from"], "READY");
+    assert_eq!(event.details["reason"], "waiting on API keys");
+
+    cmd_unblock(unblock_args("READY", false)).unwrap();
+
+    let ready_path = bucket_file(&temp_dir, "READY");
+    assert!(ready_path.exists(), "Task should be back in READY");
+    let task = TaskFile::load(&ready_path).unwrap();
+    assert!(task.frontmatter.blocked_reason.is_none());
+    assert!(task.frontmatter.blocked_at.is_none());
+    assert!(task.body.contains("### Unblocked:"));
+
+    let event = last_event();
+    assert_eq!(event.action, EventAction::Unblock);
+    assert_eq!(event.details["blocked_reason"], "waiting on API keys");
+}
+
+#[test]
+#[serial]
+fn test_block_requires_reason() {
+    let temp_dir = create_test_repo();
+    let _guard = DirGuard::new(temp_dir.path());
+
+    cmd_init().unwrap();
+    add_task();
+
+    let err = cmd_block(block_args("   ")).unwrap_err();
+    assert_eq!(err.exit_code(), exit_codes::USER_ERROR);
+    assert!(bucket_file(&temp_dir, "READY").exists());
+}
+
+#[test]
+#[serial]
+fn test_block_already_blocked_fails() {
+    let temp_dir = create_test_repo();
+    let _guard = DirGuard::new(temp_dir.path());
+
+    cmd_init().unwrap();
+    add_task();
+    cmd_block(block_args("first")).unwrap();
+
+    let err = cmd_block(block_args("second")).unwrap_err();
+    assert_eq!(err.exit_code(), exit_codes::USER_ERROR);
+    assert!(err.to_string().contains("cannot be blocked from BLOCKED"));
+}
+
+#[test]
+#[serial]
+fn test_unblock_task_not_blocked_fails() {
+    let temp_dir = create_test_repo();
+    let _guard = DirGuard::new(temp_dir.path());
+
+    cmd_init().unwrap();
+    add_task();
+
+    let err = cmd_unblock(unblock_args("READY", false)).unwrap_err();
+    assert_eq!(err.exit_code(), exit_codes::USER_ERROR);
+    assert!(err.to_string().contains("not in BLOCKED"));
+}
+
+#[test]
+#[serial]
+fn test_unblock_to_doing_requires_worktree() {
+    let temp_dir = create_test_repo();
+    let _guard = DirGuard::new(temp_dir.path());
+
+    cmd_init().unwrap();
+    add_task();
+    cmd_block(block_args("needs design review")).unwrap();
+
+    let err = cmd_unblock(unblock_args("DOING", false)).unwrap_err();
+    assert_eq!(err.exit_code(), exit_codes::USER_ERROR);
+    assert!(err.to_string().contains("no existing worktree"));
+    assert!(bucket_file(&temp_dir, "BLOCKED").exists());
+}
+
+#[test]
+#[serial]
+fn test_block_doing_task_and_resume_in_doing() {
+    let temp_dir = create_test_repo_with_remote();
+    let _guard = DirGuard::new(temp_dir.path());
+
+    cmd_init().unwrap();
+    add_task();
+    cmd_claim(ClaimArgs {
+        task_id: Some("TASK-001".to_string()),
+    })
+    .unwrap();
+
+    cmd_block(block_args("waiting on upstream fix")).unwrap();
+
+    let worktree_path = temp_dir.path().join(".worktrees/task-001-test-block");
+    assert!(worktree_path.exists(), "Worktree should be preserved");
+
+    // Simulate exhausted QA attempts so --reset-attempts has something to reset.
+    let blocked_path = bucket_file(&temp_dir, "BLOCKED");
+    let mut task = TaskFile::load(&blocked_path).unwrap();
+    task.frontmatter.qa_attempts = 3;
+    task.save(&blocked_path).unwrap();
+    crate::git::run_git(
+        temp_dir.path().join(".burl"),
+        &["commit", "-am", "Bump attempts"],
+    )
+    .unwrap();
+
+    cmd_unblock(unblock_args("doing", true)).unwrap();
+
+    let doing_path = bucket_file(&temp_dir, "DOING");
+    assert!(doing_path.exists(), "Task should be back in DOING");
+    let task = TaskFile::load(&doing_path).unwrap();
+    assert_eq!(task.frontmatter.qa_attempts, 0);
+    assert!(task.frontmatter.blocked_reason.is_none());
+    assert!(task.frontmatter.branch.is_some());
+}
+
+#[test]
+#[serial]
+fn test_unblock_doing_task_to_ready_clears_claim() {
+    let temp_dir = create_test_repo_with_remote();
+    let _guard = DirGuard::new(temp_dir.path());
+
+    cmd_init().unwrap();
+    add_task();
+    cmd_claim(ClaimArgs {
+        task_id: Some("TASK-001".to_string()),
+    })
+    .unwrap();
+    cmd_block(block_args("waiting on upstream fix")).unwrap();
+
+    cmd_unblock(unblock_args("READY", false)).unwrap();
+
+    let task = TaskFile::load(bucket_file(&temp_dir, "READY")).unwrap();
+    assert!(task.frontmatter.assigned_to.is_none());
+    assert!(task.frontmatter.started_at.is_none());
+    assert!(task.frontmatter.branch.is_some());
+
+    let ctx = crate::context::require_initialized_workflow().unwrap();
+    let mut report = crate::commands::doctor::DoctorReport::new();
+    crate::commands::doctor::check_bucket_metadata_mismatches(&ctx, &mut report).unwrap();
+    assert!(report.issues.is_empty(), "{:?}", report.issues);
+}
@@ -2,20 +2,14 @@
 
 use crate::config::Config;
 use crate::error::{BurlError, Result};
+use crate::events::{Event, EventAction, append_event};
 use crate::git::run_git;
-
-/// Get the assignee string for task metadata.
-pub fn get_assignee_string() -> String {
-    let user = std::env::var("USER")
-        .or_else(|_| std::env::var("USERNAME"))
-        .unwrap_or_else(|_| "unknown".to_string());
-
-    let host = hostname::get()
-        .map(|h| h.to_string_lossy().to_string())
-        .unwrap_or_else(|_| "unknown".to_string());
-
-    format!("{}@{}", user, host)
-}
+use crate::locks::wait_for_workflow_lock;
+use crate::task::TaskFile;
+use crate::worktree_setup::SetupReport;
+use chrono::Utc;
+use serde_json::json;
+use std::path::Path;
 
 /// Commit the claim to the workflow branch.
 pub fn commit_claim(
@@ -52,3 +46,55 @@ pub fn push_workflow_branch(ctx: &crate::context::WorkflowContext, config: &Conf
 
     Ok(())
 }
+
+/// Record a claimed task's worktree provisioning in a follow-up workflow
+/// commit: a `worktree_setup` event and, if setup failed, a History entry on
+/// the task in DOING.
+///
+/// Runs after the claim is committed and its locks are released, so it
+/// re-takes `workflow.lock` for just this write.
+pub fn record_worktree_setup(
+    ctx: &crate::context::WorkflowContext,
+    config: &Config,
+    task_id: &str,
+    doing_path: &Path,
+    report: &SetupReport,
+) -> Result<()> {
+    let _workflow_lock = wait_for_workflow_lock(ctx, "claim")?;
+    ctx.ensure_workflow_clean()?;
+
+    if !report.is_success() {
+        let mut task_file = TaskFile::load(doing_path)?;
+        task_file.append_to_history(&format!(
+            "### Worktree setup failed: {}\n\n{}",
+            Utc::now().format("%Y-%m-%d %H:%M:%S UTC"),
+            report.format_failures()
+        ));
+        task_file.save(doing_path)?;
+    }
+
+    let event = Event::new(EventAction::WorktreeSetup)
+        .with_task(task_id)
+        .with_details(json!({ "setup": report }));
+    append_event(ctx, &event)?;
+
+    if config.workflow_auto_commit {
+        run_git(&ctx.workflow_worktree, &["add", "."])
+            .map_err(|e| BurlError::GitError(format!("failed to stage worktree setup: {}", e)))?;
+        run_git(
+            &ctx.workflow_worktree,
+            &[
+                "commit",
+                "-m",
+                &format!("Record worktree setup for {}", task_id),
+            ],
+        )
+        .map_err(|e| BurlError::GitError(format!("failed to commit worktree setup: {}", e)))?;
+
+        if config.workflow_auto_push {
+            push_workflow_branch(ctx, config)?;
+        }
+    }
+
+    Ok(())
+}
@@ -9,12 +9,17 @@
 //!
 //! 1. Acquire per-task lock (`TASK-XXX.lock`)
 //! 2. Resolve `base_sha` (fetch origin/main first)
-//! 3. Create/reuse branch and worktree
+//! 3. Create/reuse branch and worktree (sparse with `worktree_mode: sparse`)
 //! 4. Verify workflow worktree has no unexpected tracked modifications
 //! 5. Acquire `workflow.lock` for workflow-state mutation
 //! 6. Atomically update task frontmatter and move READY -> DOING
 //! 7. Append claim event and commit workflow branch
-//! 8. Release locks
+//! 8. Release `workflow.lock` and `claim.lock`
+//! 9. Provision a new worktree from `worktree_setup`, recording the report
+//!    (and failures, in the task's History) in a follow-up commit, then run
+//!    the `post_claim` hook
+//!
+//! The `pre_claim` hook (see [`crate::hooks`]) runs before step 3.
 //!
 //! # Rollback
 //!
@@ -23,26 +28,31 @@
 
 mod helpers;
 mod scope;
-mod selection;
+pub(crate) mod selection;
 #[cfg(test)]
 mod tests;
 mod transaction;
 
 use crate::cli::ClaimArgs;
-use crate::config::Config;
+use crate::config::{Config, WorktreeMode};
 use crate::context::require_initialized_workflow;
 use crate::error::{BurlError, Result};
-use crate::events::{Event, EventAction, append_event};
-use crate::git_worktree::{branch_exists, setup_task_worktree};
+use crate::events::{Event, EventAction, append_event, get_actor_string};
+use crate::git_worktree::{
+    branch_exists, is_sparse_worktree, setup_task_worktree, sparse_checkout_patterns,
+};
+use crate::hooks::{HookTask, run_post_hook, run_pre_hook};
 use crate::locks::{LockGuard, acquire_claim_lock, acquire_task_lock, acquire_workflow_lock};
+use crate::output::{self, CommandResult, textln};
 use crate::task::TaskFile;
 use crate::workflow::{TaskIndex, slugify_title, validate_task_id};
+use crate::worktree_setup::provision_worktree;
 use chrono::Utc;
 use serde_json::json;
 
-use helpers::{commit_claim, get_assignee_string, push_workflow_branch};
+use helpers::{commit_claim, push_workflow_branch, record_worktree_setup};
 use scope::check_scope_conflicts;
-use selection::{check_dependencies_satisfied, select_next_task_id};
+use selection::{check_dependencies_satisfied, check_not_epic, select_next_task_id};
 use transaction::ClaimTransaction;
 
 /// Execute the `burl claim` command.
@@ -65,7 +75,7 @@ pub fn cmd_claim(args: ClaimArgs) -> Result<()> {
     // ========================================================================
 
     // Acquire global claim lock if needed (when selecting next task)
-    let _claim_lock: Option<LockGuard> = if args.task_id.is_none() && config.use_global_claim_lock {
+    let claim_lock: Option<LockGuard> = if args.task_id.is_none() && config.use_global_claim_lock {
         Some(acquire_claim_lock(&ctx)?)
     } else {
         None
@@ -79,7 +89,7 @@ pub fn cmd_claim(args: ClaimArgs) -> Result<()> {
         None => {
             // Select next available task
             let ready_tasks: Vec<&crate::workflow::TaskInfo> = index.tasks_in_bucket("READY");
-            select_next_task_id(&ctx, &ready_tasks)?.ok_or_else(|| {
+            select_next_task_id(&ctx, &ready_tasks, config.claim_tie_break)?.ok_or_else(|| {
                 BurlError::UserError(
                     "no claimable tasks in READY.\n\n\
                      All READY tasks may have unmet dependencies, or there are no READY tasks.\n\
@@ -108,6 +118,12 @@ pub fn cmd_claim(args: ClaimArgs) -> Result<()> {
         )));
     }
 
+    output::record(
+        CommandResult::new("claim")
+            .with_task(&task_info.id)
+            .with_from_bucket("READY"),
+    );
+
     // ========================================================================
     // Phase 2: Acquire per-task lock and load task file
     // ========================================================================
@@ -123,8 +139,11 @@ pub fn cmd_claim(args: ClaimArgs) -> Result<()> {
     // Rebuild index since we now have the lock
     let index = TaskIndex::build(&ctx)?;
 
+    // Epics are containers, not units of work
+    check_not_epic(&task_info.id, &index)?;
+
     // Check dependencies
-    check_dependencies_satisfied(&task_file, &index)?;
+    check_dependencies_satisfied(&task_file.frontmatter.depends_on, &index)?;
 
     // Check scope conflicts with DOING tasks
     check_scope_conflicts(
@@ -174,20 +193,23 @@ pub fn cmd_claim(args: ClaimArgs) -> Result<()> {
                     branch
                 )));
             }
-        } else if branch_exists(&ctx.repo_root, branch)? {
-            // Branch exists but worktree is missing
-            return Err(BurlError::UserError(format!(
-                "task has recorded branch '{}' but worktree at '{}' is missing.\n\n\
-                 Run `burl doctor` to diagnose and repair this inconsistency,\n\
-                 or manually recreate the worktree:\n  git worktree add {} {}",
-                branch,
-                worktree_path.display(),
-                worktree_path.display(),
-                branch
-            )));
         }
+        // If the branch exists but the worktree is missing (e.g. after
+        // `burl release`), setup recreates the worktree on the existing branch.
     }
 
+    run_pre_hook(
+        &ctx,
+        &config,
+        "claim",
+        &HookTask::new(
+            &task_info.id,
+            "READY",
+            &task_info.path,
+            &task_file.frontmatter,
+        ),
+    )?;
+
     // ========================================================================
     // Phase 5: Create/Reuse Branch and Worktree
     // ========================================================================
@@ -201,6 +223,28 @@ pub fn cmd_claim(args: ClaimArgs) -> Result<()> {
         false
     };
 
+    // In sparse mode, check out only the task's declared scope. A task
+    // without a declared scope gets a full checkout.
+    let sparse = match config.worktree_mode {
+        WorktreeMode::Sparse => {
+            let fm = &task_file.frontmatter;
+            if fm.affects.is_empty() && fm.affects_globs.is_empty() {
+                eprintln!(
+                    "Note: {} declares no affects/affects_globs; using a full checkout.",
+                    task_info.id
+                );
+                None
+            } else {
+                Some(sparse_checkout_patterns(
+                    &fm.affects,
+                    &fm.affects_globs,
+                    &config.sparse_always_include,
+                ))
+            }
+        }
+        WorktreeMode::Full => None,
+    };
+
     // Setup task worktree (handles fetch, base_sha, branch, worktree creation)
     let slug = slugify_title(&task_file.frontmatter.title);
     let worktree_info = match setup_task_worktree(
@@ -211,6 +255,7 @@ pub fn cmd_claim(args: ClaimArgs) -> Result<()> {
         &config.main_branch,
         existing_branch,
         existing_worktree_for_setup.as_deref(),
+        sparse.as_deref(),
     ) {
         Ok(info) => {
             transaction.branch_name = info.branch.clone();
@@ -224,8 +269,9 @@ pub fn cmd_claim(args: ClaimArgs) -> Result<()> {
         }
     };
 
-    // Don't change base_sha on reuse (PRD policy)
-    let base_sha = if worktree_info.reused {
+    // Don't change base_sha on reuse (PRD policy). A recreated worktree on a
+    // pre-existing branch still builds on the branch's original base.
+    let base_sha = if worktree_info.reused || branch_existed_before {
         if let Some(sha) = existing_base_sha {
             sha.to_string()
         } else {
@@ -235,6 +281,8 @@ pub fn cmd_claim(args: ClaimArgs) -> Result<()> {
         worktree_info.base_sha.clone()
     };
 
+    let sparse_checkout = is_sparse_worktree(&worktree_info.path);
+
     // ========================================================================
     // Phase 6: Workflow State Mutation (under workflow lock)
     // ========================================================================
@@ -246,7 +294,7 @@ pub fn cmd_claim(args: ClaimArgs) -> Result<()> {
     }
 
     // Acquire workflow lock
-    let _workflow_lock = match acquire_workflow_lock(&ctx, "claim") {
+    let workflow_lock = match acquire_workflow_lock(&ctx, "claim") {
         Ok(lock) => lock,
         Err(e) => {
             transaction.rollback(&ctx.repo_root);
@@ -255,7 +303,7 @@ pub fn cmd_claim(args: ClaimArgs) -> Result<()> {
     };
 
     // Update task frontmatter
-    let assignee = get_assignee_string();
+    let assignee = get_actor_string();
     let now = Utc::now();
 
     task_file.set_assigned(&assignee, Some(now));
@@ -307,7 +355,8 @@ pub fn cmd_claim(args: ClaimArgs) -> Result<()> {
             "worktree": worktree_info.path.to_string_lossy(),
             "base_sha": base_sha,
             "reused": worktree_info.reused,
-            "assigned_to": assignee
+            "sparse": sparse_checkout,
+            "assigned_to": assignee,
         }));
     append_event(&ctx, &event)?;
 
@@ -321,12 +370,58 @@ pub fn cmd_claim(args: ClaimArgs) -> Result<()> {
         }
     }
 
+    // The task has left READY, so other claims can select again. Provisioning
+    // and post_claim often install dependencies; don't hold locks meanwhile.
+    drop(workflow_lock);
+    drop(claim_lock);
+
+    // Provision a newly created worktree (copy/symlink files, run setup
+    // commands). Failures are reported but don't undo the claim.
+    let setup_report = if !worktree_info.reused && config.worktree_setup.has_provisioning() {
+        let report = provision_worktree(&ctx, &config.worktree_setup, &worktree_info.path);
+        if let Err(e) = record_worktree_setup(&ctx, &config, &task_info.id, &doing_path, &report) {
+            eprintln!(
+                "Warning: failed to record worktree setup for {}: {}",
+                task_info.id, e
+            );
+        }
+        Some(report)
+    } else {
+        None
+    };
+
+    run_post_hook(
+        &ctx,
+        &config,
+        "claim",
+        &HookTask::new(&task_info.id, "DOING", &doing_path, &task_file.frontmatter),
+    );
+
     // ========================================================================
     // Phase 8: Output
     // ========================================================================
 
+    output::record(
+        CommandResult::new("claim")
+            .with_task(&task_info.id)
+            .with_from_bucket("READY")
+            .with_to_bucket("DOING")
+            .with_git(
+                Some(&worktree_info.branch),
+                Some(&worktree_info.path.to_string_lossy()),
+            )
+            .with_details(json!({
+                "title": task_file.frontmatter.title,
+                "base_sha": base_sha,
+                "reused": worktree_info.reused,
+                "sparse": sparse_checkout,
+                "assigned_to": assignee,
+                "setup": setup_report
+            })),
+    );
+
     // Print worktree path for agents to cd into
-    println!("{}", worktree_info.path.display());
+    textln!("{}", worktree_info.path.display());
 
     // Print additional info to stderr so it doesn't interfere with scripted use
     eprintln!();
@@ -337,6 +432,17 @@ pub fn cmd_claim(args: ClaimArgs) -> Result<()> {
     if worktree_info.reused {
         eprintln!("  (reused existing worktree)");
     }
+    if sparse_checkout {
+        eprintln!(
+            "  (sparse checkout; run `burl worktree widen {} <glob>` to add paths)",
+            task_info.id
+        );
+    }
+    if let Some(report) = setup_report.as_ref().filter(|r| !r.is_success()) {
+        eprintln!();
+        eprintln!("Warning: worktree setup failed (recorded in the task's History):");
+        eprint!("{}", report.format_failures());
+    }
     eprintln!();
     eprintln!("Next steps:");
     eprintln!("  1. cd {}", worktree_info.path.display());
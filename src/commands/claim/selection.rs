@@ -1,11 +1,14 @@
 //! Task selection and dependency checking for claim operation.
 
+use crate::config::ClaimTieBreak;
 use crate::error::{BurlError, Result};
-use crate::task::TaskFile;
-use crate::workflow::{TaskIndex, TaskInfo};
+use crate::graph::DependencyGraph;
+use crate::workflow::{TaskIndex, TaskInfo, TaskKey, task_key};
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
 
 /// Priority ordering for task selection (high > medium > low > none/other)
-fn priority_rank(priority: &str) -> u32 {
+pub(crate) fn priority_rank(priority: &str) -> u32 {
     match priority.to_lowercase().as_str() {
         "high" => 0,
         "medium" => 1,
@@ -14,61 +17,113 @@ fn priority_rank(priority: &str) -> u32 {
     }
 }
 
+/// Sort key for claim order: priority, then (with the `downstream` tie-break)
+/// most open transitive dependents, then ID (prefix, then number).
+pub(crate) fn claim_order_key(
+    priority: &str,
+    downstream: usize,
+    task_id: &str,
+    tie_break: ClaimTieBreak,
+) -> (u32, Reverse<usize>, TaskKey) {
+    let downstream = match tie_break {
+        ClaimTieBreak::Downstream => downstream,
+        ClaimTieBreak::Id => 0,
+    };
+    (
+        priority_rank(priority),
+        Reverse(downstream),
+        task_key(task_id),
+    )
+}
+
 /// Select the next claimable task deterministically.
 ///
 /// Selection criteria:
 /// 1. Task must be in READY bucket
 /// 2. Sort by priority (high > medium > low > none)
-/// 3. Then by numeric ID ascending
+/// 3. With `claim_tie_break: downstream`, then by number of open tasks that
+///    transitively depend on it (most first)
+/// 4. Then by ID ascending (prefix, then number)
+///
+/// Epics (tasks with children) and tasks with unmet dependencies are skipped.
 ///
 /// Returns the task ID of the selected task.
 pub fn select_next_task_id(
     ctx: &crate::context::WorkflowContext,
     ready_tasks: &[&TaskInfo],
+    tie_break: ClaimTieBreak,
 ) -> Result<Option<String>> {
     if ready_tasks.is_empty() {
         return Ok(None);
     }
 
-    // Load task files to get priority info - collect into owned data
-    let mut tasks_with_priority: Vec<(String, u32, u32)> = Vec::new(); // (id, priority_rank, number)
+    // One index for the whole selection: its frontmatter cache serves both
+    // the graph and the sort keys.
+    let index = TaskIndex::build(ctx)?;
+    let graph = DependencyGraph::build(&index)?;
+    let downstream = match tie_break {
+        ClaimTieBreak::Downstream => graph.downstream_counts(),
+        ClaimTieBreak::Id => HashMap::new(),
+    };
+    let epics: HashSet<&str> = graph
+        .nodes()
+        .filter_map(|node| node.parent.as_deref())
+        .collect();
+
+    let mut candidates = Vec::new(); // (id, depends_on, sort key)
 
-    for task_info in ready_tasks {
-        let task_file = TaskFile::load(&task_info.path)?;
-        let rank = priority_rank(&task_file.frontmatter.priority);
-        tasks_with_priority.push((task_info.id.clone(), rank, task_info.number));
+    for task_info in ready_tasks
+        .iter()
+        .filter(|t| !epics.contains(t.id.as_str()))
+    {
+        let frontmatter = index.frontmatter(task_info)?;
+        let key = claim_order_key(
+            &frontmatter.priority,
+            downstream.get(&task_info.id).copied().unwrap_or(0),
+            &task_info.id,
+            tie_break,
+        );
+        candidates.push((task_info.id.clone(), frontmatter.depends_on, key));
     }
 
-    // Sort by priority rank (ascending = higher priority first), then by numeric ID
-    tasks_with_priority.sort_by(|a, b| {
-        let priority_cmp = a.1.cmp(&b.1);
-        if priority_cmp == std::cmp::Ordering::Equal {
-            a.2.cmp(&b.2)
-        } else {
-            priority_cmp
-        }
-    });
-
-    // Filter out tasks with unmet dependencies
-    for (task_id, _, _) in tasks_with_priority {
-        // Re-fetch task info from a fresh index
-        let index = TaskIndex::build(ctx)?;
-        if let Some(task_info) = index.find(&task_id) {
-            let task_file = TaskFile::load(&task_info.path)?;
-            if check_dependencies_satisfied(&task_file, &index).is_ok() {
-                return Ok(Some(task_id));
-            }
-        }
+    candidates.sort_by(|(_, _, a), (_, _, b)| a.cmp(b));
+    index.save_cache()?;
+
+    // First task whose dependencies are all DONE
+    Ok(candidates
+        .into_iter()
+        .find(|(_, depends_on, _)| check_dependencies_satisfied(depends_on, &index).is_ok())
+        .map(|(task_id, _, _)| task_id))
+}
+
+/// Refuse to claim an epic: a task with children is a container, not a unit
+/// of work.
+pub fn check_not_epic(task_id: &str, index: &TaskIndex) -> Result<()> {
+    let graph = DependencyGraph::build(index)?;
+    let children = graph.children(task_id);
+    if children.is_empty() {
+        return Ok(());
     }
 
-    Ok(None)
+    let listed: Vec<String> = children
+        .iter()
+        .map(|child| format!("{} [{}] {}", child.id, child.bucket, child.title))
+        .collect();
+    Err(BurlError::UserError(format!(
+        "cannot claim task '{}': it is an epic with {} child task(s).\n\n\
+         Children:\n  - {}\n\n\
+         Claim one of its children instead; the epic completes when all of them are DONE.",
+        task_id,
+        children.len(),
+        listed.join("\n  - ")
+    )))
 }
 
-/// Check if all dependencies of a task are in DONE.
-pub fn check_dependencies_satisfied(task: &TaskFile, index: &TaskIndex) -> Result<()> {
+/// Check if all of a task's dependencies (its `depends_on`) are in DONE.
+pub fn check_dependencies_satisfied(depends_on: &[String], index: &TaskIndex) -> Result<()> {
     let mut unmet_deps = Vec::new();
 
-    for dep_id in &task.frontmatter.depends_on {
+    for dep_id in depends_on {
         match index.find(dep_id) {
             Some(dep_info) => {
                 if dep_info.bucket != "DONE" {
@@ -106,4 +161,20 @@ mod tests {
         assert_eq!(priority_rank("other"), 3);
         assert_eq!(priority_rank(""), 3);
     }
+
+    #[test]
+    fn test_claim_order_key_tie_break() {
+        // Same priority: ID order unless the downstream tie-break is enabled.
+        let few = claim_order_key("high", 1, "TASK-001", ClaimTieBreak::Id);
+        let many = claim_order_key("high", 5, "TASK-002", ClaimTieBreak::Id);
+        assert!(few < many);
+
+        let few = claim_order_key("high", 1, "TASK-001", ClaimTieBreak::Downstream);
+        let many = claim_order_key("high", 5, "TASK-002", ClaimTieBreak::Downstream);
+        assert!(many < few);
+
+        // Priority still wins over downstream work.
+        let medium = claim_order_key("medium", 9, "TASK-001", ClaimTieBreak::Downstream);
+        assert!(few < medium);
+    }
 }
@@ -4,6 +4,7 @@ use crate::cli::{AddArgs, ClaimArgs};
 use crate::commands::add::cmd_add;
 use crate::commands::claim::cmd_claim;
 use crate::commands::init::cmd_init;
+use crate::git::run_git;
 use crate::task::TaskFile;
 use crate::test_support::{DirGuard, create_test_repo_with_remote};
 use serial_test::serial;
@@ -26,6 +27,9 @@ fn test_claim_explicit_task() {
         must_not_touch: vec![],
         depends_on: vec![],
         tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
     };
     cmd_add(add_args).unwrap();
 
@@ -76,6 +80,9 @@ fn test_claim_next_task_deterministic() {
         must_not_touch: vec![],
         depends_on: vec![],
         tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
     })
     .unwrap();
 
@@ -87,6 +94,9 @@ fn test_claim_next_task_deterministic() {
         must_not_touch: vec![],
         depends_on: vec![],
         tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
     })
     .unwrap();
 
@@ -98,6 +108,9 @@ fn test_claim_next_task_deterministic() {
         must_not_touch: vec![],
         depends_on: vec![],
         tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
     })
     .unwrap();
 
@@ -133,6 +146,9 @@ fn test_claim_fails_for_non_ready_task() {
         must_not_touch: vec![],
         depends_on: vec![],
         tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
     })
     .unwrap();
 
@@ -160,21 +176,37 @@ fn test_claim_fails_with_unmet_dependencies() {
     // Initialize workflow
     cmd_init().unwrap();
 
-    // Add task with dependency
+    // Add a prerequisite (left in READY) and a task depending on it
+    cmd_add(AddArgs {
+        title: "Prerequisite task".to_string(),
+        priority: "low".to_string(),
+        affects: vec![],
+        affects_globs: vec![],
+        must_not_touch: vec![],
+        depends_on: vec![],
+        tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
+    })
+    .unwrap();
     cmd_add(AddArgs {
         title: "Dependent task".to_string(),
         priority: "high".to_string(),
         affects: vec![],
         affects_globs: vec![],
         must_not_touch: vec![],
-        depends_on: vec!["TASK-002".to_string()], // Depends on non-existent task
+        depends_on: vec!["TASK-001".to_string()], // Not DONE yet
         tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
     })
     .unwrap();
 
     // Claim should fail due to unmet dependency
     let result = cmd_claim(ClaimArgs {
-        task_id: Some("TASK-001".to_string()),
+        task_id: Some("TASK-002".to_string()),
     });
 
     assert!(result.is_err());
@@ -186,6 +218,113 @@ fn test_claim_fails_with_unmet_dependencies() {
     );
 }
 
+#[test]
+#[serial]
+fn test_claim_fails_when_dependency_was_deleted() {
+    let temp_dir = create_test_repo_with_remote();
+    let _guard = DirGuard::new(temp_dir.path());
+
+    // Initialize workflow
+    cmd_init().unwrap();
+
+    for (title, depends_on) in [
+        ("Prerequisite task", vec![]),
+        ("Dependent task", vec!["TASK-001".to_string()]),
+    ] {
+        cmd_add(AddArgs {
+            title: title.to_string(),
+            priority: "high".to_string(),
+            affects: vec![],
+            affects_globs: vec![],
+            must_not_touch: vec![],
+            depends_on,
+            tags: vec![],
+            parent: None,
+            template: None,
+            kind: None,
+        })
+        .unwrap();
+    }
+
+    // Delete the prerequisite after the dependent task was added
+    let ctx = crate::context::require_initialized_workflow().unwrap();
+    let prerequisite = crate::workflow::TaskIndex::build(&ctx)
+        .unwrap()
+        .find("TASK-001")
+        .unwrap()
+        .path
+        .clone();
+    let prerequisite = prerequisite.to_string_lossy();
+    run_git(&ctx.workflow_worktree, &["rm", "-q", &prerequisite]).unwrap();
+    run_git(
+        &ctx.workflow_worktree,
+        &["commit", "-q", "-m", "Delete prerequisite"],
+    )
+    .unwrap();
+
+    // Claim should still refuse the dependent task
+    let err = cmd_claim(ClaimArgs {
+        task_id: Some("TASK-002".to_string()),
+    })
+    .unwrap_err()
+    .to_string();
+    assert!(err.contains("dependencies not satisfied"));
+    assert!(err.contains("TASK-001 (not found)"));
+}
+
+#[test]
+#[serial]
+fn test_claim_refuses_epic_and_claim_next_skips_it() {
+    let temp_dir = create_test_repo_with_remote();
+    let _guard = DirGuard::new(temp_dir.path());
+
+    cmd_init().unwrap();
+
+    // High-priority epic with a low-priority child
+    cmd_add(AddArgs {
+        title: "Epic".to_string(),
+        priority: "high".to_string(),
+        affects: vec![],
+        affects_globs: vec![],
+        must_not_touch: vec![],
+        depends_on: vec![],
+        tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
+    })
+    .unwrap();
+    cmd_add(AddArgs {
+        title: "Child".to_string(),
+        priority: "low".to_string(),
+        affects: vec![],
+        affects_globs: vec![],
+        must_not_touch: vec![],
+        depends_on: vec![],
+        tags: vec![],
+        parent: Some("TASK-001".to_string()),
+        template: None,
+        kind: None,
+    })
+    .unwrap();
+
+    let err = cmd_claim(ClaimArgs {
+        task_id: Some("TASK-001".to_string()),
+    })
+    .unwrap_err();
+    assert!(err.to_string().contains("is an epic with 1 child task(s)"));
+    assert!(err.to_string().contains("TASK-002 [READY] Child"));
+
+    // Claim next picks the child, not the higher-priority epic
+    cmd_claim(ClaimArgs { task_id: None }).unwrap();
+    assert!(
+        temp_dir
+            .path()
+            .join(".burl/.workflow/DOING/TASK-002-child.md")
+            .exists()
+    );
+}
+
 #[test]
 #[serial]
 fn test_claim_with_scope_conflict_fails_by_default() {
@@ -204,6 +343,9 @@ fn test_claim_with_scope_conflict_fails_by_default() {
         must_not_touch: vec![],
         depends_on: vec![],
         tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
     })
     .unwrap();
 
@@ -215,6 +357,9 @@ fn test_claim_with_scope_conflict_fails_by_default() {
         must_not_touch: vec![],
         depends_on: vec![],
         tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
     })
     .unwrap();
 
@@ -260,6 +405,9 @@ fn test_claim_scope_conflict_hybrid_uses_diffs_when_available() {
         must_not_touch: vec![],
         depends_on: vec![],
         tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
     })
     .unwrap();
 
@@ -272,6 +420,9 @@ fn test_claim_scope_conflict_hybrid_uses_diffs_when_available() {
         must_not_touch: vec![],
         depends_on: vec![],
         tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
     })
     .unwrap();
 
@@ -323,6 +474,9 @@ fn test_claim_base_sha_is_set() {
         must_not_touch: vec![],
         depends_on: vec![],
         tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
     })
     .unwrap();
 
@@ -342,3 +496,166 @@ fn test_claim_base_sha_is_set() {
     let base_sha = task.frontmatter.base_sha.unwrap();
     assert_eq!(base_sha.len(), 40, "base_sha should be a full SHA");
 }
+
+#[test]
+#[serial]
+fn test_claim_runs_lifecycle_hooks() {
+    let temp_dir = create_test_repo_with_remote();
+    let _guard = DirGuard::new(temp_dir.path());
+
+    cmd_init().unwrap();
+    cmd_add(AddArgs {
+        title: "Hooked task".to_string(),
+        priority: "medium".to_string(),
+        affects: vec![],
+        affects_globs: vec![],
+        must_not_touch: vec![],
+        depends_on: vec![],
+        tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
+    })
+    .unwrap();
+
+    let ctx = crate::context::require_initialized_workflow().unwrap();
+    // post_claim only installs if workflow.lock has been released.
+    let post_claim = format!(
+        "sh -c 'test ! -e {} && echo $BURL_TASK_ID > installed.txt'",
+        ctx.workflow_lock_path().display()
+    );
+    let write_hooks = |pre_claim: &str| {
+        let mut config = crate::config::Config::load(ctx.config_path()).unwrap();
+        config.hooks.pre_claim = Some(pre_claim.to_string());
+        config.hooks.post_claim = Some(post_claim.clone());
+        std::fs::write(ctx.config_path(), config.to_yaml().unwrap()).unwrap();
+        crate::git::run_git(
+            &ctx.workflow_worktree,
+            &["commit", "-am", "Configure hooks"],
+        )
+        .unwrap();
+    };
+    let claim = || {
+        cmd_claim(ClaimArgs {
+            task_id: Some("TASK-001".to_string()),
+        })
+    };
+
+    // A failing pre_claim hook aborts before any worktree or state change.
+    write_hooks("sh -c 'echo frozen >&2; exit 1'");
+    let err = claim().unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("pre_claim hook exited with code 1")
+    );
+    assert!(err.to_string().contains("frozen"));
+    assert!(
+        ctx.bucket_path("READY")
+            .join("TASK-001-hooked-task.md")
+            .exists()
+    );
+    let worktree_path = temp_dir.path().join(".worktrees/task-001-hooked-task");
+    assert!(!worktree_path.exists());
+
+    // post_claim runs in the fresh worktree after the claim commit, and is
+    // logged in a commit of its own.
+    write_hooks("true");
+    claim().unwrap();
+    assert_eq!(
+        std::fs::read_to_string(worktree_path.join("installed.txt")).unwrap(),
+        "TASK-001\n"
+    );
+    let event = crate::events::read_events(&ctx).unwrap().pop().unwrap();
+    assert_eq!(event.action, crate::events::EventAction::Hook);
+    assert_eq!(event.details["hook"], "post_claim");
+    assert_eq!(event.details["success"], true);
+    ctx.ensure_workflow_clean().unwrap();
+    let log = crate::git::run_git(&ctx.workflow_worktree, &["log", "-2", "--format=%s"]).unwrap();
+    let subjects: Vec<&str> = log.stdout.lines().collect();
+    assert_eq!(subjects[0], "Record post_claim hook for TASK-001");
+    assert!(subjects[1].contains("TASK-001"), "{:?}", subjects);
+}
+
+#[test]
+#[serial]
+fn test_claim_provisions_new_worktree() {
+    let temp_dir = create_test_repo_with_remote();
+    let _guard = DirGuard::new(temp_dir.path());
+
+    cmd_init().unwrap();
+    cmd_add(AddArgs {
+        title: "Provisioned task".to_string(),
+        priority: "medium".to_string(),
+        affects: vec![],
+        affects_globs: vec![],
+        must_not_touch: vec![],
+        depends_on: vec![],
+        tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
+    })
+    .unwrap();
+
+    let ctx = crate::context::require_initialized_workflow().unwrap();
+    std::fs::write(temp_dir.path().join(".env"), "TOKEN=abc\n").unwrap();
+    let mut config = crate::config::Config::load(ctx.config_path()).unwrap();
+    config.worktree_setup.copy = vec![".env".to_string()];
+    // Setup runs once the claim is committed and its locks are released.
+    config.worktree_setup.commands = vec![
+        format!(
+            "sh -c 'test ! -e {} && test ! -e {} && echo $CARGO_TARGET_DIR > target.txt'",
+            ctx.claim_lock_path().display(),
+            ctx.workflow_lock_path().display()
+        ),
+        "sh -c 'echo offline >&2; exit 1'".to_string(),
+    ];
+    config.worktree_setup.env.insert(
+        "CARGO_TARGET_DIR".to_string(),
+        "{repo_root}/target".to_string(),
+    );
+    std::fs::write(ctx.config_path(), config.to_yaml().unwrap()).unwrap();
+    crate::git::run_git(
+        &ctx.workflow_worktree,
+        &["commit", "-am", "Configure worktree setup"],
+    )
+    .unwrap();
+
+    // Setup failures don't undo the claim; they are recorded on the task.
+    cmd_claim(ClaimArgs { task_id: None }).unwrap();
+
+    let worktree_path = temp_dir.path().join(".worktrees/task-001-provisioned-task");
+    assert_eq!(
+        std::fs::read_to_string(worktree_path.join(".env")).unwrap(),
+        "TOKEN=abc\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(worktree_path.join("target.txt"))
+            .unwrap()
+            .trim(),
+        format!("{}/target", ctx.repo_root.display())
+    );
+
+    let task = TaskFile::load(
+        ctx.bucket_path("DOING")
+            .join("TASK-001-provisioned-task.md"),
+    )
+    .unwrap();
+    assert!(task.body.contains("### Worktree setup failed:"));
+    assert!(task.body.contains("exited with code 1\noffline"));
+
+    let mut events = crate::events::read_events(&ctx).unwrap();
+    let event = events.pop().unwrap();
+    assert_eq!(event.action, crate::events::EventAction::WorktreeSetup);
+    assert_eq!(event.details["setup"]["steps"][0]["success"], true);
+    assert_eq!(event.details["setup"]["steps"][1]["success"], true);
+    assert_eq!(event.details["setup"]["steps"][2]["success"], false);
+    assert_eq!(
+        events.pop().unwrap().action,
+        crate::events::EventAction::Claim
+    );
+    ctx.ensure_workflow_clean().unwrap();
+    let subject =
+        crate::git::run_git(&ctx.workflow_worktree, &["log", "-1", "--format=%s"]).unwrap();
+    assert_eq!(subject.stdout, "Record worktree setup for TASK-001");
+}
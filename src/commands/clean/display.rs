@@ -1,15 +1,16 @@
 //! Display and formatting utilities for clean command output.
 
 use super::types::CleanupPlan;
+use crate::output::textln;
 use std::path::Path;
 
 /// Print the cleanup plan in a readable format.
 pub fn print_cleanup_plan(plan: &CleanupPlan, repo_root: &Path) {
-    println!("Cleanup plan:");
-    println!();
+    textln!("Cleanup plan:");
+    textln!();
 
     if !plan.completed_worktrees.is_empty() {
-        println!(
+        textln!(
             "Completed task worktrees ({}):",
             plan.completed_worktrees.len()
         );
@@ -20,13 +21,13 @@ pub fn print_cleanup_plan(plan: &CleanupPlan, repo_root: &Path) {
                 .as_ref()
                 .map(|id| format!(" ({})", id))
                 .unwrap_or_default();
-            println!("  - {}{}", rel_path, task_info);
+            textln!("  - {}{}", rel_path, task_info);
         }
-        println!();
+        textln!();
     }
 
     if !plan.orphan_worktrees.is_empty() {
-        println!("Orphan worktrees ({}):", plan.orphan_worktrees.len());
+        textln!("Orphan worktrees ({}):", plan.orphan_worktrees.len());
         for candidate in &plan.orphan_worktrees {
             let rel_path = make_relative(&candidate.path, repo_root);
             let branch_info = candidate
@@ -34,21 +35,21 @@ pub fn print_cleanup_plan(plan: &CleanupPlan, repo_root: &Path) {
                 .as_ref()
                 .map(|b| format!(" [branch: {}]", b))
                 .unwrap_or_default();
-            println!("  - {}{}", rel_path, branch_info);
+            textln!("  - {}{}", rel_path, branch_info);
         }
-        println!();
+        textln!();
     }
 
     if !plan.orphan_directories.is_empty() {
-        println!(
+        textln!(
             "Orphan directories (not git worktrees) ({}):",
             plan.orphan_directories.len()
         );
         for path in &plan.orphan_directories {
             let rel_path = make_relative(path, repo_root);
-            println!("  - {}", rel_path);
+            textln!("  - {}", rel_path);
         }
-        println!();
+        textln!();
     }
 }
 
@@ -6,6 +6,7 @@ use super::types::{CleanupPlan, CleanupResult};
 use crate::context::WorkflowContext;
 use crate::error::{BurlError, Result};
 use crate::git_worktree::remove_worktree;
+use crate::output::textln;
 use std::fs;
 use std::path::Path;
 
@@ -17,7 +18,7 @@ pub fn execute_cleanup(ctx: &WorkflowContext, plan: &CleanupPlan) -> Result<Clea
     for candidate in &plan.completed_worktrees {
         match remove_worktree_safe(&ctx.repo_root, &candidate.path) {
             Ok(()) => {
-                println!(
+                textln!(
                     "Removed: {}",
                     make_relative(&candidate.path, &ctx.repo_root)
                 );
@@ -34,7 +35,7 @@ pub fn execute_cleanup(ctx: &WorkflowContext, plan: &CleanupPlan) -> Result<Clea
     for candidate in &plan.orphan_worktrees {
         match remove_worktree_safe(&ctx.repo_root, &candidate.path) {
             Ok(()) => {
-                println!(
+                textln!(
                     "Removed: {}",
                     make_relative(&candidate.path, &ctx.repo_root)
                 );
@@ -51,7 +52,7 @@ pub fn execute_cleanup(ctx: &WorkflowContext, plan: &CleanupPlan) -> Result<Clea
     for path in &plan.orphan_directories {
         match remove_directory_safe(path, &ctx.worktrees_dir) {
             Ok(()) => {
-                println!("Removed: {}", make_relative(path, &ctx.repo_root));
+                textln!("Removed: {}", make_relative(path, &ctx.repo_root));
                 result.removed_count += 1;
             }
             Err(e) => {
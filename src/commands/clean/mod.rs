@@ -29,13 +29,45 @@ mod tests;
 
 use crate::cli::CleanArgs;
 use crate::config::Config;
-use crate::context::require_initialized_workflow;
+use crate::context::{WorkflowContext, require_initialized_workflow};
 use crate::error::Result;
+use crate::output::{self, CommandResult, textln};
 
-use display::print_cleanup_plan;
+use serde_json::{Value, json};
+
+use display::{make_relative, print_cleanup_plan};
 use execution::execute_cleanup;
 use logging::log_clean_event;
 use planning::build_cleanup_plan;
+use types::{CleanupCandidate, CleanupPlan};
+
+/// Build the `--output json` details for a cleanup plan.
+fn plan_details(plan: &CleanupPlan, ctx: &WorkflowContext, args: &CleanArgs) -> Value {
+    let candidates = |list: &[CleanupCandidate]| -> Vec<Value> {
+        list.iter()
+            .map(|c| {
+                json!({
+                    "path": make_relative(&c.path, &ctx.repo_root),
+                    "task_id": c.task_id,
+                    "branch": c.branch,
+                })
+            })
+            .collect()
+    };
+
+    json!({
+        "dry_run": !args.yes,
+        "completed_worktrees": candidates(&plan.completed_worktrees),
+        "orphan_worktrees": candidates(&plan.orphan_worktrees),
+        "orphan_directories": plan
+            .orphan_directories
+            .iter()
+            .map(|p| make_relative(p, &ctx.repo_root))
+            .collect::<Vec<_>>(),
+        "removed_count": 0,
+        "skipped": [],
+    })
+}
 
 /// Execute the `burl clean` command.
 ///
@@ -60,8 +92,10 @@ pub fn cmd_clean(args: CleanArgs) -> Result<()> {
         + plan.orphan_worktrees.len()
         + plan.orphan_directories.len();
 
+    output::record(CommandResult::new("clean").with_details(plan_details(&plan, &ctx, &args)));
+
     if total_count == 0 {
-        println!("No cleanup candidates found.");
+        textln!("No cleanup candidates found.");
         return Ok(());
     }
 
@@ -70,9 +104,9 @@ pub fn cmd_clean(args: CleanArgs) -> Result<()> {
 
     // If dry-run (no --yes), just exit
     if !args.yes {
-        println!();
-        println!("Dry-run mode: no changes made.");
-        println!("Run with --yes to perform the cleanup.");
+        textln!();
+        textln!("Dry-run mode: no changes made.");
+        textln!("Run with --yes to perform the cleanup.");
         return Ok(());
     }
 
@@ -84,14 +118,23 @@ pub fn cmd_clean(args: CleanArgs) -> Result<()> {
         log_clean_event(&ctx, &config, &result)?;
     }
 
+    output::update(|r| {
+        r.details["removed_count"] = json!(result.removed_count);
+        r.details["skipped"] = result
+            .skipped
+            .iter()
+            .map(|(path, reason)| json!({ "path": make_relative(path, &ctx.repo_root), "reason": reason }))
+            .collect();
+    });
+
     // Print summary
-    println!();
-    println!("Cleanup complete:");
-    println!("  Removed: {} item(s)", result.removed_count);
+    textln!();
+    textln!("Cleanup complete:");
+    textln!("  Removed: {} item(s)", result.removed_count);
     if result.skipped_count > 0 {
-        println!("  Skipped: {} item(s)", result.skipped_count);
+        textln!("  Skipped: {} item(s)", result.skipped_count);
         for (path, reason) in &result.skipped {
-            println!("    - {}: {}", path.display(), reason);
+            textln!("    - {}: {}", path.display(), reason);
         }
     }
 
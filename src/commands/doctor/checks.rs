@@ -4,9 +4,10 @@ use crate::config::Config;
 use crate::context::WorkflowContext;
 use crate::error::Result;
 use crate::git_worktree::{branch_exists, list_worktrees};
+use crate::graph::{DependencyGraph, format_path};
 use crate::locks;
-use crate::task::TaskFile;
-use crate::workflow::TaskIndex;
+use crate::task::{TaskFile, TaskFrontmatter};
+use crate::workflow::{BUCKETS, TaskIndex};
 use std::collections::HashSet;
 use std::path::PathBuf;
 
@@ -111,15 +112,22 @@ pub fn check_orphan_locks(
     Ok(())
 }
 
-/// Check for tasks in DOING/QA missing base_sha.
+/// Whether tasks in `bucket` have a branch and worktree: DOING, the review
+/// buckets, and custom buckets (which `burl move` keeps both across).
+fn has_worktree(config: &Config, bucket: &str) -> bool {
+    bucket == "DOING" || config.states.in_review(bucket) || !BUCKETS.contains(&bucket)
+}
+
+/// Check for claimed tasks (DOING, review and custom buckets) missing base_sha.
 pub fn check_tasks_missing_base_sha(
     ctx: &WorkflowContext,
+    config: &Config,
     report: &mut DoctorReport,
 ) -> Result<()> {
     let index = TaskIndex::build(ctx)?;
 
     for task_info in index.all_tasks() {
-        if task_info.bucket != "DOING" && task_info.bucket != "QA" {
+        if !has_worktree(config, &task_info.bucket) {
             continue;
         }
 
@@ -147,15 +155,17 @@ pub fn check_tasks_missing_base_sha(
     Ok(())
 }
 
-/// Check for tasks in DOING/QA with missing worktree directory.
+/// Check for claimed tasks (DOING, review and custom buckets) with missing
+/// worktree directory.
 pub fn check_tasks_missing_worktree(
     ctx: &WorkflowContext,
+    config: &Config,
     report: &mut DoctorReport,
 ) -> Result<()> {
     let index = TaskIndex::build(ctx)?;
 
     for task_info in index.all_tasks() {
-        if task_info.bucket != "DOING" && task_info.bucket != "QA" {
+        if !has_worktree(config, &task_info.bucket) {
             continue;
         }
 
@@ -304,6 +314,78 @@ pub fn check_tasks_missing_branch(ctx: &WorkflowContext, report: &mut DoctorRepo
     Ok(())
 }
 
+/// Check `depends_on` for references to missing tasks and for cycles, and
+/// `parent` for references to missing epics.
+pub fn check_dependency_graph(ctx: &WorkflowContext, report: &mut DoctorReport) -> Result<()> {
+    let graph = DependencyGraph::load(ctx)?;
+
+    for (task_id, dep) in graph.dangling() {
+        report.issues.push(
+            Issue::new(
+                IssueSeverity::Error,
+                "dangling_dependency",
+                &format!(
+                    "Task {} depends on {} which does not exist in any bucket",
+                    task_id, dep
+                ),
+            )
+            .with_path(&task_id)
+            .with_remediation(&format!(
+                "burl edit {} --remove-depends-on {}",
+                task_id, dep
+            )),
+        );
+    }
+
+    for cycle in graph.find_cycles() {
+        report.issues.push(
+            Issue::new(
+                IssueSeverity::Error,
+                "dependency_cycle",
+                &format!(
+                    "Dependency cycle: {} (none of these tasks can be claimed)",
+                    format_path(&cycle)
+                ),
+            )
+            .with_path(&cycle[0])
+            .with_remediation(&format!(
+                "Remove one dependency from the cycle, e.g.:\n\
+                 burl edit {} --remove-depends-on {}",
+                cycle[0], cycle[1]
+            )),
+        );
+    }
+
+    for node in graph.nodes() {
+        if let Some(parent) = &node.parent
+            && graph.get(parent).is_none()
+        {
+            report.issues.push(
+                Issue::new(
+                    IssueSeverity::Warning,
+                    "missing_parent",
+                    &format!(
+                        "Task {} has parent {} which does not exist in any bucket",
+                        node.id, parent
+                    ),
+                )
+                .with_path(&node.id)
+                .with_remediation(&format!("burl edit {} --clear-parent", node.id)),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether a task carries the metadata of an active claim.
+///
+/// Branch, worktree, and base_sha alone don't count: `burl release` keeps
+/// them on the READY task so the next claim can reuse the worktree.
+pub(super) fn is_claimed(fm: &TaskFrontmatter) -> bool {
+    fm.started_at.is_some() || fm.assigned_to.is_some()
+}
+
 /// Check for bucket/metadata mismatches.
 pub fn check_bucket_metadata_mismatches(
     ctx: &WorkflowContext,
@@ -321,18 +403,15 @@ pub fn check_bucket_metadata_mismatches(
 
         match task_info.bucket.as_str() {
             "READY" => {
-                // READY task should not have started_at, branch, worktree, or base_sha
-                if fm.started_at.is_some()
-                    || fm.branch.is_some()
-                    || fm.worktree.is_some()
-                    || fm.base_sha.is_some()
-                {
+                // READY task should not be claimed (a released task may keep
+                // its branch and worktree)
+                if is_claimed(fm) {
                     report.issues.push(
                         Issue::new(
                             IssueSeverity::Warning,
                             "bucket_mismatch",
                             &format!(
-                                "Task {} in READY has work-in-progress metadata (started_at/branch/worktree/base_sha)",
+                                "Task {} in READY has work-in-progress metadata (started_at/assigned_to)",
                                 task_info.id
                             ),
                         )
@@ -384,20 +463,18 @@ pub fn check_bucket_metadata_mismatches(
                     );
                 }
             }
-            "QA" => {
-                // QA task should not have completed_at
-                if fm.completed_at.is_some() {
-                    report.issues.push(
-                        Issue::new(
-                            IssueSeverity::Warning,
-                            "bucket_mismatch",
-                            &format!("Task {} in QA has completed_at set", task_info.id),
-                        )
-                        .with_path(&task_info.path.display().to_string())
-                        .with_remediation("Task should be moved to DONE if already completed")
-                        .repairable(),
-                    );
-                }
+            // QA task should not have completed_at
+            "QA" if fm.completed_at.is_some() => {
+                report.issues.push(
+                    Issue::new(
+                        IssueSeverity::Warning,
+                        "bucket_mismatch",
+                        &format!("Task {} in QA has completed_at set", task_info.id),
+                    )
+                    .with_path(&task_info.path.display().to_string())
+                    .with_remediation("Task should be moved to DONE if already completed")
+                    .repairable(),
+                );
             }
             "DONE" => {
                 // DONE is the terminal state, no further checks needed
@@ -1,21 +1,22 @@
 //! Display and reporting functions for the doctor command.
 
 use super::{DoctorReport, IssueSeverity};
+use crate::output::textln;
 
 /// Print the doctor report.
 pub fn print_report(report: &DoctorReport, repair_mode: bool) {
     if !report.has_issues() && report.repairs.is_empty() {
-        println!("Workflow is healthy. No issues detected.");
+        textln!("Workflow is healthy. No issues detected.");
         return;
     }
 
     // Print issues
     if !report.issues.is_empty() {
-        println!("Issues detected ({}):", report.issues.len());
-        println!();
+        textln!("Issues detected ({}):", report.issues.len());
+        textln!();
 
         for (i, issue) in report.issues.iter().enumerate() {
-            println!(
+            textln!(
                 "  {}. [{}] {} - {}",
                 i + 1,
                 issue.severity,
@@ -24,37 +25,37 @@ pub fn print_report(report: &DoctorReport, repair_mode: bool) {
             );
 
             if let Some(path) = &issue.path {
-                println!("     Path: {}", path);
+                textln!("     Path: {}", path);
             }
 
             if let Some(remediation) = &issue.remediation {
-                println!(
+                textln!(
                     "     Fix:  {}",
                     remediation.lines().next().unwrap_or(remediation)
                 );
                 for line in remediation.lines().skip(1) {
-                    println!("           {}", line);
+                    textln!("           {}", line);
                 }
             }
 
             if issue.repairable && !repair_mode {
-                println!("     (auto-repairable with --repair --force)");
+                textln!("     (auto-repairable with --repair --force)");
             }
 
-            println!();
+            textln!();
         }
     }
 
     // Print repairs
     if !report.repairs.is_empty() {
-        println!("Repairs applied ({}):", report.repairs.len());
-        println!();
+        textln!("Repairs applied ({}):", report.repairs.len());
+        textln!();
 
         for repair in &report.repairs {
-            println!("  - {}", repair);
+            textln!("  - {}", repair);
         }
 
-        println!();
+        textln!();
     }
 
     // Print summary
@@ -73,22 +74,26 @@ pub fn print_report(report: &DoctorReport, repair_mode: bool) {
     if repair_mode {
         let remaining = report.issues.iter().filter(|i| !i.repairable).count();
         if remaining > 0 {
-            println!(
+            textln!(
                 "Summary: {} issue(s) remain that cannot be auto-repaired ({} errors, {} warnings).",
-                remaining, error_count, warning_count
+                remaining,
+                error_count,
+                warning_count
             );
         } else if !report.repairs.is_empty() {
-            println!("All repairable issues have been fixed.");
+            textln!("All repairable issues have been fixed.");
         }
     } else {
-        println!(
+        textln!(
             "Summary: {} errors, {} warnings, {} auto-repairable.",
-            error_count, warning_count, repairable_count
+            error_count,
+            warning_count,
+            repairable_count
         );
 
         if repairable_count > 0 {
-            println!();
-            println!("Run `burl doctor --repair --force` to apply safe repairs.");
+            textln!();
+            textln!("Run `burl doctor --repair --force` to apply safe repairs.");
         }
     }
 }
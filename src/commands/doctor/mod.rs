@@ -12,6 +12,8 @@
 //! - Orphan worktrees under `.worktrees/` not referenced by any task
 //! - Tasks that reference a branch that does not exist locally
 //! - Bucket/metadata mismatches (e.g., READY task with `started_at` set)
+//! - `depends_on` references to missing tasks, dependency cycles, and `parent`
+//!   references to missing epics
 //!
 //! # Repair mode (`--repair --force`)
 //!
@@ -31,6 +33,8 @@ use crate::cli::DoctorArgs;
 use crate::config::Config;
 use crate::context::require_initialized_workflow;
 use crate::error::{BurlError, Result};
+use crate::output::{self, CommandResult, textln};
+use serde_json::json;
 
 pub use checks::*;
 pub use display::*;
@@ -127,6 +131,30 @@ impl DoctorReport {
     }
 }
 
+/// Build the `--output json` details for a doctor report.
+fn report_details(report: &DoctorReport, repair: bool) -> serde_json::Value {
+    let issues: Vec<_> = report
+        .issues
+        .iter()
+        .map(|issue| {
+            json!({
+                "severity": issue.severity.to_string().to_lowercase(),
+                "category": issue.category,
+                "description": issue.description,
+                "path": issue.path,
+                "remediation": issue.remediation,
+                "repairable": issue.repairable,
+            })
+        })
+        .collect();
+
+    json!({
+        "repair": repair,
+        "issues": issues,
+        "repairs": report.repairs,
+    })
+}
+
 /// Execute the `burl doctor` command.
 pub fn cmd_doctor(args: DoctorArgs) -> Result<()> {
     let ctx = require_initialized_workflow()?;
@@ -148,11 +176,12 @@ pub fn cmd_doctor(args: DoctorArgs) -> Result<()> {
     check_missing_directories(&ctx, &mut report)?;
     check_stale_locks(&ctx, &config, &mut report)?;
     check_orphan_locks(&ctx, &config, &mut report)?;
-    check_tasks_missing_base_sha(&ctx, &mut report)?;
-    check_tasks_missing_worktree(&ctx, &mut report)?;
+    check_tasks_missing_base_sha(&ctx, &config, &mut report)?;
+    check_tasks_missing_worktree(&ctx, &config, &mut report)?;
     check_orphan_worktrees(&ctx, &mut report)?;
     check_tasks_missing_branch(&ctx, &mut report)?;
     check_bucket_metadata_mismatches(&ctx, &mut report)?;
+    check_dependency_graph(&ctx, &mut report)?;
 
     // If repair mode, apply safe repairs
     if args.repair && args.force {
@@ -161,6 +190,7 @@ pub fn cmd_doctor(args: DoctorArgs) -> Result<()> {
 
     // Print report
     print_report(&report, args.repair);
+    output::record(CommandResult::new("doctor").with_details(report_details(&report, args.repair)));
 
     // Exit code: 0 if healthy (no issues or all repaired), 1 if issues remain
     if !args.repair && report.has_issues() {
@@ -182,7 +212,7 @@ pub fn cmd_doctor(args: DoctorArgs) -> Result<()> {
     }
 
     if report.has_issues() && !args.repair {
-        println!();
+        textln!();
     }
 
     Ok(())
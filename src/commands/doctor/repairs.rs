@@ -13,6 +13,7 @@ use std::fs;
 use std::path::{Path, PathBuf};
 
 use super::DoctorReport;
+use super::checks::is_claimed;
 
 /// Apply safe repairs based on detected issues.
 pub fn apply_repairs(
@@ -84,18 +85,17 @@ pub fn apply_repairs(
                     let path = PathBuf::from(path);
                     if let Ok(task) = TaskFile::load(&path) {
                         let fm = &task.frontmatter;
-                        let current_bucket = get_bucket_from_path(&path);
+                        // Only built-in buckets have metadata to check
+                        // against; tasks in custom buckets stay put.
+                        let current_bucket = get_bucket_from_path(&path, &config.states.buckets)
+                            .filter(|bucket| BUCKETS.contains(bucket));
 
                         // Determine the correct bucket based on metadata
                         let target_bucket = if fm.completed_at.is_some() {
                             Some("DONE")
                         } else if fm.submitted_at.is_some() {
                             Some("QA")
-                        } else if fm.started_at.is_some()
-                            || fm.branch.is_some()
-                            || fm.worktree.is_some()
-                            || fm.base_sha.is_some()
-                        {
+                        } else if is_claimed(fm) {
                             Some("DOING")
                         } else {
                             None
@@ -161,17 +161,17 @@ pub fn apply_repairs(
     Ok(())
 }
 
-/// Get the bucket name from a task file path.
-pub fn get_bucket_from_path(path: &Path) -> Option<&'static str> {
+/// Get the bucket name (one of `buckets`, i.e. `states.buckets`) from a task
+/// file path.
+pub fn get_bucket_from_path<'a>(path: &Path, buckets: &'a [String]) -> Option<&'a str> {
     let path_str = path.to_string_lossy();
-    for bucket in BUCKETS {
-        if path_str.contains(&format!("/{}/", bucket))
-            || path_str.contains(&format!("\\{}\\", bucket))
-        {
-            return Some(bucket);
-        }
-    }
-    None
+    buckets
+        .iter()
+        .find(|bucket| {
+            path_str.contains(&format!("/{}/", bucket))
+                || path_str.contains(&format!("\\{}\\", bucket))
+        })
+        .map(String::as_str)
 }
 
 /// Commit the repairs to the workflow branch.
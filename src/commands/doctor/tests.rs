@@ -28,7 +28,7 @@ fn test_doctor_healthy_workflow() {
     // Run checks that shouldn't find issues in a fresh workflow
     check_stale_locks(&ctx, &config, &mut report).unwrap();
     check_orphan_locks(&ctx, &config, &mut report).unwrap();
-    check_tasks_missing_base_sha(&ctx, &mut report).unwrap();
+    check_tasks_missing_base_sha(&ctx, &config, &mut report).unwrap();
 
     // Fresh workflow should have no stale locks, orphan locks, or tasks
     let stale_or_orphan = report
@@ -78,6 +78,52 @@ fn test_doctor_detects_stale_lock() {
     assert!(stale_issues[0].repairable);
 }
 
+#[test]
+#[serial]
+fn test_doctor_detects_dependency_problems() {
+    let temp_dir = create_test_repo();
+    let _guard = DirGuard::new(temp_dir.path());
+
+    cmd_init().unwrap();
+    let ctx = require_initialized_workflow().unwrap();
+
+    // Hand-edited task files can bypass add/edit validation.
+    for (id, deps) in [
+        ("TASK-001", vec!["TASK-002"]),
+        ("TASK-002", vec!["TASK-001"]),
+        ("TASK-003", vec!["TASK-099"]),
+    ] {
+        let task = crate::task::TaskFile {
+            frontmatter: crate::task::TaskFrontmatter {
+                id: id.to_string(),
+                title: id.to_string(),
+                depends_on: deps.iter().map(|d| d.to_string()).collect(),
+                parent: (id == "TASK-003").then(|| "TASK-098".to_string()),
+                ..Default::default()
+            },
+            body: String::new(),
+        };
+        task.save(ctx.bucket_path("READY").join(format!("{}-x.md", id)))
+            .unwrap();
+    }
+
+    let mut report = DoctorReport::new();
+    check_dependency_graph(&ctx, &mut report).unwrap();
+
+    let categories: Vec<_> = report.issues.iter().map(|i| i.category.as_str()).collect();
+    assert_eq!(
+        categories,
+        vec!["dangling_dependency", "dependency_cycle", "missing_parent"]
+    );
+    assert!(report.issues[0].description.contains("TASK-099"));
+    assert!(
+        report.issues[1]
+            .description
+            .contains("TASK-001 -> TASK-002 -> TASK-001")
+    );
+    assert!(report.has_errors());
+}
+
 #[test]
 #[serial]
 fn test_doctor_repair_clears_stale_lock() {
@@ -190,21 +236,81 @@ fn test_doctor_report_has_errors() {
 fn test_get_bucket_from_path() {
     use super::repairs::get_bucket_from_path;
 
+    let buckets: Vec<String> = ["READY", "DOING", "QA", "REVIEW", "DONE", "BLOCKED"]
+        .iter()
+        .map(|b| b.to_string())
+        .collect();
+
     let path_ready = PathBuf::from("/some/path/.workflow/READY/TASK-001.md");
-    assert_eq!(get_bucket_from_path(&path_ready), Some("READY"));
+    assert_eq!(get_bucket_from_path(&path_ready, &buckets), Some("READY"));
 
     let path_doing = PathBuf::from("/some/path/.workflow/DOING/TASK-001.md");
-    assert_eq!(get_bucket_from_path(&path_doing), Some("DOING"));
+    assert_eq!(get_bucket_from_path(&path_doing, &buckets), Some("DOING"));
 
     let path_qa = PathBuf::from("/some/path/.workflow/QA/TASK-001.md");
-    assert_eq!(get_bucket_from_path(&path_qa), Some("QA"));
+    assert_eq!(get_bucket_from_path(&path_qa, &buckets), Some("QA"));
+
+    let path_review = PathBuf::from("/some/path/.workflow/REVIEW/TASK-001.md");
+    assert_eq!(get_bucket_from_path(&path_review, &buckets), Some("REVIEW"));
+    assert_eq!(get_bucket_from_path(&path_review, &buckets[..3]), None);
 
     let path_done = PathBuf::from("/some/path/.workflow/DONE/TASK-001.md");
-    assert_eq!(get_bucket_from_path(&path_done), Some("DONE"));
+    assert_eq!(get_bucket_from_path(&path_done, &buckets), Some("DONE"));
 
     let path_blocked = PathBuf::from("/some/path/.workflow/BLOCKED/TASK-001.md");
-    assert_eq!(get_bucket_from_path(&path_blocked), Some("BLOCKED"));
+    assert_eq!(
+        get_bucket_from_path(&path_blocked, &buckets),
+        Some("BLOCKED")
+    );
 
     let path_unknown = PathBuf::from("/some/path/OTHER/TASK-001.md");
-    assert_eq!(get_bucket_from_path(&path_unknown), None);
+    assert_eq!(get_bucket_from_path(&path_unknown, &buckets), None);
+}
+
+#[test]
+#[serial]
+fn test_doctor_checks_worktrees_of_tasks_in_custom_buckets() {
+    let temp_dir = create_test_repo();
+    let _guard = DirGuard::new(temp_dir.path());
+    cmd_init().unwrap();
+
+    let ctx = require_initialized_workflow().unwrap();
+    std::fs::write(
+        ctx.config_path(),
+        "states:\n  buckets: [READY, DOING, QA, REVIEW, STAGING, DONE, BLOCKED]\n  approve_from: [REVIEW]\n",
+    )
+    .unwrap();
+    let config = Config::load(ctx.config_path()).unwrap();
+
+    for (bucket, id) in [("REVIEW", "TASK-001"), ("STAGING", "TASK-002")] {
+        let dir = ctx.bucket_path(bucket);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join(format!("{}-moved.md", id)),
+            format!(
+                "---\nid: {}\ntitle: Moved\nbranch: {}-moved\nworktree: .worktrees/{}-moved\n---\n",
+                id,
+                id.to_lowercase(),
+                id.to_lowercase()
+            ),
+        )
+        .unwrap();
+    }
+
+    let mut report = DoctorReport::new();
+    check_tasks_missing_base_sha(&ctx, &config, &mut report).unwrap();
+    check_tasks_missing_worktree(&ctx, &config, &mut report).unwrap();
+
+    for category in ["missing_base_sha", "missing_worktree"] {
+        let mut messages: Vec<&str> = report
+            .issues
+            .iter()
+            .filter(|i| i.category == category)
+            .map(|i| i.description.as_str())
+            .collect();
+        messages.sort();
+        assert_eq!(messages.len(), 2, "{}", category);
+        assert!(messages[0].starts_with("Task TASK-001 in REVIEW"));
+        assert!(messages[1].starts_with("Task TASK-002 in STAGING"));
+    }
 }
@@ -0,0 +1,515 @@
+//! Implementation of the `burl edit` command.
+//!
+//! Applies typed frontmatter mutations to an existing task, enforcing which
+//! fields may change in the task's current bucket, then commits workflow state
+//! and records an `edit` event with a before/after diff.
+
+use crate::agent::AgentsConfig;
+use crate::cli::EditArgs;
+use crate::commands::add::{validate_parent, validate_priority};
+use crate::config::Config;
+use crate::context::{WorkflowContext, require_initialized_workflow};
+use crate::error::{BurlError, Result};
+use crate::events::{Event, EventAction, append_event};
+use crate::git::run_git;
+use crate::graph::{DependencyGraph, format_path};
+use crate::locks::{acquire_task_lock, acquire_workflow_lock};
+use crate::task::{FrontmatterEdit, TaskFile, frontmatter_diff};
+use crate::workflow::{TaskIndex, validate_task_id};
+use globset::Glob;
+use serde_json::json;
+
+/// Execute the `burl edit` command.
+///
+/// Edits are validated up front (priority, globs, dependency IDs, agent and
+/// validation profile names) and checked against the task's bucket before any
+/// state is touched. Edits that leave the frontmatter unchanged are a no-op.
+pub fn cmd_edit(args: EditArgs) -> Result<()> {
+    let ctx = require_initialized_workflow()?;
+    let config = Config::load(ctx.config_path()).unwrap_or_default();
+
+    let task_id = validate_task_id(&args.task_id)?;
+    let edits = collect_edits(&ctx, &config, &task_id, args)?;
+
+    if edits.is_empty() {
+        return Err(BurlError::UserError(
+            "no edits specified.\n\n\
+             Usage: burl edit TASK-ID [--priority ...] [--add-tag ...] [--agent ...] ...\n\
+             Run `burl edit --help` to see all available edits."
+                .to_string(),
+        ));
+    }
+
+    let index = TaskIndex::build(&ctx)?;
+    let task_info = index.find(&task_id).ok_or_else(|| {
+        BurlError::UserError(format!(
+            "task '{}' not found.\n\n\
+             Searched buckets: {}\n\n\
+             Use `burl status` to see all tasks.",
+            task_id,
+            index.buckets().join(", ")
+        ))
+    })?;
+
+    let graph = DependencyGraph::build(&index)?;
+    for edit in &edits {
+        check_edit_allowed(&task_info.bucket, edit)?;
+        if let FrontmatterEdit::AddDependency(dep) = edit {
+            if index.find(dep).is_none() {
+                return Err(BurlError::UserError(format!(
+                    "cannot add dependency '{}': task not found.",
+                    dep
+                )));
+            }
+            if let Some(cycle) = graph.cycle_if_added(&task_id, dep) {
+                return Err(BurlError::UserError(format!(
+                    "cannot add dependency '{}': it would create a cycle: {}",
+                    dep,
+                    format_path(&cycle)
+                )));
+            }
+        }
+        if let FrontmatterEdit::Parent(Some(parent)) = edit {
+            validate_parent(&graph, &task_id, parent)?;
+        }
+    }
+
+    // ========================================================================
+    // Apply edits under task + workflow locks
+    // ========================================================================
+
+    let _task_lock = acquire_task_lock(&ctx, &task_info.id, "edit")?;
+
+    ctx.ensure_workflow_clean()?;
+    let _workflow_lock = acquire_workflow_lock(&ctx, "edit")?;
+
+    let mut task_file = TaskFile::load(&task_info.path)?;
+    let before = task_file.frontmatter.clone();
+
+    let mut changed = false;
+    for edit in &edits {
+        changed |= task_file.apply_edit(edit);
+    }
+
+    if !changed {
+        println!("No changes to task {}.", task_id);
+        return Ok(());
+    }
+
+    let diff = frontmatter_diff(&before, &task_file.frontmatter)?;
+    let changed_fields: Vec<String> = diff
+        .as_object()
+        .map(|m| m.keys().cloned().collect())
+        .unwrap_or_default();
+
+    task_file.save(&task_info.path)?;
+
+    let event = Event::new(EventAction::Edit)
+        .with_task(&task_id)
+        .with_details(json!({
+            "title": task_file.frontmatter.title,
+            "bucket": task_info.bucket,
+            "changes": diff
+        }));
+    append_event(&ctx, &event)?;
+
+    if config.workflow_auto_commit {
+        commit_edit(&ctx, &task_id, &changed_fields)?;
+
+        if config.workflow_auto_push {
+            push_workflow_branch(&ctx, &config)?;
+        }
+    }
+
+    println!("Edited task: {}", task_id);
+    println!();
+    println!("  Bucket:  {}", task_info.bucket);
+    println!("  Changed: {}", changed_fields.join(", "));
+
+    Ok(())
+}
+
+/// Translate CLI arguments into validated, typed edits.
+fn collect_edits(
+    ctx: &WorkflowContext,
+    config: &Config,
+    task_id: &str,
+    args: EditArgs,
+) -> Result<Vec<FrontmatterEdit>> {
+    let mut edits = Vec::new();
+
+    if let Some(title) = args.title {
+        let title = title.trim();
+        if title.is_empty() {
+            return Err(BurlError::UserError("title cannot be empty".to_string()));
+        }
+        edits.push(FrontmatterEdit::Title(title.to_string()));
+    }
+
+    if let Some(priority) = args.priority {
+        edits.push(FrontmatterEdit::Priority(validate_priority(&priority)?));
+    }
+
+    edits.extend(
+        args.add_affects
+            .into_iter()
+            .map(FrontmatterEdit::AddAffects),
+    );
+    edits.extend(
+        args.remove_affects
+            .into_iter()
+            .map(FrontmatterEdit::RemoveAffects),
+    );
+
+    for pattern in &args.add_affects_globs {
+        validate_glob("affects_globs", pattern)?;
+    }
+    edits.extend(
+        args.add_affects_globs
+            .into_iter()
+            .map(FrontmatterEdit::AddAffectsGlob),
+    );
+    edits.extend(
+        args.remove_affects_globs
+            .into_iter()
+            .map(FrontmatterEdit::RemoveAffectsGlob),
+    );
+
+    for pattern in &args.add_must_not_touch {
+        validate_glob("must_not_touch", pattern)?;
+    }
+    edits.extend(
+        args.add_must_not_touch
+            .into_iter()
+            .map(FrontmatterEdit::AddMustNotTouch),
+    );
+    edits.extend(
+        args.remove_must_not_touch
+            .into_iter()
+            .map(FrontmatterEdit::RemoveMustNotTouch),
+    );
+
+    for dep in &args.add_depends_on {
+        let dep = validate_task_id(dep)?;
+        if dep == task_id {
+            return Err(BurlError::UserError(format!(
+                "task '{}' cannot depend on itself.",
+                task_id
+            )));
+        }
+        edits.push(FrontmatterEdit::AddDependency(dep));
+    }
+    for dep in &args.remove_depends_on {
+        edits.push(FrontmatterEdit::RemoveDependency(dep.to_uppercase()));
+    }
+
+    if let Some(parent) = &args.parent {
+        edits.push(FrontmatterEdit::Parent(Some(validate_task_id(parent)?)));
+    } else if args.clear_parent {
+        edits.push(FrontmatterEdit::Parent(None));
+    }
+
+    edits.extend(args.add_tag.into_iter().map(FrontmatterEdit::AddTag));
+    edits.extend(args.remove_tag.into_iter().map(FrontmatterEdit::RemoveTag));
+
+    if let Some(agent) = args.agent {
+        validate_agent(ctx, &agent)?;
+        edits.push(FrontmatterEdit::Agent(Some(agent)));
+    } else if args.clear_agent {
+        edits.push(FrontmatterEdit::Agent(None));
+    }
+
+    if let Some(profile) = args.validation_profile {
+        validate_profile(config, &profile)?;
+        edits.push(FrontmatterEdit::ValidationProfile(Some(profile)));
+    } else if args.clear_validation_profile {
+        edits.push(FrontmatterEdit::ValidationProfile(None));
+    }
+
+    if let Some(sha) = args.base_sha {
+        let sha = sha.trim();
+        if sha.is_empty() || !sha.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(BurlError::UserError(format!(
+                "invalid base SHA '{}': must be a hexadecimal commit id.",
+                sha
+            )));
+        }
+        edits.push(FrontmatterEdit::BaseSha(sha.to_lowercase()));
+    }
+
+    Ok(edits)
+}
+
+/// Refuse edits that don't make sense for the task's current bucket.
+///
+/// - DONE tasks are history: only title and tags may change.
+/// - `base_sha` anchors diff-based validation, so it is frozen once work is
+///   in flight (DOING/QA).
+/// - Dependencies are enforced at claim time, so they can only change before
+///   the task is claimed (READY/BLOCKED).
+fn check_edit_allowed(bucket: &str, edit: &FrontmatterEdit) -> Result<()> {
+    let allowed = match (bucket, edit) {
+        ("DONE", FrontmatterEdit::Title(_))
+        | ("DONE", FrontmatterEdit::AddTag(_))
+        | ("DONE", FrontmatterEdit::RemoveTag(_)) => true,
+        ("DONE", _) => false,
+        ("DOING" | "QA", FrontmatterEdit::BaseSha(_)) => false,
+        (
+            "DOING" | "QA",
+            FrontmatterEdit::AddDependency(_) | FrontmatterEdit::RemoveDependency(_),
+        ) => false,
+        _ => true,
+    };
+
+    if allowed {
+        return Ok(());
+    }
+
+    let hint = match (bucket, edit) {
+        ("DONE", _) => "DONE tasks only allow title and tag edits.",
+        (_, FrontmatterEdit::BaseSha(_)) => {
+            "base_sha anchors diff-based validation and cannot change while work is in progress."
+        }
+        _ => "Dependencies are checked at claim time and can only change in READY or BLOCKED.",
+    };
+
+    Err(BurlError::UserError(format!(
+        "cannot edit '{}' on a task in {}.\n\n{}",
+        edit.field(),
+        bucket,
+        hint
+    )))
+}
+
+/// Validate a scope glob pattern.
+pub(crate) fn validate_glob(field: &str, pattern: &str) -> Result<()> {
+    let pattern = pattern.trim();
+    if pattern.is_empty() {
+        return Err(BurlError::UserError(format!(
+            "{} entries must be non-empty",
+            field
+        )));
+    }
+    Glob::new(pattern).map_err(|e| {
+        BurlError::UserError(format!("invalid glob in {}: '{}' ({})", field, pattern, e))
+    })?;
+    Ok(())
+}
+
+/// Validate that a validation profile is configured.
+pub(crate) fn validate_profile(config: &Config, profile: &str) -> Result<()> {
+    if config.validation_profiles.contains_key(profile) {
+        return Ok(());
+    }
+    Err(BurlError::UserError(format!(
+        "unknown validation profile '{}'.\n\n\
+         Configured profiles: {}",
+        profile,
+        if config.validation_profiles.is_empty() {
+            "(none)".to_string()
+        } else {
+            config
+                .validation_profiles
+                .keys()
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(", ")
+        }
+    )))
+}
+
+/// Validate that an agent exists when `agents.yaml` is configured.
+pub(crate) fn validate_agent(ctx: &WorkflowContext, agent: &str) -> Result<()> {
+    if agent.trim().is_empty() {
+        return Err(BurlError::UserError("agent cannot be empty".to_string()));
+    }
+
+    if let Some(agents) = AgentsConfig::load(ctx.agents_config_path())?
+        && agents.has_agents()
+        && agents.get(agent).is_none()
+    {
+        return Err(BurlError::UserError(format!(
+            "unknown agent '{}'.\n\n\
+             Use `burl agent list` to see configured agents.",
+            agent
+        )));
+    }
+
+    Ok(())
+}
+
+/// Commit the edit to the workflow branch.
+fn commit_edit(ctx: &WorkflowContext, task_id: &str, fields: &[String]) -> Result<()> {
+    run_git(&ctx.workflow_worktree, &["add", "."])
+        .map_err(|e| BurlError::GitError(format!("failed to stage edit changes: {}", e)))?;
+
+    let staged = run_git(&ctx.workflow_worktree, &["diff", "--cached", "--name-only"])?;
+    if staged.stdout.is_empty() {
+        return Ok(());
+    }
+
+    let commit_msg = format!("Edit task {}: {}", task_id, fields.join(", "));
+
+    run_git(&ctx.workflow_worktree, &["commit", "-m", &commit_msg])
+        .map_err(|e| BurlError::GitError(format!("failed to commit edit: {}", e)))?;
+
+    Ok(())
+}
+
+/// Push the workflow branch to the remote.
+fn push_workflow_branch(ctx: &WorkflowContext, config: &Config) -> Result<()> {
+    run_git(
+        &ctx.workflow_worktree,
+        &["push", &config.remote, &config.workflow_branch],
+    )
+    .map_err(|e| BurlError::GitError(format!("failed to push workflow branch: {}", e)))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::AddArgs;
+    use crate::commands::add::cmd_add;
+    use crate::commands::init::cmd_init;
+    use crate::events::Event;
+    use crate::exit_codes;
+    use crate::test_support::{DirGuard, create_test_repo};
+    use serial_test::serial;
+
+    fn add_task(title: &str, tags: Vec<String>) {
+        cmd_add(AddArgs {
+            title: title.to_string(),
+            priority: "medium".to_string(),
+            affects: vec![],
+            affects_globs: vec![],
+            must_not_touch: vec![],
+            depends_on: vec![],
+            tags,
+            parent: None,
+            template: None,
+            kind: None,
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn test_check_edit_allowed_policy() {
+        let sha = FrontmatterEdit::BaseSha("abc123".to_string());
+        assert!(check_edit_allowed("READY", &sha).is_ok());
+        assert!(check_edit_allowed("DOING", &sha).is_err());
+        assert!(check_edit_allowed("QA", &sha).is_err());
+
+        let dep = FrontmatterEdit::AddDependency("TASK-002".to_string());
+        assert!(check_edit_allowed("BLOCKED", &dep).is_ok());
+        assert!(check_edit_allowed("DOING", &dep).is_err());
+
+        let tag = FrontmatterEdit::AddTag("x".to_string());
+        assert!(check_edit_allowed("DONE", &tag).is_ok());
+        let priority = FrontmatterEdit::Priority("high".to_string());
+        assert!(check_edit_allowed("DONE", &priority).is_err());
+        assert!(check_edit_allowed("QA", &priority).is_ok());
+    }
+
+    #[test]
+    #[serial]
+    fn test_edit_applies_mutations_and_logs_event() {
+        let temp_dir = create_test_repo();
+        let _guard = DirGuard::new(temp_dir.path());
+
+        cmd_init().unwrap();
+        add_task("Base task", vec![]);
+        add_task("Edit me", vec!["wip".to_string()]);
+
+        cmd_edit(EditArgs {
+            task_id: "task-002".to_string(),
+            priority: Some("HIGH".to_string()),
+            add_affects_globs: vec!["src/net/**".to_string()],
+            add_depends_on: vec!["TASK-001".to_string()],
+            remove_tag: vec!["wip".to_string()],
+            ..Default::default()
+        })
+        .unwrap();
+
+        let ctx = require_initialized_workflow().unwrap();
+        let task_path = ctx.bucket_path("READY").join("TASK-002-edit-me.md");
+        let task = TaskFile::load(&task_path).unwrap();
+        assert_eq!(task.frontmatter.priority, "high");
+        assert_eq!(task.frontmatter.affects_globs, vec!["src/net/**"]);
+        assert_eq!(task.frontmatter.depends_on, vec!["TASK-001"]);
+        assert!(task.frontmatter.tags.is_empty());
+
+        let events = std::fs::read_to_string(ctx.events_file()).unwrap();
+        let last: Event = serde_json::from_str(events.lines().last().unwrap()).unwrap();
+        assert_eq!(last.action, EventAction::Edit);
+        assert_eq!(last.details["changes"]["priority"]["before"], "medium");
+        assert_eq!(last.details["changes"]["priority"]["after"], "high");
+
+        // Workflow worktree should be committed (clean).
+        let status = run_git(&ctx.workflow_worktree, &["status", "--porcelain"]).unwrap();
+        assert!(status.stdout.is_empty());
+    }
+
+    #[test]
+    #[serial]
+    fn test_edit_rejects_unknown_dependency() {
+        let temp_dir = create_test_repo();
+        let _guard = DirGuard::new(temp_dir.path());
+
+        cmd_init().unwrap();
+        add_task("Edit me", vec![]);
+
+        let err = cmd_edit(EditArgs {
+            task_id: "TASK-001".to_string(),
+            add_depends_on: vec!["TASK-999".to_string()],
+            ..Default::default()
+        })
+        .unwrap_err();
+        assert_eq!(err.exit_code(), exit_codes::USER_ERROR);
+        assert!(err.to_string().contains("not found"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_edit_rejects_dependency_cycle() {
+        let temp_dir = create_test_repo();
+        let _guard = DirGuard::new(temp_dir.path());
+
+        cmd_init().unwrap();
+        add_task("First", vec![]);
+        add_task("Second", vec![]);
+        cmd_edit(EditArgs {
+            task_id: "TASK-002".to_string(),
+            add_depends_on: vec!["TASK-001".to_string()],
+            ..Default::default()
+        })
+        .unwrap();
+
+        let err = cmd_edit(EditArgs {
+            task_id: "TASK-001".to_string(),
+            add_depends_on: vec!["TASK-002".to_string()],
+            ..Default::default()
+        })
+        .unwrap_err();
+        assert_eq!(err.exit_code(), exit_codes::USER_ERROR);
+        assert!(err.to_string().contains("TASK-001 -> TASK-002 -> TASK-001"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_edit_requires_at_least_one_edit() {
+        let temp_dir = create_test_repo();
+        let _guard = DirGuard::new(temp_dir.path());
+
+        cmd_init().unwrap();
+        add_task("Edit me", vec![]);
+
+        let err = cmd_edit(EditArgs {
+            task_id: "TASK-001".to_string(),
+            ..Default::default()
+        })
+        .unwrap_err();
+        assert!(err.to_string().contains("no edits specified"));
+    }
+}
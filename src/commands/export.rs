@@ -0,0 +1,355 @@
+//! Implementation of the `burl export` command.
+//!
+//! Writes the workflow state as a single portable document (JSON, YAML, or
+//! CSV) for reporting and for migrating tasks between repositories.
+//!
+//! Each task record is the `burl list` record (frontmatter plus `bucket` and
+//! `path`) extended with the body sections scaffolded by `burl add`, parsed
+//! with [`extract_sections`]. Sections that still hold only their template
+//! placeholder are exported as `null` (acceptance criteria as an empty list).
+//!
+//! Records use the same field names as `burl import` plan entries, so a JSON
+//! or YAML export can be imported into another workflow (tasks are recreated
+//! in READY with fresh IDs and their mutual `depends_on` links preserved).
+
+use crate::agent::prompt::extract_sections;
+use crate::cli::ExportArgs;
+use crate::commands::add::ACCEPTANCE_CRITERIA_PLACEHOLDER;
+use crate::commands::list::{
+    CSV_COLUMNS, ListedTask, bucket_order, csv_cell, csv_escape, parse_buckets, task_record,
+    to_json,
+};
+use crate::context::{WorkflowContext, require_initialized_workflow};
+use crate::error::{BurlError, Result};
+use crate::events::{Event, read_events};
+use crate::task::TaskFile;
+use crate::workflow::{TaskIndex, TaskInfo, task_key};
+use chrono::Utc;
+use serde_json::{Map, Value, json};
+use std::collections::HashMap;
+
+/// Version of the export document layout.
+const EXPORT_SCHEMA_VERSION: u32 = 1;
+
+/// Body sections included in each record, as (section key, record field).
+const BODY_SECTIONS: &[(&str, &str)] = &[
+    ("objective", "objective"),
+    ("acceptance_criteria", "acceptance_criteria"),
+    ("context", "context"),
+    ("implementation_notes", "implementation_notes"),
+    ("qa_report", "qa_report"),
+];
+
+/// Extra CSV columns appended after the `burl list` columns.
+const EXTRA_CSV_COLUMNS: &[&str] = &[
+    "affects",
+    "affects_globs",
+    "must_not_touch",
+    "objective",
+    "acceptance_criteria",
+    "context",
+    "implementation_notes",
+    "qa_report",
+];
+
+/// Execute the `burl export` command.
+pub fn cmd_export(args: ExportArgs) -> Result<()> {
+    let ctx = require_initialized_workflow()?;
+    let index = TaskIndex::build(&ctx)?;
+    let buckets = parse_buckets(&args.bucket, index.buckets())?;
+    let mut infos: Vec<&TaskInfo> = index
+        .all_tasks()
+        .filter(|t| buckets.is_empty() || buckets.contains(&t.bucket))
+        .collect();
+    infos.sort_by_key(|t| (bucket_order(index.buckets(), &t.bucket), task_key(&t.id)));
+
+    let mut events_by_task: HashMap<String, Vec<Event>> = HashMap::new();
+    if args.include_events {
+        for event in read_events(&ctx)? {
+            if let Some(task) = &event.task {
+                events_by_task.entry(task.clone()).or_default().push(event);
+            }
+        }
+    }
+
+    let mut records = Vec::with_capacity(infos.len());
+    for info in infos {
+        let listed = ListedTask {
+            info,
+            task: TaskFile::load(&info.path)?,
+        };
+        let events = args
+            .include_events
+            .then(|| events_by_task.remove(&info.id).unwrap_or_default());
+        records.push(export_record(&ctx, &listed, events)?);
+    }
+
+    match args.format.as_str() {
+        "csv" => print!("{}", render_csv(&records, args.include_events)),
+        "yaml" => print!("{}", to_yaml(&export_document(records))?),
+        _ => println!("{}", to_json(&export_document(records), true)?),
+    }
+
+    Ok(())
+}
+
+/// Wrap task records in the versioned export document.
+fn export_document(records: Vec<Value>) -> Value {
+    json!({
+        "schema_version": EXPORT_SCHEMA_VERSION,
+        "exported_at": Utc::now(),
+        "tasks": records,
+    })
+}
+
+/// Build the export record for a task: the list record plus body sections
+/// and, when requested, its event history.
+fn export_record(
+    ctx: &WorkflowContext,
+    listed: &ListedTask,
+    events: Option<Vec<Event>>,
+) -> Result<Value> {
+    let mut record = match task_record(ctx, listed)? {
+        Value::Object(map) => map,
+        _ => Map::new(),
+    };
+
+    let sections = extract_sections(&listed.task.body);
+    for (key, field) in BODY_SECTIONS {
+        let content = sections.get(*key).and_then(|c| section_content(c));
+        let value = match (*key, content) {
+            ("acceptance_criteria", content) => {
+                json!(content.map(criteria_items).unwrap_or_default())
+            }
+            (_, Some(content)) => Value::String(content.to_string()),
+            (_, None) => Value::Null,
+        };
+        record.insert(field.to_string(), value);
+    }
+
+    if let Some(events) = events {
+        let events = serde_json::to_value(events).map_err(|e| {
+            BurlError::UserError(format!(
+                "failed to serialize events for '{}': {}",
+                listed.info.id, e
+            ))
+        })?;
+        record.insert("events".to_string(), events);
+    }
+
+    Ok(Value::Object(record))
+}
+
+/// Section content, or `None` if it is only a template placeholder comment
+/// or the `burl add` scaffold criteria.
+fn section_content(content: &str) -> Option<&str> {
+    let trimmed = content.trim();
+    let placeholder = (trimmed.starts_with("<!--")
+        && trimmed.ends_with("-->")
+        && trimmed.matches("-->").count() == 1)
+        || trimmed == ACCEPTANCE_CRITERIA_PLACEHOLDER;
+    (!trimmed.is_empty() && !placeholder).then_some(trimmed)
+}
+
+/// Split an acceptance-criteria section into items, keeping checkbox state.
+fn criteria_items(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(|l| {
+            l.strip_prefix("- ")
+                .or_else(|| l.strip_prefix("* "))
+                .unwrap_or(l)
+                .to_string()
+        })
+        .collect()
+}
+
+fn to_yaml(value: &Value) -> Result<String> {
+    serde_yaml::to_string(value)
+        .map_err(|e| BurlError::UserError(format!("failed to serialize YAML: {}", e)))
+}
+
+/// Render records as CSV: the `burl list` columns, scope and body columns,
+/// and (with events) an `events` column holding the event history as JSON.
+fn render_csv(records: &[Value], include_events: bool) -> String {
+    let mut columns: Vec<&str> = CSV_COLUMNS
+        .iter()
+        .chain(EXTRA_CSV_COLUMNS)
+        .copied()
+        .collect();
+    if include_events {
+        columns.push("events");
+    }
+
+    let mut out = String::new();
+    out.push_str(&columns.join(","));
+    out.push('\n');
+
+    for record in records {
+        let row: Vec<String> = columns
+            .iter()
+            .map(|col| match (*col, record.get(*col)) {
+                ("events", Some(events)) => csv_escape(&events.to_string()),
+                (_, value) => csv_escape(&csv_cell(value)),
+            })
+            .collect();
+        out.push_str(&row.join(","));
+        out.push('\n');
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{AddArgs, ImportArgs};
+    use crate::commands::add::cmd_add;
+    use crate::commands::import::cmd_import;
+    use crate::commands::init::cmd_init;
+    use crate::test_support::{DirGuard, create_test_repo};
+    use serial_test::serial;
+
+    fn collect_records(ctx: &WorkflowContext, include_events: bool) -> Vec<Value> {
+        let index = TaskIndex::build(ctx).unwrap();
+        let mut infos: Vec<&TaskInfo> = index.all_tasks().collect();
+        infos.sort_by_key(|t| task_key(&t.id));
+        let events = read_events(ctx).unwrap();
+        infos
+            .into_iter()
+            .map(|info| {
+                let listed = ListedTask {
+                    info,
+                    task: TaskFile::load(&info.path).unwrap(),
+                };
+                let task_events = include_events.then(|| {
+                    events
+                        .iter()
+                        .filter(|e| e.task.as_deref() == Some(&info.id))
+                        .cloned()
+                        .collect()
+                });
+                export_record(ctx, &listed, task_events).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_section_content_skips_placeholders() {
+        assert_eq!(section_content("<!-- Worker fills -->"), None);
+        assert_eq!(section_content("  \n"), None);
+        assert_eq!(section_content("Make it jump.\n"), Some("Make it jump."));
+        assert_eq!(
+            criteria_items("- [ ] One\n- [x] Two\nThree"),
+            vec!["[ ] One", "[x] Two", "Three"]
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_export_record_includes_sections_and_events() {
+        let temp_dir = create_test_repo();
+        let _guard = DirGuard::new(temp_dir.path());
+        cmd_init().unwrap();
+        cmd_add(AddArgs {
+            title: "Player jump".to_string(),
+            priority: "high".to_string(),
+            affects: vec![],
+            affects_globs: vec!["src/player/**".to_string()],
+            must_not_touch: vec![],
+            depends_on: vec![],
+            tags: vec!["player".to_string()],
+            parent: None,
+            template: None,
+            kind: None,
+        })
+        .unwrap();
+
+        let ctx = require_initialized_workflow().unwrap();
+        let records = collect_records(&ctx, true);
+        assert_eq!(records.len(), 1);
+        let record = &records[0];
+        assert_eq!(record["id"], "TASK-001");
+        assert_eq!(record["bucket"], "READY");
+        assert!(record["objective"].is_null());
+        assert_eq!(record["acceptance_criteria"], json!([]));
+        assert_eq!(record["events"][0]["action"], "add");
+
+        let csv = render_csv(&records, true);
+        let header = csv.lines().next().unwrap();
+        assert!(header.starts_with("id,bucket,title"));
+        assert!(header.ends_with(",qa_report,events"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_export_round_trips_through_import() {
+        let temp_dir = create_test_repo();
+        let guard = DirGuard::new(temp_dir.path());
+        cmd_init().unwrap();
+
+        let plan = temp_dir.path().join("plan.yaml");
+        std::fs::write(
+            &plan,
+            r#"
+tasks:
+  - ref: physics
+    title: Physics step
+    affects_globs: ["src/physics/**"]
+    context: Fixed timestep.
+  - title: Player jump
+    priority: high
+    depends_on: [physics]
+    objective: Space makes the player jump.
+    acceptance_criteria: ["[x] Jump height is configurable", Has tests]
+"#,
+        )
+        .unwrap();
+        cmd_import(ImportArgs {
+            file: plan.to_string_lossy().to_string(),
+            format: None,
+        })
+        .unwrap();
+
+        let ctx = require_initialized_workflow().unwrap();
+        let original = collect_records(&ctx, false);
+        let exported = temp_dir.path().join("export.yaml");
+        std::fs::write(
+            &exported,
+            to_yaml(&export_document(original.clone())).unwrap(),
+        )
+        .unwrap();
+
+        // Import the export into a fresh workflow.
+        drop(guard);
+        let target = create_test_repo();
+        let _guard = DirGuard::new(target.path());
+        cmd_init().unwrap();
+        cmd_import(ImportArgs {
+            file: exported.to_string_lossy().to_string(),
+            format: None,
+        })
+        .unwrap();
+
+        let ctx = require_initialized_workflow().unwrap();
+        let imported = collect_records(&ctx, false);
+        assert_eq!(imported.len(), original.len());
+        for (a, b) in original.iter().zip(&imported) {
+            for field in [
+                "id",
+                "title",
+                "priority",
+                "affects_globs",
+                "depends_on",
+                "objective",
+                "acceptance_criteria",
+                "context",
+            ] {
+                assert_eq!(a[field], b[field], "field {} differs", field);
+            }
+        }
+        assert_eq!(imported[1]["depends_on"], json!(["TASK-001"]));
+    }
+}
@@ -0,0 +1,317 @@
+//! Implementation of the `burl graph` command.
+//!
+//! Renders the task dependency graph as plain text, Graphviz DOT, or Mermaid.
+//! Nodes are colored by bucket; edges point from a prerequisite to the task
+//! that depends on it, so the graph reads in execution order. Dependencies on
+//! missing tasks are drawn as dashed "missing" nodes, and edges that are part
+//! of a cycle are highlighted.
+
+use crate::cli::GraphArgs;
+use crate::commands::list::parse_buckets;
+use crate::context::require_initialized_workflow;
+use crate::error::Result;
+use crate::graph::{DependencyGraph, GraphNode, format_path};
+use crate::workflow::TaskIndex;
+use std::collections::HashSet;
+
+/// Fill color per bucket (shared by DOT and Mermaid output).
+fn bucket_color(bucket: &str) -> &'static str {
+    match bucket {
+        "READY" => "#cfe2ff",
+        "DOING" => "#fff3cd",
+        "QA" => "#ffe5b4",
+        "DONE" => "#d1e7dd",
+        "BLOCKED" => "#f8d7da",
+        _ => "#e9ecef",
+    }
+}
+
+/// Fill color for dependencies on tasks that do not exist.
+const MISSING_COLOR: &str = "#ffffff";
+
+/// Highlight color for edges that are part of a cycle.
+const CYCLE_COLOR: &str = "#dc3545";
+
+/// Execute the `burl graph` command.
+pub fn cmd_graph(args: GraphArgs) -> Result<()> {
+    let ctx = require_initialized_workflow()?;
+    let index = TaskIndex::build(&ctx)?;
+    let buckets = parse_buckets(&args.bucket, index.buckets())?;
+
+    let graph = DependencyGraph::build(&index)?;
+    index.save_cache()?;
+    let view = GraphView::new(&graph, &buckets);
+
+    let rendered = match args.format.as_str() {
+        "dot" => render_dot(&view),
+        "mermaid" => render_mermaid(&view),
+        _ => render_text(&view),
+    };
+    print!("{}", rendered);
+
+    Ok(())
+}
+
+/// The subset of the graph selected for rendering.
+struct GraphView<'a> {
+    graph: &'a DependencyGraph,
+    /// Nodes shown (filtered by bucket), in ID order.
+    nodes: Vec<&'a GraphNode>,
+    /// Edges `(dependency, dependent)` between shown nodes or to missing tasks.
+    edges: Vec<(String, String)>,
+    /// Dependency IDs that do not exist in any bucket.
+    missing: Vec<String>,
+    /// Edges `(dependency, dependent)` that lie on a cycle.
+    cycle_edges: HashSet<(String, String)>,
+    /// All cycles in the workflow.
+    cycles: Vec<Vec<String>>,
+}
+
+impl<'a> GraphView<'a> {
+    fn new(graph: &'a DependencyGraph, buckets: &[String]) -> Self {
+        let nodes: Vec<&GraphNode> = graph
+            .nodes()
+            .filter(|n| buckets.is_empty() || buckets.contains(&n.bucket))
+            .collect();
+        let shown: HashSet<&str> = nodes.iter().map(|n| n.id.as_str()).collect();
+
+        let mut edges = Vec::new();
+        let mut missing: Vec<String> = Vec::new();
+        for node in &nodes {
+            for dep in &node.depends_on {
+                if graph.get(dep).is_none() {
+                    if !missing.contains(dep) {
+                        missing.push(dep.clone());
+                    }
+                } else if !shown.contains(dep.as_str()) {
+                    continue;
+                }
+                edges.push((dep.clone(), node.id.clone()));
+            }
+        }
+
+        let cycles = graph.find_cycles();
+        let cycle_edges = cycles
+            .iter()
+            .flat_map(|cycle| {
+                // A cycle path lists dependents before their dependencies.
+                cycle
+                    .windows(2)
+                    .map(|pair| (pair[1].clone(), pair[0].clone()))
+            })
+            .collect();
+
+        Self {
+            graph,
+            nodes,
+            edges,
+            missing,
+            cycle_edges,
+            cycles,
+        }
+    }
+}
+
+/// Render an indented text listing with dependency status.
+fn render_text(view: &GraphView) -> String {
+    if view.nodes.is_empty() {
+        return "No tasks found.\n".to_string();
+    }
+
+    let mut out = String::new();
+    for node in &view.nodes {
+        out.push_str(&format!("{} [{}] {}\n", node.id, node.bucket, node.title));
+        for dep in &node.depends_on {
+            let status = match view.graph.get(dep) {
+                Some(dep_node) => format!("[{}] {}", dep_node.bucket, dep_node.title),
+                None => "(missing)".to_string(),
+            };
+            out.push_str(&format!("  <- {} {}\n", dep, status));
+        }
+    }
+
+    if !view.missing.is_empty() {
+        out.push_str(&format!(
+            "\nMissing dependencies: {}\n",
+            view.missing.join(", ")
+        ));
+    }
+    if !view.cycles.is_empty() {
+        out.push_str("\nCycles:\n");
+        for cycle in &view.cycles {
+            out.push_str(&format!("  {}\n", format_path(cycle)));
+        }
+    }
+
+    out
+}
+
+/// Render a Graphviz DOT digraph.
+fn render_dot(view: &GraphView) -> String {
+    let mut out = String::new();
+    out.push_str("digraph burl {\n");
+    out.push_str("  rankdir=LR;\n");
+    out.push_str("  node [shape=box, style=\"rounded,filled\", fontname=\"Helvetica\"];\n");
+
+    for node in &view.nodes {
+        out.push_str(&format!(
+            "  \"{}\" [label=\"{}\\n{}\\n[{}]\", fillcolor=\"{}\"];\n",
+            node.id,
+            node.id,
+            dot_escape(&node.title),
+            node.bucket,
+            bucket_color(&node.bucket)
+        ));
+    }
+    for id in &view.missing {
+        out.push_str(&format!(
+            "  \"{}\" [label=\"{}\\n(missing)\", style=\"dashed\", fillcolor=\"{}\"];\n",
+            id, id, MISSING_COLOR
+        ));
+    }
+    for (from, to) in &view.edges {
+        if view.cycle_edges.contains(&(from.clone(), to.clone())) {
+            out.push_str(&format!(
+                "  \"{}\" -> \"{}\" [color=\"{}\", penwidth=2];\n",
+                from, to, CYCLE_COLOR
+            ));
+        } else {
+            out.push_str(&format!("  \"{}\" -> \"{}\";\n", from, to));
+        }
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+/// Render a Mermaid flowchart.
+fn render_mermaid(view: &GraphView) -> String {
+    let mut out = String::new();
+    out.push_str("graph LR\n");
+
+    for node in &view.nodes {
+        out.push_str(&format!(
+            "  {}[\"{}: {}\"]:::{}\n",
+            mermaid_id(&node.id),
+            node.id,
+            mermaid_escape(&node.title),
+            node.bucket.to_lowercase()
+        ));
+    }
+    for id in &view.missing {
+        out.push_str(&format!(
+            "  {}[\"{} (missing)\"]:::missing\n",
+            mermaid_id(id),
+            id
+        ));
+    }
+
+    let mut cycle_links = Vec::new();
+    for (i, (from, to)) in view.edges.iter().enumerate() {
+        out.push_str(&format!("  {} --> {}\n", mermaid_id(from), mermaid_id(to)));
+        if view.cycle_edges.contains(&(from.clone(), to.clone())) {
+            cycle_links.push(i.to_string());
+        }
+    }
+
+    // Built-in buckets, then any custom bucket a shown node is in.
+    let mut buckets: Vec<&str> = crate::workflow::BUCKETS.to_vec();
+    for node in &view.nodes {
+        if !buckets.contains(&node.bucket.as_str()) {
+            buckets.push(&node.bucket);
+        }
+    }
+    for bucket in buckets {
+        out.push_str(&format!(
+            "  classDef {} fill:{},stroke:#333\n",
+            bucket.to_lowercase(),
+            bucket_color(bucket)
+        ));
+    }
+    out.push_str(&format!(
+        "  classDef missing fill:{},stroke:#333,stroke-dasharray:4\n",
+        MISSING_COLOR
+    ));
+    if !cycle_links.is_empty() {
+        out.push_str(&format!(
+            "  linkStyle {} stroke:{},stroke-width:2px\n",
+            cycle_links.join(","),
+            CYCLE_COLOR
+        ));
+    }
+
+    out
+}
+
+fn dot_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn mermaid_id(id: &str) -> String {
+    id.replace('-', "_")
+}
+
+fn mermaid_escape(text: &str) -> String {
+    text.replace('"', "#quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(id: &str, bucket: &str, deps: &[&str]) -> GraphNode {
+        GraphNode {
+            id: id.to_string(),
+            title: format!("Title \"{}\"", id),
+            bucket: bucket.to_string(),
+            priority: "medium".to_string(),
+            depends_on: deps.iter().map(|d| d.to_string()).collect(),
+            parent: None,
+        }
+    }
+
+    fn sample() -> DependencyGraph {
+        let mut graph = DependencyGraph::default();
+        graph.insert(node("TASK-001", "DONE", &[]));
+        graph.insert(node("TASK-002", "READY", &["TASK-001", "TASK-009"]));
+        graph.insert(node("TASK-003", "READY", &["TASK-004"]));
+        graph.insert(node("TASK-004", "BLOCKED", &["TASK-003"]));
+        graph
+    }
+
+    #[test]
+    fn test_render_dot_colors_buckets_and_cycles() {
+        let graph = sample();
+        let dot = render_dot(&GraphView::new(&graph, &[]));
+
+        assert!(dot.starts_with("digraph burl {"));
+        assert!(dot.contains("\"TASK-001\" [label=\"TASK-001\\nTitle \\\"TASK-001\\\"\\n[DONE]\", fillcolor=\"#d1e7dd\"]"));
+        assert!(dot.contains("\"TASK-001\" -> \"TASK-002\";"));
+        assert!(dot.contains("\"TASK-009\" [label=\"TASK-009\\n(missing)\""));
+        assert!(dot.contains("\"TASK-004\" -> \"TASK-003\" [color=\"#dc3545\""));
+    }
+
+    #[test]
+    fn test_render_mermaid_and_bucket_filter() {
+        let graph = sample();
+        let mermaid = render_mermaid(&GraphView::new(&graph, &["READY".to_string()]));
+
+        assert!(mermaid.starts_with("graph LR\n"));
+        assert!(mermaid.contains("TASK_002[\"TASK-002: Title #quot;TASK-002#quot;\"]:::ready"));
+        assert!(!mermaid.contains("TASK_001["));
+        assert!(!mermaid.contains("TASK_001 --> TASK_002"));
+        assert!(mermaid.contains("TASK_009 --> TASK_002"));
+        assert!(mermaid.contains("classDef blocked fill:#f8d7da"));
+    }
+
+    #[test]
+    fn test_render_text_reports_missing_and_cycles() {
+        let graph = sample();
+        let text = render_text(&GraphView::new(&graph, &[]));
+
+        assert!(text.contains("TASK-002 [READY] Title \"TASK-002\"\n  <- TASK-001 [DONE]"));
+        assert!(text.contains("  <- TASK-009 (missing)"));
+        assert!(text.contains("Missing dependencies: TASK-009"));
+        assert!(text.contains("TASK-003 -> TASK-004 -> TASK-003"));
+    }
+}
@@ -0,0 +1,807 @@
+//! Implementation of the `burl import` command.
+//!
+//! Creates many READY tasks from a plan file in a single workflow-lock
+//! critical section. Two plan formats are supported:
+//!
+//! - **YAML**: a `tasks:` list (or a bare list) of task entries.
+//! - **Markdown**: one `## Title` section per task, with an optional leading
+//!   ```` ```yaml ```` block for metadata and `### Objective`,
+//!   `### Acceptance Criteria`, `### Context`, `### Implementation Notes`
+//!   subsections for the body.
+//!
+//! Entries may carry a symbolic `ref` (defaulting to the slugified title).
+//! `depends_on` entries are resolved against refs in the same file first and
+//! then against existing task IDs, so sibling tasks can depend on each other
+//! before their IDs are allocated. A `parent` epic is resolved the same way.
+
+use crate::agent::prompt::extract_sections;
+use crate::cli::ImportArgs;
+use crate::commands::add::{
+    TaskBodySections, render_task_body, validate_parent, validate_priority,
+};
+use crate::commands::edit::{validate_agent, validate_glob, validate_profile};
+use crate::config::Config;
+use crate::context::{WorkflowContext, require_initialized_workflow};
+use crate::error::{BurlError, Result};
+use crate::events::{Event, EventAction, append_event};
+use crate::fs::atomic_write_file;
+use crate::git::run_git;
+use crate::graph::{DependencyGraph, GraphNode, format_path};
+use crate::locks::acquire_workflow_lock;
+use crate::task::{TaskFile, TaskFrontmatter};
+use crate::workflow::{
+    TaskIndex, generate_task_filename, generate_task_id, slugify_title, split_task_id,
+    validate_filename_safe, validate_task_id,
+};
+use chrono::Utc;
+use serde::Deserialize;
+use serde_json::json;
+use std::collections::{HashMap, hash_map::Entry};
+use std::path::Path;
+
+/// A YAML plan file with a top-level `tasks:` list.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct PlanFile {
+    tasks: Vec<PlanTask>,
+}
+
+/// One task entry in a plan file.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct PlanTask {
+    /// Symbolic reference used by sibling `depends_on` entries.
+    #[serde(rename = "ref")]
+    reference: Option<String>,
+    /// Task ID from an export; only used as a reference (IDs are reallocated).
+    id: Option<String>,
+    /// Task kind (one of `id_prefixes`). Defaults to the prefix of `id` when
+    /// that prefix is configured, else the default prefix.
+    kind: Option<String>,
+    title: String,
+    priority: Option<String>,
+    affects: Vec<String>,
+    affects_globs: Vec<String>,
+    must_not_touch: Vec<String>,
+    depends_on: Vec<String>,
+    /// Epic this task belongs to (a ref in the plan or an existing task ID).
+    parent: Option<String>,
+    tags: Vec<String>,
+    agent: Option<String>,
+    validation_profile: Option<String>,
+    objective: Option<String>,
+    acceptance_criteria: Vec<String>,
+    context: Option<String>,
+    implementation_notes: Option<String>,
+}
+
+impl PlanTask {
+    /// The symbolic key other entries use to reference this task.
+    fn key(&self) -> String {
+        self.reference
+            .as_deref()
+            .or(self.id.as_deref())
+            .map(|r| r.trim().to_lowercase())
+            .unwrap_or_else(|| slugify_title(&self.title))
+    }
+}
+
+/// Execute the `burl import` command.
+///
+/// Parses and validates the whole plan up front, then allocates IDs,
+/// resolves dependencies, writes every task into READY, appends one `add`
+/// event per task, and makes a single workflow commit.
+pub fn cmd_import(args: ImportArgs) -> Result<()> {
+    let ctx = require_initialized_workflow()?;
+    let config = Config::load(ctx.config_path()).unwrap_or_default();
+
+    // ========================================================================
+    // Phase 1: Parse and validate the plan
+    // ========================================================================
+
+    let path = Path::new(&args.file);
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        BurlError::UserError(format!(
+            "failed to read plan file '{}': {}",
+            path.display(),
+            e
+        ))
+    })?;
+
+    let markdown = match args.format.as_deref() {
+        Some(format) => format == "markdown",
+        None => matches!(
+            path.extension().and_then(|e| e.to_str()),
+            Some("md" | "markdown")
+        ),
+    };
+    let mut plan = if markdown {
+        parse_markdown_plan(&content)?
+    } else {
+        parse_yaml_plan(&content)?
+    };
+
+    if plan.is_empty() {
+        return Err(BurlError::UserError(format!(
+            "plan file '{}' contains no tasks.",
+            path.display()
+        )));
+    }
+
+    for task in &mut plan {
+        validate_plan_task(&ctx, &config, task)?;
+    }
+    let keys = build_key_map(&plan)?;
+
+    // ========================================================================
+    // Phase 2: Allocate IDs and resolve dependencies (under workflow lock)
+    // ========================================================================
+
+    let _workflow_lock = acquire_workflow_lock(&ctx, "import")?;
+
+    let index = TaskIndex::build(&ctx)?;
+    let mut next_numbers: HashMap<&str, u32> = HashMap::new();
+    let mut ids: Vec<String> = Vec::with_capacity(plan.len());
+    for entry in &plan {
+        let prefix = entry.kind.as_deref().unwrap_or("TASK");
+        let next = match next_numbers.entry(prefix) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(index.next_number(prefix)?),
+        };
+        *next += 1;
+        ids.push(generate_task_id(prefix, *next - 1, config.id_width));
+    }
+
+    let mut created = Vec::with_capacity(plan.len());
+    for (i, entry) in plan.iter().enumerate() {
+        let depends_on = resolve_dependencies(entry, i, &keys, &ids, &index)?;
+        let parent = resolve_parent(entry, i, &keys, &ids, &index)?;
+
+        let filename = generate_task_filename(&ids[i], &entry.title);
+        validate_filename_safe(&filename)?;
+        let task_path = ctx.bucket_path("READY").join(&filename);
+        if task_path.exists() {
+            return Err(BurlError::UserError(format!(
+                "task file already exists: {}",
+                task_path.display()
+            )));
+        }
+
+        let task = TaskFile {
+            frontmatter: TaskFrontmatter {
+                id: ids[i].clone(),
+                title: entry.title.clone(),
+                priority: entry.priority.clone().unwrap_or_default(),
+                created: Some(Utc::now()),
+                affects: entry.affects.clone(),
+                affects_globs: entry.affects_globs.clone(),
+                must_not_touch: entry.must_not_touch.clone(),
+                depends_on,
+                parent,
+                tags: entry.tags.clone(),
+                agent: entry.agent.clone(),
+                validation_profile: entry.validation_profile.clone(),
+                ..Default::default()
+            },
+            body: render_task_body(&TaskBodySections {
+                objective: entry.objective.clone(),
+                acceptance_criteria: entry.acceptance_criteria.clone(),
+                context: entry.context.clone(),
+                implementation_notes: entry.implementation_notes.clone(),
+            }),
+        };
+        let content = task.to_string()?;
+        created.push((task, filename, task_path, content));
+    }
+
+    // Plan entries may only reference each other acyclically, and parents
+    // must be valid epics.
+    let mut graph = DependencyGraph::build(&index)?;
+    for (task, _, _, _) in &created {
+        graph.insert(GraphNode {
+            id: task.frontmatter.id.clone(),
+            title: task.frontmatter.title.clone(),
+            bucket: "READY".to_string(),
+            priority: task.frontmatter.priority.clone(),
+            depends_on: task.frontmatter.depends_on.clone(),
+            parent: task.frontmatter.parent.clone(),
+        });
+    }
+    if let Some(cycle) = graph
+        .find_cycles()
+        .into_iter()
+        .find(|cycle| cycle.iter().any(|id| ids.contains(id)))
+    {
+        return Err(BurlError::UserError(format!(
+            "plan dependencies form a cycle: {}",
+            format_path(&cycle)
+        )));
+    }
+    for (task, _, _, _) in &created {
+        if let Some(parent) = &task.frontmatter.parent {
+            validate_parent(&graph, &task.frontmatter.id, parent)?;
+        }
+    }
+
+    // ========================================================================
+    // Phase 3: Write tasks, log events, commit
+    // ========================================================================
+
+    write_task_files(
+        created
+            .iter()
+            .map(|(_, _, task_path, content)| (task_path.as_path(), content.as_str())),
+    )?;
+
+    let source = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| args.file.clone());
+
+    for ((task, filename, task_path, _), entry) in created.iter().zip(&plan) {
+        let event = Event::new(EventAction::Add)
+            .with_task(&task.frontmatter.id)
+            .with_details(json!({
+                "title": task.frontmatter.title,
+                "priority": task.frontmatter.priority,
+                "parent": task.frontmatter.parent,
+                "filename": filename,
+                "path": task_path.display().to_string(),
+                "import": {
+                    "source": source,
+                    "ref": entry.key(),
+                },
+            }));
+        append_event(&ctx, &event)?;
+    }
+
+    if config.workflow_auto_commit {
+        commit_import(&ctx, &source, &ids)?;
+
+        if config.workflow_auto_push {
+            push_workflow_branch(&ctx, &config)?;
+        }
+    }
+
+    // ========================================================================
+    // Phase 4: Print results
+    // ========================================================================
+
+    println!("Imported {} task(s) from {}:", created.len(), source);
+    println!();
+    for ((task, _, _, _), entry) in created.iter().zip(&plan) {
+        println!(
+            "  {}  [{}] {}  (ref: {})",
+            task.frontmatter.id,
+            task.frontmatter.priority,
+            task.frontmatter.title,
+            entry.key()
+        );
+        if !task.frontmatter.depends_on.is_empty() {
+            println!(
+                "             depends on: {}",
+                task.frontmatter.depends_on.join(", ")
+            );
+        }
+        if let Some(parent) = &task.frontmatter.parent {
+            println!("             parent: {}", parent);
+        }
+    }
+
+    Ok(())
+}
+
+/// Write all task files, removing the ones already written if any write
+/// fails so a re-run doesn't duplicate them.
+fn write_task_files<'a>(files: impl IntoIterator<Item = (&'a Path, &'a str)>) -> Result<()> {
+    let mut written: Vec<&Path> = Vec::new();
+    for (path, content) in files {
+        if let Err(e) = atomic_write_file(path, content) {
+            for path in written {
+                let _ = std::fs::remove_file(path);
+            }
+            return Err(e);
+        }
+        written.push(path);
+    }
+    Ok(())
+}
+
+/// Parse a YAML plan: either `tasks: [...]` or a bare list of tasks.
+fn parse_yaml_plan(content: &str) -> Result<Vec<PlanTask>> {
+    let value: serde_yaml::Value = serde_yaml::from_str(content)
+        .map_err(|e| BurlError::UserError(format!("failed to parse plan YAML: {}", e)))?;
+
+    let tasks = if value.is_sequence() {
+        serde_yaml::from_value::<Vec<PlanTask>>(value)
+    } else {
+        serde_yaml::from_value::<PlanFile>(value).map(|plan| plan.tasks)
+    };
+
+    tasks.map_err(|e| BurlError::UserError(format!("invalid plan file: {}", e)))
+}
+
+/// Parse a markdown plan with one `## Title` section per task.
+fn parse_markdown_plan(content: &str) -> Result<Vec<PlanTask>> {
+    // Split into (title, lines) sections on level-2 headings outside code fences.
+    let mut sections: Vec<(String, Vec<&str>)> = Vec::new();
+    let mut in_fence = false;
+    for line in content.lines() {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+        }
+        if !in_fence && let Some(title) = line.strip_prefix("## ") {
+            sections.push((title.trim().to_string(), Vec::new()));
+        } else if let Some((_, lines)) = sections.last_mut() {
+            lines.push(line);
+        }
+    }
+
+    sections
+        .into_iter()
+        .map(|(title, lines)| parse_markdown_task(title, &lines))
+        .collect()
+}
+
+/// Parse a single `## Title` section of a markdown plan.
+fn parse_markdown_task(title: String, lines: &[&str]) -> Result<PlanTask> {
+    let mut rest = lines;
+    while let Some((first, tail)) = rest.split_first()
+        && first.trim().is_empty()
+    {
+        rest = tail;
+    }
+
+    // Optional leading ```yaml metadata block.
+    let mut task = PlanTask::default();
+    if let Some(first) = rest.first()
+        && matches!(first.trim(), "```yaml" | "```yml")
+    {
+        let end = rest[1..]
+            .iter()
+            .position(|l| l.trim() == "```")
+            .ok_or_else(|| {
+                BurlError::UserError(format!(
+                    "unterminated ```yaml block in plan task '{}'",
+                    title
+                ))
+            })?;
+        let yaml = rest[1..=end].join("\n");
+        task = serde_yaml::from_str(&yaml).map_err(|e| {
+            BurlError::UserError(format!("invalid metadata for plan task '{}': {}", title, e))
+        })?;
+        rest = &rest[end + 2..];
+    }
+    task.title = title;
+
+    // Prose before the first subsection is the objective.
+    let first_heading = rest
+        .iter()
+        .position(|l| l.trim_start().starts_with("###"))
+        .unwrap_or(rest.len());
+    let prose = rest[..first_heading].join("\n");
+    if !prose.trim().is_empty() {
+        task.objective = Some(prose.trim().to_string());
+    }
+
+    let body = rest[first_heading..].join("\n");
+    for (name, content) in extract_sections(&body) {
+        match name.as_str() {
+            "objective" => task.objective = Some(content),
+            "acceptance_criteria" => {
+                task.acceptance_criteria = content
+                    .lines()
+                    .map(str::trim)
+                    .filter(|l| !l.is_empty())
+                    .map(|l| {
+                        l.strip_prefix("- ")
+                            .or_else(|| l.strip_prefix("* "))
+                            .unwrap_or(l)
+                            .to_string()
+                    })
+                    .collect();
+            }
+            "context" => task.context = Some(content),
+            "implementation_notes" => task.implementation_notes = Some(content),
+            other => {
+                return Err(BurlError::UserError(format!(
+                    "unknown section '{}' in plan task '{}'.\n\n\
+                     Supported sections: Objective, Acceptance Criteria, Context, Implementation Notes.",
+                    other.replace('_', " "),
+                    task.title
+                )));
+            }
+        }
+    }
+
+    Ok(task)
+}
+
+/// Validate and normalize a plan entry before anything is written.
+fn validate_plan_task(ctx: &WorkflowContext, config: &Config, task: &mut PlanTask) -> Result<()> {
+    task.title = task.title.trim().to_string();
+    if task.title.is_empty() {
+        return Err(BurlError::UserError(
+            "every plan task needs a non-empty title".to_string(),
+        ));
+    }
+
+    let context = |e: BurlError| match e {
+        BurlError::UserError(msg) => {
+            BurlError::UserError(format!("plan task '{}': {}", task.title, msg))
+        }
+        other => other,
+    };
+
+    task.priority =
+        Some(validate_priority(task.priority.as_deref().unwrap_or("medium")).map_err(context)?);
+    let exported_prefix = task
+        .id
+        .as_deref()
+        .and_then(split_task_id)
+        .map(|(prefix, _)| prefix.to_string())
+        .filter(|prefix| config.id_prefixes.contains(prefix));
+    task.kind = Some(match (&task.kind, exported_prefix) {
+        (None, Some(prefix)) => prefix,
+        (kind, _) => config.id_prefix(kind.as_deref()).map_err(context)?,
+    });
+    for pattern in &task.affects_globs {
+        validate_glob("affects_globs", pattern).map_err(context)?;
+    }
+    for pattern in &task.must_not_touch {
+        validate_glob("must_not_touch", pattern).map_err(context)?;
+    }
+    if let Some(agent) = &task.agent {
+        validate_agent(ctx, agent).map_err(context)?;
+    }
+    if let Some(profile) = &task.validation_profile {
+        validate_profile(config, profile).map_err(context)?;
+    }
+
+    Ok(())
+}
+
+/// Map each symbolic key to its plan position (`None` when ambiguous).
+///
+/// Explicit `ref`s must be unique; implicit (title-derived) keys only fail
+/// when an ambiguous key is actually referenced.
+fn build_key_map(plan: &[PlanTask]) -> Result<HashMap<String, Option<usize>>> {
+    let mut keys: HashMap<String, Option<usize>> = HashMap::new();
+    for (i, task) in plan.iter().enumerate() {
+        let key = task.key();
+        if let Some(existing) = keys.get_mut(&key) {
+            if task.reference.is_some() {
+                return Err(BurlError::UserError(format!(
+                    "duplicate ref '{}' in plan file.",
+                    key
+                )));
+            }
+            *existing = None;
+        } else {
+            keys.insert(key, Some(i));
+        }
+    }
+    Ok(keys)
+}
+
+/// What a plan reference (`depends_on` or `parent` entry) points at.
+enum PlanRef {
+    /// Another entry in the plan, by position.
+    Entry(usize),
+    /// A task that already exists in the workflow.
+    Existing(String),
+    /// A key shared by several plan entries.
+    Ambiguous,
+    /// Neither a plan key nor an existing task.
+    Unknown,
+}
+
+/// Look up a reference: plan keys win over existing task IDs.
+fn lookup_ref(
+    reference: &str,
+    keys: &HashMap<String, Option<usize>>,
+    index: &TaskIndex,
+) -> PlanRef {
+    match keys.get(&reference.trim().to_lowercase()) {
+        Some(Some(i)) => PlanRef::Entry(*i),
+        Some(None) => PlanRef::Ambiguous,
+        None => match validate_task_id(reference)
+            .ok()
+            .filter(|id| index.find(id).is_some())
+        {
+            Some(id) => PlanRef::Existing(id),
+            None => PlanRef::Unknown,
+        },
+    }
+}
+
+/// Resolve a plan entry's `depends_on` to task IDs.
+fn resolve_dependencies(
+    task: &PlanTask,
+    position: usize,
+    keys: &HashMap<String, Option<usize>>,
+    ids: &[String],
+    index: &TaskIndex,
+) -> Result<Vec<String>> {
+    let mut resolved: Vec<String> = Vec::new();
+    for dep in &task.depends_on {
+        let id = match lookup_ref(dep, keys, index) {
+            PlanRef::Entry(i) if i == position => {
+                return Err(BurlError::UserError(format!(
+                    "plan task '{}' cannot depend on itself.",
+                    task.title
+                )));
+            }
+            PlanRef::Entry(i) => ids[i].clone(),
+            PlanRef::Existing(id) => id,
+            PlanRef::Ambiguous => {
+                return Err(BurlError::UserError(format!(
+                    "plan task '{}' depends on '{}', which matches several tasks in the plan.\n\n\
+                     Give the tasks distinct `ref` values.",
+                    task.title, dep
+                )));
+            }
+            PlanRef::Unknown => {
+                return Err(BurlError::UserError(format!(
+                    "plan task '{}' depends on '{}', which is neither a ref in the plan nor an existing task.",
+                    task.title, dep
+                )));
+            }
+        };
+        if !resolved.contains(&id) {
+            resolved.push(id);
+        }
+    }
+    Ok(resolved)
+}
+
+/// Resolve a plan entry's `parent` to a task ID.
+fn resolve_parent(
+    task: &PlanTask,
+    position: usize,
+    keys: &HashMap<String, Option<usize>>,
+    ids: &[String],
+    index: &TaskIndex,
+) -> Result<Option<String>> {
+    let Some(parent) = &task.parent else {
+        return Ok(None);
+    };
+    match lookup_ref(parent, keys, index) {
+        PlanRef::Entry(i) if i == position => Err(BurlError::UserError(format!(
+            "plan task '{}' cannot be its own parent.",
+            task.title
+        ))),
+        PlanRef::Entry(i) => Ok(Some(ids[i].clone())),
+        PlanRef::Existing(id) => Ok(Some(id)),
+        PlanRef::Ambiguous => Err(BurlError::UserError(format!(
+            "plan task '{}' has parent '{}', which matches several tasks in the plan.\n\n\
+             Give the tasks distinct `ref` values.",
+            task.title, parent
+        ))),
+        PlanRef::Unknown => Err(BurlError::UserError(format!(
+            "plan task '{}' has parent '{}', which is neither a ref in the plan nor an existing task.",
+            task.title, parent
+        ))),
+    }
+}
+
+/// Commit the imported tasks to the workflow branch.
+fn commit_import(ctx: &WorkflowContext, source: &str, ids: &[String]) -> Result<()> {
+    run_git(&ctx.workflow_worktree, &["add", "."])
+        .map_err(|e| BurlError::GitError(format!("failed to stage imported tasks: {}", e)))?;
+
+    let staged = run_git(&ctx.workflow_worktree, &["diff", "--cached", "--name-only"])?;
+    if staged.stdout.is_empty() {
+        return Ok(());
+    }
+
+    let range = match (ids.first(), ids.last()) {
+        (Some(first), Some(last)) if first != last => format!("{}..{}", first, last),
+        (Some(first), _) => first.clone(),
+        _ => String::new(),
+    };
+    let commit_msg = format!("Import {} task(s) from {}: {}", ids.len(), source, range);
+
+    run_git(&ctx.workflow_worktree, &["commit", "-m", &commit_msg])
+        .map_err(|e| BurlError::GitError(format!("failed to commit imported tasks: {}", e)))?;
+
+    Ok(())
+}
+
+/// Push the workflow branch to the remote.
+fn push_workflow_branch(ctx: &WorkflowContext, config: &Config) -> Result<()> {
+    run_git(
+        &ctx.workflow_worktree,
+        &["push", &config.remote, &config.workflow_branch],
+    )
+    .map_err(|e| BurlError::GitError(format!("failed to push workflow branch: {}", e)))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::AddArgs;
+    use crate::commands::add::cmd_add;
+    use crate::commands::init::cmd_init;
+    use crate::exit_codes;
+    use crate::test_support::{DirGuard, create_test_repo};
+    use serial_test::serial;
+
+    fn import(dir: &Path, name: &str, content: &str) -> Result<()> {
+        let file = dir.join(name);
+        std::fs::write(&file, content).unwrap();
+        cmd_import(ImportArgs {
+            file: file.to_string_lossy().to_string(),
+            format: None,
+        })
+    }
+
+    #[test]
+    fn test_parse_markdown_plan() {
+        let plan = parse_markdown_plan(
+            "# Sprint 12\n\nIgnored preamble.\n\n\
+             ## Physics step\n\nFixed-timestep integration.\n\n\
+             ## Player jump\n\
+             ```yaml\nref: jump\npriority: high\ndepends_on: [physics-step]\n```\n\n\
+             ### Objective\nSpace makes the player jump.\n\n\
+             ### Acceptance Criteria\n- [ ] Jump height is configurable\n- Has tests\n",
+        )
+        .unwrap();
+
+        assert_eq!(plan.len(), 2);
+        assert_eq!(plan[0].title, "Physics step");
+        assert_eq!(plan[0].key(), "physics-step");
+        assert_eq!(
+            plan[0].objective.as_deref(),
+            Some("Fixed-timestep integration.")
+        );
+        assert_eq!(plan[1].key(), "jump");
+        assert_eq!(plan[1].priority.as_deref(), Some("high"));
+        assert_eq!(plan[1].depends_on, vec!["physics-step"]);
+        assert_eq!(
+            plan[1].acceptance_criteria,
+            vec!["[ ] Jump height is configurable", "Has tests"]
+        );
+
+        let err = parse_markdown_plan("## Task\n### Rollout\nlater\n").unwrap_err();
+        assert!(err.to_string().contains("unknown section 'rollout'"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_import_yaml_resolves_refs_with_single_commit() {
+        let temp_dir = create_test_repo();
+        let _guard = DirGuard::new(temp_dir.path());
+        cmd_init().unwrap();
+        cmd_add(AddArgs {
+            title: "Existing".to_string(),
+            priority: "medium".to_string(),
+            affects: vec![],
+            affects_globs: vec![],
+            must_not_touch: vec![],
+            depends_on: vec![],
+            tags: vec![],
+            parent: None,
+            template: None,
+            kind: None,
+        })
+        .unwrap();
+
+        let ctx = require_initialized_workflow().unwrap();
+        let commits_before = run_git(&ctx.workflow_worktree, &["rev-list", "--count", "HEAD"])
+            .unwrap()
+            .stdout;
+
+        import(
+            temp_dir.path(),
+            "plan.yaml",
+            r#"
+tasks:
+  - title: Player jump
+    depends_on: [physics]
+    priority: high
+    affects_globs: ["src/player/**"]
+    objective: Space makes the player jump.
+    acceptance_criteria: [Jump height is configurable]
+  - ref: physics
+    title: Physics step
+    depends_on: [TASK-001]
+    parent: TASK-001
+  - title: Jump sound
+    parent: player-jump
+"#,
+        )
+        .unwrap();
+
+        let jump =
+            TaskFile::load(ctx.bucket_path("READY").join("TASK-002-player-jump.md")).unwrap();
+        assert_eq!(jump.frontmatter.priority, "high");
+        assert_eq!(jump.frontmatter.depends_on, vec!["TASK-003"]);
+        assert!(
+            jump.body
+                .contains("## Objective\nSpace makes the player jump.\n")
+        );
+        assert!(jump.body.contains("- [ ] Jump height is configurable"));
+
+        let physics =
+            TaskFile::load(ctx.bucket_path("READY").join("TASK-003-physics-step.md")).unwrap();
+        assert_eq!(physics.frontmatter.priority, "medium");
+        assert_eq!(physics.frontmatter.depends_on, vec!["TASK-001"]);
+        assert_eq!(physics.frontmatter.parent.as_deref(), Some("TASK-001"));
+
+        let sound =
+            TaskFile::load(ctx.bucket_path("READY").join("TASK-004-jump-sound.md")).unwrap();
+        assert_eq!(sound.frontmatter.parent.as_deref(), Some("TASK-002"));
+
+        let commits_after = run_git(&ctx.workflow_worktree, &["rev-list", "--count", "HEAD"])
+            .unwrap()
+            .stdout;
+        assert_eq!(
+            commits_after.trim().parse::<u32>().unwrap(),
+            commits_before.trim().parse::<u32>().unwrap() + 1
+        );
+
+        let events = std::fs::read_to_string(ctx.events_file()).unwrap();
+        let imported: Vec<Event> = events
+            .lines()
+            .map(|l| serde_json::from_str::<Event>(l).unwrap())
+            .filter(|e| e.details.get("import").is_some())
+            .collect();
+        assert_eq!(imported.len(), 3);
+        assert!(imported.iter().all(|e| e.action == EventAction::Add));
+    }
+
+    #[test]
+    #[serial]
+    fn test_import_rejects_invalid_plan_without_writing() {
+        let temp_dir = create_test_repo();
+        let _guard = DirGuard::new(temp_dir.path());
+        cmd_init().unwrap();
+        let ctx = require_initialized_workflow().unwrap();
+
+        let err = import(
+            temp_dir.path(),
+            "plan.yaml",
+            "- title: First\n- title: Second\n  depends_on: [nowhere]\n",
+        )
+        .unwrap_err();
+        assert_eq!(err.exit_code(), exit_codes::USER_ERROR);
+        assert!(err.to_string().contains("'nowhere'"));
+
+        let err = import(
+            temp_dir.path(),
+            "plan.md",
+            "## Bad glob\n```yaml\naffects_globs: [\"src/[\"]\n```\n",
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("invalid glob"));
+
+        let err = import(
+            temp_dir.path(),
+            "cycle.yaml",
+            "- {ref: a, title: A, depends_on: [b]}\n- {ref: b, title: B, depends_on: [a]}\n",
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("TASK-001 -> TASK-002 -> TASK-001"));
+
+        let err = import(temp_dir.path(), "kind.yaml", "- {title: A, kind: docs}\n").unwrap_err();
+        assert!(err.to_string().contains("unknown task kind 'docs'"));
+
+        assert!(TaskIndex::build(&ctx).unwrap().all_tasks().next().is_none());
+    }
+
+    #[test]
+    fn test_write_task_files_removes_written_files_on_failure() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let first = temp_dir.path().join("TASK-001-first.md");
+        // A non-empty directory in the way makes the second write fail.
+        let second = temp_dir.path().join("TASK-002-second.md");
+        std::fs::create_dir_all(second.join("blocker")).unwrap();
+
+        write_task_files([(first.as_path(), "first"), (second.as_path(), "second")]).unwrap_err();
+        assert!(!first.exists());
+        assert!(second.is_dir());
+    }
+}
@@ -97,6 +97,7 @@ pub fn cmd_init() -> Result<()> {
     }
     println!("  .burl/.workflow/events/");
     println!("  .burl/.workflow/prompts/");
+    println!("  .burl/.workflow/task-templates/");
     println!("  .burl/.workflow/locks/  (untracked)");
     println!("  .burl/.workflow/agent-logs/  (untracked)");
     println!("  .worktrees/             (untracked)");
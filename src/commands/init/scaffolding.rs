@@ -10,8 +10,6 @@ use crate::fs::atomic_write_file;
 use std::fs;
 use std::path::Path;
 
-use super::BUCKETS;
-
 const DEFAULT_AGENTS_YAML: &str = r#"agents: {}
 
 defaults:
@@ -38,8 +36,10 @@ pub(super) fn create_workflow_structure(ctx: &WorkflowContext) -> Result<()> {
         ))
     })?;
 
-    // Create bucket directories with .gitkeep files
-    for bucket in BUCKETS {
+    // Create bucket directories with .gitkeep files, including custom
+    // buckets from an existing config
+    let config = Config::load(ctx.config_path()).unwrap_or_default();
+    for bucket in &config.states.buckets {
         let bucket_path = ctx.bucket_path(bucket);
         create_dir_with_gitkeep(&bucket_path)?;
     }
@@ -52,6 +52,9 @@ pub(super) fn create_workflow_structure(ctx: &WorkflowContext) -> Result<()> {
     let prompts_path = ctx.prompts_dir();
     create_dir_with_gitkeep(&prompts_path)?;
 
+    // Create task templates directory with .gitkeep (tracked, durable)
+    create_dir_with_gitkeep(&ctx.task_templates_dir())?;
+
     // Create locks directory (no .gitkeep - it's untracked)
     fs::create_dir_all(&ctx.locks_dir).map_err(|e| {
         BurlError::UserError(format!(
@@ -87,7 +90,7 @@ pub(super) fn create_workflow_structure(ctx: &WorkflowContext) -> Result<()> {
 
     // Create .gitignore in .workflow to ignore locks/
     let gitignore_path = ctx.workflow_state_dir.join(".gitignore");
-    let required_entries = ["locks/", "agent-logs/"];
+    let required_entries = ["locks/", "agent-logs/", "validation-logs/"];
     let existing = std::fs::read_to_string(&gitignore_path).unwrap_or_default();
     let mut missing_entries = Vec::new();
     for entry in required_entries {
@@ -116,7 +119,7 @@ pub(super) fn create_workflow_structure(ctx: &WorkflowContext) -> Result<()> {
     } else {
         atomic_write_file(
             &gitignore_path,
-            "# Machine-local files (never commit)\nlocks/\nagent-logs/\n",
+            "# Machine-local files (never commit)\nlocks/\nagent-logs/\nvalidation-logs/\n",
         )?;
     }
 
@@ -100,6 +100,9 @@ fn test_create_workflow_structure() {
     assert!(ctx.prompts_dir().exists());
     assert!(ctx.prompts_dir().join(".gitkeep").exists());
 
+    // Verify task templates directory
+    assert!(ctx.task_templates_dir().join(".gitkeep").exists());
+
     // Verify locks directory
     assert!(ctx.locks_dir.exists());
     // locks/ should NOT have .gitkeep (it's untracked)
@@ -122,6 +125,7 @@ fn test_create_workflow_structure() {
     let gitignore_content = std::fs::read_to_string(&gitignore_path).unwrap();
     assert!(gitignore_content.contains("locks/"));
     assert!(gitignore_content.contains("agent-logs/"));
+    assert!(gitignore_content.contains("validation-logs/"));
 }
 
 #[test]
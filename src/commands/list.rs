@@ -0,0 +1,509 @@
+//! Implementation of the `burl list` command.
+//!
+//! Lists tasks across buckets with optional filters, in a human-readable table
+//! or a machine-readable format (JSON, NDJSON, CSV) for scripts and dashboards.
+//! `--query` (and `burl search`) accept the [`crate::query`] language.
+//!
+//! Each machine-readable record mirrors `TaskFrontmatter` (including unknown
+//! `extra` fields) plus the resolved `bucket` and repo-relative `path`.
+
+use crate::cli::{ListArgs, SearchArgs};
+use crate::config::Config;
+use crate::context::{WorkflowContext, require_initialized_workflow};
+use crate::duration::parse_duration;
+use crate::error::{BurlError, Result};
+use crate::events::get_actor_string;
+use crate::query::{TaskQuery, assignee_matches};
+use crate::task::TaskFile;
+use crate::workflow::{TaskIndex, TaskInfo, task_key};
+use chrono::{DateTime, Utc};
+use serde_json::{Map, Value};
+
+/// Columns emitted by `--format csv`, in order.
+pub(crate) const CSV_COLUMNS: &[&str] = &[
+    "id",
+    "bucket",
+    "title",
+    "priority",
+    "assigned_to",
+    "agent",
+    "qa_attempts",
+    "tags",
+    "depends_on",
+    "created",
+    "started_at",
+    "submitted_at",
+    "completed_at",
+    "blocked_reason",
+    "branch",
+    "path",
+];
+
+/// A task matched by `burl list`, with its resolved location.
+pub(crate) struct ListedTask<'a> {
+    pub info: &'a TaskInfo,
+    pub task: TaskFile,
+}
+
+/// Execute the `burl list` command.
+pub fn cmd_list(args: ListArgs) -> Result<()> {
+    let ctx = require_initialized_workflow()?;
+
+    let config = Config::load(ctx.config_path()).unwrap_or_default();
+
+    let filter = ListFilter::from_args(&args, &config.states.buckets)?;
+    print_tasks(&ctx, &filter, &args.format)
+}
+
+/// Execute the `burl search` command: `list` driven by a query.
+pub fn cmd_search(args: SearchArgs) -> Result<()> {
+    let ctx = require_initialized_workflow()?;
+
+    let config = Config::load(ctx.config_path()).unwrap_or_default();
+
+    let filter = ListFilter {
+        query: Some(TaskQuery::parse(
+            &args.query.join(" "),
+            &config.states.buckets,
+        )?),
+        ..Default::default()
+    };
+    print_tasks(&ctx, &filter, &args.format)
+}
+
+/// Collect the tasks matching `filter` and print them in `format`.
+fn print_tasks(ctx: &WorkflowContext, filter: &ListFilter, format: &str) -> Result<()> {
+    let index = TaskIndex::build(ctx)?;
+    let tasks = collect_tasks(&index, filter, Utc::now())?;
+
+    match format {
+        "json" => {
+            let records = tasks
+                .iter()
+                .map(|t| task_record(ctx, t))
+                .collect::<Result<Vec<_>>>()?;
+            println!("{}", to_json(&Value::Array(records), true)?);
+        }
+        "ndjson" => {
+            for t in &tasks {
+                println!("{}", to_json(&task_record(ctx, t)?, false)?);
+            }
+        }
+        "csv" => print!("{}", render_csv(ctx, &tasks)?),
+        _ => print_table(&tasks),
+    }
+
+    Ok(())
+}
+
+/// Filters accepted by `burl list`.
+#[derive(Debug, Default)]
+struct ListFilter {
+    buckets: Vec<String>,
+    tags: Vec<String>,
+    priority: Option<String>,
+    agent: Option<String>,
+    assigned_to: Option<String>,
+    stalled: Option<chrono::Duration>,
+    query: Option<TaskQuery>,
+}
+
+impl ListFilter {
+    /// Build the filter; bucket names are checked against `known_buckets`.
+    fn from_args(args: &ListArgs, known_buckets: &[String]) -> Result<Self> {
+        let buckets = parse_buckets(&args.bucket, known_buckets)?;
+
+        let priority = args
+            .priority
+            .as_deref()
+            .map(crate::commands::add::validate_priority)
+            .transpose()?;
+
+        let assigned_to = args.assigned_to.as_ref().map(|a| {
+            if a == "me" {
+                get_actor_string()
+            } else {
+                a.clone()
+            }
+        });
+
+        let stalled = args.stalled.as_deref().map(parse_duration).transpose()?;
+        let query = args
+            .query
+            .as_deref()
+            .map(|q| TaskQuery::parse(q, known_buckets))
+            .transpose()?;
+
+        Ok(Self {
+            buckets,
+            tags: args.tag.clone(),
+            priority,
+            agent: args.agent.clone(),
+            assigned_to,
+            stalled,
+            query,
+        })
+    }
+
+    fn matches(&self, info: &TaskInfo, task: &TaskFile, now: DateTime<Utc>) -> bool {
+        let fm = &task.frontmatter;
+
+        if !self.buckets.is_empty() && !self.buckets.contains(&info.bucket) {
+            return false;
+        }
+        if !self.tags.iter().all(|tag| fm.tags.contains(tag)) {
+            return false;
+        }
+        if let Some(priority) = &self.priority
+            && &fm.priority != priority
+        {
+            return false;
+        }
+        if let Some(agent) = &self.agent
+            && fm.agent.as_ref() != Some(agent)
+        {
+            return false;
+        }
+        if let Some(assignee) = &self.assigned_to
+            && !fm
+                .assigned_to
+                .as_deref()
+                .is_some_and(|a| assignee_matches(a, assignee))
+        {
+            return false;
+        }
+        if let Some(threshold) = self.stalled
+            && !is_stalled(info, task, now, threshold)
+        {
+            return false;
+        }
+        if let Some(query) = &self.query
+            && !query.matches(info, task)
+        {
+            return false;
+        }
+
+        true
+    }
+}
+
+/// Normalize `--bucket` values to uppercase names from `known` (the
+/// configured buckets).
+pub(crate) fn parse_buckets(values: &[String], known: &[String]) -> Result<Vec<String>> {
+    values
+        .iter()
+        .map(|b| {
+            let upper = b.to_uppercase();
+            if known.contains(&upper) {
+                Ok(upper)
+            } else {
+                Err(BurlError::UserError(format!(
+                    "invalid bucket '{}': must be one of {}",
+                    b,
+                    known.join(", ")
+                )))
+            }
+        })
+        .collect()
+}
+
+/// A task is stalled if it has sat in DOING (since `started_at`) or QA (since
+/// `submitted_at`) for longer than the threshold.
+fn is_stalled(
+    info: &TaskInfo,
+    task: &TaskFile,
+    now: DateTime<Utc>,
+    threshold: chrono::Duration,
+) -> bool {
+    let since = match info.bucket.as_str() {
+        "DOING" => task.frontmatter.started_at,
+        "QA" => task.frontmatter.submitted_at,
+        _ => None,
+    };
+    since.is_some_and(|t| now.signed_duration_since(t) > threshold)
+}
+
+/// Load and filter tasks, sorted by bucket order then task number.
+fn collect_tasks<'a>(
+    index: &'a TaskIndex,
+    filter: &ListFilter,
+    now: DateTime<Utc>,
+) -> Result<Vec<ListedTask<'a>>> {
+    let mut infos: Vec<&TaskInfo> = index.all_tasks().collect();
+    infos.sort_by_key(|t| (bucket_order(index.buckets(), &t.bucket), task_key(&t.id)));
+
+    let mut tasks = Vec::new();
+    for info in infos {
+        let task = TaskFile::load(&info.path)?;
+        if filter.matches(info, &task, now) {
+            tasks.push(ListedTask { info, task });
+        }
+    }
+    Ok(tasks)
+}
+
+/// Position of `bucket` in the configured bucket order.
+pub(crate) fn bucket_order(buckets: &[String], bucket: &str) -> usize {
+    buckets
+        .iter()
+        .position(|b| b == bucket)
+        .unwrap_or(buckets.len())
+}
+
+/// Build the machine-readable record for a task.
+///
+/// The record is the serialized frontmatter (including `extra` fields) plus
+/// `bucket` and `path` (relative to the repository root).
+pub(crate) fn task_record(ctx: &WorkflowContext, listed: &ListedTask) -> Result<Value> {
+    let mut record = match serde_json::to_value(&listed.task.frontmatter) {
+        Ok(Value::Object(map)) => map,
+        Ok(_) => Map::new(),
+        Err(e) => {
+            return Err(BurlError::UserError(format!(
+                "failed to serialize task '{}': {}",
+                listed.info.id, e
+            )));
+        }
+    };
+
+    record.insert(
+        "bucket".to_string(),
+        Value::String(listed.info.bucket.clone()),
+    );
+    record.insert(
+        "path".to_string(),
+        Value::String(relative_path(ctx, listed.info)),
+    );
+
+    Ok(Value::Object(record))
+}
+
+fn relative_path(ctx: &WorkflowContext, info: &TaskInfo) -> String {
+    info.path
+        .strip_prefix(&ctx.repo_root)
+        .unwrap_or(&info.path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+pub(crate) fn to_json(value: &Value, pretty: bool) -> Result<String> {
+    let result = if pretty {
+        serde_json::to_string_pretty(value)
+    } else {
+        serde_json::to_string(value)
+    };
+    result.map_err(|e| BurlError::UserError(format!("failed to serialize JSON: {}", e)))
+}
+
+/// Render tasks as CSV with a fixed header (see `CSV_COLUMNS`).
+///
+/// List fields are joined with `;`.
+fn render_csv(ctx: &WorkflowContext, tasks: &[ListedTask]) -> Result<String> {
+    let mut out = String::new();
+    out.push_str(&CSV_COLUMNS.join(","));
+    out.push('\n');
+
+    for t in tasks {
+        let record = task_record(ctx, t)?;
+        let row: Vec<String> = CSV_COLUMNS
+            .iter()
+            .map(|col| csv_escape(&csv_cell(record.get(*col))))
+            .collect();
+        out.push_str(&row.join(","));
+        out.push('\n');
+    }
+
+    Ok(out)
+}
+
+pub(crate) fn csv_cell(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Array(items)) => items
+            .iter()
+            .map(|v| csv_cell(Some(v)))
+            .collect::<Vec<_>>()
+            .join(";"),
+        Some(other) => other.to_string(),
+    }
+}
+
+/// Quote a CSV field if it contains a delimiter, quote, or newline (RFC 4180).
+pub(crate) fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn print_table(tasks: &[ListedTask]) {
+    if tasks.is_empty() {
+        println!("No tasks found.");
+        return;
+    }
+
+    let id_width = tasks
+        .iter()
+        .map(|t| t.info.id.len())
+        .max()
+        .unwrap_or(0)
+        .max("ID".len());
+
+    println!(
+        "{:<id_width$}  {:<7}  {:<8}  {:<24}  TITLE",
+        "ID", "BUCKET", "PRIORITY", "ASSIGNED"
+    );
+    for t in tasks {
+        let fm = &t.task.frontmatter;
+        println!(
+            "{:<id_width$}  {:<7}  {:<8}  {:<24}  {}",
+            t.info.id,
+            t.info.bucket,
+            fm.priority,
+            fm.assigned_to.as_deref().unwrap_or("-"),
+            fm.title
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::AddArgs;
+    use crate::commands::add::cmd_add;
+    use crate::commands::init::cmd_init;
+    use crate::test_support::{DirGuard, create_test_repo};
+    use serial_test::serial;
+
+    fn add_task(title: &str, priority: &str, tags: &[&str]) {
+        cmd_add(AddArgs {
+            title: title.to_string(),
+            priority: priority.to_string(),
+            affects: vec![],
+            affects_globs: vec![],
+            must_not_touch: vec![],
+            depends_on: vec![],
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            parent: None,
+            template: None,
+            kind: None,
+        })
+        .unwrap();
+    }
+
+    fn list_args() -> ListArgs {
+        ListArgs {
+            bucket: vec![],
+            tag: vec![],
+            priority: None,
+            agent: None,
+            assigned_to: None,
+            stalled: None,
+            query: None,
+            format: "table".to_string(),
+        }
+    }
+
+    fn listed_ids(args: &ListArgs) -> Vec<String> {
+        let ctx = require_initialized_workflow().unwrap();
+        let index = TaskIndex::build(&ctx).unwrap();
+        let filter = ListFilter::from_args(args, index.buckets()).unwrap();
+        collect_tasks(&index, &filter, Utc::now())
+            .unwrap()
+            .iter()
+            .map(|t| t.info.id.clone())
+            .collect()
+    }
+
+    #[test]
+    #[serial]
+    fn test_list_filters_by_tag_priority_and_bucket() {
+        let temp_dir = create_test_repo();
+        let _guard = DirGuard::new(temp_dir.path());
+
+        cmd_init().unwrap();
+        add_task("Net task", "high", &["net"]);
+        add_task("UI task", "low", &["ui"]);
+        add_task("Net cleanup", "low", &["net", "cleanup"]);
+
+        assert_eq!(
+            listed_ids(&list_args()),
+            vec!["TASK-001", "TASK-002", "TASK-003"]
+        );
+
+        let mut args = list_args();
+        args.tag = vec!["net".to_string()];
+        assert_eq!(listed_ids(&args), vec!["TASK-001", "TASK-003"]);
+
+        args.priority = Some("LOW".to_string());
+        assert_eq!(listed_ids(&args), vec!["TASK-003"]);
+
+        let mut args = list_args();
+        args.bucket = vec!["qa".to_string()];
+        assert!(listed_ids(&args).is_empty());
+
+        let mut args = list_args();
+        args.bucket = vec!["NOPE".to_string()];
+        let buckets = crate::config::StatesConfig::default().buckets;
+        assert!(ListFilter::from_args(&args, &buckets).is_err());
+
+        let mut args = list_args();
+        args.query = Some("tag:net -title:cleanup bucket:ready".to_string());
+        assert_eq!(listed_ids(&args), vec!["TASK-001"]);
+        args.tag = vec!["cleanup".to_string()];
+        assert!(listed_ids(&args).is_empty());
+    }
+
+    #[test]
+    #[serial]
+    fn test_task_record_includes_bucket_path_and_extra_fields() {
+        let temp_dir = create_test_repo();
+        let _guard = DirGuard::new(temp_dir.path());
+
+        cmd_init().unwrap();
+        add_task("Record task", "high", &["net"]);
+
+        let ctx = require_initialized_workflow().unwrap();
+        let index = TaskIndex::build(&ctx).unwrap();
+        let info = index.find("TASK-001").unwrap();
+        let mut task = TaskFile::load(&info.path).unwrap();
+        task.frontmatter.extra.insert(
+            "estimate".to_string(),
+            serde_yaml::Value::String("2d".to_string()),
+        );
+
+        let record = task_record(&ctx, &ListedTask { info, task }).unwrap();
+        assert_eq!(record["id"], "TASK-001");
+        assert_eq!(record["bucket"], "READY");
+        assert_eq!(record["priority"], "high");
+        assert_eq!(record["tags"][0], "net");
+        assert_eq!(record["estimate"], "2d");
+        assert_eq!(
+            record["path"],
+            ".burl/.workflow/READY/TASK-001-record-task.md"
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_render_csv_escapes_fields() {
+        let temp_dir = create_test_repo();
+        let _guard = DirGuard::new(temp_dir.path());
+
+        cmd_init().unwrap();
+        add_task("Fix parser, \"quoted\"", "medium", &["a", "b"]);
+
+        let ctx = require_initialized_workflow().unwrap();
+        let index = TaskIndex::build(&ctx).unwrap();
+        let tasks = collect_tasks(&index, &ListFilter::default(), Utc::now()).unwrap();
+        let csv = render_csv(&ctx, &tasks).unwrap();
+
+        let mut lines = csv.lines();
+        assert_eq!(lines.next().unwrap(), CSV_COLUMNS.join(","));
+        let row = lines.next().unwrap();
+        assert!(row.starts_with("TASK-001,READY,\"Fix parser, \"\"quoted\"\"\",medium,"));
+        assert!(row.contains(",a;b,"));
+    }
+}
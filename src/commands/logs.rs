@@ -0,0 +1,193 @@
+//! Implementation of the `burl logs` command.
+//!
+//! Prints a task's saved agent logs (`agent-logs/<TASK-ID>/`) or, with
+//! `--validation`, the full step output of a validation run
+//! (`validation-logs/<TASK-ID>/<attempt>/`, see [`crate::validation_logs`]).
+
+use crate::cli::LogsArgs;
+use crate::context::{WorkflowContext, require_initialized_workflow};
+use crate::error::{BurlError, Result};
+use crate::validation_logs::{attempt_dir, attempts, log_step_name, sanitize_step_name};
+use crate::workflow::validate_task_id;
+use std::path::{Path, PathBuf};
+
+/// Execute the `burl logs` command.
+pub fn cmd_logs(args: LogsArgs) -> Result<()> {
+    let ctx = require_initialized_workflow()?;
+    let task_id = validate_task_id(&args.task_id)?;
+
+    if args.validation {
+        print_validation_logs(&ctx, &task_id, args.attempt, args.step.as_deref())
+    } else {
+        print_agent_logs(&ctx, &task_id)
+    }
+}
+
+fn print_agent_logs(ctx: &WorkflowContext, task_id: &str) -> Result<()> {
+    let dir = ctx.task_agent_logs_dir(task_id);
+    let files: Vec<PathBuf> = ["stdout.log", "stderr.log"]
+        .iter()
+        .map(|name| dir.join(name))
+        .filter(|path| path.is_file())
+        .collect();
+    if files.is_empty() {
+        return Err(BurlError::UserError(format!(
+            "no agent logs for {}.\n\n\
+             Agent logs are written by `burl agent run`. \
+             Use `burl logs {} --validation` for validation logs.",
+            task_id, task_id
+        )));
+    }
+
+    print_files(&files)
+}
+
+fn print_validation_logs(
+    ctx: &WorkflowContext,
+    task_id: &str,
+    attempt: Option<u32>,
+    step: Option<&str>,
+) -> Result<()> {
+    let available = attempts(ctx, task_id);
+    let Some(&latest) = available.last() else {
+        return Err(BurlError::UserError(format!(
+            "no validation logs for {}.\n\n\
+             Logs are saved when `burl validate` or `burl approve` runs validation commands.",
+            task_id
+        )));
+    };
+
+    let attempt = attempt.unwrap_or(latest);
+    if !available.contains(&attempt) {
+        return Err(BurlError::UserError(format!(
+            "no validation attempt {} for {}.\n\nAvailable attempts: {}",
+            attempt,
+            task_id,
+            join(&available)
+        )));
+    }
+
+    let dir = attempt_dir(ctx, task_id, attempt);
+    let logs = step_logs(&dir)?;
+
+    let files = match step {
+        Some(step) => {
+            let wanted = sanitize_step_name(step);
+            let matching: Vec<PathBuf> = logs
+                .iter()
+                .filter(|path| log_step_name(path) == Some(wanted.as_str()))
+                .cloned()
+                .collect();
+            if matching.is_empty() {
+                let steps: Vec<&str> = logs.iter().filter_map(|path| log_step_name(path)).collect();
+                return Err(BurlError::UserError(format!(
+                    "no log for step '{}' in validation attempt {} of {}.\n\nSteps with logs: {}",
+                    step,
+                    attempt,
+                    task_id,
+                    join(&steps)
+                )));
+            }
+            matching
+        }
+        None => logs,
+    };
+
+    println!("{} validation attempt {}", task_id, attempt);
+    println!();
+    print_files(&files)
+}
+
+/// `.log` files in an attempt directory, sorted by name.
+fn step_logs(dir: &Path) -> Result<Vec<PathBuf>> {
+    let entries = std::fs::read_dir(dir).map_err(|e| {
+        BurlError::UserError(format!(
+            "failed to read validation logs '{}': {}",
+            dir.display(),
+            e
+        ))
+    })?;
+    let mut logs: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "log"))
+        .collect();
+    logs.sort();
+    Ok(logs)
+}
+
+/// Print each file under a `==> name <==` header.
+fn print_files(files: &[PathBuf]) -> Result<()> {
+    for (idx, path) in files.iter().enumerate() {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            BurlError::UserError(format!("failed to read log '{}': {}", path.display(), e))
+        })?;
+        if idx > 0 {
+            println!();
+        }
+        println!("==> {} <==", step_name(path));
+        print!("{}", content);
+        if !content.is_empty() && !content.ends_with('\n') {
+            println!();
+        }
+    }
+    Ok(())
+}
+
+fn step_name(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+fn join<T: ToString>(items: &[T]) -> String {
+    items
+        .iter()
+        .map(|item| item.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::init::cmd_init;
+    use crate::test_support::{DirGuard, create_test_repo};
+    use crate::validate::ValidationStepResult;
+    use crate::validation_logs::save_validation_logs;
+    use serial_test::serial;
+
+    fn args(validation: bool, attempt: Option<u32>, step: Option<&str>) -> LogsArgs {
+        LogsArgs {
+            task_id: "TASK-001".to_string(),
+            validation,
+            attempt,
+            step: step.map(str::to_string),
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_logs_validation_attempts_and_steps() {
+        let temp_dir = create_test_repo();
+        let _guard = DirGuard::new(temp_dir.path());
+        cmd_init().unwrap();
+        let ctx = require_initialized_workflow().unwrap();
+
+        let err = cmd_logs(args(true, None, None)).unwrap_err();
+        assert!(err.to_string().contains("no validation logs for TASK-001"));
+        let err = cmd_logs(args(false, None, None)).unwrap_err();
+        assert!(err.to_string().contains("no agent logs for TASK-001"));
+
+        let mut test = ValidationStepResult::pass("test");
+        test.log = Some("ok\n".to_string());
+        save_validation_logs(&ctx, "TASK-001", &[test]).unwrap();
+
+        cmd_logs(args(true, None, None)).unwrap();
+        cmd_logs(args(true, Some(1), Some("test"))).unwrap();
+
+        let err = cmd_logs(args(true, Some(7), None)).unwrap_err();
+        assert!(err.to_string().contains("Available attempts: 1"));
+        let err = cmd_logs(args(true, None, Some("lint"))).unwrap_err();
+        assert!(err.to_string().contains("Steps with logs: test"));
+    }
+}
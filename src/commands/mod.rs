@@ -6,13 +6,25 @@
 pub mod add;
 pub mod agent;
 pub mod approve;
+mod archive;
+pub mod block;
 pub mod claim;
 pub mod clean;
 pub mod doctor;
+mod edit;
+mod export;
+mod graph;
+mod import;
 pub mod init;
+mod list;
+mod logs;
 pub mod monitor;
+mod move_cmd;
+mod plan;
 pub mod reject;
+pub mod release;
 mod show;
+mod split;
 mod status;
 pub mod submit;
 pub mod validate_cmd;
@@ -21,7 +33,8 @@ mod worktree;
 
 use crate::cli::{
     AgentAction, AgentCommand, ApproveArgs, ClaimArgs, CleanArgs, Command, DoctorArgs, LockAction,
-    LockClearArgs, LockCommand, MonitorArgs, RejectArgs, SubmitArgs, ValidateArgs, WatchArgs,
+    LockClearArgs, LockCommand, MonitorArgs, RejectArgs, ReleaseArgs, SubmitArgs, ValidateArgs,
+    WatchArgs,
 };
 use crate::config::Config;
 use crate::context::require_initialized_workflow;
@@ -38,17 +51,31 @@ pub fn dispatch(command: Command) -> Result<()> {
     match command {
         Command::Init => init::cmd_init(),
         Command::Add(args) => add::cmd_add(args),
+        Command::Import(args) => import::cmd_import(args),
+        Command::Export(args) => export::cmd_export(args),
         Command::Status => status::cmd_status(),
         Command::Show(args) => show::cmd_show(args),
+        Command::List(args) => list::cmd_list(args),
+        Command::Search(args) => list::cmd_search(args),
+        Command::Edit(args) => edit::cmd_edit(*args),
+        Command::Graph(args) => graph::cmd_graph(args),
+        Command::Plan(args) => plan::cmd_plan(args),
         Command::Claim(args) => cmd_claim(args),
+        Command::Release(args) => cmd_release(args),
         Command::Submit(args) => cmd_submit(args),
         Command::Validate(args) => cmd_validate(args),
         Command::Approve(args) => cmd_approve(args),
         Command::Reject(args) => cmd_reject(args),
+        Command::Block(args) => block::cmd_block(args),
+        Command::Unblock(args) => block::cmd_unblock(args),
+        Command::Move(args) => move_cmd::cmd_move(args),
+        Command::Split(args) => split::cmd_split(args),
         Command::Worktree(args) => worktree::cmd_worktree(args),
+        Command::Logs(args) => logs::cmd_logs(args),
         Command::Lock(lock_cmd) => dispatch_lock(lock_cmd),
         Command::Doctor(args) => cmd_doctor(args),
         Command::Clean(args) => cmd_clean(args),
+        Command::Archive(args) => archive::cmd_archive(args),
         Command::Watch(args) => cmd_watch(args),
         Command::Monitor(args) => cmd_monitor(args),
         Command::Agent(agent_cmd) => dispatch_agent(agent_cmd),
@@ -81,6 +108,10 @@ fn cmd_claim(args: ClaimArgs) -> Result<()> {
     claim::cmd_claim(args)
 }
 
+fn cmd_release(args: ReleaseArgs) -> Result<()> {
+    release::cmd_release(args)
+}
+
 fn cmd_submit(args: SubmitArgs) -> Result<()> {
     submit::cmd_submit(args)
 }
@@ -2,7 +2,8 @@
 //!
 //! This is a lightweight, refresh-based dashboard intended to be cross-platform
 //! without pulling in a full TUI dependency stack. It uses ANSI escape codes
-//! to clear the screen between refreshes.
+//! to clear the screen between refreshes. `--query` narrows the bucket counts
+//! and task sections to tasks matching a [`crate::query`] expression.
 
 use crate::cli::MonitorArgs;
 use crate::config::Config;
@@ -10,8 +11,8 @@ use crate::context::require_initialized_workflow;
 use crate::error::Result;
 use crate::events::Event;
 use crate::locks;
-use crate::task::TaskFile;
-use crate::workflow::{BUCKETS, TaskIndex};
+use crate::query::TaskQuery;
+use crate::workflow::{BUCKETS, TaskIndex, TaskInfo};
 use chrono::{DateTime, Duration as ChronoDuration, Utc};
 use std::cmp::Ordering;
 use std::fs;
@@ -37,13 +38,18 @@ struct TaskRow {
 pub fn cmd_monitor(args: MonitorArgs) -> Result<()> {
     let ctx = require_initialized_workflow()?;
     let config = Config::load(ctx.config_path()).unwrap_or_default();
+    let query = args
+        .query
+        .as_deref()
+        .map(|q| TaskQuery::parse(q, &config.states.buckets))
+        .transpose()?;
 
     loop {
         if args.clear {
             clear_screen();
         }
 
-        render_once(&ctx, &config, &args)?;
+        render_once(&ctx, &config, &args, query.as_ref())?;
 
         if args.once {
             break;
@@ -59,11 +65,18 @@ fn render_once(
     ctx: &crate::context::WorkflowContext,
     config: &Config,
     args: &MonitorArgs,
+    query: Option<&TaskQuery>,
 ) -> Result<()> {
     let now = Utc::now();
 
     let index = TaskIndex::build(ctx)?;
-    let bucket_counts = index.bucket_counts();
+    let in_bucket = |bucket: &str| -> Vec<&TaskInfo> {
+        let tasks = index.tasks_in_bucket(bucket);
+        match query {
+            Some(query) => query.filter(tasks),
+            None => tasks,
+        }
+    };
     let active_locks = locks::list_locks(ctx, config)?;
 
     println!("Burl Monitor  (Ctrl+C to exit)");
@@ -71,24 +84,28 @@ fn render_once(
     println!();
     println!("Repo:     {}", ctx.repo_root.display());
     println!("Workflow: {}", ctx.workflow_worktree.display());
+    if let Some(filter) = &args.query {
+        println!("Query:    {}", filter);
+    }
     println!();
 
     // Buckets
     println!("Buckets:");
-    let total: usize = bucket_counts.values().sum();
-    for bucket in BUCKETS {
-        let count = bucket_counts.get(*bucket).copied().unwrap_or(0);
-        print!("  {:8} {:>3}", bucket, count);
+    let mut total = 0;
+    for bucket in index.buckets() {
+        let tasks = in_bucket(bucket);
+        total += tasks.len();
+        print!("  {:8} {:>3}", bucket, tasks.len());
 
         // Add quick highlights
-        if *bucket == "DOING" {
-            let stalled = count_stalled(&index, "DOING", DOING_STALL_HOURS);
+        if bucket == "DOING" {
+            let stalled = count_stalled(&index, &tasks, "DOING", DOING_STALL_HOURS);
             if stalled > 0 {
                 print!("  ({} stalled)", stalled);
             }
         }
-        if *bucket == "QA" {
-            let stalled = count_stalled(&index, "QA", QA_STALL_HOURS);
+        if bucket == "QA" {
+            let stalled = count_stalled(&index, &tasks, "QA", QA_STALL_HOURS);
             if stalled > 0 {
                 print!("  ({} stalled)", stalled);
             }
@@ -127,8 +144,9 @@ fn render_once(
 
     // DOING tasks
     render_bucket_tasks(
-        "DOING",
         &index,
+        "DOING",
+        in_bucket("DOING"),
         args.limit,
         |a, b| match (a.started_at, b.started_at) {
             (Some(a_ts), Some(b_ts)) => a_ts.cmp(&b_ts),
@@ -156,8 +174,9 @@ fn render_once(
 
     // QA tasks
     render_bucket_tasks(
-        "QA",
         &index,
+        "QA",
+        in_bucket("QA"),
         args.limit,
         |a, b| match (a.submitted_at, b.submitted_at) {
             (Some(a_ts), Some(b_ts)) => a_ts.cmp(&b_ts),
@@ -184,6 +203,32 @@ fn render_once(
         },
     )?;
 
+    // Tasks in custom buckets (`states.buckets`)
+    for bucket in index
+        .buckets()
+        .iter()
+        .filter(|b| !BUCKETS.contains(&b.as_str()))
+    {
+        render_bucket_tasks(
+            &index,
+            bucket,
+            in_bucket(bucket),
+            args.limit,
+            |a, b| a.id.cmp(&b.id),
+            now,
+            |t| {
+                let assignee = t.assigned_to.as_deref().unwrap_or("-");
+                format!(
+                    "{}  [{}]  {}  {}",
+                    t.id,
+                    t.priority,
+                    assignee,
+                    truncate_title(&t.title, 80)
+                )
+            },
+        )?;
+    }
+
     // Recent events
     if args.tail > 0 {
         let events = read_last_events(&ctx.events_file(), args.tail);
@@ -208,18 +253,23 @@ fn render_once(
     }
 
     // Helpful hint
-    if total == 0 {
+    if total == 0 && query.is_some() {
+        println!("No tasks match the query.");
+        println!();
+    } else if total == 0 {
         println!("No tasks in the workflow. Run `burl add \"title\"` to create a task.");
         println!();
     }
 
+    index.save_cache()?;
     io::stdout().flush().ok();
     Ok(())
 }
 
 fn render_bucket_tasks<FSort, FLine>(
-    bucket: &str,
     index: &TaskIndex,
+    bucket: &str,
+    tasks: Vec<&TaskInfo>,
     limit: usize,
     sort: FSort,
     now: DateTime<Utc>,
@@ -229,22 +279,21 @@ where
     FSort: Fn(&TaskRow, &TaskRow) -> Ordering,
     FLine: Fn(&TaskRow) -> String,
 {
-    let tasks = index.tasks_in_bucket(bucket);
     if tasks.is_empty() {
         return Ok(());
     }
 
     let mut rows: Vec<TaskRow> = Vec::new();
     for info in tasks {
-        match TaskFile::load(&info.path) {
-            Ok(task_file) => rows.push(TaskRow {
+        match index.frontmatter(info) {
+            Ok(frontmatter) => rows.push(TaskRow {
                 id: info.id.clone(),
-                title: task_file.frontmatter.title,
-                priority: task_file.frontmatter.priority,
-                assigned_to: task_file.frontmatter.assigned_to,
-                qa_attempts: task_file.frontmatter.qa_attempts,
-                started_at: task_file.frontmatter.started_at,
-                submitted_at: task_file.frontmatter.submitted_at,
+                title: frontmatter.title,
+                priority: frontmatter.priority,
+                assigned_to: frontmatter.assigned_to,
+                qa_attempts: frontmatter.qa_attempts,
+                started_at: frontmatter.started_at,
+                submitted_at: frontmatter.submitted_at,
             }),
             Err(_) => rows.push(TaskRow {
                 id: info.id.clone(),
@@ -288,21 +337,18 @@ where
     Ok(())
 }
 
-fn count_stalled(index: &TaskIndex, bucket: &str, stall_hours: i64) -> usize {
+fn count_stalled(index: &TaskIndex, tasks: &[&TaskInfo], bucket: &str, stall_hours: i64) -> usize {
     let now = Utc::now();
     let threshold = ChronoDuration::hours(stall_hours);
 
-    index
-        .tasks_in_bucket(bucket)
+    tasks
         .iter()
-        .filter_map(|info| TaskFile::load(&info.path).ok())
-        .filter(|task| match bucket {
-            "DOING" => task
-                .frontmatter
+        .filter_map(|info| index.frontmatter(info).ok())
+        .filter(|frontmatter| match bucket {
+            "DOING" => frontmatter
                 .started_at
                 .is_some_and(|ts| now.signed_duration_since(ts) > threshold),
-            "QA" => task
-                .frontmatter
+            "QA" => frontmatter
                 .submitted_at
                 .is_some_and(|ts| now.signed_duration_since(ts) > threshold),
             _ => false,
@@ -0,0 +1,633 @@
+//! Implementation of the `burl move` command.
+//!
+//! Moves a task along an edge declared under `states.transitions` in
+//! config.yaml, e.g. `QA -> REVIEW` for a custom review bucket. The built-in
+//! transitions keep their dedicated commands (`claim`, `submit`, `approve`,
+//! ...); `move` only takes configured edges, each of which enters or leaves a
+//! custom bucket, and never moves a task to DONE.
+//!
+//! Before the task moves, the gates configured for the edge run in order:
+//!
+//! - `approval`: the caller must pass `--approve`
+//! - `scope` / `stubs`: the same checks as `burl submit`, against the task
+//!   worktree and its `base_sha`
+//! - `profile`: the named validation profile's command steps
+//!
+//! Like `block`, the command takes the per-task lock plus `workflow.lock`,
+//! appends a history entry and a `move` event, and commits the workflow branch.
+
+use crate::cli::MoveArgs;
+use crate::config::{Config, StateTransition, TransitionGate};
+use crate::context::{WorkflowContext, require_initialized_workflow};
+use crate::diff::{added_lines, changed_files};
+use crate::error::{BurlError, Result};
+use crate::events::{Event, EventAction, append_event, get_actor_string};
+use crate::git::run_git;
+use crate::locks::{acquire_task_lock, acquire_workflow_lock};
+use crate::output::{self, CommandResult, textln};
+use crate::task::TaskFile;
+use crate::validate::{
+    ValidationStepResult, run_command_steps, validate_scope, validate_stubs_with_config,
+};
+use crate::workflow::{TaskIndex, validate_task_id};
+use chrono::Utc;
+use serde_json::json;
+
+/// Execute the `burl move` command.
+///
+/// # Exit Codes
+///
+/// - 0: Success
+/// - 1: User error (unknown bucket, undeclared transition, missing `--approve`)
+/// - 2: A scope, stubs, or profile gate failed
+/// - 4: Lock contention
+pub fn cmd_move(args: MoveArgs) -> Result<()> {
+    let ctx = require_initialized_workflow()?;
+    let config = Config::load(ctx.config_path()).unwrap_or_default();
+
+    // ========================================================================
+    // Phase 1: Resolve the task and the transition
+    // ========================================================================
+
+    let destination = args.state.trim().to_uppercase();
+    if !config.states.buckets.contains(&destination) {
+        return Err(BurlError::UserError(format!(
+            "unknown bucket '{}'.\n\n\
+             Configured buckets: {}",
+            args.state,
+            config.states.buckets.join(", ")
+        )));
+    }
+
+    let task_id = validate_task_id(&args.task_id)?;
+    let index = TaskIndex::build(&ctx)?;
+    let task_info = index.find(&task_id).ok_or_else(|| {
+        BurlError::UserError(format!(
+            "task '{}' not found.\n\n\
+             Use `burl status` to see available tasks.",
+            task_id
+        ))
+    })?;
+
+    if task_info.archived {
+        return Err(BurlError::UserError(format!(
+            "task '{}' is archived and cannot be moved.",
+            task_info.id
+        )));
+    }
+
+    let from_bucket = task_info.bucket.as_str();
+    let transition = config
+        .states
+        .transition(from_bucket, &destination)
+        .ok_or_else(|| {
+            undeclared_transition_error(&config, &task_info.id, from_bucket, &destination)
+        })?;
+
+    output::record(
+        CommandResult::new("move")
+            .with_task(&task_info.id)
+            .with_from_bucket(from_bucket),
+    );
+
+    // ========================================================================
+    // Phase 2: Acquire per-task lock and run gates
+    // ========================================================================
+
+    let _task_lock = acquire_task_lock(&ctx, &task_info.id, "move")?;
+    let mut task_file = TaskFile::load(&task_info.path)?;
+
+    if transition.gates.contains(&TransitionGate::Approval) && !args.approve {
+        return Err(BurlError::UserError(format!(
+            "moving {} from {} to {} requires human approval.\n\n\
+             Re-run with --approve to confirm:\n  burl move {} {} --approve",
+            task_info.id, from_bucket, destination, task_info.id, destination
+        )));
+    }
+
+    let results = run_gates(&ctx, &config, transition, &task_file, &task_info.id)?;
+    output::update(|r| {
+        r.branch = task_file.frontmatter.branch.clone();
+        r.worktree = task_file.frontmatter.worktree.clone();
+        r.validation = results.clone();
+    });
+
+    let failures: Vec<&ValidationStepResult> = results.iter().filter(|r| !r.is_success()).collect();
+    if !failures.is_empty() {
+        let mut msg = format!(
+            "cannot move {} from {} to {}: {} gate(s) failed.\n",
+            task_info.id,
+            from_bucket,
+            destination,
+            failures.len()
+        );
+        for failure in failures {
+            msg.push_str(&format!(
+                "\n[{}] {}\n",
+                failure.name,
+                failure.message.as_deref().unwrap_or("failed")
+            ));
+        }
+        return Err(BurlError::ValidationError(msg));
+    }
+
+    // ========================================================================
+    // Phase 3: Acquire workflow lock and move the task
+    // ========================================================================
+
+    ctx.ensure_workflow_clean()?;
+    let _workflow_lock = acquire_workflow_lock(&ctx, "move")?;
+
+    let now = Utc::now();
+    task_file.append_to_history(&format!(
+        "### Moved: {}\n\n\
+         **Actor:** {}\n\
+         **From:** {}\n\
+         **To:** {}\n",
+        now.format("%Y-%m-%d %H:%M:%S UTC"),
+        get_actor_string(),
+        from_bucket,
+        destination
+    ));
+    task_file.save(&task_info.path)?;
+
+    let filename = task_info
+        .path
+        .file_name()
+        .ok_or_else(|| BurlError::UserError("invalid task file path".to_string()))?;
+    let destination_path = ctx.bucket_path(&destination).join(filename);
+    crate::fs::move_file(&task_info.path, &destination_path).map_err(|e| {
+        BurlError::UserError(format!(
+            "failed to move task from {} to {}: {}\n\n\
+             Task file: {}\n\
+             Destination: {}",
+            from_bucket,
+            destination,
+            e,
+            task_info.path.display(),
+            destination_path.display()
+        ))
+    })?;
+
+    // ========================================================================
+    // Phase 4: Event, commit, output
+    // ========================================================================
+
+    let gates: Vec<&str> = transition.gates.iter().map(|g| g.as_str()).collect();
+    let event = Event::new(EventAction::Move)
+        .with_task(&task_info.id)
+        .with_details(json!({
+            "title": task_file.frontmatter.title,
+            "from": from_bucket,
+            "to": destination,
+            "gates": gates,
+            "profile": transition.profile,
+        }));
+    append_event(&ctx, &event)?;
+
+    if config.workflow_auto_commit {
+        commit_move(
+            &ctx,
+            &format!(
+                "Move task {} ({} -> {})",
+                task_info.id, from_bucket, destination
+            ),
+        )?;
+
+        if config.workflow_auto_push {
+            push_workflow_branch(&ctx, &config)?;
+        }
+    }
+
+    output::update(|r| {
+        r.to_bucket = Some(destination.clone());
+        r.details = json!({
+            "title": task_file.frontmatter.title,
+            "gates": gates,
+            "profile": transition.profile,
+        });
+    });
+
+    textln!();
+    textln!("Moved task: {}", task_info.id);
+    textln!("  Title:       {}", task_file.frontmatter.title);
+    textln!("  From:        {}", from_bucket);
+    textln!("  To:          {}", destination);
+    if !results.is_empty() {
+        textln!();
+        textln!("Gates:");
+        for result in &results {
+            let status = result.label();
+            textln!("  {}: {}", result.name, status);
+        }
+    }
+
+    Ok(())
+}
+
+/// Error for a move that no configured transition allows.
+fn undeclared_transition_error(config: &Config, task_id: &str, from: &str, to: &str) -> BurlError {
+    let allowed: Vec<&str> = config
+        .states
+        .transitions
+        .iter()
+        .filter(|t| t.from == from)
+        .map(|t| t.to.as_str())
+        .collect();
+    let allowed = if allowed.is_empty() {
+        "(none)".to_string()
+    } else {
+        allowed.join(", ")
+    };
+
+    BurlError::UserError(format!(
+        "no transition from {} to {} is configured for task '{}'.\n\n\
+         Configured `burl move` destinations from {}: {}\n\
+         Built-in transitions use their own commands (claim, submit, approve, reject, block, ...);\n\
+         declare other edges under `states.transitions` in config.yaml.",
+        from, to, task_id, from, allowed
+    ))
+}
+
+/// Run the scope, stubs, and profile gates of a transition.
+///
+/// Returns one result per gate; the approval gate is checked by the caller.
+fn run_gates(
+    ctx: &WorkflowContext,
+    config: &Config,
+    transition: &StateTransition,
+    task_file: &TaskFile,
+    task_id: &str,
+) -> Result<Vec<ValidationStepResult>> {
+    let checks_scope = transition.gates.contains(&TransitionGate::Scope);
+    let checks_stubs = transition.gates.contains(&TransitionGate::Stubs);
+    let profile = match &transition.profile {
+        Some(name) => Some(config.validation_profiles.get(name).ok_or_else(|| {
+            BurlError::UserError(format!(
+                "transition {} -> {} uses validation profile '{}', which is not configured.",
+                transition.from, transition.to, name
+            ))
+        })?),
+        None => None,
+    };
+
+    if !checks_scope && !checks_stubs && profile.is_none() {
+        return Ok(Vec::new());
+    }
+
+    let refs = crate::task_git::require_task_git_refs(
+        ctx,
+        task_id,
+        task_file.frontmatter.branch.as_deref(),
+        task_file.frontmatter.worktree.as_deref(),
+    )?;
+    if !refs.worktree_path.exists() {
+        return Err(BurlError::UserError(format!(
+            "task worktree does not exist at '{}'.\n\n\
+             Run `burl doctor` to diagnose and repair this inconsistency.",
+            refs.worktree_path.display()
+        )));
+    }
+    let base_sha = task_file.frontmatter.base_sha.as_deref().ok_or_else(|| {
+        BurlError::UserError(format!(
+            "task '{}' has no recorded base_sha.\n\n\
+             This task may be in an invalid state. Run `burl doctor` to diagnose.",
+            task_id
+        ))
+    })?;
+
+    let changed = changed_files(&refs.worktree_path, base_sha)?;
+    let mut results = Vec::new();
+
+    if checks_scope {
+        let scope = validate_scope(&task_file.frontmatter, &changed)?;
+        results.push(if scope.passed {
+            ValidationStepResult::pass("scope")
+        } else {
+            ValidationStepResult::fail("scope", scope.format_error(task_id))
+        });
+    }
+
+    if checks_stubs {
+        let added = added_lines(&refs.worktree_path, base_sha)?;
+        let stubs = validate_stubs_with_config(config, &added)?;
+        results.push(if stubs.passed {
+            ValidationStepResult::pass("stubs")
+        } else {
+            ValidationStepResult::fail("stubs", stubs.format_error())
+        });
+    }
+
+    if let Some(profile) = profile {
+        results.extend(run_command_steps(
+            &profile.steps,
+            &changed,
+            &refs.worktree_path,
+            &config.worktree_setup.resolved_env(&ctx.repo_root),
+            profile.parallelism(),
+            None,
+        ));
+    }
+
+    Ok(results)
+}
+
+/// Commit the move to the workflow branch.
+fn commit_move(ctx: &WorkflowContext, commit_msg: &str) -> Result<()> {
+    run_git(&ctx.workflow_worktree, &["add", "."])
+        .map_err(|e| BurlError::GitError(format!("failed to stage changes: {}", e)))?;
+
+    let staged = run_git(&ctx.workflow_worktree, &["diff", "--cached", "--name-only"])?;
+    if staged.stdout.is_empty() {
+        return Ok(());
+    }
+
+    run_git(&ctx.workflow_worktree, &["commit", "-m", commit_msg])
+        .map_err(|e| BurlError::GitError(format!("failed to commit: {}", e)))?;
+
+    Ok(())
+}
+
+/// Push the workflow branch to the remote.
+fn push_workflow_branch(ctx: &WorkflowContext, config: &Config) -> Result<()> {
+    run_git(
+        &ctx.workflow_worktree,
+        &["push", &config.remote, &config.workflow_branch],
+    )
+    .map_err(|e| BurlError::GitError(format!("failed to push workflow branch: {}", e)))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{AddArgs, ApproveArgs, ClaimArgs, SubmitArgs};
+    use crate::commands::add::cmd_add;
+    use crate::commands::approve::cmd_approve;
+    use crate::commands::claim::cmd_claim;
+    use crate::commands::init::cmd_init;
+    use crate::commands::submit::cmd_submit;
+    use crate::events::read_events;
+    use crate::exit_codes;
+    use crate::test_support::{DirGuard, create_test_repo, create_test_repo_with_remote};
+    use serial_test::serial;
+    use std::path::{Path, PathBuf};
+
+    fn move_args(state: &str, approve: bool) -> MoveArgs {
+        MoveArgs {
+            task_id: "TASK-001".to_string(),
+            state: state.to_string(),
+            approve,
+        }
+    }
+
+    /// Add a TRIAGE bucket with READY -> TRIAGE (approval) and TRIAGE -> READY.
+    fn configure_triage(ctx: &WorkflowContext) {
+        let mut config = Config::load(ctx.config_path()).unwrap();
+        config.states.buckets.push("TRIAGE".to_string());
+        config.states.transitions = vec![
+            StateTransition {
+                from: "READY".to_string(),
+                to: "TRIAGE".to_string(),
+                gates: vec![TransitionGate::Approval],
+                profile: None,
+            },
+            StateTransition {
+                from: "TRIAGE".to_string(),
+                to: "READY".to_string(),
+                ..Default::default()
+            },
+        ];
+        std::fs::write(ctx.config_path(), config.to_yaml().unwrap()).unwrap();
+        run_git(
+            &ctx.workflow_worktree,
+            &["commit", "-am", "Configure states"],
+        )
+        .unwrap();
+    }
+
+    /// Add a REVIEW bucket (the only bucket `approve` accepts from) reached
+    /// from QA through an edge with `gates` and, optionally, a `check` profile
+    /// running `command`.
+    fn configure_review(ctx: &WorkflowContext, gates: &str, command: Option<&str>) {
+        let mut yaml = String::from("build_command: \"\"\n");
+        if let Some(command) = command {
+            yaml.push_str(&format!(
+                "validation_profiles:\n  check:\n    steps:\n      - name: check\n        command: \"{}\"\n",
+                command
+            ));
+        }
+        yaml.push_str(&format!(
+            "states:\n  buckets: [READY, DOING, QA, REVIEW, DONE, BLOCKED]\n  approve_from: [REVIEW]\n  transitions:\n    - from: QA\n      to: REVIEW\n      gates: [{}]\n",
+            gates
+        ));
+        if command.is_some() {
+            yaml.push_str("      profile: check\n");
+        }
+        std::fs::write(ctx.config_path(), yaml).unwrap();
+        run_git(
+            &ctx.workflow_worktree,
+            &["commit", "-am", "Configure states"],
+        )
+        .unwrap();
+    }
+
+    /// Add, claim, implement (within `src/**`) and submit TASK-001.
+    fn submit_task(repo: &Path) -> PathBuf {
+        cmd_add(AddArgs {
+            title: "Review me".to_string(),
+            priority: "medium".to_string(),
+            affects: vec![],
+            affects_globs: vec!["src/**".to_string()],
+            must_not_touch: vec![],
+            depends_on: vec![],
+            tags: vec![],
+            parent: None,
+            template: None,
+            kind: None,
+        })
+        .unwrap();
+        cmd_claim(ClaimArgs {
+            task_id: Some("TASK-001".to_string()),
+        })
+        .unwrap();
+
+        let worktree = repo.join(".worktrees/task-001-review-me");
+        commit_file(&worktree, "src/lib.rs", "pub fn reviewed() {}\n");
+        cmd_submit(SubmitArgs {
+            task_id: Some("TASK-001".to_string()),
+        })
+        .unwrap();
+        worktree
+    }
+
+    fn commit_file(worktree: &Path, path: &str, content: &str) {
+        let path = worktree.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, content).unwrap();
+        run_git(worktree, &["add", "."]).unwrap();
+        run_git(worktree, &["commit", "-m", "Change"]).unwrap();
+    }
+
+    fn bucket_of(ctx: &WorkflowContext, task_id: &str) -> String {
+        TaskIndex::build(ctx)
+            .unwrap()
+            .find(task_id)
+            .unwrap()
+            .bucket
+            .clone()
+    }
+
+    #[test]
+    #[serial]
+    fn test_move_fails_scope_gate() {
+        let temp_dir = create_test_repo_with_remote();
+        let _guard = DirGuard::new(temp_dir.path());
+        cmd_init().unwrap();
+
+        let ctx = require_initialized_workflow().unwrap();
+        configure_review(&ctx, "scope", None);
+        let worktree = submit_task(temp_dir.path());
+        commit_file(&worktree, "README.md", "# Out of scope\n");
+
+        let err = cmd_move(move_args("REVIEW", false)).unwrap_err();
+        assert_eq!(err.exit_code(), exit_codes::VALIDATION_FAILURE);
+        assert!(err.to_string().contains("1 gate(s) failed"));
+        assert!(err.to_string().contains("[scope]"));
+        assert!(err.to_string().contains("README.md"));
+        assert_eq!(bucket_of(&ctx, "TASK-001"), "QA");
+    }
+
+    #[test]
+    #[serial]
+    fn test_move_fails_stubs_gate() {
+        let temp_dir = create_test_repo_with_remote();
+        let _guard = DirGuard::new(temp_dir.path());
+        cmd_init().unwrap();
+
+        let ctx = require_initialized_workflow().unwrap();
+        configure_review(&ctx, "scope, stubs", None);
+        let worktree = submit_task(temp_dir.path());
+        commit_file(
+            &worktree,
+            "src/stub.rs",
+            "pub fn later() {\n    todo!()\n}\n",
+        );
+
+        let err = cmd_move(move_args("REVIEW", false)).unwrap_err();
+        assert_eq!(err.exit_code(), exit_codes::VALIDATION_FAILURE);
+        assert!(err.to_string().contains("1 gate(s) failed"));
+        assert!(err.to_string().contains("[stubs]"));
+        assert!(err.to_string().contains("src/stub.rs"));
+        assert_eq!(bucket_of(&ctx, "TASK-001"), "QA");
+    }
+
+    #[test]
+    #[serial]
+    fn test_move_runs_profile_gate() {
+        let temp_dir = create_test_repo_with_remote();
+        let _guard = DirGuard::new(temp_dir.path());
+        cmd_init().unwrap();
+
+        let ctx = require_initialized_workflow().unwrap();
+        configure_review(&ctx, "", Some("git no-such-subcommand"));
+        submit_task(temp_dir.path());
+
+        let err = cmd_move(move_args("REVIEW", false)).unwrap_err();
+        assert_eq!(err.exit_code(), exit_codes::VALIDATION_FAILURE);
+        assert!(err.to_string().contains("[check]"));
+        assert_eq!(bucket_of(&ctx, "TASK-001"), "QA");
+
+        // A passing profile lets the task through.
+        configure_review(&ctx, "", Some("git --version"));
+        cmd_move(move_args("REVIEW", false)).unwrap();
+        assert_eq!(bucket_of(&ctx, "TASK-001"), "REVIEW");
+    }
+
+    #[test]
+    #[serial]
+    fn test_approve_from_custom_review_bucket() {
+        let temp_dir = create_test_repo_with_remote();
+        let _guard = DirGuard::new(temp_dir.path());
+        cmd_init().unwrap();
+
+        let ctx = require_initialized_workflow().unwrap();
+        configure_review(&ctx, "scope, stubs", None);
+        submit_task(temp_dir.path());
+
+        // QA is no longer an approve_from bucket.
+        let approve = || {
+            cmd_approve(ApproveArgs {
+                task_id: "TASK-001".to_string(),
+                no_cache: false,
+            })
+        };
+        let err = approve().unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Only tasks in REVIEW can be approved")
+        );
+
+        cmd_move(move_args("REVIEW", false)).unwrap();
+        approve().unwrap();
+        assert_eq!(bucket_of(&ctx, "TASK-001"), "DONE");
+    }
+
+    #[test]
+    #[serial]
+    fn test_move_along_configured_transitions() {
+        let temp_dir = create_test_repo();
+        let _guard = DirGuard::new(temp_dir.path());
+        cmd_init().unwrap();
+
+        let ctx = require_initialized_workflow().unwrap();
+        configure_triage(&ctx);
+        cmd_add(AddArgs {
+            title: "Needs triage".to_string(),
+            priority: "medium".to_string(),
+            affects: vec![],
+            affects_globs: vec![],
+            must_not_touch: vec![],
+            depends_on: vec![],
+            tags: vec![],
+            parent: None,
+            template: None,
+            kind: None,
+        })
+        .unwrap();
+
+        // The approval gate requires --approve.
+        let err = cmd_move(move_args("triage", false)).unwrap_err();
+        assert_eq!(err.exit_code(), exit_codes::USER_ERROR);
+        assert!(err.to_string().contains("requires human approval"));
+
+        cmd_move(move_args("triage", true)).unwrap();
+        let index = TaskIndex::build(&ctx).unwrap();
+        assert_eq!(index.find("TASK-001").unwrap().bucket, "TRIAGE");
+        assert_eq!(index.bucket_counts()["TRIAGE"], 1);
+
+        let task = TaskFile::load(&index.find("TASK-001").unwrap().path).unwrap();
+        assert!(task.body.contains("**From:** READY\n**To:** TRIAGE"));
+
+        let event = read_events(&ctx).unwrap().pop().unwrap();
+        assert_eq!(event.action, EventAction::Move);
+        assert_eq!(event.details["from"], "READY");
+        assert_eq!(event.details["to"], "TRIAGE");
+        assert_eq!(event.details["gates"], json!(["approval"]));
+
+        // Undeclared edges and unknown buckets are rejected.
+        let err = cmd_move(move_args("DOING", false)).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("no transition from TRIAGE to DOING")
+        );
+        assert!(err.to_string().contains("from TRIAGE: READY"));
+        let err = cmd_move(move_args("LIMBO", false)).unwrap_err();
+        assert!(err.to_string().contains("unknown bucket 'LIMBO'"));
+
+        cmd_move(move_args("READY", false)).unwrap();
+        let index = TaskIndex::build(&ctx).unwrap();
+        assert_eq!(index.find("TASK-001").unwrap().bucket, "READY");
+    }
+}
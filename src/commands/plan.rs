@@ -0,0 +1,290 @@
+//! Implementation of the `burl plan` command.
+//!
+//! Analyzes the remaining work in the `depends_on` graph:
+//! - the READY tasks that can be claimed now, in the order `burl claim` would
+//!   pick them (honoring `claim_tie_break`),
+//! - READY tasks still waiting on unfinished dependencies,
+//! - the critical path: the longest chain of open tasks, which bounds how
+//!   soon everything can be done,
+//! - for each open task, how many open tasks transitively depend on it.
+
+use crate::cli::PlanArgs;
+use crate::commands::claim::selection::claim_order_key;
+use crate::commands::list::to_json;
+use crate::config::{ClaimTieBreak, Config};
+use crate::context::require_initialized_workflow;
+use crate::error::Result;
+use crate::graph::{DependencyGraph, GraphNode};
+use crate::workflow::{TaskIndex, task_key};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+/// Execute the `burl plan` command.
+pub fn cmd_plan(args: PlanArgs) -> Result<()> {
+    let ctx = require_initialized_workflow()?;
+    let config = Config::load(ctx.config_path()).unwrap_or_default();
+
+    let index = TaskIndex::build(&ctx)?;
+    let graph = DependencyGraph::build(&index)?;
+    index.save_cache()?;
+    let plan = Plan::new(&graph, config.claim_tie_break);
+
+    match args.format.as_str() {
+        "json" => println!("{}", to_json(&plan.to_value(), true)?),
+        _ => print!("{}", render_text(&plan)),
+    }
+
+    Ok(())
+}
+
+/// Readiness analysis of the dependency graph.
+struct Plan<'a> {
+    graph: &'a DependencyGraph,
+    tie_break: ClaimTieBreak,
+    /// Claimable tasks in claim order.
+    claimable: Vec<&'a GraphNode>,
+    /// READY tasks with unfinished dependencies, with those dependencies.
+    waiting: Vec<(&'a GraphNode, Vec<String>)>,
+    /// Critical path in execution order.
+    critical_path: Vec<String>,
+    /// Open transitive dependents per task.
+    downstream: HashMap<String, usize>,
+}
+
+impl<'a> Plan<'a> {
+    fn new(graph: &'a DependencyGraph, tie_break: ClaimTieBreak) -> Self {
+        let downstream = graph.downstream_counts();
+
+        let mut claimable = graph.claimable();
+        claimable.sort_by_key(|node| {
+            claim_order_key(
+                &node.priority,
+                downstream.get(&node.id).copied().unwrap_or(0),
+                &node.id,
+                tie_break,
+            )
+        });
+
+        let waiting = graph
+            .nodes()
+            .filter(|node| node.bucket == "READY")
+            .filter_map(|node| {
+                let unmet: Vec<String> = node
+                    .depends_on
+                    .iter()
+                    .filter(|dep| graph.get(dep).is_none_or(|d| d.bucket != "DONE"))
+                    .cloned()
+                    .collect();
+                (!unmet.is_empty()).then_some((node, unmet))
+            })
+            .collect();
+
+        Self {
+            graph,
+            tie_break,
+            claimable,
+            waiting,
+            critical_path: graph.critical_path(),
+            downstream,
+        }
+    }
+
+    fn downstream_of(&self, id: &str) -> usize {
+        self.downstream.get(id).copied().unwrap_or(0)
+    }
+
+    /// Open tasks in ID order.
+    fn open_tasks(&self) -> impl Iterator<Item = &'a GraphNode> + 'a {
+        self.graph.nodes().filter(|node| node.bucket != "DONE")
+    }
+
+    fn to_value(&self) -> Value {
+        let claimable: Vec<Value> = self
+            .claimable
+            .iter()
+            .map(|node| {
+                json!({
+                    "id": node.id,
+                    "title": node.title,
+                    "priority": node.priority,
+                    "downstream": self.downstream_of(&node.id),
+                })
+            })
+            .collect();
+        let waiting: Vec<Value> = self
+            .waiting
+            .iter()
+            .map(|(node, unmet)| {
+                json!({
+                    "id": node.id,
+                    "title": node.title,
+                    "unmet_dependencies": unmet,
+                })
+            })
+            .collect();
+        let critical_path: Vec<Value> = self
+            .critical_path
+            .iter()
+            .filter_map(|id| self.graph.get(id))
+            .map(|node| json!({"id": node.id, "bucket": node.bucket, "title": node.title}))
+            .collect();
+        let tasks: Vec<Value> = self
+            .open_tasks()
+            .map(|node| {
+                json!({
+                    "id": node.id,
+                    "bucket": node.bucket,
+                    "title": node.title,
+                    "downstream": self.downstream_of(&node.id),
+                })
+            })
+            .collect();
+
+        json!({
+            "claim_tie_break": self.tie_break,
+            "claimable": claimable,
+            "waiting": waiting,
+            "critical_path": critical_path,
+            "tasks": tasks,
+        })
+    }
+}
+
+/// Render the plan as human-readable sections.
+fn render_text(plan: &Plan) -> String {
+    if plan.open_tasks().next().is_none() {
+        return "No open tasks.\n".to_string();
+    }
+
+    let mut out = String::new();
+
+    out.push_str("Claimable now (claim order):\n");
+    if plan.claimable.is_empty() {
+        out.push_str("  (none)\n");
+    }
+    for node in &plan.claimable {
+        out.push_str(&format!(
+            "  {}  {:<6}  unblocks {:<3}  {}\n",
+            node.id,
+            node.priority,
+            plan.downstream_of(&node.id),
+            node.title
+        ));
+    }
+
+    if !plan.waiting.is_empty() {
+        out.push_str("\nWaiting on dependencies:\n");
+        for (node, unmet) in &plan.waiting {
+            out.push_str(&format!("  {}  {}\n", node.id, node.title));
+            for dep in unmet {
+                let status = match plan.graph.get(dep) {
+                    Some(dep_node) => format!("[{}]", dep_node.bucket),
+                    None => "(missing)".to_string(),
+                };
+                out.push_str(&format!("    <- {} {}\n", dep, status));
+            }
+        }
+    }
+
+    out.push_str(&format!(
+        "\nCritical path ({} task(s)):\n",
+        plan.critical_path.len()
+    ));
+    for id in &plan.critical_path {
+        if let Some(node) = plan.graph.get(id) {
+            out.push_str(&format!("  {} [{}] {}\n", node.id, node.bucket, node.title));
+        }
+    }
+
+    let mut blocking: Vec<&GraphNode> = plan
+        .open_tasks()
+        .filter(|node| plan.downstream_of(&node.id) > 0)
+        .collect();
+    blocking.sort_by_key(|node| {
+        (
+            std::cmp::Reverse(plan.downstream_of(&node.id)),
+            task_key(&node.id),
+        )
+    });
+    if !blocking.is_empty() {
+        out.push_str("\nDownstream work (open dependents):\n");
+        for node in blocking {
+            out.push_str(&format!(
+                "  {}  {:>3}  {}\n",
+                node.id,
+                plan.downstream_of(&node.id),
+                node.title
+            ));
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(id: &str, bucket: &str, priority: &str, deps: &[&str]) -> GraphNode {
+        GraphNode {
+            id: id.to_string(),
+            title: format!("Title {}", id),
+            bucket: bucket.to_string(),
+            priority: priority.to_string(),
+            depends_on: deps.iter().map(|d| d.to_string()).collect(),
+            parent: None,
+        }
+    }
+
+    /// TASK-003 unblocks two tasks; TASK-002 unblocks none.
+    fn sample() -> DependencyGraph {
+        let mut graph = DependencyGraph::default();
+        graph.insert(node("TASK-001", "DONE", "high", &[]));
+        graph.insert(node("TASK-002", "READY", "medium", &["TASK-001"]));
+        graph.insert(node("TASK-003", "READY", "medium", &[]));
+        graph.insert(node("TASK-004", "READY", "low", &["TASK-003"]));
+        graph.insert(node("TASK-005", "BLOCKED", "low", &["TASK-004"]));
+        graph
+    }
+
+    fn claim_order(plan: &Plan) -> Vec<String> {
+        plan.claimable.iter().map(|n| n.id.clone()).collect()
+    }
+
+    #[test]
+    fn test_plan_claim_order_follows_tie_break() {
+        let graph = sample();
+
+        let plan = Plan::new(&graph, ClaimTieBreak::Id);
+        assert_eq!(claim_order(&plan), vec!["TASK-002", "TASK-003"]);
+
+        let plan = Plan::new(&graph, ClaimTieBreak::Downstream);
+        assert_eq!(claim_order(&plan), vec!["TASK-003", "TASK-002"]);
+        assert_eq!(plan.downstream_of("TASK-003"), 2);
+        assert_eq!(plan.critical_path, vec!["TASK-003", "TASK-004", "TASK-005"]);
+    }
+
+    #[test]
+    fn test_plan_text_and_json() {
+        let graph = sample();
+        let plan = Plan::new(&graph, ClaimTieBreak::Downstream);
+
+        let text = render_text(&plan);
+        assert!(text.contains("  TASK-003  medium  unblocks 2    Title TASK-003\n"));
+        assert!(text.contains(
+            "Waiting on dependencies:\n  TASK-004  Title TASK-004\n    <- TASK-003 [READY]"
+        ));
+        assert!(text.contains("Critical path (3 task(s)):\n  TASK-003 [READY]"));
+        assert!(text.contains("Downstream work (open dependents):\n  TASK-003    2"));
+
+        let value = plan.to_value();
+        assert_eq!(value["claim_tie_break"], "downstream");
+        assert_eq!(value["claimable"][0]["id"], "TASK-003");
+        assert_eq!(
+            value["waiting"][0]["unmet_dependencies"],
+            json!(["TASK-003"])
+        );
+        assert_eq!(value["critical_path"][2]["id"], "TASK-005");
+        assert_eq!(value["tasks"].as_array().unwrap().len(), 4);
+    }
+}
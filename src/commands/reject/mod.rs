@@ -13,7 +13,7 @@
 //!
 //! 1. Acquire per-task lock (`TASK-XXX.lock`)
 //! 2. Verify task is in QA
-//! 3. Verify --reason is non-empty
+//! 3. Verify --reason is non-empty, then run the `pre_reject` hook (see [`crate::hooks`])
 //! 4. Acquire `workflow.lock` for workflow-state mutation
 //! 5. Increment qa_attempts
 //! 6. Append reason to QA Report with timestamp and actor
@@ -21,7 +21,7 @@
 //! 8. Optional: boost priority on retry if configured
 //! 9. Move QA -> READY (or BLOCKED)
 //! 10. Clear submitted_at for rework
-//! 11. Append reject event and commit workflow branch
+//! 11. Append reject event, run the `post_reject` hook, and commit workflow branch
 //! 12. If workflow_auto_push, push the workflow branch
 //! 13. Release locks
 
@@ -33,8 +33,10 @@ use crate::cli::RejectArgs;
 use crate::config::Config;
 use crate::context::require_initialized_workflow;
 use crate::error::{BurlError, Result};
-use crate::events::{Event, EventAction, append_event};
+use crate::events::{Event, EventAction, append_event, get_actor_string};
+use crate::hooks::{HookTask, run_post_hook, run_pre_hook};
 use crate::locks::{acquire_task_lock, acquire_workflow_lock};
+use crate::output::{self, CommandResult, textln};
 use crate::task::TaskFile;
 use crate::workflow::{TaskIndex, validate_task_id};
 use chrono::Utc;
@@ -42,19 +44,6 @@ use serde_json::json;
 
 use git_ops::{commit_reject, push_workflow_branch};
 
-/// Get the actor string for event metadata and QA Report.
-fn get_actor_string() -> String {
-    let user = std::env::var("USER")
-        .or_else(|_| std::env::var("USERNAME"))
-        .unwrap_or_else(|_| "unknown".to_string());
-
-    let host = hostname::get()
-        .map(|h| h.to_string_lossy().to_string())
-        .unwrap_or_else(|_| "unknown".to_string());
-
-    format!("{}@{}", user, host)
-}
-
 /// Execute the `burl reject` command.
 ///
 /// Rejects a task in QA by incrementing qa_attempts, appending the rejection reason,
@@ -101,16 +90,22 @@ pub fn cmd_reject(args: RejectArgs) -> Result<()> {
         ))
     })?;
 
-    // Verify task is in QA bucket
-    if task_info.bucket != "QA" {
+    // Verify task is in QA (or a review bucket from `states.approve_from`)
+    if !config.states.in_review(&task_info.bucket) {
         return Err(BurlError::UserError(format!(
             "task '{}' is not in QA (currently in {}).\n\n\
-             Only tasks in QA can be rejected.\n\
+             Only tasks in QA or a review bucket can be rejected.\n\
              Use `burl status` to see tasks in each bucket.",
             task_info.id, task_info.bucket
         )));
     }
 
+    output::record(
+        CommandResult::new("reject")
+            .with_task(&task_info.id)
+            .with_from_bucket(&task_info.bucket),
+    );
+
     // ========================================================================
     // Phase 3: Acquire per-task lock and load task file
     // ========================================================================
@@ -119,6 +114,18 @@ pub fn cmd_reject(args: RejectArgs) -> Result<()> {
 
     let mut task_file = TaskFile::load(&task_info.path)?;
 
+    run_pre_hook(
+        &ctx,
+        &config,
+        "reject",
+        &HookTask::new(
+            &task_info.id,
+            &task_info.bucket,
+            &task_info.path,
+            &task_file.frontmatter,
+        ),
+    )?;
+
     // ========================================================================
     // Phase 4: Workflow state mutation (requires workflow lock)
     // ========================================================================
@@ -127,7 +134,7 @@ pub fn cmd_reject(args: RejectArgs) -> Result<()> {
     ctx.ensure_workflow_clean()?;
 
     // Acquire workflow lock
-    let _workflow_lock = acquire_workflow_lock(&ctx, "reject")?;
+    let workflow_lock = acquire_workflow_lock(&ctx, "reject")?;
 
     // Increment qa_attempts
     task_file.increment_qa_attempts();
@@ -170,10 +177,14 @@ pub fn cmd_reject(args: RejectArgs) -> Result<()> {
     // Clear submitted_at for rework
     task_file.frontmatter.submitted_at = None;
 
+    if let Some(reason) = &blocked_reason {
+        task_file.set_blocked(reason, now);
+    }
+
     // Save the updated task file
     task_file.save(&task_info.path)?;
 
-    // Move task QA -> destination bucket
+    // Move task QA (or review bucket) -> destination bucket
     let filename = task_info
         .path
         .file_name()
@@ -182,9 +193,10 @@ pub fn cmd_reject(args: RejectArgs) -> Result<()> {
 
     crate::fs::move_file(&task_info.path, &destination_path).map_err(|e| {
         BurlError::UserError(format!(
-            "failed to move task from QA to {}: {}\n\n\
+            "failed to move task from {} to {}: {}\n\n\
              Task file: {}\n\
              Destination: {}",
+            task_info.bucket,
             destination_bucket,
             e,
             task_info.path.display(),
@@ -215,36 +227,67 @@ pub fn cmd_reject(args: RejectArgs) -> Result<()> {
         }
     }
 
+    drop(workflow_lock);
+    run_post_hook(
+        &ctx,
+        &config,
+        "reject",
+        &HookTask::new(
+            &task_id,
+            destination_bucket,
+            &destination_path,
+            &task_file.frontmatter,
+        ),
+    );
+
     // ========================================================================
     // Phase 5: Print results
     // ========================================================================
 
-    println!();
-    println!("Rejected task: {}", task_id);
-    println!("  Title:       {}", task_file.frontmatter.title);
-    println!("  Reason:      {}", reason);
-    println!("  From:        QA");
-    println!("  To:          {}", destination_bucket);
-    println!("  QA Attempts: {}/{}", qa_attempts, config.qa_max_attempts);
+    output::record(
+        CommandResult::new("reject")
+            .with_task(&task_id)
+            .with_from_bucket(&task_info.bucket)
+            .with_to_bucket(destination_bucket)
+            .with_git(
+                task_file.frontmatter.branch.as_deref(),
+                task_file.frontmatter.worktree.as_deref(),
+            )
+            .with_details(json!({
+                "title": task_file.frontmatter.title,
+                "reason": reason,
+                "qa_attempts": qa_attempts,
+                "max_attempts": config.qa_max_attempts,
+                "blocked_reason": blocked_reason,
+            })),
+    );
+
+    textln!();
+    textln!("Rejected task: {}", task_id);
+    textln!("  Title:       {}", task_file.frontmatter.title);
+    textln!("  Reason:      {}", reason);
+    textln!("  From:        {}", task_info.bucket);
+    textln!("  To:          {}", destination_bucket);
+    textln!("  QA Attempts: {}/{}", qa_attempts, config.qa_max_attempts);
 
     if destination_bucket == "BLOCKED" {
-        println!();
-        println!(
+        textln!();
+        textln!(
             "This task has exceeded the maximum QA attempts ({}).",
             config.qa_max_attempts
         );
-        println!("It has been moved to BLOCKED and requires manual intervention.");
+        textln!("It has been moved to BLOCKED and requires manual intervention.");
     } else {
         if config.auto_priority_boost_on_retry {
-            println!(
+            textln!(
                 "  Priority:    {} (boosted)",
                 task_file.frontmatter.priority
             );
         }
-        println!();
-        println!("The task branch and worktree have been preserved for rework.");
+        textln!();
+        textln!("The task branch and worktree have been preserved for rework.");
         if let Some(worktree) = &task_file.frontmatter.worktree {
-            println!("  Worktree: {}", worktree);
+            textln!("  Worktree: {}", worktree);
         }
     }
 
@@ -26,6 +26,9 @@ fn setup_task_in_qa(temp_dir: &TempDir) -> PathBuf {
         must_not_touch: vec![],
         depends_on: vec![],
         tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
     })
     .unwrap();
 
@@ -82,6 +85,9 @@ fn test_reject_task_not_in_qa_fails() {
         must_not_touch: vec![],
         depends_on: vec![],
         tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
     })
     .unwrap();
 
@@ -343,6 +349,13 @@ fn test_reject_max_attempts_moves_to_blocked() {
     // Verify task metadata
     let task = TaskFile::load(&blocked_path).unwrap();
     assert_eq!(task.frontmatter.qa_attempts, 1);
+    assert!(
+        task.frontmatter
+            .blocked_reason
+            .as_deref()
+            .is_some_and(|r| r.contains("max QA attempts"))
+    );
+    assert!(task.frontmatter.blocked_at.is_some());
 }
 
 #[test]
@@ -0,0 +1,44 @@
+//! Git operations for the release command.
+//!
+//! This module contains git-related helpers for committing and pushing
+//! the release workflow state changes.
+
+use crate::config::Config;
+use crate::error::{BurlError, Result};
+use crate::git::run_git;
+
+/// Commit the release to the workflow branch.
+pub(super) fn commit_release(
+    ctx: &crate::context::WorkflowContext,
+    task_id: &str,
+    title: &str,
+) -> Result<()> {
+    run_git(&ctx.workflow_worktree, &["add", "."])
+        .map_err(|e| BurlError::GitError(format!("failed to stage release changes: {}", e)))?;
+
+    let staged = run_git(&ctx.workflow_worktree, &["diff", "--cached", "--name-only"])?;
+    if staged.stdout.is_empty() {
+        return Ok(());
+    }
+
+    let commit_msg = format!("Release task {}: {}", task_id, title);
+
+    run_git(&ctx.workflow_worktree, &["commit", "-m", &commit_msg])
+        .map_err(|e| BurlError::GitError(format!("failed to commit release: {}", e)))?;
+
+    Ok(())
+}
+
+/// Push the workflow branch to the remote.
+pub(super) fn push_workflow_branch(
+    ctx: &crate::context::WorkflowContext,
+    config: &Config,
+) -> Result<()> {
+    run_git(
+        &ctx.workflow_worktree,
+        &["push", &config.remote, &config.workflow_branch],
+    )
+    .map_err(|e| BurlError::GitError(format!("failed to push workflow branch: {}", e)))?;
+
+    Ok(())
+}
@@ -0,0 +1,285 @@
+//! Implementation of the `burl release` command.
+//!
+//! This module implements the DOING -> READY transition (the inverse of claim):
+//! - Verify task is in DOING
+//! - Remove the task worktree (unless `--keep-worktree`)
+//! - Clear `assigned_to` and `started_at`
+//! - Move DOING -> READY
+//! - Optionally delete the task branch (`--drop-branch`)
+//! - Append release event and commit workflow branch
+//!
+//! # Transaction Steps
+//!
+//! 1. Acquire per-task lock (`TASK-XXX.lock`)
+//! 2. Run the `pre_release` hook (see [`crate::hooks`])
+//! 3. Remove the worktree (branch is kept so committed work survives)
+//! 4. Verify workflow worktree has no unexpected tracked modifications
+//! 5. Acquire `workflow.lock` for workflow-state mutation
+//! 6. Atomically update task frontmatter and move DOING -> READY
+//! 7. Append release event, run the `post_release` hook, and commit workflow branch
+//! 8. With `--drop-branch`, delete the task branch (best-effort)
+//! 9. Release locks
+//!
+//! # Rollback
+//!
+//! If the workflow mutation fails after the worktree was removed, the worktree
+//! is recreated on the (still existing) task branch.
+
+mod git_ops;
+#[cfg(test)]
+mod tests;
+mod transaction;
+
+use crate::cli::ReleaseArgs;
+use crate::config::Config;
+use crate::context::require_initialized_workflow;
+use crate::error::{BurlError, Result};
+use crate::events::{Event, EventAction, append_event};
+use crate::git_worktree::{cleanup_task_worktree, remove_worktree};
+use crate::hooks::{HookTask, run_post_hook, run_pre_hook};
+use crate::locks::{acquire_task_lock, acquire_workflow_lock};
+use crate::output::{self, CommandResult, textln};
+use crate::task::TaskFile;
+use crate::workflow::{TaskIndex, validate_task_id};
+use serde_json::json;
+
+use git_ops::{commit_release, push_workflow_branch};
+use transaction::ReleaseTransaction;
+
+/// Execute the `burl release` command.
+///
+/// Returns a DOING task to READY so it can be claimed again.
+///
+/// By default the task worktree is removed but the branch (and the recorded
+/// branch/worktree/base_sha) are kept, so a later claim recreates the
+/// worktree on the same branch. `--keep-worktree` leaves the worktree in
+/// place; `--drop-branch` also deletes the branch and clears git metadata so
+/// the next claim starts fresh.
+///
+/// # Exit Codes
+///
+/// - 0: Success
+/// - 1: User error (task not in DOING, invalid recorded git state)
+/// - 3: Git failure (e.g. worktree has uncommitted changes)
+/// - 4: Lock contention
+pub fn cmd_release(args: ReleaseArgs) -> Result<()> {
+    let ctx = require_initialized_workflow()?;
+    let config = Config::load(ctx.config_path()).unwrap_or_default();
+
+    // ========================================================================
+    // Phase 1: Task Resolution and Validation
+    // ========================================================================
+
+    let index = TaskIndex::build(&ctx)?;
+
+    let task_id = validate_task_id(&args.task_id)?;
+
+    let task_info = index.find(&task_id).ok_or_else(|| {
+        BurlError::UserError(format!(
+            "task '{}' not found.\n\n\
+             Use `burl status` to see available tasks.",
+            task_id
+        ))
+    })?;
+
+    if task_info.bucket != "DOING" {
+        return Err(BurlError::UserError(format!(
+            "task '{}' is not in DOING (currently in {}).\n\n\
+             Only tasks in DOING can be released.",
+            task_info.id, task_info.bucket
+        )));
+    }
+
+    output::record(
+        CommandResult::new("release")
+            .with_task(&task_info.id)
+            .with_from_bucket("DOING"),
+    );
+
+    // ========================================================================
+    // Phase 2: Acquire per-task lock and load task file
+    // ========================================================================
+
+    let _task_lock = acquire_task_lock(&ctx, &task_info.id, "release")?;
+
+    let mut task_file = TaskFile::load(&task_info.path)?;
+
+    let git_refs = crate::task_git::validate_task_git_refs_if_present(
+        &ctx,
+        &task_info.id,
+        task_file.frontmatter.branch.as_deref(),
+        task_file.frontmatter.worktree.as_deref(),
+    )?;
+
+    run_pre_hook(
+        &ctx,
+        &config,
+        "release",
+        &HookTask::new(
+            &task_info.id,
+            "DOING",
+            &task_info.path,
+            &task_file.frontmatter,
+        ),
+    )?;
+
+    // ========================================================================
+    // Phase 3: Remove worktree (branch is preserved)
+    // ========================================================================
+
+    let mut transaction = ReleaseTransaction::new();
+
+    if !args.keep_worktree
+        && let Some(refs) = &git_refs
+        && refs.worktree_path.exists()
+    {
+        remove_worktree(&ctx.repo_root, &refs.worktree_path, false)?;
+        transaction.branch_name = refs.branch.clone();
+        transaction.removed_worktree = Some(refs.worktree_path.clone());
+    }
+
+    // ========================================================================
+    // Phase 4: Workflow State Mutation (under workflow lock)
+    // ========================================================================
+
+    if let Err(e) = ctx.ensure_workflow_clean() {
+        transaction.rollback(&ctx.repo_root);
+        return Err(e);
+    }
+
+    let workflow_lock = match acquire_workflow_lock(&ctx, "release") {
+        Ok(lock) => lock,
+        Err(e) => {
+            transaction.rollback(&ctx.repo_root);
+            return Err(e);
+        }
+    };
+
+    let previous_assignee = task_file.frontmatter.assigned_to.clone();
+    task_file.clear_claim();
+    if args.drop_branch {
+        task_file.clear_git_info();
+    }
+
+    if let Err(e) = task_file.save(&task_info.path) {
+        transaction.rollback(&ctx.repo_root);
+        return Err(e);
+    }
+
+    let filename = match task_info.path.file_name() {
+        Some(name) => name,
+        None => {
+            transaction.rollback(&ctx.repo_root);
+            return Err(BurlError::UserError("invalid task file path".to_string()));
+        }
+    };
+    let ready_path = ctx.bucket_path("READY").join(filename);
+
+    if let Err(e) = crate::fs::move_file(&task_info.path, &ready_path) {
+        transaction.rollback(&ctx.repo_root);
+        return Err(BurlError::UserError(format!(
+            "failed to move task from DOING to READY: {}\n\n\
+             Task file: {}\n\
+             Destination: {}",
+            e,
+            task_info.path.display(),
+            ready_path.display()
+        )));
+    }
+
+    // ========================================================================
+    // Phase 5: Event Logging and Commit
+    // ========================================================================
+
+    let worktree_removed = transaction.removed_worktree.is_some();
+    let event = Event::new(EventAction::Release)
+        .with_task(&task_id)
+        .with_details(json!({
+            "title": task_file.frontmatter.title,
+            "previous_assignee": previous_assignee,
+            "branch": git_refs.as_ref().map(|r| r.branch.clone()),
+            "worktree_removed": worktree_removed,
+            "branch_dropped": args.drop_branch && git_refs.is_some(),
+        }));
+    append_event(&ctx, &event)?;
+
+    if config.workflow_auto_commit {
+        commit_release(&ctx, &task_id, &task_file.frontmatter.title)?;
+
+        if config.workflow_auto_push {
+            push_workflow_branch(&ctx, &config)?;
+        }
+    }
+
+    drop(workflow_lock);
+    run_post_hook(
+        &ctx,
+        &config,
+        "release",
+        &HookTask::new(&task_id, "READY", &ready_path, &task_file.frontmatter),
+    );
+
+    // ========================================================================
+    // Phase 6: Branch cleanup (best-effort, workflow state already committed)
+    // ========================================================================
+
+    if args.drop_branch
+        && let Some(refs) = &git_refs
+        && let Err(e) = cleanup_task_worktree(
+            &ctx.repo_root,
+            &refs.branch,
+            Some(&refs.worktree_path),
+            true,
+        )
+    {
+        eprintln!(
+            "Warning: task released but failed to clean up branch '{}': {}",
+            refs.branch, e
+        );
+    }
+
+    // ========================================================================
+    // Phase 7: Output
+    // ========================================================================
+
+    output::record(
+        CommandResult::new("release")
+            .with_task(&task_id)
+            .with_from_bucket("DOING")
+            .with_to_bucket("READY")
+            .with_git(
+                git_refs.as_ref().map(|r| r.branch.as_str()),
+                task_file.frontmatter.worktree.as_deref(),
+            )
+            .with_details(json!({
+                "title": task_file.frontmatter.title,
+                "previous_assignee": previous_assignee,
+                "worktree_removed": worktree_removed,
+                "branch_dropped": args.drop_branch && git_refs.is_some(),
+            })),
+    );
+
+    textln!();
+    textln!("Released task: {}", task_id);
+    textln!("  Title:       {}", task_file.frontmatter.title);
+    textln!("  From:        DOING");
+    textln!("  To:          READY");
+
+    match (&git_refs, args.drop_branch, args.keep_worktree) {
+        (Some(refs), true, _) => {
+            textln!("  Branch:      {} (deleted)", refs.branch);
+        }
+        (Some(refs), false, true) => {
+            textln!("  Branch:      {} (kept)", refs.branch);
+            textln!("  Worktree:    {} (kept)", refs.worktree_path.display());
+        }
+        (Some(refs), false, false) => {
+            textln!("  Branch:      {} (kept)", refs.branch);
+            textln!();
+            textln!("The worktree was removed; `burl claim` will recreate it on the same branch.");
+        }
+        (None, _, _) => {}
+    }
+
+    Ok(())
+}
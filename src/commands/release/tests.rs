@@ -0,0 +1,226 @@
+//! Tests for the release command.
+
+use super::*;
+use crate::cli::{AddArgs, ClaimArgs, DoctorArgs};
+use crate::commands::add::cmd_add;
+use crate::commands::claim::cmd_claim;
+use crate::commands::doctor::cmd_doctor;
+use crate::commands::init::cmd_init;
+use crate::exit_codes;
+use crate::git_worktree::branch_exists;
+use crate::test_support::{DirGuard, create_test_repo_with_remote};
+use serial_test::serial;
+use std::path::PathBuf;
+use std::process::Command as ProcessCommand;
+use tempfile::TempDir;
+
+/// Helper to create a claimed task with one commit on its branch.
+fn setup_task_in_doing(temp_dir: &TempDir) -> PathBuf {
+    let worktree_path = temp_dir.path().join(".worktrees/task-001-test-release");
+
+    cmd_add(AddArgs {
+        title: "Test release".to_string(),
+        priority: "medium".to_string(),
+        affects: vec![],
+        affects_globs: vec!["src/**".to_string()],
+        must_not_touch: vec![],
+        depends_on: vec![],
+        tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
+    })
+    .unwrap();
+
+    cmd_claim(ClaimArgs {
+        task_id: Some("TASK-001".to_string()),
+    })
+    .unwrap();
+
+    std::fs::create_dir_all(worktree_path.join("src")).unwrap();
+    std::fs::write(worktree_path.join("src/lib.rs"), "pub fn f() {}\n").unwrap();
+    ProcessCommand::new("git")
+        .current_dir(&worktree_path)
+        .args(["add", "."])
+        .output()
+        .expect("failed to add files");
+    ProcessCommand::new("git")
+        .current_dir(&worktree_path)
+        .args(["commit", "-m", "Work in progress"])
+        .output()
+        .expect("failed to commit");
+
+    worktree_path
+}
+
+fn release_args(keep_worktree: bool, drop_branch: bool) -> ReleaseArgs {
+    ReleaseArgs {
+        task_id: "TASK-001".to_string(),
+        keep_worktree,
+        drop_branch,
+    }
+}
+
+#[test]
+#[serial]
+fn test_release_task_not_in_doing_fails() {
+    let temp_dir = create_test_repo_with_remote();
+    let _guard = DirGuard::new(temp_dir.path());
+
+    cmd_init().unwrap();
+    cmd_add(AddArgs {
+        title: "Test task".to_string(),
+        priority: "high".to_string(),
+        affects: vec![],
+        affects_globs: vec![],
+        must_not_touch: vec![],
+        depends_on: vec![],
+        tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
+    })
+    .unwrap();
+
+    let err = cmd_release(release_args(false, false)).unwrap_err();
+    assert_eq!(err.exit_code(), exit_codes::USER_ERROR);
+    assert!(err.to_string().contains("not in DOING"));
+}
+
+#[test]
+#[serial]
+fn test_release_removes_worktree_and_keeps_branch() {
+    let temp_dir = create_test_repo_with_remote();
+    let _guard = DirGuard::new(temp_dir.path());
+
+    cmd_init().unwrap();
+    let worktree_path = setup_task_in_doing(&temp_dir);
+
+    cmd_release(release_args(false, false)).unwrap();
+
+    let ready_path = temp_dir
+        .path()
+        .join(".burl/.workflow/READY/TASK-001-test-release.md");
+    assert!(ready_path.exists(), "Task should be back in READY");
+
+    let task = TaskFile::load(&ready_path).unwrap();
+    assert!(task.frontmatter.assigned_to.is_none());
+    assert!(task.frontmatter.started_at.is_none());
+    assert_eq!(
+        task.frontmatter.branch.as_deref(),
+        Some("task-001-test-release")
+    );
+    let base_sha = task.frontmatter.base_sha.clone();
+
+    assert!(!worktree_path.exists(), "Worktree should be removed");
+    assert!(branch_exists(temp_dir.path(), "task-001-test-release").unwrap());
+
+    // Re-claiming recreates the worktree on the same branch with the original base.
+    cmd_claim(ClaimArgs {
+        task_id: Some("TASK-001".to_string()),
+    })
+    .unwrap();
+    assert!(worktree_path.join("src/lib.rs").exists());
+
+    let doing_path = temp_dir
+        .path()
+        .join(".burl/.workflow/DOING/TASK-001-test-release.md");
+    let task = TaskFile::load(&doing_path).unwrap();
+    assert_eq!(task.frontmatter.base_sha, base_sha);
+}
+
+#[test]
+#[serial]
+fn test_released_task_passes_doctor() {
+    let temp_dir = create_test_repo_with_remote();
+    let _guard = DirGuard::new(temp_dir.path());
+
+    cmd_init().unwrap();
+    setup_task_in_doing(&temp_dir);
+    cmd_release(release_args(false, false)).unwrap();
+
+    // The kept branch/worktree/base_sha aren't a bucket mismatch, so a
+    // repair must not move the task back to DOING.
+    let ctx = crate::context::require_initialized_workflow().unwrap();
+    let mut report = crate::commands::doctor::DoctorReport::new();
+    crate::commands::doctor::check_bucket_metadata_mismatches(&ctx, &mut report).unwrap();
+    assert!(report.issues.is_empty(), "{:?}", report.issues);
+
+    cmd_doctor(DoctorArgs {
+        repair: true,
+        force: true,
+    })
+    .unwrap();
+    assert!(
+        ctx.bucket_path("READY")
+            .join("TASK-001-test-release.md")
+            .exists()
+    );
+}
+
+#[test]
+#[serial]
+fn test_release_keep_worktree() {
+    let temp_dir = create_test_repo_with_remote();
+    let _guard = DirGuard::new(temp_dir.path());
+
+    cmd_init().unwrap();
+    let worktree_path = setup_task_in_doing(&temp_dir);
+
+    cmd_release(release_args(true, false)).unwrap();
+
+    assert!(worktree_path.exists(), "Worktree should be kept");
+    let ready_path = temp_dir
+        .path()
+        .join(".burl/.workflow/READY/TASK-001-test-release.md");
+    assert!(ready_path.exists());
+}
+
+#[test]
+#[serial]
+fn test_release_drop_branch_clears_git_state() {
+    let temp_dir = create_test_repo_with_remote();
+    let _guard = DirGuard::new(temp_dir.path());
+
+    cmd_init().unwrap();
+    let worktree_path = setup_task_in_doing(&temp_dir);
+
+    cmd_release(release_args(false, true)).unwrap();
+
+    assert!(!worktree_path.exists());
+    assert!(!branch_exists(temp_dir.path(), "task-001-test-release").unwrap());
+
+    let ready_path = temp_dir
+        .path()
+        .join(".burl/.workflow/READY/TASK-001-test-release.md");
+    let task = TaskFile::load(&ready_path).unwrap();
+    assert!(task.frontmatter.branch.is_none());
+    assert!(task.frontmatter.worktree.is_none());
+    assert!(task.frontmatter.base_sha.is_none());
+
+    let ctx = crate::context::WorkflowContext::resolve().unwrap();
+    let events = std::fs::read_to_string(ctx.events_file()).unwrap();
+    let last: Event = serde_json::from_str(events.lines().last().unwrap()).unwrap();
+    assert_eq!(last.action, EventAction::Release);
+    assert_eq!(last.details["branch_dropped"], true);
+}
+
+#[test]
+#[serial]
+fn test_release_refuses_dirty_worktree() {
+    let temp_dir = create_test_repo_with_remote();
+    let _guard = DirGuard::new(temp_dir.path());
+
+    cmd_init().unwrap();
+    let worktree_path = setup_task_in_doing(&temp_dir);
+    std::fs::write(worktree_path.join("src/lib.rs"), "pub fn g() {}\n").unwrap();
+
+    let err = cmd_release(release_args(false, false)).unwrap_err();
+    assert_eq!(err.exit_code(), exit_codes::GIT_FAILURE);
+
+    // Nothing moved.
+    let doing_path = temp_dir
+        .path()
+        .join(".burl/.workflow/DOING/TASK-001-test-release.md");
+    assert!(doing_path.exists());
+}
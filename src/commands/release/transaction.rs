@@ -0,0 +1,31 @@
+//! Transaction management for release operation.
+
+use crate::git_worktree::create_worktree;
+use std::path::{Path, PathBuf};
+
+/// Information about a release operation for rollback purposes.
+pub struct ReleaseTransaction {
+    /// The task branch whose worktree was removed in this transaction.
+    pub branch_name: String,
+    /// The worktree path removed in this transaction (for rollback).
+    pub removed_worktree: Option<PathBuf>,
+}
+
+impl ReleaseTransaction {
+    pub fn new() -> Self {
+        Self {
+            branch_name: String::new(),
+            removed_worktree: None,
+        }
+    }
+
+    /// Rollback the transaction by recreating the worktree if it was removed.
+    pub fn rollback(self, repo_root: &Path) {
+        if let Some(path) = self.removed_worktree
+            && !self.branch_name.is_empty()
+        {
+            // Try to recreate the worktree - ignore errors during rollback
+            let _ = create_worktree(repo_root, &path, &self.branch_name);
+        }
+    }
+}
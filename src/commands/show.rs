@@ -1,12 +1,15 @@
 //! Implementation of the `burl show` command.
 //!
-//! Displays the content and metadata of a specific task.
+//! Displays the content and metadata of a specific task, including its parent
+//! epic and, for epics, each child with its bucket. Archived tasks can be
+//! shown too.
 
 use crate::cli::ShowArgs;
 use crate::context::require_initialized_workflow;
 use crate::error::{BurlError, Result};
+use crate::graph::DependencyGraph;
 use crate::task::TaskFile;
-use crate::workflow::{BUCKETS, TaskIndex, validate_task_id};
+use crate::workflow::{TaskIndex, validate_task_id};
 
 /// Execute the `burl show` command.
 ///
@@ -27,7 +30,7 @@ pub fn cmd_show(args: ShowArgs) -> Result<()> {
              Searched buckets: {}\n\n\
              Use `burl status` to see all tasks.",
             task_id,
-            BUCKETS.join(", ")
+            index.buckets().join(", ")
         ))
     })?;
 
@@ -36,7 +39,11 @@ pub fn cmd_show(args: ShowArgs) -> Result<()> {
 
     // Print task header
     println!("================================================================================");
-    println!("{} [{}]", task_id, task_info.bucket);
+    if task_info.archived {
+        println!("{} [{}, archived]", task_id, task_info.bucket);
+    } else {
+        println!("{} [{}]", task_id, task_info.bucket);
+    }
     println!("================================================================================");
     println!();
 
@@ -119,6 +126,34 @@ pub fn cmd_show(args: ShowArgs) -> Result<()> {
         }
     }
 
+    // Print epic hierarchy if any
+    let graph = DependencyGraph::build(&index)?;
+    index.save_cache()?;
+    if let Some(parent) = &task.frontmatter.parent {
+        println!();
+        match graph.get(parent) {
+            Some(node) => println!("Parent: {} [{}] {}", node.id, node.bucket, node.title),
+            None => println!("Parent: {} (missing)", parent),
+        }
+    }
+    if let Some(rollup) = graph.rollup(&task_id) {
+        println!();
+        println!("Children: {}", rollup.summary());
+        for child in graph.children(&task_id) {
+            println!("  - {} [{}] {}", child.id, child.bucket, child.title);
+        }
+    }
+    if !task.frontmatter.superseded_by.is_empty() {
+        println!();
+        println!("Superseded by:");
+        for id in &task.frontmatter.superseded_by {
+            match graph.get(id) {
+                Some(node) => println!("  - {} [{}] {}", node.id, node.bucket, node.title),
+                None => println!("  - {} (missing)", id),
+            }
+        }
+    }
+
     // Print tags if any
     if !task.frontmatter.tags.is_empty() {
         println!();
@@ -170,6 +205,9 @@ mod tests {
             must_not_touch: vec![],
             depends_on: vec![],
             tags: vec!["feature".to_string()],
+            parent: None,
+            template: None,
+            kind: None,
         };
         cmd_add(add_args).unwrap();
 
@@ -196,6 +234,9 @@ mod tests {
             must_not_touch: vec![],
             depends_on: vec![],
             tags: vec![],
+            parent: None,
+            template: None,
+            kind: None,
         };
         cmd_add(add_args).unwrap();
 
@@ -0,0 +1,914 @@
+//! Implementation of the `burl split` command.
+//!
+//! Replaces one task with several smaller ones:
+//! - each `--into "title:scope1,scope2"` becomes a new READY task whose
+//!   `affects`/`affects_globs` are a slice of the original task's scope,
+//! - children copy the original body sections, priority, tags, agent,
+//!   `must_not_touch`, epic `parent`, and `depends_on` (with `--sequential`,
+//!   each child also depends on the previous one),
+//! - tasks that depended on the original now depend on every child,
+//! - the original records `superseded_by` and moves to DONE; its worktree is
+//!   removed but the branch is kept so committed work survives.
+//!
+//! # Scope partitioning
+//!
+//! A child entry equal to an original `affects` or `affects_globs` entry keeps
+//! that kind; otherwise entries containing glob metacharacters become
+//! `affects_globs` and the rest `affects`. When the original task declares a
+//! scope, every child entry must lie inside it and every original entry must
+//! be handed to (or narrowed by) some child, so no part of the scope is lost.
+//!
+//! # Transaction Steps
+//!
+//! 1. Acquire per-task lock (`TASK-XXX.lock`)
+//! 2. Remove the worktree (branch is kept)
+//! 3. Verify workflow worktree has no unexpected tracked modifications
+//! 4. Acquire `workflow.lock` for workflow-state mutation
+//! 5. Write the children to READY, rewire dependents, move the original to DONE
+//! 6. Append split and add events, commit workflow branch once
+//! 7. Release locks
+//!
+//! # Rollback
+//!
+//! If the workflow mutation fails, created child files are removed and the
+//! worktree is recreated on the task branch.
+
+use crate::agent::prompt::extract_sections;
+use crate::cli::SplitArgs;
+use crate::commands::add::{TaskBodySections, render_task_body};
+use crate::config::Config;
+use crate::context::{WorkflowContext, require_initialized_workflow};
+use crate::error::{BurlError, Result};
+use crate::events::{Event, EventAction, append_event};
+use crate::fs::atomic_write_file;
+use crate::git::run_git;
+use crate::git_worktree::{create_worktree, remove_worktree};
+use crate::graph::DependencyGraph;
+use crate::locks::{acquire_task_lock, acquire_workflow_lock};
+use crate::output::{self, CommandResult, textln};
+use crate::task::{TaskFile, TaskFrontmatter};
+use crate::workflow::{
+    TaskIndex, generate_task_filename, generate_task_id, split_task_id, validate_filename_safe,
+    validate_task_id,
+};
+use chrono::Utc;
+use globset::Glob;
+use serde_json::json;
+use std::path::PathBuf;
+
+/// Buckets from which a task can be split.
+const SPLITTABLE_BUCKETS: &[&str] = &["READY", "DOING", "BLOCKED"];
+
+/// Characters that make a scope entry a glob pattern.
+const GLOB_CHARS: &[char] = &['*', '?', '[', '{'];
+
+/// A child task requested with `--into`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ChildSpec {
+    title: String,
+    scope: Vec<String>,
+}
+
+/// The scope assigned to one child task.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct ChildScope {
+    affects: Vec<String>,
+    affects_globs: Vec<String>,
+}
+
+/// Execute the `burl split` command.
+///
+/// # Exit Codes
+///
+/// - 0: Success
+/// - 1: User error (bad `--into` spec, scope not partitioned, task in QA/DONE)
+/// - 3: Git failure (e.g. worktree has uncommitted changes)
+/// - 4: Lock contention
+pub fn cmd_split(args: SplitArgs) -> Result<()> {
+    let ctx = require_initialized_workflow()?;
+    let config = Config::load(ctx.config_path()).unwrap_or_default();
+
+    // ========================================================================
+    // Phase 1: Task Resolution and Validation
+    // ========================================================================
+
+    let specs = args
+        .into
+        .iter()
+        .map(|spec| parse_split_spec(spec))
+        .collect::<Result<Vec<_>>>()?;
+    if specs.len() < 2 {
+        return Err(BurlError::UserError(
+            "a task must be split into at least two children (repeat --into).".to_string(),
+        ));
+    }
+
+    let index = TaskIndex::build(&ctx)?;
+    let task_id = validate_task_id(&args.task_id)?;
+    let task_info = index.find(&task_id).ok_or_else(|| {
+        BurlError::UserError(format!(
+            "task '{}' not found.\n\n\
+             Use `burl status` to see available tasks.",
+            task_id
+        ))
+    })?;
+
+    if !SPLITTABLE_BUCKETS.contains(&task_info.bucket.as_str()) {
+        return Err(BurlError::UserError(format!(
+            "task '{}' is in {}; only tasks in READY, DOING, or BLOCKED can be split.",
+            task_info.id, task_info.bucket
+        )));
+    }
+
+    let graph = DependencyGraph::build(&index)?;
+    if graph.is_epic(&task_id) {
+        return Err(BurlError::UserError(format!(
+            "task '{}' is an epic; add child tasks with `burl add --parent {}` instead of splitting it.",
+            task_id, task_id
+        )));
+    }
+
+    let from_bucket = task_info.bucket.clone();
+    output::record(
+        CommandResult::new("split")
+            .with_task(&task_info.id)
+            .with_from_bucket(&from_bucket),
+    );
+
+    // ========================================================================
+    // Phase 2: Acquire per-task lock, load task file, partition scope
+    // ========================================================================
+
+    let _task_lock = acquire_task_lock(&ctx, &task_info.id, "split")?;
+
+    let mut task_file = TaskFile::load(&task_info.path)?;
+    let scopes = partition_scope(&task_file.frontmatter, &specs)?;
+
+    let git_refs = crate::task_git::validate_task_git_refs_if_present(
+        &ctx,
+        &task_info.id,
+        task_file.frontmatter.branch.as_deref(),
+        task_file.frontmatter.worktree.as_deref(),
+    )?;
+
+    // ========================================================================
+    // Phase 3: Remove worktree (branch is preserved)
+    // ========================================================================
+
+    let mut transaction = SplitTransaction::default();
+
+    if let Some(refs) = &git_refs
+        && refs.worktree_path.exists()
+    {
+        remove_worktree(&ctx.repo_root, &refs.worktree_path, false)?;
+        transaction.branch_name = refs.branch.clone();
+        transaction.removed_worktree = Some(refs.worktree_path.clone());
+    }
+
+    // ========================================================================
+    // Phase 4: Workflow State Mutation (under workflow lock)
+    // ========================================================================
+
+    if let Err(e) = ctx.ensure_workflow_clean() {
+        transaction.rollback(&ctx.repo_root);
+        return Err(e);
+    }
+
+    let _workflow_lock = match acquire_workflow_lock(&ctx, "split") {
+        Ok(lock) => lock,
+        Err(e) => {
+            transaction.rollback(&ctx.repo_root);
+            return Err(e);
+        }
+    };
+
+    let request = SplitRequest {
+        specs: &specs,
+        scopes: &scopes,
+        sequential: args.sequential,
+        id_width: config.id_width,
+    };
+    let outcome = match write_split(
+        &ctx,
+        &task_info.path,
+        &mut task_file,
+        &request,
+        &mut transaction,
+    ) {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            transaction.rollback(&ctx.repo_root);
+            return Err(e);
+        }
+    };
+
+    // ========================================================================
+    // Phase 5: Event Logging and Commit
+    // ========================================================================
+
+    let child_ids: Vec<String> = outcome.children.iter().map(|c| c.id.clone()).collect();
+    let worktree_removed = transaction.removed_worktree.is_some();
+
+    let event = Event::new(EventAction::Split)
+        .with_task(&task_id)
+        .with_details(json!({
+            "title": task_file.frontmatter.title,
+            "from": from_bucket,
+            "children": child_ids,
+            "rewired": outcome.rewired,
+            "branch": git_refs.as_ref().map(|r| r.branch.clone()),
+            "worktree_removed": worktree_removed,
+        }));
+    append_event(&ctx, &event)?;
+
+    for child in &outcome.children {
+        let event = Event::new(EventAction::Add)
+            .with_task(&child.id)
+            .with_details(json!({
+                "title": child.title,
+                "priority": task_file.frontmatter.priority,
+                "parent": task_file.frontmatter.parent,
+                "split_from": task_id,
+                "filename": child.filename,
+                "path": child.path.display().to_string()
+            }));
+        append_event(&ctx, &event)?;
+    }
+
+    if config.workflow_auto_commit {
+        commit_split(&ctx, &task_id, &child_ids)?;
+
+        if config.workflow_auto_push {
+            push_workflow_branch(&ctx, &config)?;
+        }
+    }
+
+    // ========================================================================
+    // Phase 6: Output
+    // ========================================================================
+
+    output::record(
+        CommandResult::new("split")
+            .with_task(&task_id)
+            .with_from_bucket(&from_bucket)
+            .with_to_bucket("DONE")
+            .with_git(
+                git_refs.as_ref().map(|r| r.branch.as_str()),
+                task_file.frontmatter.worktree.as_deref(),
+            )
+            .with_details(json!({
+                "title": task_file.frontmatter.title,
+                "children": outcome.children.iter().map(|c| json!({
+                    "id": c.id,
+                    "title": c.title,
+                    "affects": c.scope.affects,
+                    "affects_globs": c.scope.affects_globs,
+                    "depends_on": c.depends_on,
+                })).collect::<Vec<_>>(),
+                "rewired": outcome.rewired,
+                "worktree_removed": worktree_removed,
+            })),
+    );
+
+    textln!();
+    textln!("Split task: {}", task_id);
+    textln!("  Title:       {}", task_file.frontmatter.title);
+    textln!("  From:        {}", from_bucket);
+    textln!("  To:          DONE (superseded)");
+    textln!();
+    textln!("Created tasks:");
+    for child in &outcome.children {
+        textln!("  {}  {}", child.id, child.title);
+        let scope: Vec<&str> = child
+            .scope
+            .affects
+            .iter()
+            .chain(&child.scope.affects_globs)
+            .map(String::as_str)
+            .collect();
+        if !scope.is_empty() {
+            textln!("    scope:      {}", scope.join(", "));
+        }
+        if !child.depends_on.is_empty() {
+            textln!("    depends on: {}", child.depends_on.join(", "));
+        }
+    }
+    if !outcome.rewired.is_empty() {
+        textln!();
+        textln!(
+            "Now depending on {}: {}",
+            child_ids.join(", "),
+            outcome.rewired.join(", ")
+        );
+    }
+    if let Some(refs) = &git_refs {
+        textln!();
+        textln!("Branch {} was kept; its worktree was removed.", refs.branch);
+    }
+
+    Ok(())
+}
+
+/// Parse an `--into` value of the form `title:scope1,scope2`.
+///
+/// The title may itself contain colons; the scope follows the last one.
+fn parse_split_spec(spec: &str) -> Result<ChildSpec> {
+    let invalid = |reason: &str| {
+        BurlError::UserError(format!(
+            "invalid --into '{}': {}.\n\n\
+             Expected \"title:scope1,scope2\" (e.g. \"Parser:src/parse/**\").",
+            spec, reason
+        ))
+    };
+
+    let (title, scope) = spec
+        .rsplit_once(':')
+        .ok_or_else(|| invalid("missing ':' before the scope"))?;
+    let title = title.trim();
+    if title.is_empty() {
+        return Err(invalid("title is empty"));
+    }
+    let scope: Vec<String> = scope
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(str::to_string)
+        .collect();
+    if scope.is_empty() {
+        return Err(invalid("no scope entries"));
+    }
+
+    Ok(ChildSpec {
+        title: title.to_string(),
+        scope,
+    })
+}
+
+/// Assign each child's scope entries to `affects`/`affects_globs` and check
+/// that together they partition the original task's scope.
+fn partition_scope(original: &TaskFrontmatter, specs: &[ChildSpec]) -> Result<Vec<ChildScope>> {
+    let original_globs = original
+        .affects_globs
+        .iter()
+        .map(|pattern| {
+            Glob::new(pattern)
+                .map(|glob| glob.compile_matcher())
+                .map_err(|e| {
+                    BurlError::UserError(format!(
+                        "invalid glob pattern in affects_globs: '{}' - {}",
+                        pattern, e
+                    ))
+                })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let scoped = !original.affects.is_empty() || !original.affects_globs.is_empty();
+
+    let mut seen: Vec<&str> = Vec::new();
+    let mut samples: Vec<String> = Vec::new();
+    let mut scopes = Vec::new();
+
+    for spec in specs {
+        let mut scope = ChildScope::default();
+        for entry in &spec.scope {
+            if seen.contains(&entry.as_str()) {
+                return Err(BurlError::UserError(format!(
+                    "scope entry '{}' is assigned to more than one child.",
+                    entry
+                )));
+            }
+            seen.push(entry);
+
+            let is_glob = if original.affects.contains(entry) {
+                false
+            } else {
+                original.affects_globs.contains(entry) || entry.contains(GLOB_CHARS)
+            };
+
+            let sample = sample_path(entry);
+            let inside = original.affects.contains(entry)
+                || original.affects_globs.contains(entry)
+                || original.affects.iter().any(|path| is_under(&sample, path))
+                || original_globs.iter().any(|glob| glob.is_match(&sample));
+            if scoped && !inside {
+                return Err(BurlError::UserError(format!(
+                    "scope entry '{}' of child '{}' is outside the scope of task '{}'.\n\n\
+                     Children may only narrow the original affects/affects_globs.",
+                    entry, spec.title, original.id
+                )));
+            }
+            samples.push(sample);
+
+            if is_glob {
+                scope.affects_globs.push(entry.clone());
+            } else {
+                scope.affects.push(entry.clone());
+            }
+        }
+        scopes.push(scope);
+    }
+
+    // Every original entry must be handed to, or narrowed by, some child.
+    let uncovered: Vec<&str> = original
+        .affects
+        .iter()
+        .filter(|path| !seen.contains(&path.as_str()) && !samples.iter().any(|s| is_under(s, path)))
+        .chain(
+            original
+                .affects_globs
+                .iter()
+                .zip(&original_globs)
+                .filter(|(pattern, glob)| {
+                    !seen.contains(&pattern.as_str()) && !samples.iter().any(|s| glob.is_match(s))
+                })
+                .map(|(pattern, _)| pattern),
+        )
+        .map(String::as_str)
+        .collect();
+    if !uncovered.is_empty() {
+        return Err(BurlError::UserError(format!(
+            "scope of task '{}' is not fully assigned: {}.\n\n\
+             Add each entry (or a narrower path/glob inside it) to one of the --into specs.",
+            original.id,
+            uncovered.join(", ")
+        )));
+    }
+
+    Ok(scopes)
+}
+
+/// Whether `path` is `base` or lies under the directory `base`.
+fn is_under(path: &str, base: &str) -> bool {
+    let base = base.trim_end_matches('/');
+    path == base || path.starts_with(&format!("{}/", base))
+}
+
+/// A concrete path matched by a scope entry, used to test containment.
+///
+/// `**` becomes two directory levels, `*`/`?` a single character, character
+/// classes their first member, and alternations their first alternative.
+fn sample_path(pattern: &str) -> String {
+    let mut out = String::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                out.push_str("x/x");
+            }
+            '*' | '?' => out.push('x'),
+            '[' => {
+                let class: String = chars.by_ref().take_while(|&c| c != ']').collect();
+                out.push(
+                    class
+                        .chars()
+                        .find(|c| *c != '!' && *c != '^')
+                        .unwrap_or('x'),
+                );
+            }
+            '{' => {
+                let alternatives: String = chars.by_ref().take_while(|&c| c != '}').collect();
+                out.push_str(alternatives.split(',').next().unwrap_or_default());
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Inputs for the workflow mutation phase.
+struct SplitRequest<'a> {
+    specs: &'a [ChildSpec],
+    scopes: &'a [ChildScope],
+    sequential: bool,
+    /// Minimum digits in the children's IDs.
+    id_width: usize,
+}
+
+/// A child task written by [`write_split`].
+struct CreatedChild {
+    id: String,
+    title: String,
+    filename: String,
+    path: PathBuf,
+    scope: ChildScope,
+    depends_on: Vec<String>,
+}
+
+/// Result of the workflow mutation phase.
+struct SplitOutcome {
+    children: Vec<CreatedChild>,
+    /// Tasks whose `depends_on` now lists the children instead of the original.
+    rewired: Vec<String>,
+}
+
+/// Write the children, rewire dependents, and supersede the original task.
+fn write_split(
+    ctx: &WorkflowContext,
+    task_path: &std::path::Path,
+    task_file: &mut TaskFile,
+    request: &SplitRequest,
+    transaction: &mut SplitTransaction,
+) -> Result<SplitOutcome> {
+    let original = &task_file.frontmatter;
+
+    // Allocate IDs under the workflow lock so they can't race with `add`.
+    // Children keep the original's prefix (splitting BUG-042 yields BUGs).
+    let prefix = split_task_id(&original.id)
+        .map(|(prefix, _)| prefix)
+        .unwrap_or("TASK");
+    let index = TaskIndex::build(ctx)?;
+    let first_number = index.next_number(prefix)?;
+
+    let sections = extract_sections(&task_file.body);
+    let section = |name: &str| sections.get(name).cloned();
+    let split_note = format!("Split from {}: {}", original.id, original.title);
+    let body = render_task_body(&TaskBodySections {
+        objective: section("objective"),
+        acceptance_criteria: section("acceptance criteria")
+            .map(|criteria| {
+                criteria
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty())
+                    .map(|line| line.strip_prefix("- ").unwrap_or(line).to_string())
+                    .collect()
+            })
+            .unwrap_or_default(),
+        context: Some(match section("context") {
+            Some(context) => format!("{}\n\n{}", split_note, context),
+            None => split_note,
+        }),
+        implementation_notes: section("implementation notes"),
+    });
+
+    let now = Utc::now();
+    let mut children: Vec<CreatedChild> = Vec::new();
+    for (offset, (spec, scope)) in request.specs.iter().zip(request.scopes).enumerate() {
+        let id = generate_task_id(prefix, first_number + offset as u32, request.id_width);
+        let filename = generate_task_filename(&id, &spec.title);
+        validate_filename_safe(&filename)?;
+        let path = ctx.bucket_path("READY").join(&filename);
+        if path.exists() {
+            return Err(BurlError::UserError(format!(
+                "task file already exists: {}",
+                path.display()
+            )));
+        }
+
+        let mut depends_on = original.depends_on.clone();
+        if request.sequential
+            && let Some(previous) = children.last()
+        {
+            depends_on.push(previous.id.clone());
+        }
+
+        let child = TaskFile {
+            frontmatter: TaskFrontmatter {
+                id: id.clone(),
+                title: spec.title.clone(),
+                priority: original.priority.clone(),
+                created: Some(now),
+                affects: scope.affects.clone(),
+                affects_globs: scope.affects_globs.clone(),
+                must_not_touch: original.must_not_touch.clone(),
+                depends_on: depends_on.clone(),
+                parent: original.parent.clone(),
+                tags: original.tags.clone(),
+                agent: original.agent.clone(),
+                validation_profile: original.validation_profile.clone(),
+                ..Default::default()
+            },
+            body: body.clone(),
+        };
+        atomic_write_file(&path, &child.to_string()?)?;
+        transaction.created_files.push(path.clone());
+
+        children.push(CreatedChild {
+            id,
+            title: spec.title.clone(),
+            filename,
+            path,
+            scope: scope.clone(),
+            depends_on,
+        });
+    }
+    let child_ids: Vec<String> = children.iter().map(|c| c.id.clone()).collect();
+
+    // Dependents of the original wait for all of its replacements.
+    let mut rewired = Vec::new();
+    for info in index.all_tasks() {
+        if info.id == original.id || info.bucket == "DONE" {
+            continue;
+        }
+        let mut dependent = TaskFile::load(&info.path)?;
+        let Some(pos) = dependent
+            .frontmatter
+            .depends_on
+            .iter()
+            .position(|dep| *dep == original.id)
+        else {
+            continue;
+        };
+        dependent.frontmatter.depends_on.remove(pos);
+        for (i, child) in child_ids.iter().enumerate() {
+            if !dependent.frontmatter.depends_on.contains(child) {
+                dependent
+                    .frontmatter
+                    .depends_on
+                    .insert(pos + i, child.clone());
+            }
+        }
+        dependent.save(&info.path)?;
+        rewired.push(info.id.clone());
+    }
+
+    task_file.frontmatter.superseded_by = child_ids.clone();
+    task_file.set_completed(now);
+    task_file.clear_assigned();
+    task_file.append_to_history(&format!(
+        "### Split: {}\n\n\
+         **Into:** {}\n",
+        now.format("%Y-%m-%d %H:%M:%S UTC"),
+        child_ids.join(", ")
+    ));
+    task_file.save(task_path)?;
+
+    let filename = task_path
+        .file_name()
+        .ok_or_else(|| BurlError::UserError("invalid task file path".to_string()))?;
+    crate::fs::move_file(task_path, ctx.bucket_path("DONE").join(filename))?;
+
+    Ok(SplitOutcome { children, rewired })
+}
+
+/// Information about a split operation for rollback purposes.
+#[derive(Default)]
+struct SplitTransaction {
+    /// The task branch whose worktree was removed in this transaction.
+    branch_name: String,
+    /// The worktree path removed in this transaction.
+    removed_worktree: Option<PathBuf>,
+    /// Child task files written in this transaction.
+    created_files: Vec<PathBuf>,
+}
+
+impl SplitTransaction {
+    /// Remove created child files and recreate the worktree if it was removed.
+    fn rollback(self, repo_root: &std::path::Path) {
+        for path in &self.created_files {
+            let _ = std::fs::remove_file(path);
+        }
+        if let Some(path) = self.removed_worktree
+            && !self.branch_name.is_empty()
+        {
+            // Try to recreate the worktree - ignore errors during rollback
+            let _ = create_worktree(repo_root, &path, &self.branch_name);
+        }
+    }
+}
+
+/// Commit the split to the workflow branch.
+fn commit_split(ctx: &WorkflowContext, task_id: &str, child_ids: &[String]) -> Result<()> {
+    run_git(&ctx.workflow_worktree, &["add", "."])
+        .map_err(|e| BurlError::GitError(format!("failed to stage split changes: {}", e)))?;
+
+    let staged = run_git(&ctx.workflow_worktree, &["diff", "--cached", "--name-only"])?;
+    if staged.stdout.is_empty() {
+        return Ok(());
+    }
+
+    let commit_msg = format!("Split task {} into {}", task_id, child_ids.join(", "));
+
+    run_git(&ctx.workflow_worktree, &["commit", "-m", &commit_msg])
+        .map_err(|e| BurlError::GitError(format!("failed to commit split: {}", e)))?;
+
+    Ok(())
+}
+
+/// Push the workflow branch to the remote.
+fn push_workflow_branch(ctx: &WorkflowContext, config: &Config) -> Result<()> {
+    run_git(
+        &ctx.workflow_worktree,
+        &["push", &config.remote, &config.workflow_branch],
+    )
+    .map_err(|e| BurlError::GitError(format!("failed to push workflow branch: {}", e)))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::AddArgs;
+    use crate::commands::add::cmd_add;
+    use crate::commands::init::cmd_init;
+    use crate::events::read_events;
+    use crate::test_support::{DirGuard, create_test_repo};
+    use serial_test::serial;
+
+    fn spec(title: &str, scope: &[&str]) -> ChildSpec {
+        ChildSpec {
+            title: title.to_string(),
+            scope: scope.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_parse_split_spec() {
+        assert_eq!(
+            parse_split_spec("Net: parser:src/net/parse/**, README.md").unwrap(),
+            spec("Net: parser", &["src/net/parse/**", "README.md"])
+        );
+        assert!(parse_split_spec("no scope").is_err());
+        assert!(parse_split_spec(":src/**").is_err());
+        assert!(parse_split_spec("Title: , ").is_err());
+    }
+
+    #[test]
+    fn test_partition_scope() {
+        let original = TaskFrontmatter {
+            id: "TASK-001".to_string(),
+            affects: vec!["README.md".to_string(), "docs".to_string()],
+            affects_globs: vec!["src/**".to_string()],
+            ..Default::default()
+        };
+
+        let scopes = partition_scope(
+            &original,
+            &[
+                spec("A", &["src/net/**", "README.md"]),
+                spec("B", &["src/*.rs", "src/lib.rs", "docs/net.md"]),
+            ],
+        )
+        .unwrap();
+        assert_eq!(scopes[0].affects_globs, vec!["src/net/**"]);
+        assert_eq!(scopes[0].affects, vec!["README.md"]);
+        assert_eq!(scopes[1].affects_globs, vec!["src/*.rs"]);
+        assert_eq!(scopes[1].affects, vec!["src/lib.rs", "docs/net.md"]);
+
+        let err = partition_scope(
+            &original,
+            &[spec("A", &["src/**", "README.md", "tests/**"])],
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("'tests/**' of child 'A' is outside")
+        );
+
+        let err = partition_scope(&original, &[spec("A", &["src/**"]), spec("B", &["docs"])])
+            .unwrap_err();
+        assert!(err.to_string().contains("not fully assigned: README.md"));
+
+        let err = partition_scope(&original, &[spec("A", &["src/**"]), spec("B", &["src/**"])])
+            .unwrap_err();
+        assert!(err.to_string().contains("assigned to more than one child"));
+
+        // An unscoped task can be split into any scopes.
+        let unscoped = TaskFrontmatter::default();
+        let scopes = partition_scope(&unscoped, &[spec("A", &["lib/**", "Makefile"])]).unwrap();
+        assert_eq!(scopes[0].affects_globs, vec!["lib/**"]);
+        assert_eq!(scopes[0].affects, vec!["Makefile"]);
+    }
+
+    #[test]
+    fn test_sample_path() {
+        assert_eq!(sample_path("src/**/*.rs"), "src/x/x/x.rs");
+        assert_eq!(sample_path("src/[ab]?.{rs,toml}"), "src/ax.rs");
+    }
+
+    #[test]
+    #[serial]
+    fn test_split_creates_children_and_supersedes_original() {
+        let temp_dir = create_test_repo();
+        let _guard = DirGuard::new(temp_dir.path());
+        cmd_init().unwrap();
+
+        cmd_add(AddArgs {
+            title: "Networking".to_string(),
+            priority: "high".to_string(),
+            affects: vec!["README.md".to_string()],
+            affects_globs: vec!["src/net/**".to_string()],
+            must_not_touch: vec!["src/ui/**".to_string()],
+            depends_on: vec![],
+            tags: vec!["net".to_string()],
+            parent: None,
+            template: None,
+            kind: None,
+        })
+        .unwrap();
+        cmd_add(AddArgs {
+            title: "Use networking".to_string(),
+            priority: "medium".to_string(),
+            affects: vec![],
+            affects_globs: vec![],
+            must_not_touch: vec![],
+            depends_on: vec!["TASK-001".to_string()],
+            tags: vec![],
+            parent: None,
+            template: None,
+            kind: None,
+        })
+        .unwrap();
+
+        cmd_split(SplitArgs {
+            task_id: "task-001".to_string(),
+            into: vec![
+                "Protocol:src/net/proto/**,README.md".to_string(),
+                "Transport:src/net/tcp.rs".to_string(),
+            ],
+            sequential: true,
+        })
+        .unwrap();
+
+        let ctx = require_initialized_workflow().unwrap();
+        let index = TaskIndex::build(&ctx).unwrap();
+
+        let original = TaskFile::load(&index.find("TASK-001").unwrap().path).unwrap();
+        assert_eq!(index.find("TASK-001").unwrap().bucket, "DONE");
+        assert_eq!(
+            original.frontmatter.superseded_by,
+            vec!["TASK-003", "TASK-004"]
+        );
+        assert!(original.frontmatter.completed_at.is_some());
+        assert!(original.body.contains("**Into:** TASK-003, TASK-004"));
+
+        let protocol = TaskFile::load(&index.find("TASK-003").unwrap().path).unwrap();
+        assert_eq!(index.find("TASK-003").unwrap().bucket, "READY");
+        assert_eq!(protocol.frontmatter.affects_globs, vec!["src/net/proto/**"]);
+        assert_eq!(protocol.frontmatter.affects, vec!["README.md"]);
+        assert_eq!(protocol.frontmatter.must_not_touch, vec!["src/ui/**"]);
+        assert_eq!(protocol.frontmatter.priority, "high");
+        assert_eq!(protocol.frontmatter.tags, vec!["net"]);
+        assert!(protocol.body.contains("Split from TASK-001: Networking"));
+        assert!(protocol.body.contains("- [ ] Criterion 1"));
+
+        let transport = TaskFile::load(&index.find("TASK-004").unwrap().path).unwrap();
+        assert_eq!(transport.frontmatter.affects, vec!["src/net/tcp.rs"]);
+        assert_eq!(transport.frontmatter.depends_on, vec!["TASK-003"]);
+
+        let dependent = TaskFile::load(&index.find("TASK-002").unwrap().path).unwrap();
+        assert_eq!(
+            dependent.frontmatter.depends_on,
+            vec!["TASK-003", "TASK-004"]
+        );
+
+        let events = read_events(&ctx).unwrap();
+        let split = events
+            .iter()
+            .find(|e| e.action == EventAction::Split)
+            .unwrap();
+        assert_eq!(split.task.as_deref(), Some("TASK-001"));
+        assert_eq!(split.details["children"], json!(["TASK-003", "TASK-004"]));
+        assert_eq!(split.details["rewired"], json!(["TASK-002"]));
+        let split_adds = events
+            .iter()
+            .filter(|e| e.action == EventAction::Add && e.details["split_from"] == "TASK-001")
+            .count();
+        assert_eq!(split_adds, 2);
+
+        let log = run_git(&ctx.workflow_worktree, &["log", "-1", "--format=%s"]).unwrap();
+        assert_eq!(
+            log.stdout.trim(),
+            "Split task TASK-001 into TASK-003, TASK-004"
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_split_rejects_incomplete_partition() {
+        let temp_dir = create_test_repo();
+        let _guard = DirGuard::new(temp_dir.path());
+        cmd_init().unwrap();
+
+        cmd_add(AddArgs {
+            title: "Networking".to_string(),
+            priority: "medium".to_string(),
+            affects: vec![],
+            affects_globs: vec!["src/net/**".to_string(), "docs/**".to_string()],
+            must_not_touch: vec![],
+            depends_on: vec![],
+            tags: vec![],
+            parent: None,
+            template: None,
+            kind: None,
+        })
+        .unwrap();
+
+        let err = cmd_split(SplitArgs {
+            task_id: "TASK-001".to_string(),
+            into: vec![
+                "Protocol:src/net/proto/**".to_string(),
+                "Transport:src/net/tcp.rs".to_string(),
+            ],
+            sequential: false,
+        })
+        .unwrap_err();
+        assert!(err.to_string().contains("not fully assigned: docs/**"));
+
+        let ctx = require_initialized_workflow().unwrap();
+        let index = TaskIndex::build(&ctx).unwrap();
+        assert_eq!(index.find("TASK-001").unwrap().bucket, "READY");
+        assert!(index.find("TASK-002").is_none());
+    }
+}
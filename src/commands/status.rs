@@ -1,14 +1,14 @@
 //! Implementation of the `burl status` command.
 //!
-//! Displays workflow status including task counts per bucket and highlights
-//! for locked, stalled, or over-attempt tasks.
+//! Displays workflow status including task counts per bucket, progress of
+//! open epics, and highlights for locked, stalled, or over-attempt tasks.
 
 use crate::config::Config;
 use crate::context::require_initialized_workflow;
 use crate::error::Result;
+use crate::graph::DependencyGraph;
 use crate::locks;
-use crate::task::TaskFile;
-use crate::workflow::{BUCKETS, TaskIndex};
+use crate::workflow::TaskIndex;
 use chrono::{Duration, Utc};
 
 /// Stall threshold in hours for DOING tasks.
@@ -21,6 +21,7 @@ const QA_STALL_HOURS: i64 = 24;
 ///
 /// Displays:
 /// - Task counts per bucket
+/// - Child progress per open epic
 /// - Locked tasks
 /// - Stale locks
 /// - Tasks with high qa_attempts
@@ -44,14 +45,25 @@ pub fn cmd_status() -> Result<()> {
     // Print bucket counts
     println!("Buckets:");
     let total: usize = bucket_counts.values().sum();
-    for bucket in BUCKETS {
-        let count = bucket_counts.get(*bucket).copied().unwrap_or(0);
+    for bucket in index.buckets() {
+        let count = bucket_counts.get(bucket).copied().unwrap_or(0);
         println!("  {:8} {:>3}", bucket, count);
     }
     println!("  --------");
     println!("  {:8} {:>3}", "Total", total);
     println!();
 
+    // Print progress per open epic
+    let epic_lines = epic_rollup_lines(&DependencyGraph::build(&index)?);
+    index.save_cache()?;
+    if !epic_lines.is_empty() {
+        println!("Epics:");
+        for line in &epic_lines {
+            println!("  {}", line);
+        }
+        println!();
+    }
+
     // Collect issues to highlight
     let mut issues: Vec<String> = Vec::new();
 
@@ -92,10 +104,10 @@ pub fn cmd_status() -> Result<()> {
         .all_tasks()
         .filter(|t| t.bucket == "QA")
         .filter_map(|t| {
-            if let Ok(task) = TaskFile::load(&t.path)
-                && task.frontmatter.qa_attempts >= config.qa_max_attempts.saturating_sub(1)
+            if let Ok(frontmatter) = index.frontmatter(t)
+                && frontmatter.qa_attempts >= config.qa_max_attempts.saturating_sub(1)
             {
-                return Some((t.id.clone(), task.frontmatter.qa_attempts));
+                return Some((t.id.clone(), frontmatter.qa_attempts));
             }
             None
         })
@@ -122,8 +134,8 @@ pub fn cmd_status() -> Result<()> {
         .tasks_in_bucket("DOING")
         .iter()
         .filter_map(|t| {
-            if let Ok(task) = TaskFile::load(&t.path)
-                && let Some(started_at) = task.frontmatter.started_at
+            if let Ok(frontmatter) = index.frontmatter(t)
+                && let Some(started_at) = frontmatter.started_at
                 && now.signed_duration_since(started_at) > stall_threshold_doing
             {
                 return Some((t.id.clone(), started_at));
@@ -150,8 +162,8 @@ pub fn cmd_status() -> Result<()> {
         .tasks_in_bucket("QA")
         .iter()
         .filter_map(|t| {
-            if let Ok(task) = TaskFile::load(&t.path)
-                && let Some(submitted_at) = task.frontmatter.submitted_at
+            if let Ok(frontmatter) = index.frontmatter(t)
+                && let Some(submitted_at) = frontmatter.submitted_at
                 && now.signed_duration_since(submitted_at) > stall_threshold_qa
             {
                 return Some((t.id.clone(), submitted_at));
@@ -207,6 +219,19 @@ pub fn cmd_status() -> Result<()> {
     Ok(())
 }
 
+/// One line per epic that is not yet DONE: ID, child rollup, and title.
+fn epic_rollup_lines(graph: &DependencyGraph) -> Vec<String> {
+    graph
+        .nodes()
+        .filter(|node| node.bucket != "DONE")
+        .filter_map(|node| {
+            graph
+                .rollup(&node.id)
+                .map(|rollup| format!("{}  {}  {}", node.id, rollup.summary(), node.title))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -245,6 +270,9 @@ mod tests {
                 must_not_touch: vec![],
                 depends_on: vec![],
                 tags: vec![],
+                parent: None,
+                template: None,
+                kind: None,
             };
             cmd_add(args).unwrap();
         }
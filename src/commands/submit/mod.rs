@@ -10,11 +10,12 @@
 //! 2. Verify task is in DOING with valid worktree/branch/base_sha
 //! 3. Verify at least one commit exists since base_sha
 //! 4. Run validations (scope + stubs) against `{base_sha}..HEAD`
-//! 5. If push_task_branch_on_submit: push task branch to remote
-//! 6. Acquire `workflow.lock` for workflow-state mutation
-//! 7. Set submitted_at, move DOING -> QA
-//! 8. Append submit event and commit workflow branch
-//! 9. Release locks
+//! 5. Run the `pre_submit` hook (see [`crate::hooks`])
+//! 6. If push_task_branch_on_submit: push task branch to remote
+//! 7. Acquire `workflow.lock` for workflow-state mutation
+//! 8. Set submitted_at, move DOING -> QA
+//! 9. Append submit event, run the `post_submit` hook, and commit workflow branch
+//! 10. Release locks
 
 use crate::cli::SubmitArgs;
 use crate::config::Config;
@@ -23,7 +24,9 @@ use crate::diff::{added_lines, changed_files};
 use crate::error::{BurlError, Result};
 use crate::events::{Event, EventAction, append_event};
 use crate::git_worktree::get_current_branch;
+use crate::hooks::{HookTask, run_post_hook, run_pre_hook};
 use crate::locks::{acquire_task_lock, acquire_workflow_lock};
+use crate::output::{self, CommandResult, textln};
 use crate::task::TaskFile;
 use crate::validate::{validate_scope, validate_stubs_with_config};
 use crate::workflow::{TaskIndex, validate_task_id};
@@ -99,6 +102,12 @@ pub fn cmd_submit(args: SubmitArgs) -> Result<()> {
         )));
     }
 
+    output::record(
+        CommandResult::new("submit")
+            .with_task(&task_info.id)
+            .with_from_bucket("DOING"),
+    );
+
     // ========================================================================
     // Phase 2: Acquire per-task lock and load task file
     // ========================================================================
@@ -121,6 +130,11 @@ pub fn cmd_submit(args: SubmitArgs) -> Result<()> {
     let expected_branch = refs.branch;
     let worktree_path = refs.worktree_path;
 
+    output::update(|r| {
+        r.branch = task_file.frontmatter.branch.clone();
+        r.worktree = task_file.frontmatter.worktree.clone();
+    });
+
     if !worktree_path.exists() {
         return Err(BurlError::UserError(format!(
             "task worktree does not exist at '{}'.\n\n\
@@ -177,6 +191,7 @@ pub fn cmd_submit(args: SubmitArgs) -> Result<()> {
     // Validate scope
     let scope_result = validate_scope(&task_file.frontmatter, &changed)?;
     if !scope_result.passed {
+        output::update(|r| r.scope_violations = scope_result.violations.clone());
         let error_msg = scope_result.format_error(&task_id);
         return Err(BurlError::ValidationError(error_msg));
     }
@@ -184,10 +199,23 @@ pub fn cmd_submit(args: SubmitArgs) -> Result<()> {
     // Validate stubs
     let stub_result = validate_stubs_with_config(&config, &added)?;
     if !stub_result.passed {
+        output::update(|r| r.stub_violations = stub_result.violations.clone());
         let error_msg = stub_result.format_error();
         return Err(BurlError::ValidationError(error_msg));
     }
 
+    run_pre_hook(
+        &ctx,
+        &config,
+        "submit",
+        &HookTask::new(
+            &task_info.id,
+            "DOING",
+            &task_info.path,
+            &task_file.frontmatter,
+        ),
+    )?;
+
     // ========================================================================
     // Phase 6: Push task branch (if configured)
     // ========================================================================
@@ -204,7 +232,7 @@ pub fn cmd_submit(args: SubmitArgs) -> Result<()> {
     ctx.ensure_workflow_clean()?;
 
     // Acquire workflow lock
-    let _workflow_lock = acquire_workflow_lock(&ctx, "submit")?;
+    let workflow_lock = acquire_workflow_lock(&ctx, "submit")?;
 
     // Update task frontmatter
     let now = Utc::now();
@@ -259,24 +287,46 @@ pub fn cmd_submit(args: SubmitArgs) -> Result<()> {
         }
     }
 
+    drop(workflow_lock);
+    run_post_hook(
+        &ctx,
+        &config,
+        "submit",
+        &HookTask::new(&task_info.id, "QA", &qa_path, &task_file.frontmatter),
+    );
+
     // ========================================================================
     // Phase 9: Output
     // ========================================================================
 
-    println!("Submitted task: {}", task_info.id);
-    println!("  Title:         {}", task_file.frontmatter.title);
-    println!("  From:          DOING");
-    println!("  To:            QA");
-    println!("  Commits:       {}", commit_count);
-    println!("  Files changed: {}", changed.len());
+    output::update(|r| {
+        r.to_bucket = Some("QA".to_string());
+        r.details = json!({
+            "title": task_file.frontmatter.title,
+            "base_sha": base_sha,
+            "commit_count": commit_count,
+            "files_changed": changed.len(),
+            "lines_added": added.len(),
+            "pushed": config.push_task_branch_on_submit
+        });
+    });
+
+    textln!("Submitted task: {}", task_info.id);
+    textln!("  Title:         {}", task_file.frontmatter.title);
+    textln!("  From:          DOING");
+    textln!("  To:            QA");
+    textln!("  Commits:       {}", commit_count);
+    textln!("  Files changed: {}", changed.len());
     if config.push_task_branch_on_submit {
-        println!(
+        textln!(
             "  Pushed:        {} -> {}/{}",
-            expected_branch, config.remote, expected_branch
+            expected_branch,
+            config.remote,
+            expected_branch
         );
     }
-    println!();
-    println!("Task is now awaiting review in QA.");
+    textln!();
+    textln!("Task is now awaiting review in QA.");
 
     Ok(())
 }
@@ -29,6 +29,9 @@ fn test_submit_no_commits_fails() {
         must_not_touch: vec![],
         depends_on: vec![],
         tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
     })
     .unwrap();
 
@@ -67,6 +70,9 @@ fn test_submit_with_scope_violation_fails() {
         must_not_touch: vec![],
         depends_on: vec![],
         tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
     })
     .unwrap();
 
@@ -127,6 +133,9 @@ fn test_submit_with_stub_violation_fails() {
         must_not_touch: vec![],
         depends_on: vec![],
         tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
     })
     .unwrap();
 
@@ -187,6 +196,9 @@ fn test_submit_valid_task_moves_to_qa() {
         must_not_touch: vec![],
         depends_on: vec![],
         tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
     })
     .unwrap();
 
@@ -263,6 +275,9 @@ fn test_submit_task_not_in_doing_fails() {
         must_not_touch: vec![],
         depends_on: vec![],
         tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
     })
     .unwrap();
 
@@ -315,6 +330,9 @@ fn test_submit_without_task_id_single_doing() {
         must_not_touch: vec![],
         depends_on: vec![],
         tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
     })
     .unwrap();
 
@@ -27,12 +27,18 @@ use crate::config::ValidationCommandStep;
 use crate::context::require_initialized_workflow;
 use crate::diff::{added_lines, changed_files};
 use crate::error::{BurlError, Result};
+use crate::fs::atomic_write_file;
 use crate::git_worktree::get_current_branch;
 use crate::locks::acquire_task_lock;
+use crate::output::{self, CommandResult, textln};
 use crate::task::TaskFile;
-use crate::validate::{ValidationStepResult, ValidationStepStatus, run_command_steps};
-use crate::validate::{validate_scope, validate_stubs_with_config};
+use crate::validate::{BUILD_COMMAND_PROFILE, StepCache, ValidationStepResult, run_command_steps};
+use crate::validate::{junit_report, sarif_report, validate_scope, validate_stubs_with_config};
+use crate::validation_logs::save_and_link;
 use crate::workflow::{TaskIndex, validate_task_id};
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
 
 pub use report::write_qa_report_and_event;
 
@@ -68,15 +74,21 @@ pub fn cmd_validate(args: ValidateArgs) -> Result<()> {
         ))
     })?;
 
-    // Verify task is in QA bucket
-    if task_info.bucket != "QA" {
+    // Verify task is in QA (or a review bucket from `states.approve_from`)
+    if !config.states.in_review(&task_info.bucket) {
         return Err(BurlError::UserError(format!(
             "task '{}' is not in QA (currently in {}).\n\n\
-             Only tasks in QA can be validated with `burl validate`.",
+             Only tasks in QA or a review bucket can be validated with `burl validate`.",
             task_info.id, task_info.bucket
         )));
     }
 
+    output::record(
+        CommandResult::new("validate")
+            .with_task(&task_info.id)
+            .with_from_bucket(&task_info.bucket),
+    );
+
     // ========================================================================
     // Phase 2: Acquire per-task lock and load task file
     // ========================================================================
@@ -99,6 +111,11 @@ pub fn cmd_validate(args: ValidateArgs) -> Result<()> {
     let expected_branch = refs.branch;
     let worktree_path = refs.worktree_path;
 
+    output::update(|r| {
+        r.branch = task_file.frontmatter.branch.clone();
+        r.worktree = task_file.frontmatter.worktree.clone();
+    });
+
     if !worktree_path.exists() {
         return Err(BurlError::UserError(format!(
             "task worktree does not exist at '{}'.\n\n\
@@ -173,7 +190,16 @@ pub fn cmd_validate(args: ValidateArgs) -> Result<()> {
     }
 
     // --- Command validation pipeline ---
-    let pipeline_results = run_validation_pipeline(&config, &task_file, &changed, &worktree_path);
+    let env = config.worktree_setup.resolved_env(&ctx.repo_root);
+    let cache_path = (!args.no_cache).then(|| ctx.validation_cache_path());
+    let pipeline_results = run_validation_pipeline(
+        &config,
+        &task_file,
+        &changed,
+        &worktree_path,
+        &env,
+        cache_path,
+    );
     for result in pipeline_results {
         if !result.is_success() {
             all_passed = false;
@@ -185,7 +211,8 @@ pub fn cmd_validate(args: ValidateArgs) -> Result<()> {
     // Phase 5: Write QA Report and Event
     // ========================================================================
 
-    let summary = format_validation_summary(&validation_results, all_passed);
+    let mut summary = format_validation_summary(&validation_results, all_passed);
+    summary.push_str(&save_and_link(&ctx, &task_id, &validation_results));
     write_qa_report_and_event(
         &ctx,
         &task_info.path,
@@ -196,30 +223,48 @@ pub fn cmd_validate(args: ValidateArgs) -> Result<()> {
         &config,
     )?;
 
+    if let Some(path) = &args.report_junit {
+        atomic_write_file(path, &junit_report(&task_id, &validation_results))?;
+    }
+    if let Some(path) = &args.report_sarif {
+        let sarif = sarif_report(&task_id, &scope_result.violations, &stub_result.violations);
+        let json = serde_json::to_string_pretty(&sarif).map_err(|e| {
+            BurlError::UserError(format!("failed to serialize SARIF report: {}", e))
+        })?;
+        atomic_write_file(path, &json)?;
+    }
+
     // ========================================================================
     // Phase 6: Output
     // ========================================================================
 
-    println!("Validated task: {}", task_info.id);
-    println!("  Title:  {}", task_file.frontmatter.title);
-    println!("  Status: {}", task_info.bucket);
-    println!();
+    output::update(|r| {
+        r.to_bucket = Some(task_info.bucket.clone());
+        r.validation = validation_results.clone();
+        r.scope_violations = scope_result.violations.clone();
+        r.stub_violations = stub_result.violations.clone();
+        r.details = json!({
+            "title": task_file.frontmatter.title,
+            "all_passed": all_passed,
+        });
+    });
+
+    textln!("Validated task: {}", task_info.id);
+    textln!("  Title:  {}", task_file.frontmatter.title);
+    textln!("  Status: {}", task_info.bucket);
+    textln!();
 
     for result in &validation_results {
-        let status = match result.status {
-            ValidationStepStatus::Pass => "PASS",
-            ValidationStepStatus::Fail => "FAIL",
-            ValidationStepStatus::Skip => "SKIP",
-        };
-        println!("  {}: {}", result.name, status);
+        let status = result.label();
+        textln!("  {}: {}", result.name, status);
     }
 
-    println!();
+    textln!();
     if all_passed {
-        println!("All validations passed.");
+        textln!("All validations passed.");
         Ok(())
     } else {
-        println!("Validation failed. See QA Report in task file for details.");
+        textln!("Validation failed. See QA Report in task file for details.");
         Err(BurlError::ValidationError(
             "one or more validation checks failed".to_string(),
         ))
@@ -238,11 +283,7 @@ fn format_validation_summary(results: &[ValidationStepResult], all_passed: bool)
     );
 
     for result in results {
-        let status = match result.status {
-            ValidationStepStatus::Pass => "PASS",
-            ValidationStepStatus::Fail => "FAIL",
-            ValidationStepStatus::Skip => "SKIP",
-        };
+        let status = result.label();
         summary.push_str(&format!("- **{}**: {}\n", result.name, status));
 
         if let Some(msg) = &result.message {
@@ -261,6 +302,8 @@ fn run_validation_pipeline(
     task_file: &TaskFile,
     changed_files: &[String],
     worktree_path: &std::path::Path,
+    env: &BTreeMap<String, String>,
+    cache_path: Option<PathBuf>,
 ) -> Vec<ValidationStepResult> {
     let profile_name = task_file
         .frontmatter
@@ -269,7 +312,7 @@ fn run_validation_pipeline(
         .or(config.default_validation_profile.as_deref());
 
     let Some(profile_name) = profile_name else {
-        return run_legacy_build_command(config, worktree_path);
+        return run_legacy_build_command(config, worktree_path, env, cache_path);
     };
 
     let Some(profile) = config.validation_profiles.get(profile_name) else {
@@ -290,12 +333,27 @@ fn run_validation_pipeline(
         )];
     }
 
-    run_command_steps(&profile.steps, changed_files, worktree_path)
+    let mut cache =
+        cache_path.and_then(|path| StepCache::open(path, profile_name, worktree_path, env));
+    let results = run_command_steps(
+        &profile.steps,
+        changed_files,
+        worktree_path,
+        env,
+        profile.parallelism(),
+        cache.as_mut(),
+    );
+    if let Some(cache) = cache {
+        cache.save();
+    }
+    results
 }
 
 fn run_legacy_build_command(
     config: &Config,
     worktree_path: &std::path::Path,
+    env: &BTreeMap<String, String>,
+    cache_path: Option<PathBuf>,
 ) -> Vec<ValidationStepResult> {
     if config.build_command.trim().is_empty() {
         return Vec::new();
@@ -307,5 +365,18 @@ fn run_legacy_build_command(
         ..Default::default()
     };
 
-    run_command_steps(std::slice::from_ref(&step), &[], worktree_path)
+    let mut cache = cache_path
+        .and_then(|path| StepCache::open(path, BUILD_COMMAND_PROFILE, worktree_path, env));
+    let results = run_command_steps(
+        std::slice::from_ref(&step),
+        &[],
+        worktree_path,
+        env,
+        1,
+        cache.as_mut(),
+    );
+    if let Some(cache) = cache {
+        cache.save();
+    }
+    results
 }
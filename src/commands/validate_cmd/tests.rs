@@ -8,7 +8,8 @@ use crate::commands::init::cmd_init;
 use crate::commands::submit::cmd_submit;
 use crate::exit_codes;
 use crate::task::TaskFrontmatter;
-use crate::test_support::{DirGuard, create_test_repo_with_remote};
+use crate::test_support::{DirGuard, create_test_repo, create_test_repo_with_remote};
+use crate::validate::ValidationStepStatus;
 use serial_test::serial;
 use std::path::PathBuf;
 use std::process::Command as ProcessCommand;
@@ -27,6 +28,9 @@ fn setup_task_in_qa(temp_dir: &TempDir) -> PathBuf {
         must_not_touch: vec![],
         depends_on: vec![],
         tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
     })
     .unwrap();
 
@@ -142,6 +146,8 @@ validation_profiles:
         &task,
         &["src/lib.rs".to_string()],
         worktree.path(),
+        &BTreeMap::new(),
+        None,
     );
 
     assert_eq!(results.len(), 1);
@@ -170,7 +176,14 @@ validation_profiles:
 
     let task = make_task(Some("override"));
     let worktree = TempDir::new().unwrap();
-    let results = super::run_validation_pipeline(&config, &task, &[], worktree.path());
+    let results = super::run_validation_pipeline(
+        &config,
+        &task,
+        &[],
+        worktree.path(),
+        &BTreeMap::new(),
+        None,
+    );
 
     assert_eq!(results.len(), 1);
     assert_eq!(results[0].name, "bad");
@@ -183,7 +196,14 @@ fn test_run_validation_pipeline_unknown_profile_fails() {
     let task = make_task(Some("missing"));
     let worktree = TempDir::new().unwrap();
 
-    let results = super::run_validation_pipeline(&config, &task, &[], worktree.path());
+    let results = super::run_validation_pipeline(
+        &config,
+        &task,
+        &[],
+        worktree.path(),
+        &BTreeMap::new(),
+        None,
+    );
 
     assert_eq!(results.len(), 1);
     assert_eq!(results[0].name, "validation");
@@ -203,7 +223,14 @@ fn test_run_validation_pipeline_no_profile_uses_legacy_build_command() {
     let task = make_task(None);
     let worktree = TempDir::new().unwrap();
 
-    let results = super::run_validation_pipeline(&config, &task, &[], worktree.path());
+    let results = super::run_validation_pipeline(
+        &config,
+        &task,
+        &[],
+        worktree.path(),
+        &BTreeMap::new(),
+        None,
+    );
 
     assert_eq!(results.len(), 1);
     assert_eq!(results[0].name, "build/test");
@@ -225,13 +252,113 @@ validation_profiles:
 
     let task = make_task(None);
     let worktree = TempDir::new().unwrap();
-    let results = super::run_validation_pipeline(&config, &task, &[], worktree.path());
+    let results = super::run_validation_pipeline(
+        &config,
+        &task,
+        &[],
+        worktree.path(),
+        &BTreeMap::new(),
+        None,
+    );
 
     assert_eq!(results.len(), 1);
     assert_eq!(results[0].name, "validation");
     assert_eq!(results[0].status, ValidationStepStatus::Skip);
 }
 
+#[test]
+#[cfg(unix)]
+fn test_run_validation_pipeline_reuses_cached_passes() {
+    let runs = TempDir::new().unwrap();
+    let counter = runs.path().join("runs");
+    let config = Config::from_yaml(&format!(
+        r#"
+default_validation_profile: quick
+validation_profiles:
+  quick:
+    steps:
+      - name: count
+        command: sh -c 'echo run >> {}'
+      - name: fails
+        command: sh -c 'exit 1'
+"#,
+        counter.display()
+    ))
+    .unwrap();
+
+    let task = make_task(None);
+    let worktree = create_test_repo();
+    let cache_path = runs.path().join("validation.cache.json");
+    let run = |cache_path: Option<PathBuf>| {
+        super::run_validation_pipeline(
+            &config,
+            &task,
+            &[],
+            worktree.path(),
+            &BTreeMap::new(),
+            cache_path,
+        )
+    };
+    let run_count = || std::fs::read_to_string(&counter).unwrap().lines().count();
+
+    let first = run(Some(cache_path.clone()));
+    assert_eq!(first[0].status, ValidationStepStatus::Pass);
+    assert_eq!(run_count(), 1);
+
+    // Same tree: the pass is reused, the failure is rerun.
+    let second = run(Some(cache_path.clone()));
+    assert_eq!(second[0].status, ValidationStepStatus::CachedPass);
+    assert_eq!(second[0].label(), "CACHED-PASS");
+    assert!(second[0].is_success());
+    assert_eq!(second[1].status, ValidationStepStatus::Fail);
+    assert_eq!(run_count(), 1);
+
+    // `--no-cache` reruns everything.
+    let forced = run(None);
+    assert_eq!(forced[0].status, ValidationStepStatus::Pass);
+    assert_eq!(run_count(), 2);
+}
+
+#[test]
+#[cfg(unix)]
+fn test_run_validation_pipeline_caches_legacy_build_command() {
+    let runs = TempDir::new().unwrap();
+    let counter = runs.path().join("runs");
+    let config = Config::from_yaml(&format!(
+        "build_command: \"sh -c 'echo run >> {}'\"\n",
+        counter.display()
+    ))
+    .unwrap();
+
+    let task = make_task(None);
+    let worktree = create_test_repo();
+    let cache_path = runs.path().join("validation.cache.json");
+    let run = |cache_path: Option<PathBuf>| {
+        super::run_validation_pipeline(
+            &config,
+            &task,
+            &[],
+            worktree.path(),
+            &BTreeMap::new(),
+            cache_path,
+        )
+    };
+    let run_count = || std::fs::read_to_string(&counter).unwrap().lines().count();
+
+    assert_eq!(
+        run(Some(cache_path.clone()))[0].status,
+        ValidationStepStatus::Pass
+    );
+    let second = run(Some(cache_path.clone()));
+    assert_eq!(second[0].name, "build/test");
+    assert_eq!(second[0].status, ValidationStepStatus::CachedPass);
+    assert_eq!(run_count(), 1);
+
+    // `--no-cache` reruns the build.
+    assert_eq!(run(None)[0].status, ValidationStepStatus::Pass);
+    assert_eq!(run_count(), 2);
+}
+
 #[test]
 #[serial]
 fn test_validate_task_not_in_qa_fails() {
@@ -250,12 +377,18 @@ fn test_validate_task_not_in_qa_fails() {
         must_not_touch: vec![],
         depends_on: vec![],
         tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
     })
     .unwrap();
 
     // Try to validate task in READY - should fail
     let result = cmd_validate(ValidateArgs {
         task_id: "TASK-001".to_string(),
+        no_cache: false,
+        report_junit: None,
+        report_sarif: None,
     });
 
     assert!(result.is_err());
@@ -276,6 +409,9 @@ fn test_validate_nonexistent_task_fails() {
     // Try to validate a task that doesn't exist
     let result = cmd_validate(ValidateArgs {
         task_id: "TASK-999".to_string(),
+        no_cache: false,
+        report_junit: None,
+        report_sarif: None,
     });
 
     assert!(result.is_err());
@@ -303,6 +439,9 @@ fn test_validate_passing_task() {
     // Validate the task
     let result = cmd_validate(ValidateArgs {
         task_id: "TASK-001".to_string(),
+        no_cache: false,
+        report_junit: None,
+        report_sarif: None,
     });
 
     assert!(result.is_ok(), "Validate should succeed: {:?}", result);
@@ -319,6 +458,51 @@ fn test_validate_passing_task() {
     assert!(task.body.contains("**Result:** PASS"));
 }
 
+#[test]
+#[serial]
+fn test_validate_saves_full_step_logs() {
+    let temp_dir = create_test_repo_with_remote();
+    let _guard = DirGuard::new(temp_dir.path());
+    cmd_init().unwrap();
+
+    let config_path = temp_dir.path().join(".burl/.workflow/config.yaml");
+    std::fs::write(&config_path, "build_command: \"git --version\"\n").unwrap();
+    setup_task_in_qa(&temp_dir);
+
+    // Cached passes produce no log, so rerun the build both times.
+    for _ in 0..2 {
+        cmd_validate(ValidateArgs {
+            task_id: "TASK-001".to_string(),
+            no_cache: true,
+            report_junit: None,
+            report_sarif: None,
+        })
+        .unwrap();
+    }
+
+    let ctx = crate::context::require_initialized_workflow().unwrap();
+    assert_eq!(
+        crate::validation_logs::attempts(&ctx, "TASK-001"),
+        vec![1, 2]
+    );
+    let log = std::fs::read_to_string(
+        ctx.task_validation_logs_dir("TASK-001")
+            .join("2/01-build-test.log"),
+    )
+    .unwrap();
+    assert!(log.starts_with("$ git --version\n"));
+    assert!(log.contains("result: exit code 0"));
+    assert!(log.contains("git version"));
+
+    let qa_path = temp_dir
+        .path()
+        .join(".burl/.workflow/QA/TASK-001-test-validate.md");
+    let task = TaskFile::load(&qa_path).unwrap();
+    assert!(task.body.contains(
+        "**Full logs:** `.burl/.workflow/validation-logs/TASK-001/2/` (`burl logs TASK-001 --validation --attempt 2`)"
+    ));
+}
+
 #[test]
 #[serial]
 fn test_validate_with_scope_violation() {
@@ -341,6 +525,9 @@ fn test_validate_with_scope_violation() {
         must_not_touch: vec![],
         depends_on: vec![],
         tags: vec![],
+        parent: None,
+        template: None,
+        kind: None,
     })
     .unwrap();
 
@@ -422,8 +609,14 @@ fn test_validate_with_scope_violation() {
         .expect("failed to commit");
 
     // Validate should fail with scope violation
+    let reports = TempDir::new().unwrap();
+    let junit_path = reports.path().join("validate.xml");
+    let sarif_path = reports.path().join("validate.sarif");
     let result = cmd_validate(ValidateArgs {
         task_id: "TASK-001".to_string(),
+        no_cache: false,
+        report_junit: Some(junit_path.to_string_lossy().to_string()),
+        report_sarif: Some(sarif_path.to_string_lossy().to_string()),
     });
 
     assert!(result.is_err());
@@ -434,6 +627,20 @@ fn test_validate_with_scope_violation() {
     let task = TaskFile::load(&qa_path).unwrap();
     assert!(task.body.contains("**Result:** FAIL"));
     assert!(task.body.contains("**scope**: FAIL"));
+
+    // Reports are written even when validation fails
+    let junit = std::fs::read_to_string(&junit_path).unwrap();
+    assert!(junit.contains("<testcase classname=\"TASK-001\" name=\"scope\">"));
+    assert!(junit.contains("<testcase classname=\"TASK-001\" name=\"stubs\"/>"));
+    let sarif: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&sarif_path).unwrap()).unwrap();
+    let results = sarif["runs"][0]["results"].as_array().unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0]["ruleId"], "burl/scope/out-of-scope");
+    assert_eq!(
+        results[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+        "not_allowed/bad.rs"
+    );
 }
 
 #[test]
@@ -455,6 +662,9 @@ fn test_validate_with_empty_build_command() {
     // Validate - should only run scope/stubs, not build
     let result = cmd_validate(ValidateArgs {
         task_id: "TASK-001".to_string(),
+        no_cache: false,
+        report_junit: None,
+        report_sarif: None,
     });
 
     assert!(result.is_ok(), "Validate should succeed: {:?}", result);
@@ -3,10 +3,15 @@
 //! `watch` provides a simple automation loop to:
 //! - keep claiming READY tasks up to `config.max_parallel`
 //! - optionally dispatch agents for newly claimed tasks (`--dispatch`)
-//! - process QA tasks (validate, or approve if `--approve` is set)
+//! - process QA tasks (validate, or approve if `--approve` is set); tasks in
+//!   the review buckets of `states.approve_from` are processed too, and are
+//!   only approved from those buckets
+//!
+//! `--query` restricts all three to tasks matching a `burl search` query.
 //!
 //! To avoid spamming repeated QA report entries, `watch` tracks the last-seen
-//! HEAD SHA per QA task and only re-processes a task when its HEAD changes.
+//! HEAD SHA per QA task and only re-processes a task when its HEAD (or, for
+//! review buckets other than QA, its bucket) changes.
 
 use crate::agent::prompt::{TaskContext, generate_and_write_prompt};
 use crate::agent::{AgentsConfig, execute_agent, resolve_agent};
@@ -18,8 +23,9 @@ use crate::error::{BurlError, Result};
 use crate::events::{Event, EventAction, append_event};
 use crate::fs::atomic_write_file;
 use crate::git::run_git;
+use crate::query::TaskQuery;
 use crate::task::TaskFile;
-use crate::workflow::TaskIndex;
+use crate::workflow::{TaskIndex, TaskInfo};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::collections::{HashMap, HashSet};
@@ -52,6 +58,12 @@ pub fn cmd_watch(args: WatchArgs) -> Result<()> {
     let ctx = require_initialized_workflow()?;
     let config = Config::load(ctx.config_path()).unwrap_or_default();
 
+    let query = args
+        .query
+        .as_deref()
+        .map(|q| TaskQuery::parse(q, &config.states.buckets))
+        .transpose()?;
+
     let state_path = watch_state_path(&ctx);
     let mut state = load_watch_state(&state_path);
 
@@ -78,13 +90,16 @@ pub fn cmd_watch(args: WatchArgs) -> Result<()> {
         args.claim, args.qa, args.approve, args.dispatch
     );
     eprintln!("  interval: {}ms", args.interval_ms);
+    if let Some(query) = &args.query {
+        eprintln!("  query:    {}", query);
+    }
     eprintln!();
 
     loop {
         let mut changed_state = false;
 
         // Prune state to only current QA/DOING tasks (keeps file small over time).
-        let current_qa_ids = current_qa_task_ids(&ctx)?;
+        let current_qa_ids = current_qa_task_ids(&ctx, &config)?;
         let current_doing_ids = current_doing_task_ids(&ctx)?;
         if prune_state(&mut state, &current_qa_ids, &current_doing_ids) {
             changed_state = true;
@@ -92,16 +107,16 @@ pub fn cmd_watch(args: WatchArgs) -> Result<()> {
 
         if args.claim {
             // Claim changes are durable via command implementations; no watch-state update.
-            let _ = claim_up_to_max_parallel(&ctx, &config)?;
+            let _ = claim_up_to_max_parallel(&ctx, &config, query.as_ref())?;
         }
 
         if let Some(ref agents_cfg) = agents_config
-            && dispatch_doing_tasks(&ctx, agents_cfg, &mut state)?
+            && dispatch_doing_tasks(&ctx, &config, agents_cfg, query.as_ref(), &mut state)?
         {
             changed_state = true;
         }
 
-        if args.qa && process_qa_tasks(&ctx, &args, &mut state)? {
+        if args.qa && process_qa_tasks(&ctx, &config, &args, query.as_ref(), &mut state)? {
             changed_state = true;
         }
 
@@ -155,15 +170,22 @@ fn save_watch_state(path: &Path, state: &WatchState) -> Result<()> {
     Ok(())
 }
 
-fn current_qa_task_ids(ctx: &WorkflowContext) -> Result<HashSet<String>> {
+fn current_qa_task_ids(ctx: &WorkflowContext, config: &Config) -> Result<HashSet<String>> {
     let index = TaskIndex::build(ctx)?;
-    Ok(index
-        .tasks_in_bucket("QA")
+    Ok(review_tasks(&index, config)
         .into_iter()
         .map(|t| t.id.clone())
         .collect())
 }
 
+/// Tasks in QA and in the `states.approve_from` review buckets.
+fn review_tasks<'a>(index: &'a TaskIndex, config: &Config) -> Vec<&'a TaskInfo> {
+    index
+        .all_tasks()
+        .filter(|t| config.states.in_review(&t.bucket))
+        .collect()
+}
+
 fn current_doing_task_ids(ctx: &WorkflowContext) -> Result<HashSet<String>> {
     let index = TaskIndex::build(ctx)?;
     Ok(index
@@ -187,7 +209,11 @@ fn prune_state(
     state.qa_head_sha.len() != qa_before || state.dispatched_tasks.len() != dispatched_before
 }
 
-fn claim_up_to_max_parallel(ctx: &WorkflowContext, config: &Config) -> Result<bool> {
+fn claim_up_to_max_parallel(
+    ctx: &WorkflowContext,
+    config: &Config,
+    query: Option<&TaskQuery>,
+) -> Result<bool> {
     let mut did_claim = false;
 
     loop {
@@ -199,7 +225,22 @@ fn claim_up_to_max_parallel(ctx: &WorkflowContext, config: &Config) -> Result<bo
             break;
         }
 
-        match claim::cmd_claim(ClaimArgs { task_id: None }) {
+        // With a query, pick the next matching task ourselves and claim it
+        // explicitly; otherwise let `claim` select from all of READY.
+        let task_id = match query {
+            Some(query) => {
+                let ready = query.filter(index.tasks_in_bucket("READY"));
+                let tie_break = config.claim_tie_break;
+                let Some(id) = claim::selection::select_next_task_id(ctx, &ready, tie_break)?
+                else {
+                    break;
+                };
+                Some(id)
+            }
+            None => None,
+        };
+
+        match claim::cmd_claim(ClaimArgs { task_id }) {
             Ok(()) => {
                 did_claim = true;
                 continue;
@@ -222,11 +263,16 @@ fn claim_up_to_max_parallel(ctx: &WorkflowContext, config: &Config) -> Result<bo
 
 fn process_qa_tasks(
     ctx: &WorkflowContext,
+    config: &Config,
     args: &WatchArgs,
+    query: Option<&TaskQuery>,
     state: &mut WatchState,
 ) -> Result<bool> {
     let index = TaskIndex::build(ctx)?;
-    let qa_tasks = index.tasks_in_bucket("QA");
+    let mut qa_tasks = review_tasks(&index, config);
+    if let Some(query) = query {
+        qa_tasks = query.filter(qa_tasks);
+    }
     if qa_tasks.is_empty() {
         return Ok(false);
     }
@@ -236,16 +282,16 @@ fn process_qa_tasks(
     for task_info in qa_tasks {
         let task_id = task_info.id.clone();
 
-        // Load task file to find worktree path.
-        let task_file = match TaskFile::load(&task_info.path) {
-            Ok(t) => t,
+        // Load task frontmatter to find worktree path.
+        let frontmatter = match index.frontmatter(task_info) {
+            Ok(f) => f,
             Err(e) => {
                 eprintln!("watch: failed to load {}: {}", task_id, e);
                 continue;
             }
         };
 
-        let Some(worktree) = task_file.frontmatter.worktree.as_ref() else {
+        let Some(worktree) = frontmatter.worktree.as_ref() else {
             eprintln!("watch: {} missing worktree; run `burl doctor`", task_id);
             continue;
         };
@@ -261,7 +307,10 @@ fn process_qa_tasks(
         }
 
         let head_sha = match run_git(&worktree_path, &["rev-parse", "HEAD"]) {
-            Ok(out) => out.stdout,
+            // Outside QA, include the bucket so a task moved on to a review
+            // bucket is processed again there.
+            Ok(out) if task_info.bucket == "QA" => out.stdout,
+            Ok(out) => format!("{}:{}", task_info.bucket, out.stdout),
             Err(e) => {
                 eprintln!("watch: failed to read HEAD for {}: {}", task_id, e);
                 continue;
@@ -281,10 +330,11 @@ fn process_qa_tasks(
         state.qa_head_sha.insert(task_id.clone(), head_sha);
         changed_state = true;
 
-        if args.approve {
+        if args.approve && config.states.can_approve_from(&task_info.bucket) {
             eprintln!("watch: approving {}", task_id);
             if let Err(e) = approve::cmd_approve(ApproveArgs {
                 task_id: task_id.clone(),
+                no_cache: false,
             }) {
                 eprintln!("watch: approve failed for {}: {}", task_id, e);
             }
@@ -292,6 +342,9 @@ fn process_qa_tasks(
             eprintln!("watch: validating {}", task_id);
             if let Err(e) = validate_cmd::cmd_validate(ValidateArgs {
                 task_id: task_id.clone(),
+                no_cache: false,
+                report_junit: None,
+                report_sarif: None,
             }) {
                 // Validation failures are expected; keep going.
                 eprintln!("watch: validate failed for {}: {}", task_id, e);
@@ -299,17 +352,26 @@ fn process_qa_tasks(
         }
     }
 
+    if let Err(e) = index.save_cache() {
+        eprintln!("watch: failed to save index cache: {}", e);
+    }
+
     Ok(changed_state)
 }
 
 /// Dispatch agents for DOING tasks that haven't been dispatched yet.
 fn dispatch_doing_tasks(
     ctx: &WorkflowContext,
+    config: &Config,
     agents_config: &AgentsConfig,
+    query: Option<&TaskQuery>,
     state: &mut WatchState,
 ) -> Result<bool> {
     let index = TaskIndex::build(ctx)?;
-    let doing_tasks = index.tasks_in_bucket("DOING");
+    let mut doing_tasks = index.tasks_in_bucket("DOING");
+    if let Some(query) = query {
+        doing_tasks = query.filter(doing_tasks);
+    }
     if doing_tasks.is_empty() {
         return Ok(false);
     }
@@ -413,6 +475,7 @@ fn dispatch_doing_tasks(
             &vars,
             &worktree_str,
             timeout,
+            &config.worktree_setup.resolved_env(&ctx.repo_root),
         ) {
             Ok(result) => {
                 state.dispatched_tasks.insert(task_id.clone());
@@ -1,22 +1,38 @@
 //! Implementation of the `burl worktree` command.
 //!
-//! Prints the recorded worktree path for a task.
+//! Prints the recorded worktree path for a task; `burl worktree widen` adds
+//! paths to a sparse task worktree (see `worktree_mode: sparse`) and logs a
+//! `worktree_widen` event.
 
-use crate::cli::WorktreeArgs;
+use crate::cli::{WorktreeAction, WorktreeArgs, WorktreeWidenArgs};
+use crate::config::Config;
 use crate::context::require_initialized_workflow;
 use crate::error::{BurlError, Result};
+use crate::events::{Event, EventAction, append_event};
+use crate::git::run_git;
+use crate::git_worktree::{
+    is_sparse_worktree, sparse_checkout_patterns, sparse_patterns, widen_sparse_checkout,
+};
+use crate::locks::{acquire_task_lock, acquire_workflow_lock};
+use crate::output::{self, CommandResult, textln};
 use crate::task::TaskFile;
-use crate::workflow::{BUCKETS, TaskIndex, validate_task_id};
+use crate::task_git::require_task_git_refs;
+use crate::workflow::{TaskIndex, validate_task_id};
+use serde_json::json;
 
 /// Execute the `burl worktree` command.
 ///
 /// Prints the recorded worktree path from the task's frontmatter.
 /// If no worktree is recorded, exits with an error.
 pub fn cmd_worktree(args: WorktreeArgs) -> Result<()> {
+    if let Some(WorktreeAction::Widen(widen)) = args.action {
+        return cmd_worktree_widen(widen);
+    }
+
     let ctx = require_initialized_workflow()?;
 
-    // Validate and normalize task ID
-    let task_id = validate_task_id(&args.task_id)?;
+    // Validate and normalize task ID (clap requires it without a subcommand)
+    let task_id = validate_task_id(args.task_id.as_deref().unwrap_or_default())?;
 
     // Build task index and find the task
     let index = TaskIndex::build(&ctx)?;
@@ -27,7 +43,7 @@ pub fn cmd_worktree(args: WorktreeArgs) -> Result<()> {
              Searched buckets: {}\n\n\
              Use `burl status` to see all tasks.",
             task_id,
-            BUCKETS.join(", ")
+            index.buckets().join(", ")
         ))
     })?;
 
@@ -51,15 +67,142 @@ pub fn cmd_worktree(args: WorktreeArgs) -> Result<()> {
     Ok(())
 }
 
+/// Execute `burl worktree widen`.
+///
+/// Adds the given paths/globs to the sparse checkout of the task's worktree.
+/// The task's declared scope is unchanged, so validation still reports edits
+/// outside of it. The widening is logged as a `worktree_widen` event.
+fn cmd_worktree_widen(args: WorktreeWidenArgs) -> Result<()> {
+    let ctx = require_initialized_workflow()?;
+    let config = Config::load(ctx.config_path()).unwrap_or_default();
+    let task_id = validate_task_id(&args.task_id)?;
+
+    let index = TaskIndex::build(&ctx)?;
+    let task_info = index.find(&task_id).ok_or_else(|| {
+        BurlError::UserError(format!(
+            "task '{}' not found.\n\n\
+             Use `burl status` to see all tasks.",
+            task_id
+        ))
+    })?;
+
+    let _task_lock = acquire_task_lock(&ctx, &task_id, "widen")?;
+    let task = TaskFile::load(&task_info.path)?;
+    let refs = require_task_git_refs(
+        &ctx,
+        &task_id,
+        task.frontmatter.branch.as_deref(),
+        task.frontmatter.worktree.as_deref(),
+    )?;
+
+    if !refs.worktree_path.exists() {
+        return Err(BurlError::UserError(format!(
+            "worktree for task '{}' does not exist: {}\n\n\
+             Re-claim the task to recreate it: burl claim {}",
+            task_id,
+            refs.worktree_path.display(),
+            task_id
+        )));
+    }
+    if !is_sparse_worktree(&refs.worktree_path) {
+        return Err(BurlError::UserError(format!(
+            "worktree for task '{}' is a full checkout; there is nothing to widen.\n\n\
+             Sparse worktrees are created on claim when config.yaml sets `worktree_mode: sparse`.",
+            task_id
+        )));
+    }
+
+    ctx.ensure_workflow_clean()?;
+    let _workflow_lock = acquire_workflow_lock(&ctx, "widen")?;
+
+    let patterns = sparse_checkout_patterns(&[], &args.globs, &[]);
+    widen_sparse_checkout(&refs.worktree_path, &patterns)?;
+    let checkout = sparse_patterns(&refs.worktree_path)?;
+    let details = json!({
+        "globs": args.globs,
+        "patterns": checkout,
+    });
+
+    let event = Event::new(EventAction::WorktreeWiden)
+        .with_task(&task_id)
+        .with_details(details.clone());
+    append_event(&ctx, &event)?;
+
+    if config.workflow_auto_commit {
+        commit_widen(&ctx, &task_id)?;
+
+        if config.workflow_auto_push {
+            run_git(
+                &ctx.workflow_worktree,
+                &["push", &config.remote, &config.workflow_branch],
+            )
+            .map_err(|e| BurlError::GitError(format!("failed to push workflow branch: {}", e)))?;
+        }
+    }
+
+    output::record(
+        CommandResult::new("worktree widen")
+            .with_task(&task_id)
+            .with_git(
+                task.frontmatter.branch.as_deref(),
+                task.frontmatter.worktree.as_deref(),
+            )
+            .with_details(details),
+    );
+
+    textln!("Widened sparse checkout of {}:", task_id);
+    for pattern in &checkout {
+        textln!("  {}", pattern);
+    }
+
+    Ok(())
+}
+
+/// Commit the widen event to the workflow branch.
+fn commit_widen(ctx: &crate::context::WorkflowContext, task_id: &str) -> Result<()> {
+    run_git(&ctx.workflow_worktree, &["add", "."])
+        .map_err(|e| BurlError::GitError(format!("failed to stage changes: {}", e)))?;
+
+    let staged = run_git(&ctx.workflow_worktree, &["diff", "--cached", "--name-only"])?;
+    if staged.stdout.is_empty() {
+        return Ok(());
+    }
+
+    let commit_msg = format!("Widen worktree of {}", task_id);
+    run_git(&ctx.workflow_worktree, &["commit", "-m", &commit_msg])
+        .map_err(|e| BurlError::GitError(format!("failed to commit: {}", e)))?;
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cli::ClaimArgs;
     use crate::cli::{AddArgs, WorktreeArgs};
     use crate::commands::add::cmd_add;
+    use crate::commands::claim::cmd_claim;
     use crate::commands::init::cmd_init;
-    use crate::test_support::{DirGuard, create_test_repo};
+    use crate::config::WorktreeMode;
+    use crate::test_support::{DirGuard, create_test_repo, create_test_repo_with_remote};
     use serial_test::serial;
 
+    fn add_task(title: &str, affects: Vec<String>) {
+        cmd_add(AddArgs {
+            title: title.to_string(),
+            priority: "medium".to_string(),
+            affects,
+            affects_globs: vec![],
+            must_not_touch: vec![],
+            depends_on: vec![],
+            tags: vec![],
+            parent: None,
+            template: None,
+            kind: None,
+        })
+        .unwrap();
+    }
+
     #[test]
     #[serial]
     fn test_worktree_task_not_found() {
@@ -69,7 +212,8 @@ mod tests {
         cmd_init().unwrap();
 
         let args = WorktreeArgs {
-            task_id: "TASK-999".to_string(),
+            task_id: Some("TASK-999".to_string()),
+            action: None,
         };
         let result = cmd_worktree(args);
         assert!(result.is_err());
@@ -93,11 +237,15 @@ mod tests {
             must_not_touch: vec![],
             depends_on: vec![],
             tags: vec![],
+            parent: None,
+            template: None,
+            kind: None,
         };
         cmd_add(add_args).unwrap();
 
         let args = WorktreeArgs {
-            task_id: "TASK-001".to_string(),
+            task_id: Some("TASK-001".to_string()),
+            action: None,
         };
         let result = cmd_worktree(args);
         assert!(result.is_err());
@@ -114,11 +262,88 @@ mod tests {
         cmd_init().unwrap();
 
         let args = WorktreeArgs {
-            task_id: "../TASK-001".to_string(),
+            task_id: Some("../TASK-001".to_string()),
+            action: None,
         };
         let result = cmd_worktree(args);
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert!(err.to_string().contains("path traversal"));
     }
+
+    #[test]
+    #[serial]
+    fn test_worktree_widen_sparse_checkout() {
+        let temp_dir = create_test_repo_with_remote();
+        let _guard = DirGuard::new(temp_dir.path());
+        let root = temp_dir.path();
+        for file in ["src/a/lib.rs", "src/b/lib.rs", "docs/guide.md"] {
+            std::fs::create_dir_all(root.join(file).parent().unwrap()).unwrap();
+            std::fs::write(root.join(file), "content\n").unwrap();
+        }
+        run_git(root, &["add", "."]).unwrap();
+        run_git(root, &["commit", "-m", "Add sources"]).unwrap();
+        run_git(root, &["push", "origin", "main"]).unwrap();
+
+        cmd_init().unwrap();
+        let ctx = require_initialized_workflow().unwrap();
+        let mut config = Config::load(ctx.config_path()).unwrap();
+        config.worktree_mode = WorktreeMode::Sparse;
+        config.sparse_always_include = vec!["README.md".to_string()];
+        std::fs::write(ctx.config_path(), config.to_yaml().unwrap()).unwrap();
+        run_git(
+            &ctx.workflow_worktree,
+            &["commit", "-am", "Use sparse worktrees"],
+        )
+        .unwrap();
+
+        add_task("Sparse task", vec!["src/a/".to_string()]);
+        add_task("Unscoped task", vec![]);
+        for id in ["TASK-001", "TASK-002"] {
+            cmd_claim(ClaimArgs {
+                task_id: Some(id.to_string()),
+            })
+            .unwrap();
+        }
+
+        // Only the declared scope (plus always-included paths) is checked out.
+        let worktree = root.join(".worktrees/task-001-sparse-task");
+        assert!(worktree.join("src/a/lib.rs").exists());
+        assert!(worktree.join("README.md").exists());
+        assert!(!worktree.join("src/b").exists());
+        assert!(!worktree.join("docs").exists());
+
+        cmd_worktree(WorktreeArgs {
+            task_id: None,
+            action: Some(WorktreeAction::Widen(WorktreeWidenArgs {
+                task_id: "TASK-001".to_string(),
+                globs: vec!["docs/".to_string()],
+            })),
+        })
+        .unwrap();
+        assert!(worktree.join("docs/guide.md").exists());
+        assert!(!worktree.join("src/b").exists());
+
+        // The widening is logged and committed to the workflow branch.
+        let event = crate::events::read_events(&ctx).unwrap().pop().unwrap();
+        assert_eq!(event.action, EventAction::WorktreeWiden);
+        assert_eq!(event.task.as_deref(), Some("TASK-001"));
+        assert_eq!(event.details["globs"], json!(["docs/"]));
+        ctx.ensure_workflow_clean().unwrap();
+
+        // A task without a declared scope gets a full checkout.
+        assert!(
+            root.join(".worktrees/task-002-unscoped-task/src/b/lib.rs")
+                .exists()
+        );
+        let err = cmd_worktree(WorktreeArgs {
+            task_id: None,
+            action: Some(WorktreeAction::Widen(WorktreeWidenArgs {
+                task_id: "TASK-002".to_string(),
+                globs: vec!["docs/".to_string()],
+            })),
+        })
+        .unwrap_err();
+        assert!(err.to_string().contains("full checkout"));
+    }
 }
@@ -14,5 +14,7 @@ mod tests;
 // Re-export public API
 pub use model::Config;
 pub use types::{
-    ConflictDetectionMode, ConflictPolicy, MergeStrategy, ValidationCommandStep, ValidationProfile,
+    ClaimTieBreak, ConflictDetectionMode, ConflictPolicy, HooksConfig, MergeStrategy,
+    StateTransition, StatesConfig, TransitionGate, ValidationCommandStep, ValidationProfile,
+    WorktreeMode, WorktreeSetupConfig,
 };
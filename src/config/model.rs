@@ -36,6 +36,23 @@ pub struct Config {
     #[serde(default)]
     pub workflow_auto_push: bool,
 
+    // =========================================================================
+    // Task ID settings
+    // =========================================================================
+    /// Task ID prefixes (e.g. `[TASK, BUG, NET]`). Each prefix is numbered
+    /// separately: `TASK-001`, `BUG-001`, ...
+    #[serde(default = "default_id_prefixes")]
+    pub id_prefixes: Vec<String>,
+
+    /// Prefix used by `burl add` without `--kind` (default: the first entry of
+    /// `id_prefixes`).
+    #[serde(default)]
+    pub default_id_prefix: Option<String>,
+
+    /// Minimum number of digits in new task IDs (zero-padded, 3-9).
+    #[serde(default = "default_id_width")]
+    pub id_width: usize,
+
     // =========================================================================
     // Git settings
     // =========================================================================
@@ -70,6 +87,11 @@ pub struct Config {
     #[serde(default = "default_true")]
     pub use_global_claim_lock: bool,
 
+    /// How `claim` without a task ID breaks ties between READY tasks of equal
+    /// priority (`id` or `downstream`).
+    #[serde(default)]
+    pub claim_tie_break: ClaimTieBreak,
+
     // =========================================================================
     // QA settings
     // =========================================================================
@@ -110,6 +132,36 @@ pub struct Config {
     #[serde(default = "default_stub_check_extensions")]
     pub stub_check_extensions: Vec<String>,
 
+    // =========================================================================
+    // State machine settings
+    // =========================================================================
+    /// Buckets, review buckets, and extra `burl move` transitions.
+    #[serde(default)]
+    pub states: StatesConfig,
+
+    // =========================================================================
+    // Lifecycle hooks
+    // =========================================================================
+    /// Commands run before/after claim, release, submit, approve, and reject.
+    #[serde(default, skip_serializing_if = "HooksConfig::is_empty")]
+    pub hooks: HooksConfig,
+
+    // =========================================================================
+    // Worktree settings
+    // =========================================================================
+    /// Full or sparse checkout for new task worktrees.
+    #[serde(default)]
+    pub worktree_mode: WorktreeMode,
+
+    /// Sparse-checkout patterns (gitignore syntax, e.g. `/Cargo.toml`) always
+    /// included in sparse task worktrees.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sparse_always_include: Vec<String>,
+
+    /// Files, commands, and environment used to provision new task worktrees.
+    #[serde(default, skip_serializing_if = "WorktreeSetupConfig::is_empty")]
+    pub worktree_setup: WorktreeSetupConfig,
+
     // =========================================================================
     // Conflict settings
     // =========================================================================
@@ -130,6 +182,9 @@ impl Default for Config {
             workflow_worktree: default_workflow_worktree(),
             workflow_auto_commit: default_true(),
             workflow_auto_push: false,
+            id_prefixes: default_id_prefixes(),
+            default_id_prefix: None,
+            id_width: default_id_width(),
             main_branch: default_main_branch(),
             remote: default_remote(),
             merge_strategy: MergeStrategy::default(),
@@ -137,6 +192,7 @@ impl Default for Config {
             push_task_branch_on_submit: false,
             lock_stale_minutes: default_lock_stale_minutes(),
             use_global_claim_lock: default_true(),
+            claim_tie_break: ClaimTieBreak::default(),
             qa_max_attempts: default_qa_max_attempts(),
             auto_priority_boost_on_retry: default_true(),
             build_command: default_build_command(),
@@ -144,6 +200,11 @@ impl Default for Config {
             validation_profiles: BTreeMap::new(),
             stub_patterns: default_stub_patterns(),
             stub_check_extensions: default_stub_check_extensions(),
+            states: StatesConfig::default(),
+            hooks: HooksConfig::default(),
+            worktree_mode: WorktreeMode::default(),
+            sparse_always_include: Vec::new(),
+            worktree_setup: WorktreeSetupConfig::default(),
             conflict_detection: ConflictDetectionMode::default(),
             conflict_policy: ConflictPolicy::default(),
         }
@@ -2,8 +2,10 @@
 
 use super::model::Config;
 use crate::error::{BurlError, Result};
+use crate::validate::pipeline::step_dependencies;
+use crate::workflow::{ARCHIVE_DIR, BUCKETS};
 use globset::Glob;
-use std::path::Path;
+use std::path::{Component, Path};
 
 impl Config {
     /// Load config from a YAML file.
@@ -54,6 +56,18 @@ impl Config {
     /// Validation rules:
     /// - `lock_stale_minutes` must be positive
     /// - `qa_max_attempts` must be positive
+    /// - `id_prefixes` must be non-empty, unique, uppercase letters and digits
+    ///   starting with a letter; `default_id_prefix` must be one of them
+    /// - `id_width` must be between 3 and 9
+    /// - `states` must list valid, unique buckets including the built-in ones,
+    ///   and its review buckets and transitions must refer to them
+    /// - validation profile `needs` must name other steps of the profile without
+    ///   cycles, and `max_parallel` must be positive
+    /// - `hooks` commands must be non-empty and parse as shell words, and
+    ///   `hooks.timeout_seconds` must be positive
+    /// - `worktree_setup` paths must be repo-relative without `..`, its
+    ///   commands must parse as shell words, `env` names must be valid, and
+    ///   `timeout_seconds` must be positive
     /// - `stub_check_extensions` entries must be non-empty and have no leading dots
     pub fn validate(&self) -> Result<()> {
         // Validate lock_stale_minutes
@@ -70,6 +84,45 @@ impl Config {
             ));
         }
 
+        // Validate task ID settings
+        if self.id_prefixes.is_empty() {
+            return Err(BurlError::UserError(
+                "config validation failed: id_prefixes must not be empty".to_string(),
+            ));
+        }
+        let mut seen_prefixes = std::collections::HashSet::new();
+        for prefix in &self.id_prefixes {
+            let mut chars = prefix.chars();
+            let valid = chars.next().is_some_and(|c| c.is_ascii_uppercase())
+                && chars.all(|c| c.is_ascii_uppercase() || c.is_ascii_digit());
+            if !valid {
+                return Err(BurlError::UserError(format!(
+                    "config validation failed: id_prefixes entry '{}' must be uppercase letters and digits, starting with a letter (e.g. BUG)",
+                    prefix
+                )));
+            }
+            if !seen_prefixes.insert(prefix.as_str()) {
+                return Err(BurlError::UserError(format!(
+                    "config validation failed: id_prefixes has duplicate entry '{}'",
+                    prefix
+                )));
+            }
+        }
+        if let Some(ref default_prefix) = self.default_id_prefix
+            && !self.id_prefixes.contains(default_prefix)
+        {
+            return Err(BurlError::UserError(format!(
+                "config validation failed: default_id_prefix '{}' not found in id_prefixes",
+                default_prefix
+            )));
+        }
+        if !(3..=9).contains(&self.id_width) {
+            return Err(BurlError::UserError(format!(
+                "config validation failed: id_width must be between 3 and 9 (found {})",
+                self.id_width
+            )));
+        }
+
         // Validate stub_check_extensions
         for ext in &self.stub_check_extensions {
             if ext.is_empty() {
@@ -155,12 +208,251 @@ impl Config {
                         ))
                     })?;
                 }
+
+                if step.timeout_seconds == Some(0) {
+                    return Err(BurlError::UserError(format!(
+                        "config validation failed: validation_profiles.{}.steps[{}].timeout_seconds must be greater than 0",
+                        profile_name, idx
+                    )));
+                }
+
+                if let Some(cwd) = &step.cwd {
+                    let p = Path::new(cwd);
+                    if cwd.trim().is_empty()
+                        || p.is_absolute()
+                        || p.components().any(|c| matches!(c, Component::ParentDir))
+                    {
+                        return Err(BurlError::UserError(format!(
+                            "config validation failed: validation_profiles.{}.steps[{}].cwd '{}' must be a relative path inside the worktree",
+                            profile_name, idx, cwd
+                        )));
+                    }
+                }
+
+                for key in step.env.keys() {
+                    if !is_valid_env_name(key) {
+                        return Err(BurlError::UserError(format!(
+                            "config validation failed: validation_profiles.{}.steps[{}].env name '{}' is not a valid variable name",
+                            profile_name, idx, key
+                        )));
+                    }
+                }
+            }
+
+            if profile.max_parallel == Some(0) {
+                return Err(BurlError::UserError(format!(
+                    "config validation failed: validation_profiles.{}.max_parallel must be greater than 0",
+                    profile_name
+                )));
+            }
+            step_dependencies(&profile.steps).map_err(|e| {
+                BurlError::UserError(format!(
+                    "config validation failed: validation_profiles.{}: {}",
+                    profile_name, e
+                ))
+            })?;
+        }
+
+        self.validate_states()?;
+
+        for (name, command) in self.hooks.entries() {
+            let Some(command) = command else { continue };
+            match shell_words::split(command) {
+                Ok(args) if !args.is_empty() => {}
+                Ok(_) => {
+                    return Err(BurlError::UserError(format!(
+                        "config validation failed: hooks.{} must be non-empty",
+                        name
+                    )));
+                }
+                Err(e) => {
+                    return Err(BurlError::UserError(format!(
+                        "config validation failed: hooks.{} is not a valid command: {}",
+                        name, e
+                    )));
+                }
+            }
+        }
+
+        if self.hooks.timeout_seconds == Some(0) {
+            return Err(BurlError::UserError(
+                "config validation failed: hooks.timeout_seconds must be greater than 0"
+                    .to_string(),
+            ));
+        }
+
+        self.validate_worktree_setup()?;
+
+        Ok(())
+    }
+
+    /// Validate the `worktree_setup` section.
+    fn validate_worktree_setup(&self) -> Result<()> {
+        let setup = &self.worktree_setup;
+        let fail = |msg: String| {
+            Err(BurlError::UserError(format!(
+                "config validation failed: worktree_setup.{}",
+                msg
+            )))
+        };
+
+        for (field, paths) in [("copy", &setup.copy), ("symlink", &setup.symlink)] {
+            for path in paths {
+                let p = Path::new(path);
+                if path.trim().is_empty()
+                    || p.is_absolute()
+                    || p.components().any(|c| matches!(c, Component::ParentDir))
+                {
+                    return fail(format!(
+                        "{} entry '{}' must be a relative path inside the repository",
+                        field, path
+                    ));
+                }
+            }
+        }
+
+        for (idx, command) in setup.commands.iter().enumerate() {
+            match shell_words::split(command) {
+                Ok(args) if !args.is_empty() => {}
+                Ok(_) => return fail(format!("commands[{}] must be non-empty", idx)),
+                Err(e) => {
+                    return fail(format!("commands[{}] is not a valid command: {}", idx, e));
+                }
+            }
+        }
+
+        for key in setup.env.keys() {
+            if !is_valid_env_name(key) {
+                return fail(format!("env name '{}' is not a valid variable name", key));
             }
         }
 
+        if setup.timeout_seconds == Some(0) {
+            return fail("timeout_seconds must be greater than 0".to_string());
+        }
+
         Ok(())
     }
 
+    /// Validate the `states` section: bucket names, review buckets, and
+    /// `burl move` transitions.
+    fn validate_states(&self) -> Result<()> {
+        let states = &self.states;
+        let fail = |msg: String| {
+            Err(BurlError::UserError(format!(
+                "config validation failed: {}",
+                msg
+            )))
+        };
+
+        let mut seen = std::collections::HashSet::new();
+        for bucket in &states.buckets {
+            let mut chars = bucket.chars();
+            let valid = chars.next().is_some_and(|c| c.is_ascii_uppercase())
+                && chars.all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_');
+            if !valid || bucket == ARCHIVE_DIR {
+                return fail(format!(
+                    "states.buckets entry '{}' must be uppercase letters, digits and '_', starting with a letter (and not ARCHIVE)",
+                    bucket
+                ));
+            }
+            if !seen.insert(bucket.as_str()) {
+                return fail(format!("states.buckets has duplicate entry '{}'", bucket));
+            }
+        }
+        for builtin in BUCKETS {
+            if !seen.contains(builtin) {
+                return fail(format!(
+                    "states.buckets must include the built-in bucket '{}'",
+                    builtin
+                ));
+            }
+        }
+
+        if states.approve_from.is_empty() {
+            return fail("states.approve_from must not be empty".to_string());
+        }
+        for bucket in &states.approve_from {
+            if !seen.contains(bucket.as_str()) {
+                return fail(format!(
+                    "states.approve_from entry '{}' not found in states.buckets",
+                    bucket
+                ));
+            }
+            if matches!(bucket.as_str(), "READY" | "DOING" | "DONE" | "BLOCKED") {
+                return fail(format!(
+                    "states.approve_from entry '{}' must be QA or a custom bucket",
+                    bucket
+                ));
+            }
+        }
+
+        for (idx, transition) in states.transitions.iter().enumerate() {
+            for (field, bucket) in [("from", &transition.from), ("to", &transition.to)] {
+                if !seen.contains(bucket.as_str()) {
+                    return fail(format!(
+                        "states.transitions[{}].{} '{}' not found in states.buckets",
+                        idx, field, bucket
+                    ));
+                }
+            }
+            if transition.from == transition.to {
+                return fail(format!(
+                    "states.transitions[{}] must move between two different buckets",
+                    idx
+                ));
+            }
+            if transition.from == "DONE" || transition.to == "DONE" {
+                return fail(format!(
+                    "states.transitions[{}] cannot enter or leave DONE; list the bucket in states.approve_from to approve from it",
+                    idx
+                ));
+            }
+            if BUCKETS.contains(&transition.from.as_str())
+                && BUCKETS.contains(&transition.to.as_str())
+            {
+                return fail(format!(
+                    "states.transitions[{}] must enter or leave a custom bucket; moves between built-in buckets use claim, submit, reject, block and unblock",
+                    idx
+                ));
+            }
+            if let Some(profile) = &transition.profile
+                && !self.validation_profiles.contains_key(profile)
+            {
+                return fail(format!(
+                    "states.transitions[{}].profile '{}' not found in validation_profiles",
+                    idx, profile
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Task ID prefix for a new task of `kind` (case-insensitive), or the
+    /// default prefix when no kind is given.
+    pub fn id_prefix(&self, kind: Option<&str>) -> Result<String> {
+        let Some(kind) = kind else {
+            return Ok(self
+                .default_id_prefix
+                .clone()
+                .or_else(|| self.id_prefixes.first().cloned())
+                .unwrap_or_else(|| "TASK".to_string()));
+        };
+
+        let prefix = kind.trim().to_ascii_uppercase();
+        if self.id_prefixes.contains(&prefix) {
+            return Ok(prefix);
+        }
+        Err(BurlError::UserError(format!(
+            "unknown task kind '{}'.\n\n\
+             Configured id_prefixes: {}\n\
+             Add the prefix to `id_prefixes` in config.yaml to use it.",
+            kind,
+            self.id_prefixes.join(", ")
+        )))
+    }
+
     /// Get stub_check_extensions normalized to lowercase.
     pub fn normalized_extensions(&self) -> Vec<String> {
         self.stub_check_extensions
@@ -169,3 +461,8 @@ impl Config {
             .collect()
     }
 }
+
+/// Whether `name` can be used as an environment variable name.
+fn is_valid_env_name(name: &str) -> bool {
+    !name.is_empty() && !name.contains('=') && !name.contains('\0')
+}
@@ -1,7 +1,13 @@
 //! Tests for config functionality.
 
-use crate::config::types::{default_stub_check_extensions, default_stub_patterns};
-use crate::config::{Config, ConflictDetectionMode, ConflictPolicy, MergeStrategy};
+use crate::config::types::{
+    DEFAULT_HOOK_TIMEOUT_SECS, DEFAULT_SETUP_TIMEOUT_SECS, default_stub_check_extensions,
+    default_stub_patterns,
+};
+use crate::config::{
+    ClaimTieBreak, Config, ConflictDetectionMode, ConflictPolicy, MergeStrategy, TransitionGate,
+    WorktreeMode,
+};
 
 #[test]
 fn test_default_config() {
@@ -12,12 +18,16 @@ fn test_default_config() {
     assert_eq!(config.workflow_worktree, ".burl");
     assert!(config.workflow_auto_commit);
     assert!(!config.workflow_auto_push);
+    assert_eq!(config.id_prefixes, vec!["TASK"]);
+    assert_eq!(config.default_id_prefix, None);
+    assert_eq!(config.id_width, 3);
     assert_eq!(config.main_branch, "main");
     assert_eq!(config.remote, "origin");
     assert_eq!(config.merge_strategy, MergeStrategy::RebaseFfOnly);
     assert!(!config.push_main_on_approve);
     assert_eq!(config.lock_stale_minutes, 120);
     assert!(config.use_global_claim_lock);
+    assert_eq!(config.claim_tie_break, ClaimTieBreak::Id);
     assert_eq!(config.qa_max_attempts, 3);
     assert!(config.auto_priority_boost_on_retry);
     assert_eq!(config.build_command, "cargo test");
@@ -253,6 +263,297 @@ fn test_validate_zero_qa_max_attempts() {
     assert!(err.to_string().contains("greater than 0"));
 }
 
+#[test]
+fn test_parse_id_prefixes() {
+    let yaml = r#"
+id_prefixes: [TASK, BUG, NET]
+default_id_prefix: BUG
+id_width: 4
+"#;
+    let config = Config::from_yaml(yaml).unwrap();
+
+    assert_eq!(config.id_prefixes, vec!["TASK", "BUG", "NET"]);
+    assert_eq!(config.id_width, 4);
+    assert_eq!(config.id_prefix(None).unwrap(), "BUG");
+    assert_eq!(config.id_prefix(Some("net")).unwrap(), "NET");
+    let err = config.id_prefix(Some("docs")).unwrap_err();
+    assert!(err.to_string().contains("unknown task kind 'docs'"));
+
+    let config = Config::default();
+    assert_eq!(config.id_prefix(None).unwrap(), "TASK");
+    assert!(config.id_prefix(Some("bug")).is_err());
+}
+
+#[test]
+fn test_validate_id_prefixes() {
+    for yaml in [
+        "id_prefixes: []",
+        "id_prefixes: [bug]",
+        "id_prefixes: [MY-BUG]",
+        "id_prefixes: [BUG, BUG]",
+        "default_id_prefix: BUG",
+        "id_width: 2",
+        "id_width: 10",
+    ] {
+        let err = Config::from_yaml(yaml).unwrap_err();
+        assert!(
+            err.to_string().contains("config validation failed"),
+            "{}",
+            yaml
+        );
+    }
+}
+
+#[test]
+fn test_parse_states() {
+    let yaml = r#"
+validation_profiles:
+  quick:
+    steps:
+      - name: test
+        command: cargo test
+states:
+  buckets: [READY, DOING, QA, REVIEW, DONE, BLOCKED]
+  approve_from: [REVIEW]
+  transitions:
+    - from: QA
+      to: REVIEW
+      gates: [scope, stubs, approval]
+      profile: quick
+"#;
+    let config = Config::from_yaml(yaml).unwrap();
+
+    assert_eq!(config.states.buckets[3], "REVIEW");
+    let transition = config.states.transition("QA", "REVIEW").unwrap();
+    assert_eq!(
+        transition.gates,
+        vec![
+            TransitionGate::Scope,
+            TransitionGate::Stubs,
+            TransitionGate::Approval
+        ]
+    );
+    assert_eq!(transition.profile.as_deref(), Some("quick"));
+    assert!(config.states.transition("REVIEW", "QA").is_none());
+    assert!(config.states.can_approve_from("REVIEW"));
+    assert!(!config.states.can_approve_from("QA"));
+    assert!(config.states.in_review("QA"));
+
+    let config = Config::default();
+    assert_eq!(
+        config.states.buckets,
+        vec!["READY", "DOING", "QA", "DONE", "BLOCKED"]
+    );
+    assert!(config.states.can_approve_from("QA"));
+}
+
+#[test]
+fn test_validate_states() {
+    for yaml in [
+        "states: {buckets: [READY, DOING, QA, DONE]}",
+        "states: {buckets: [READY, DOING, QA, DONE, BLOCKED, review]}",
+        "states: {buckets: [READY, DOING, QA, DONE, BLOCKED, ARCHIVE]}",
+        "states: {buckets: [READY, DOING, QA, QA, DONE, BLOCKED]}",
+        "states: {approve_from: []}",
+        "states: {approve_from: [REVIEW]}",
+        "states: {approve_from: [DOING]}",
+        "states: {transitions: [{from: QA, to: REVIEW}]}",
+        "states: {transitions: [{from: QA, to: QA}]}",
+        "states: {transitions: [{from: QA, to: DONE}]}",
+        "states: {transitions: [{from: DOING, to: QA}]}",
+        "states: {transitions: [{from: QA, to: READY}]}",
+        "states: {buckets: [READY, DOING, QA, REVIEW, DONE, BLOCKED], transitions: [{from: QA, to: REVIEW, profile: missing}]}",
+        "states: {buckets: [READY, DOING, QA, REVIEW, DONE, BLOCKED], transitions: [{from: QA, to: REVIEW, gates: [lint]}]}",
+    ] {
+        assert!(Config::from_yaml(yaml).is_err(), "{}", yaml);
+    }
+}
+
+#[test]
+fn test_parse_parallel_validation_steps() {
+    let yaml = r#"
+validation_profiles:
+  ci:
+    max_parallel: 2
+    steps:
+      - name: lint
+        command: cargo clippy
+        parallel_group: checks
+      - name: test
+        command: cargo test
+        parallel_group: checks
+      - name: docs
+        command: cargo doc
+        needs: [test]
+"#;
+    let config = Config::from_yaml(yaml).unwrap();
+    let profile = &config.validation_profiles["ci"];
+    assert_eq!(profile.parallelism(), 2);
+    assert_eq!(profile.steps[0].parallel_group.as_deref(), Some("checks"));
+    assert_eq!(profile.steps[2].needs, vec!["test"]);
+
+    for (yaml, expected) in [
+        (
+            "validation_profiles: {ci: {max_parallel: 0, steps: []}}",
+            "max_parallel",
+        ),
+        (
+            "validation_profiles: {ci: {steps: [{name: a, command: x, needs: [b]}]}}",
+            "unknown step 'b'",
+        ),
+        (
+            "validation_profiles: {ci: {steps: [{name: a, command: x, needs: [b]}, {name: b, command: y}]}}",
+            "cycle",
+        ),
+    ] {
+        let err = Config::from_yaml(yaml).unwrap_err();
+        assert!(err.to_string().contains(expected), "{}", yaml);
+    }
+}
+
+#[test]
+fn test_parse_validation_step_options() {
+    let yaml = r#"
+validation_profiles:
+  ci:
+    steps:
+      - name: web
+        command: npm test
+        cwd: web
+        timeout_seconds: 600
+        env:
+          CI: "1"
+        allow_failure: true
+"#;
+    let config = Config::from_yaml(yaml).unwrap();
+    let step = &config.validation_profiles["ci"].steps[0];
+    assert_eq!(step.cwd.as_deref(), Some("web"));
+    assert_eq!(step.timeout_seconds, Some(600));
+    assert_eq!(step.env["CI"], "1");
+    assert!(step.allow_failure);
+
+    for (yaml, expected) in [
+        (
+            "validation_profiles: {ci: {steps: [{name: a, command: x, timeout_seconds: 0}]}}",
+            "timeout_seconds must be greater than 0",
+        ),
+        (
+            "validation_profiles: {ci: {steps: [{name: a, command: x, cwd: ../other}]}}",
+            "must be a relative path inside the worktree",
+        ),
+        (
+            "validation_profiles: {ci: {steps: [{name: a, command: x, cwd: /tmp}]}}",
+            "must be a relative path inside the worktree",
+        ),
+        (
+            "validation_profiles: {ci: {steps: [{name: a, command: x, env: {'A=B': c}}]}}",
+            "not a valid variable name",
+        ),
+    ] {
+        let err = Config::from_yaml(yaml).unwrap_err();
+        assert!(err.to_string().contains(expected), "{}", yaml);
+    }
+}
+
+#[test]
+fn test_parse_hooks() {
+    let yaml = r#"
+hooks:
+  post_claim: npm ci
+  pre_submit: "./scripts/check.sh --quick"
+"#;
+    let config = Config::from_yaml(yaml).unwrap();
+
+    assert_eq!(config.hooks.command("post_claim"), Some("npm ci"));
+    assert_eq!(
+        config.hooks.command("pre_submit"),
+        Some("./scripts/check.sh --quick")
+    );
+    assert_eq!(config.hooks.command("pre_claim"), None);
+    assert_eq!(config.hooks.timeout().as_secs(), DEFAULT_HOOK_TIMEOUT_SECS);
+    assert!(!config.to_yaml().unwrap().contains("pre_claim"));
+    assert!(!Config::default().to_yaml().unwrap().contains("hooks"));
+
+    let config = Config::from_yaml("hooks: {timeout_seconds: 30}").unwrap();
+    assert_eq!(config.hooks.timeout().as_secs(), 30);
+    assert!(config.to_yaml().unwrap().contains("timeout_seconds: 30"));
+
+    for yaml in [
+        "hooks: {pre_claim: ''}",
+        "hooks: {post_approve: \"echo 'oops\"}",
+        "hooks: {timeout_seconds: 0}",
+    ] {
+        let err = Config::from_yaml(yaml).unwrap_err();
+        assert!(err.to_string().contains("hooks."), "{}", yaml);
+    }
+}
+
+#[test]
+fn test_parse_worktree_mode() {
+    let config = Config::default();
+    assert_eq!(config.worktree_mode, WorktreeMode::Full);
+    assert!(!config.to_yaml().unwrap().contains("sparse_always_include"));
+
+    let yaml = r#"
+worktree_mode: sparse
+sparse_always_include: ["/Cargo.toml", "/.cargo/"]
+"#;
+    let config = Config::from_yaml(yaml).unwrap();
+    assert_eq!(config.worktree_mode, WorktreeMode::Sparse);
+    assert_eq!(
+        config.sparse_always_include,
+        vec!["/Cargo.toml", "/.cargo/"]
+    );
+
+    assert!(Config::from_yaml("worktree_mode: shallow").is_err());
+}
+
+#[test]
+fn test_parse_worktree_setup() {
+    let yaml = r#"
+worktree_setup:
+  copy: [.env]
+  symlink: [node_modules]
+  commands: ["npm ci --offline"]
+  env:
+    CARGO_TARGET_DIR: "{repo_root}/target"
+  timeout_seconds: 300
+"#;
+    let config = Config::from_yaml(yaml).unwrap();
+    let setup = &config.worktree_setup;
+
+    assert_eq!(setup.copy, vec![".env"]);
+    assert_eq!(setup.symlink, vec!["node_modules"]);
+    assert_eq!(setup.commands, vec!["npm ci --offline"]);
+    assert!(setup.has_provisioning());
+    assert_eq!(setup.timeout().as_secs(), 300);
+    assert_eq!(
+        Config::default().worktree_setup.timeout().as_secs(),
+        DEFAULT_SETUP_TIMEOUT_SECS
+    );
+    assert_eq!(
+        setup.resolved_env(std::path::Path::new("/repo"))["CARGO_TARGET_DIR"],
+        "/repo/target"
+    );
+    assert!(
+        !Config::default()
+            .to_yaml()
+            .unwrap()
+            .contains("worktree_setup")
+    );
+
+    for yaml in [
+        "worktree_setup: {copy: [../secrets]}",
+        "worktree_setup: {symlink: [/etc/passwd]}",
+        "worktree_setup: {commands: ['']}",
+        "worktree_setup: {env: {'A=B': x}}",
+        "worktree_setup: {timeout_seconds: 0}",
+    ] {
+        let err = Config::from_yaml(yaml).unwrap_err();
+        assert!(err.to_string().contains("worktree_setup."), "{}", yaml);
+    }
+}
+
 #[test]
 fn test_validate_empty_stub_extension() {
     let yaml = r#"
@@ -316,6 +617,17 @@ fn test_conflict_policy_parsing() {
     assert_eq!(config.conflict_policy, ConflictPolicy::Ignore);
 }
 
+#[test]
+fn test_claim_tie_break_parsing() {
+    let config = Config::from_yaml("claim_tie_break: downstream").unwrap();
+    assert_eq!(config.claim_tie_break, ClaimTieBreak::Downstream);
+
+    let config = Config::from_yaml("claim_tie_break: id").unwrap();
+    assert_eq!(config.claim_tie_break, ClaimTieBreak::Id);
+
+    assert!(Config::from_yaml("claim_tie_break: random").is_err());
+}
+
 #[test]
 fn test_merge_strategy_from_str() {
     assert_eq!(
@@ -3,8 +3,10 @@
 //! This module defines enums, constants, and default value functions
 //! used by the Config struct.
 
+use crate::workflow::BUCKETS;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
+use std::path::Path;
 
 /// Merge strategy for task branches.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -71,6 +73,29 @@ pub enum ConflictDetectionMode {
     Hybrid,
 }
 
+/// How `burl claim` (without a task ID) orders READY tasks of equal priority.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ClaimTieBreak {
+    /// Lowest task number first (default).
+    #[default]
+    Id,
+    /// Task with the most open transitive dependents first, then lowest number.
+    Downstream,
+}
+
+/// How `burl claim` checks out new task worktrees.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum WorktreeMode {
+    /// Full checkout of the task branch (default).
+    #[default]
+    Full,
+    /// Sparse checkout limited to the task's `affects`/`affects_globs` plus
+    /// `sparse_always_include`.
+    Sparse,
+}
+
 /// Default stub patterns for detecting incomplete code.
 pub fn default_stub_patterns() -> Vec<String> {
     vec![
@@ -110,6 +135,12 @@ pub(crate) fn default_workflow_branch() -> String {
 pub(crate) fn default_workflow_worktree() -> String {
     ".burl".to_string()
 }
+pub(crate) fn default_id_prefixes() -> Vec<String> {
+    vec!["TASK".to_string()]
+}
+pub(crate) fn default_id_width() -> usize {
+    3
+}
 pub(crate) fn default_main_branch() -> String {
     "main".to_string()
 }
@@ -141,12 +172,31 @@ pub struct ValidationProfile {
     /// Ordered command steps to run.
     pub steps: Vec<ValidationCommandStep>,
 
+    /// Maximum number of steps running at once (default: available CPUs).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_parallel: Option<usize>,
+
     /// Unknown fields preserved for forward compatibility.
     #[serde(flatten)]
     pub extra: BTreeMap<String, serde_yaml::Value>,
 }
 
+impl ValidationProfile {
+    /// How many steps may run at once.
+    pub fn parallelism(&self) -> usize {
+        self.max_parallel.unwrap_or_else(|| {
+            std::thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(1)
+        })
+    }
+}
+
 /// A single command step in a validation profile.
+///
+/// By default a step starts once every earlier step has finished. Steps that
+/// share a `parallel_group` don't wait for each other, and a step with `needs`
+/// waits only for (and requires the success of) the named steps.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(default)]
 pub struct ValidationCommandStep {
@@ -164,7 +214,263 @@ pub struct ValidationCommandStep {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub run_if_changed_extensions: Vec<String>,
 
+    /// Run concurrently with the other steps of this group.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parallel_group: Option<String>,
+
+    /// Names of steps that must pass before this one runs.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub needs: Vec<String>,
+
+    /// Kill the step (and every process it started) after this many seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_seconds: Option<u64>,
+
+    /// Extra environment variables, layered over `worktree_setup.env`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+
+    /// Working directory, relative to the task worktree.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+
+    /// Report a failure as a warning instead of failing validation.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub allow_failure: bool,
+
     /// Unknown fields preserved for forward compatibility.
     #[serde(flatten)]
     pub extra: BTreeMap<String, serde_yaml::Value>,
 }
+
+/// Commands run before and after task transitions.
+///
+/// Each hook is a shell-words parsed command (no shell), like
+/// [`ValidationCommandStep::command`]. See [`crate::hooks`] for how hooks are
+/// invoked.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HooksConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pre_claim: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_claim: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pre_release: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_release: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pre_submit: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_submit: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pre_approve: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_approve: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pre_reject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_reject: Option<String>,
+
+    /// Kill a hook (and everything it started) after this many seconds
+    /// (default: [`DEFAULT_HOOK_TIMEOUT_SECS`]).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_seconds: Option<u64>,
+}
+
+/// Hook timeout used when `hooks.timeout_seconds` is not set.
+pub const DEFAULT_HOOK_TIMEOUT_SECS: u64 = 600;
+
+impl HooksConfig {
+    /// How long a hook may run before it is killed.
+    pub fn timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.timeout_seconds.unwrap_or(DEFAULT_HOOK_TIMEOUT_SECS))
+    }
+
+    /// Whether the block is unset (no hook and no timeout configured).
+    pub fn is_empty(&self) -> bool {
+        self.timeout_seconds.is_none()
+            && self.entries().iter().all(|(_, command)| command.is_none())
+    }
+
+    /// Every hook as a `(name, command)` pair.
+    pub fn entries(&self) -> [(&'static str, Option<&str>); 10] {
+        [
+            ("pre_claim", self.pre_claim.as_deref()),
+            ("post_claim", self.post_claim.as_deref()),
+            ("pre_release", self.pre_release.as_deref()),
+            ("post_release", self.post_release.as_deref()),
+            ("pre_submit", self.pre_submit.as_deref()),
+            ("post_submit", self.post_submit.as_deref()),
+            ("pre_approve", self.pre_approve.as_deref()),
+            ("post_approve", self.post_approve.as_deref()),
+            ("pre_reject", self.pre_reject.as_deref()),
+            ("post_reject", self.post_reject.as_deref()),
+        ]
+    }
+
+    /// The command configured for the hook `name`, if any.
+    pub fn command(&self, name: &str) -> Option<&str> {
+        self.entries()
+            .into_iter()
+            .find(|(hook, _)| *hook == name)
+            .and_then(|(_, command)| command)
+    }
+}
+
+/// Provisioning applied to freshly created task worktrees.
+///
+/// Task worktrees are plain checkouts: untracked files (like `.env`) and
+/// installed dependencies are missing. `burl claim` applies this block after
+/// creating a worktree; see [`crate::worktree_setup`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WorktreeSetupConfig {
+    /// Repo-relative paths copied from the main worktree.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub copy: Vec<String>,
+
+    /// Repo-relative paths symlinked to the main worktree.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub symlink: Vec<String>,
+
+    /// Commands run in the new worktree, in order (shell-words parsed; no shell).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub commands: Vec<String>,
+
+    /// Environment for setup commands, hooks, agents, and validation steps
+    /// (e.g. a shared `CARGO_TARGET_DIR`). `{repo_root}` in a value expands
+    /// to the main worktree path.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+
+    /// Kill a setup command (and everything it started) after this many
+    /// seconds (default: [`DEFAULT_SETUP_TIMEOUT_SECS`]).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_seconds: Option<u64>,
+}
+
+/// Setup command timeout used when `worktree_setup.timeout_seconds` is not set.
+pub const DEFAULT_SETUP_TIMEOUT_SECS: u64 = 1800;
+
+impl WorktreeSetupConfig {
+    /// Whether nothing is configured.
+    pub fn is_empty(&self) -> bool {
+        self.copy.is_empty()
+            && self.symlink.is_empty()
+            && self.commands.is_empty()
+            && self.env.is_empty()
+            && self.timeout_seconds.is_none()
+    }
+
+    /// How long a setup command may run before it is killed.
+    pub fn timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.timeout_seconds.unwrap_or(DEFAULT_SETUP_TIMEOUT_SECS))
+    }
+
+    /// Whether claim has anything to do in a new worktree.
+    pub fn has_provisioning(&self) -> bool {
+        !self.copy.is_empty() || !self.symlink.is_empty() || !self.commands.is_empty()
+    }
+
+    /// The configured environment with `{repo_root}` expanded.
+    pub fn resolved_env(&self, repo_root: &Path) -> BTreeMap<String, String> {
+        let root = repo_root.to_string_lossy();
+        self.env
+            .iter()
+            .map(|(key, value)| (key.clone(), value.replace("{repo_root}", &root)))
+            .collect()
+    }
+}
+
+/// A check run before `burl move` takes a transition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransitionGate {
+    /// Changed files must stay within the task's declared scope.
+    Scope,
+    /// Added lines must not match `stub_patterns`.
+    Stubs,
+    /// A human must confirm the move with `burl move --approve`.
+    Approval,
+}
+
+impl TransitionGate {
+    /// Name used in config, events, and messages.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Scope => "scope",
+            Self::Stubs => "stubs",
+            Self::Approval => "approval",
+        }
+    }
+}
+
+/// An edge `burl move` may take between two buckets.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StateTransition {
+    /// Source bucket.
+    pub from: String,
+
+    /// Destination bucket.
+    pub to: String,
+
+    /// Gates that must pass before the task moves.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub gates: Vec<TransitionGate>,
+
+    /// Validation profile to run in the task worktree before the task moves.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+}
+
+/// The workflow state machine: buckets and the extra transitions between them.
+///
+/// The built-in transitions (claim, submit, approve, reject, block, ...) keep
+/// their dedicated commands; `transitions` declares additional edges taken
+/// with `burl move`, such as `QA -> REVIEW`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatesConfig {
+    /// All buckets in display order; must include the built-in buckets.
+    pub buckets: Vec<String>,
+
+    /// Buckets holding tasks awaiting final review. `burl approve` only
+    /// accepts tasks from these; `burl validate` and `burl reject` accept them
+    /// in addition to QA.
+    pub approve_from: Vec<String>,
+
+    /// Allowed `burl move` edges.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub transitions: Vec<StateTransition>,
+}
+
+impl Default for StatesConfig {
+    fn default() -> Self {
+        Self {
+            buckets: BUCKETS.iter().map(|b| b.to_string()).collect(),
+            approve_from: vec!["QA".to_string()],
+            transitions: Vec::new(),
+        }
+    }
+}
+
+impl StatesConfig {
+    /// The declared `burl move` edge from `from` to `to`, if any.
+    pub fn transition(&self, from: &str, to: &str) -> Option<&StateTransition> {
+        self.transitions
+            .iter()
+            .find(|t| t.from == from && t.to == to)
+    }
+
+    /// Whether `burl approve` accepts tasks in `bucket`.
+    pub fn can_approve_from(&self, bucket: &str) -> bool {
+        self.approve_from.iter().any(|b| b == bucket)
+    }
+
+    /// Whether `burl validate`/`burl reject` accept tasks in `bucket`.
+    pub fn in_review(&self, bucket: &str) -> bool {
+        bucket == "QA" || self.can_approve_from(bucket)
+    }
+}
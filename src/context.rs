@@ -201,6 +201,11 @@ impl WorkflowContext {
         self.workflow_state_dir.join(bucket)
     }
 
+    /// Get the path to the archive of old DONE tasks (`ARCHIVE/YYYY-MM/`).
+    pub fn archive_dir(&self) -> PathBuf {
+        self.workflow_state_dir.join(crate::workflow::ARCHIVE_DIR)
+    }
+
     /// Get the path to the config file.
     pub fn config_path(&self) -> PathBuf {
         self.workflow_state_dir.join("config.yaml")
@@ -226,6 +231,16 @@ impl WorkflowContext {
         self.locks_dir.join("workflow.lock")
     }
 
+    /// Get the path to the task frontmatter cache (untracked, machine-local).
+    pub fn index_cache_path(&self) -> PathBuf {
+        self.locks_dir.join("index.cache.json")
+    }
+
+    /// Get the path to the validation result cache (untracked, machine-local).
+    pub fn validation_cache_path(&self) -> PathBuf {
+        self.locks_dir.join("validation.cache.json")
+    }
+
     /// Get the path to the claim lock file.
     pub fn claim_lock_path(&self) -> PathBuf {
         self.locks_dir.join("claim.lock")
@@ -243,6 +258,11 @@ impl WorkflowContext {
         self.workflow_state_dir.join("prompts")
     }
 
+    /// Get the path to the task templates directory (`burl add --template`).
+    pub fn task_templates_dir(&self) -> PathBuf {
+        self.workflow_state_dir.join("task-templates")
+    }
+
     /// Get the path to a specific prompt file.
     pub fn prompt_file_path(&self, task_id: &str) -> PathBuf {
         self.prompts_dir().join(format!("{}.md", task_id))
@@ -257,6 +277,16 @@ impl WorkflowContext {
     pub fn task_agent_logs_dir(&self, task_id: &str) -> PathBuf {
         self.agent_logs_dir().join(task_id)
     }
+
+    /// Get the path to the validation logs directory (untracked).
+    pub fn validation_logs_dir(&self) -> PathBuf {
+        self.workflow_state_dir.join("validation-logs")
+    }
+
+    /// Get the path to a specific task's validation log directory.
+    pub fn task_validation_logs_dir(&self, task_id: &str) -> PathBuf {
+        self.validation_logs_dir().join(task_id)
+    }
 }
 
 /// Convenience function to resolve context and ensure workflow is initialized.
@@ -0,0 +1,70 @@
+//! Parsing of human-friendly durations used by CLI flags.
+//!
+//! Durations are written as a non-negative integer followed by a unit:
+//! `s` (seconds), `m` (minutes), `h` (hours), `d` (days), or `w` (weeks),
+//! e.g. `90m`, `24h`, `30d`.
+
+use crate::error::{BurlError, Result};
+use chrono::Duration;
+
+/// Parse a duration such as `24h` or `30d`.
+pub fn parse_duration(value: &str) -> Result<Duration> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (amount, unit) = value.split_at(split);
+
+    let invalid = || {
+        BurlError::UserError(format!(
+            "invalid duration '{}': expected a number followed by s, m, h, d, or w (e.g. 24h, 30d)",
+            value
+        ))
+    };
+
+    let amount: i64 = amount.parse().map_err(|_| invalid())?;
+    let seconds_per_unit = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => return Err(invalid()),
+    };
+
+    amount
+        .checked_mul(seconds_per_unit)
+        .and_then(Duration::try_seconds)
+        .ok_or_else(invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration_units() {
+        assert_eq!(parse_duration("45s").unwrap(), Duration::seconds(45));
+        assert_eq!(parse_duration("90m").unwrap(), Duration::minutes(90));
+        assert_eq!(parse_duration("24h").unwrap(), Duration::hours(24));
+        assert_eq!(parse_duration("30d").unwrap(), Duration::days(30));
+        assert_eq!(parse_duration("2w").unwrap(), Duration::weeks(2));
+        assert_eq!(parse_duration(" 1h ").unwrap(), Duration::hours(1));
+    }
+
+    #[test]
+    fn test_parse_duration_rejects_invalid() {
+        for value in [
+            "",
+            "h",
+            "24",
+            "24x",
+            "-1h",
+            "1.5h",
+            "1 h",
+            "99999999999999999w",
+        ] {
+            assert!(parse_duration(value).is_err(), "{value} should be invalid");
+        }
+    }
+}
@@ -44,6 +44,17 @@ impl BurlError {
             BurlError::LockError(_) => exit_codes::LOCK_FAILURE,
         }
     }
+
+    /// Returns a stable, machine-readable category for this error type.
+    pub fn category(&self) -> &'static str {
+        match self {
+            BurlError::NotImplemented(_) => "not_implemented",
+            BurlError::UserError(_) => "user_error",
+            BurlError::ValidationError(_) => "validation_error",
+            BurlError::GitError(_) => "git_error",
+            BurlError::LockError(_) => "lock_error",
+        }
+    }
 }
 
 /// Result type alias for burl operations.
@@ -83,6 +94,27 @@ mod tests {
         assert_eq!(err.exit_code(), exit_codes::LOCK_FAILURE);
     }
 
+    #[test]
+    fn error_categories_are_stable() {
+        assert_eq!(
+            BurlError::NotImplemented("x".to_string()).category(),
+            "not_implemented"
+        );
+        assert_eq!(
+            BurlError::UserError("x".to_string()).category(),
+            "user_error"
+        );
+        assert_eq!(
+            BurlError::ValidationError("x".to_string()).category(),
+            "validation_error"
+        );
+        assert_eq!(BurlError::GitError("x".to_string()).category(), "git_error");
+        assert_eq!(
+            BurlError::LockError("x".to_string()).category(),
+            "lock_error"
+        );
+    }
+
     #[test]
     fn error_messages_are_descriptive() {
         let err = BurlError::NotImplemented("burl init".to_string());
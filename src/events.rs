@@ -47,8 +47,12 @@ pub enum EventAction {
     Init,
     /// Task added to READY
     Add,
+    /// Task frontmatter edited
+    Edit,
     /// Task claimed (READY -> DOING)
     Claim,
+    /// Task released (DOING -> READY)
+    Release,
     /// Task submitted (DOING -> QA)
     Submit,
     /// Task validation run
@@ -57,10 +61,28 @@ pub enum EventAction {
     Approve,
     /// Task rejected (QA -> READY)
     Reject,
+    /// Epic completed because all of its children reached DONE
+    EpicComplete,
+    /// Task blocked (READY/DOING/QA -> BLOCKED)
+    Block,
+    /// Task unblocked (BLOCKED -> READY/DOING)
+    Unblock,
+    /// Task moved along a configured `states.transitions` edge
+    Move,
+    /// Task split into child tasks (superseded original moved to DONE)
+    Split,
+    /// A `post_*` lifecycle hook ran
+    Hook,
+    /// A newly claimed task's worktree was provisioned from `worktree_setup`
+    WorktreeSetup,
+    /// Paths added to a task's sparse worktree (`burl worktree widen`)
+    WorktreeWiden,
     /// Lock cleared manually
     LockClear,
     /// Cleanup operation
     Clean,
+    /// Old DONE tasks moved into the archive
+    Archive,
     /// Agent dispatch started
     AgentDispatch,
     /// Agent execution completed
@@ -72,13 +94,24 @@ impl std::fmt::Display for EventAction {
         match self {
             EventAction::Init => write!(f, "init"),
             EventAction::Add => write!(f, "add"),
+            EventAction::Edit => write!(f, "edit"),
             EventAction::Claim => write!(f, "claim"),
+            EventAction::Release => write!(f, "release"),
             EventAction::Submit => write!(f, "submit"),
             EventAction::Validate => write!(f, "validate"),
             EventAction::Approve => write!(f, "approve"),
             EventAction::Reject => write!(f, "reject"),
+            EventAction::EpicComplete => write!(f, "epic_complete"),
+            EventAction::Block => write!(f, "block"),
+            EventAction::Unblock => write!(f, "unblock"),
+            EventAction::Move => write!(f, "move"),
+            EventAction::Split => write!(f, "split"),
+            EventAction::Hook => write!(f, "hook"),
+            EventAction::WorktreeSetup => write!(f, "worktree_setup"),
+            EventAction::WorktreeWiden => write!(f, "worktree_widen"),
             EventAction::LockClear => write!(f, "lock_clear"),
             EventAction::Clean => write!(f, "clean"),
+            EventAction::Archive => write!(f, "archive"),
             EventAction::AgentDispatch => write!(f, "agent_dispatch"),
             EventAction::AgentComplete => write!(f, "agent_complete"),
         }
@@ -144,8 +177,9 @@ impl Event {
     }
 }
 
-/// Get the actor string for event metadata.
-fn get_actor_string() -> String {
+/// The current user's actor string (`user@host`), recorded in events, task
+/// metadata, and History and QA Report entries.
+pub(crate) fn get_actor_string() -> String {
     let user = std::env::var("USER")
         .or_else(|_| std::env::var("USERNAME"))
         .unwrap_or_else(|_| "unknown".to_string());
@@ -234,6 +268,30 @@ pub fn append_event(ctx: &WorkflowContext, event: &Event) -> Result<()> {
     Ok(())
 }
 
+/// Read all events from the events log, oldest first.
+///
+/// A missing log yields no events; malformed lines are skipped.
+pub fn read_events(ctx: &WorkflowContext) -> Result<Vec<Event>> {
+    let events_file = events_file_path(ctx);
+    if !events_file.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = fs::read_to_string(&events_file).map_err(|e| {
+        BurlError::UserError(format!(
+            "failed to read events file '{}': {}",
+            events_file.display(),
+            e
+        ))
+    })?;
+
+    Ok(content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -404,6 +462,24 @@ mod tests {
         assert_eq!(parsed2.task, Some("TASK-001".to_string()));
     }
 
+    #[test]
+    fn test_read_events_skips_malformed_lines() {
+        let (_temp_dir, ctx) = create_test_workflow();
+        assert!(read_events(&ctx).unwrap().is_empty());
+
+        append_event(&ctx, &Event::new(EventAction::Init)).unwrap();
+        let mut file = OpenOptions::new()
+            .append(true)
+            .open(events_file_path(&ctx))
+            .unwrap();
+        writeln!(file, "not json").unwrap();
+        append_event(&ctx, &Event::new(EventAction::Add).with_task("TASK-001")).unwrap();
+
+        let events = read_events(&ctx).unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[1].task.as_deref(), Some("TASK-001"));
+    }
+
     #[test]
     fn test_append_event_trailing_newline() {
         let (_temp_dir, ctx) = create_test_workflow();
@@ -470,13 +546,24 @@ mod tests {
     fn test_event_action_display() {
         assert_eq!(format!("{}", EventAction::Init), "init");
         assert_eq!(format!("{}", EventAction::Add), "add");
+        assert_eq!(format!("{}", EventAction::Edit), "edit");
         assert_eq!(format!("{}", EventAction::Claim), "claim");
+        assert_eq!(format!("{}", EventAction::Release), "release");
         assert_eq!(format!("{}", EventAction::Submit), "submit");
         assert_eq!(format!("{}", EventAction::Validate), "validate");
         assert_eq!(format!("{}", EventAction::Approve), "approve");
         assert_eq!(format!("{}", EventAction::Reject), "reject");
+        assert_eq!(format!("{}", EventAction::EpicComplete), "epic_complete");
+        assert_eq!(format!("{}", EventAction::Block), "block");
+        assert_eq!(format!("{}", EventAction::Unblock), "unblock");
+        assert_eq!(format!("{}", EventAction::Move), "move");
+        assert_eq!(format!("{}", EventAction::Split), "split");
+        assert_eq!(format!("{}", EventAction::Hook), "hook");
+        assert_eq!(format!("{}", EventAction::WorktreeSetup), "worktree_setup");
+        assert_eq!(format!("{}", EventAction::WorktreeWiden), "worktree_widen");
         assert_eq!(format!("{}", EventAction::LockClear), "lock_clear");
         assert_eq!(format!("{}", EventAction::Clean), "clean");
+        assert_eq!(format!("{}", EventAction::Archive), "archive");
         assert_eq!(format!("{}", EventAction::AgentDispatch), "agent_dispatch");
         assert_eq!(format!("{}", EventAction::AgentComplete), "agent_complete");
     }
@@ -6,7 +6,7 @@
 //! - Fetching from remote
 //! - Determining base_sha (origin/main HEAD at claim time)
 //! - Creating/reusing task branches
-//! - Creating/attaching task worktrees
+//! - Creating/attaching task worktrees (optionally sparse)
 //! - Removing worktrees and deleting branches
 //!
 //! All git failures are mapped to exit code 3 (BurlError::GitError).
@@ -15,6 +15,7 @@ mod branch;
 mod cleanup;
 mod naming;
 mod remote;
+mod sparse;
 mod verification;
 mod worktree;
 
@@ -23,6 +24,10 @@ pub use branch::{branch_exists, create_branch, delete_branch};
 pub use cleanup::{cleanup_task_worktree, remove_worktree};
 pub use naming::{task_branch_name, task_worktree_path};
 pub use remote::{fetch_main, get_base_sha};
+pub use sparse::{
+    create_sparse_worktree, is_sparse_worktree, sparse_checkout_patterns, sparse_patterns,
+    widen_sparse_checkout,
+};
 pub use verification::{get_current_branch, verify_worktree_branch};
 pub use worktree::{
     ExistingWorktree, WorktreeInfo, create_worktree, find_worktree_for_branch, list_worktrees,
@@ -5,29 +5,31 @@ use std::path::PathBuf;
 
 /// Generate the conventional branch name for a task.
 ///
-/// Format: `task-{numeric_id}-{slug}`
-/// Example: `task-001-player-jump`
+/// Format: `{prefix}-{numeric_id}-{slug}`, lowercased
+/// Example: `task-001-player-jump`, `bug-042-login-crash`
 ///
 /// # Arguments
 ///
-/// * `task_id` - The task ID (e.g., "TASK-001" or "001")
+/// * `task_id` - The task ID (e.g., "TASK-001", "BUG-042", or "001")
 /// * `slug` - Optional slug for the task (derived from title if not provided)
 pub fn task_branch_name(task_id: &str, slug: Option<&str>) -> String {
-    // Extract numeric part from task ID (e.g., "TASK-001" -> "001", "001" -> "001")
-    let numeric = task_id
-        .strip_prefix("TASK-")
-        .unwrap_or(task_id)
-        .to_lowercase();
+    // Lowercase the full ID (e.g., "BUG-042" -> "bug-042"); a bare number
+    // keeps the default prefix ("001" -> "task-001").
+    let id = if task_id.chars().all(|c| c.is_ascii_digit()) {
+        format!("task-{}", task_id)
+    } else {
+        sanitize_slug(task_id)
+    };
 
     match slug {
-        Some(s) if !s.is_empty() => format!("task-{}-{}", numeric, sanitize_slug(s)),
-        _ => format!("task-{}", numeric),
+        Some(s) if !s.is_empty() => format!("{}-{}", id, sanitize_slug(s)),
+        _ => id,
     }
 }
 
 /// Generate the conventional worktree path for a task.
 ///
-/// Format: `.worktrees/task-{numeric_id}-{slug}/`
+/// Format: `.worktrees/{prefix}-{numeric_id}-{slug}/`
 /// Example: `.worktrees/task-001-player-jump/`
 ///
 /// # Arguments
@@ -83,6 +85,11 @@ mod tests {
             "task-001-player-jump"
         );
         assert_eq!(task_branch_name("001", Some("feature")), "task-001-feature");
+        assert_eq!(
+            task_branch_name("BUG-042", Some("Login crash")),
+            "bug-042-login-crash"
+        );
+        assert_eq!(task_branch_name("NET2-007", None), "net2-007");
     }
 
     #[test]
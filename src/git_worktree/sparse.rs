@@ -0,0 +1,180 @@
+//! Sparse-checkout task worktrees.
+//!
+//! Sparse worktrees use git's non-cone sparse-checkout, so patterns follow
+//! gitignore syntax. The sparse-checkout file is per worktree; the main
+//! worktree and other task worktrees are unaffected.
+
+use crate::error::{BurlError, Result};
+use crate::git::run_git;
+use std::path::Path;
+
+use super::cleanup::remove_worktree;
+
+/// Build sparse-checkout patterns from a task's scope.
+///
+/// `affects` paths and `affects_globs` are anchored at the repository root
+/// (`src/player/` -> `/src/player/`). `always_include` entries are used as
+/// given, except that unanchored ones are anchored the same way; negated
+/// patterns (`!/*/`) are kept verbatim.
+pub fn sparse_checkout_patterns(
+    affects: &[String],
+    affects_globs: &[String],
+    always_include: &[String],
+) -> Vec<String> {
+    let mut patterns: Vec<String> = Vec::new();
+    for entry in always_include.iter().chain(affects).chain(affects_globs) {
+        let pattern = normalize_pattern(entry);
+        if !pattern.is_empty() && !patterns.contains(&pattern) {
+            patterns.push(pattern);
+        }
+    }
+    patterns
+}
+
+/// Anchor a scope path or glob at the repository root.
+fn normalize_pattern(entry: &str) -> String {
+    let entry = entry.trim().replace('\\', "/");
+    if entry.is_empty() || entry.starts_with('!') || entry.starts_with('/') {
+        return entry;
+    }
+    let entry = entry.trim_start_matches("./");
+    if entry.is_empty() {
+        return String::new();
+    }
+    format!("/{}", entry)
+}
+
+/// Create a worktree for `branch` that only checks out `patterns`.
+///
+/// The worktree is added without a checkout, sparse-checkout is configured,
+/// and only then are the matching files checked out. If either step fails,
+/// the worktree is removed again.
+pub fn create_sparse_worktree<P: AsRef<Path>>(
+    repo_root: P,
+    worktree_path: &Path,
+    branch: &str,
+    patterns: &[String],
+) -> Result<()> {
+    let repo_root = repo_root.as_ref();
+    let worktree_str = worktree_path.to_string_lossy();
+
+    if let Some(parent) = worktree_path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| {
+            BurlError::GitError(format!(
+                "failed to create worktrees directory '{}': {}",
+                parent.display(),
+                e
+            ))
+        })?;
+    }
+
+    run_git(
+        repo_root,
+        &["worktree", "add", "--no-checkout", &worktree_str, branch],
+    )
+    .map_err(|e| {
+        BurlError::GitError(format!(
+            "failed to create worktree at '{}' for branch '{}': {}",
+            worktree_str, branch, e
+        ))
+    })?;
+
+    let mut args = vec!["sparse-checkout", "set", "--no-cone"];
+    args.extend(patterns.iter().map(String::as_str));
+    if let Err(e) =
+        run_git(worktree_path, &args).and_then(|_| run_git(worktree_path, &["checkout"]))
+    {
+        let _ = remove_worktree(repo_root, worktree_path, true);
+        return Err(BurlError::GitError(format!(
+            "failed to configure sparse checkout in '{}': {}",
+            worktree_str, e
+        )));
+    }
+
+    Ok(())
+}
+
+/// Whether the worktree at `worktree_path` uses sparse-checkout.
+pub fn is_sparse_worktree(worktree_path: &Path) -> bool {
+    run_git(worktree_path, &["config", "--get", "core.sparseCheckout"])
+        .is_ok_and(|output| output.stdout.trim() == "true")
+}
+
+/// The sparse-checkout patterns of a worktree.
+pub fn sparse_patterns(worktree_path: &Path) -> Result<Vec<String>> {
+    let output = run_git(worktree_path, &["sparse-checkout", "list"])?;
+    Ok(output.stdout.lines().map(str::to_string).collect())
+}
+
+/// Add patterns to a sparse worktree and check out the newly matching files.
+pub fn widen_sparse_checkout(worktree_path: &Path, patterns: &[String]) -> Result<()> {
+    let mut args = vec!["sparse-checkout", "add"];
+    args.extend(patterns.iter().map(String::as_str));
+    run_git(worktree_path, &args).map_err(|e| {
+        BurlError::GitError(format!(
+            "failed to widen sparse checkout in '{}': {}",
+            worktree_path.display(),
+            e
+        ))
+    })?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::create_test_repo;
+
+    #[test]
+    fn test_sparse_checkout_patterns() {
+        let patterns = sparse_checkout_patterns(
+            &["src/player/".to_string(), "./Cargo.toml".to_string()],
+            &["tests/**/*.rs".to_string(), "src/player/".to_string()],
+            &["/*".to_string(), "!/*/".to_string()],
+        );
+        assert_eq!(
+            patterns,
+            vec![
+                "/*",
+                "!/*/",
+                "/src/player/",
+                "/Cargo.toml",
+                "/tests/**/*.rs"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_create_and_widen_sparse_worktree() {
+        let temp_dir = create_test_repo();
+        let path = temp_dir.path();
+        for file in ["src/a/lib.rs", "src/b/lib.rs", "docs/guide.md"] {
+            let file = path.join(file);
+            std::fs::create_dir_all(file.parent().unwrap()).unwrap();
+            std::fs::write(file, "content\n").unwrap();
+        }
+        run_git(path, &["add", "."]).unwrap();
+        run_git(path, &["commit", "-m", "Add files"]).unwrap();
+        run_git(path, &["branch", "sparse-test"]).unwrap();
+
+        let worktree = path.join("sparse-worktree");
+        create_sparse_worktree(path, &worktree, "sparse-test", &["/src/a/".to_string()]).unwrap();
+
+        assert!(is_sparse_worktree(&worktree));
+        assert!(!is_sparse_worktree(path));
+        assert!(worktree.join("src/a/lib.rs").exists());
+        assert!(!worktree.join("src/b/lib.rs").exists());
+        assert!(!worktree.join("docs").exists());
+
+        widen_sparse_checkout(&worktree, &["/docs/".to_string()]).unwrap();
+        assert!(worktree.join("docs/guide.md").exists());
+        assert_eq!(
+            sparse_patterns(&worktree).unwrap(),
+            vec!["/src/a/", "/docs/"]
+        );
+
+        // The checkout is clean: files outside the patterns aren't deletions.
+        let status = run_git(&worktree, &["status", "--porcelain"]).unwrap();
+        assert!(status.stdout.is_empty());
+    }
+}
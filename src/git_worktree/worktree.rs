@@ -8,6 +8,7 @@ use std::path::{Path, PathBuf};
 use super::branch::{branch_exists, create_branch};
 use super::naming::{task_branch_name, task_worktree_path};
 use super::remote::{fetch_main, get_base_sha};
+use super::sparse::create_sparse_worktree;
 
 /// Result of creating or attaching to a task worktree.
 #[derive(Debug, Clone)]
@@ -154,11 +155,14 @@ pub fn create_worktree<P: AsRef<Path>>(
 /// * `main_branch` - Name of the main branch (from config)
 /// * `existing_branch` - Optional existing branch name from task metadata (for reuse)
 /// * `existing_worktree` - Optional existing worktree path from task metadata (for reuse)
+/// * `sparse` - Sparse-checkout patterns for a newly created worktree (`None`
+///   for a full checkout); ignored when an existing worktree is reused
 ///
 /// # Returns
 ///
 /// * `Ok(WorktreeInfo)` - Information about the created/reused worktree
 /// * `Err(BurlError::GitError)` - Git operation failed (exit code 3)
+#[allow(clippy::too_many_arguments)]
 pub fn setup_task_worktree(
     ctx: &WorkflowContext,
     task_id: &str,
@@ -167,6 +171,7 @@ pub fn setup_task_worktree(
     main_branch: &str,
     existing_branch: Option<&str>,
     existing_worktree: Option<&str>,
+    sparse: Option<&[String]>,
 ) -> Result<WorktreeInfo> {
     // Step 1: Fetch main to ensure we have latest state
     fetch_main(&ctx.repo_root, remote, main_branch)?;
@@ -241,7 +246,12 @@ pub fn setup_task_worktree(
     }
 
     // Step 8: Create worktree
-    create_worktree(&ctx.repo_root, &worktree_path, &branch_name)?;
+    match sparse {
+        Some(patterns) => {
+            create_sparse_worktree(&ctx.repo_root, &worktree_path, &branch_name, patterns)?
+        }
+        None => create_worktree(&ctx.repo_root, &worktree_path, &branch_name)?,
+    }
 
     Ok(WorktreeInfo {
         path: worktree_path,
@@ -381,6 +391,7 @@ mod tests {
             "main",
             None,
             None,
+            None,
         )
         .unwrap();
 
@@ -420,6 +431,7 @@ mod tests {
             "main",
             None,
             None,
+            None,
         )
         .unwrap();
         assert!(!info1.reused);
@@ -433,6 +445,7 @@ mod tests {
             "main",
             Some(&info1.branch),
             Some(&info1.path.to_string_lossy()),
+            None,
         )
         .unwrap();
         assert!(info2.reused);
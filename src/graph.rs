@@ -0,0 +1,569 @@
+//! Task dependency graph built from `depends_on`.
+//!
+//! Edges point from a task to the tasks it depends on. Nodes also record their
+//! `parent` epic, so the graph answers hierarchy questions (children, rollup)
+//! as well. The graph is used to
+//! reject dependency cycles and dangling references when tasks are created or
+//! edited, to report them in `burl doctor`, to render `burl graph`, and to
+//! analyze remaining work for `burl plan` (critical path, downstream counts,
+//! and claimable tasks).
+
+use crate::context::WorkflowContext;
+use crate::error::Result;
+use crate::workflow::{TaskIndex, TaskInfo, TaskKey, task_key};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+/// A task in the dependency graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphNode {
+    /// Task ID (e.g., "TASK-001").
+    pub id: String,
+    /// Task title.
+    pub title: String,
+    /// Bucket the task is in.
+    pub bucket: String,
+    /// Priority level.
+    pub priority: String,
+    /// IDs of the tasks this task depends on.
+    pub depends_on: Vec<String>,
+    /// Epic this task belongs to.
+    pub parent: Option<String>,
+}
+
+/// Progress of an epic's direct children.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EpicRollup {
+    /// Number of direct children.
+    pub total: usize,
+    /// Number of direct children in DONE.
+    pub done: usize,
+    /// Child count per bucket.
+    pub by_bucket: BTreeMap<String, usize>,
+}
+
+impl EpicRollup {
+    /// Whether every child is in DONE.
+    pub fn is_complete(&self) -> bool {
+        self.total > 0 && self.done == self.total
+    }
+
+    /// Short summary, e.g. `2/5 done (DOING 1, READY 2)`.
+    pub fn summary(&self) -> String {
+        let open: Vec<String> = self
+            .by_bucket
+            .iter()
+            .filter(|(bucket, _)| bucket.as_str() != "DONE")
+            .map(|(bucket, count)| format!("{} {}", bucket, count))
+            .collect();
+        if open.is_empty() {
+            format!("{}/{} done", self.done, self.total)
+        } else {
+            format!("{}/{} done ({})", self.done, self.total, open.join(", "))
+        }
+    }
+}
+
+/// Dependency graph over all tasks in the workflow.
+#[derive(Debug, Default)]
+pub struct DependencyGraph {
+    /// Nodes keyed by ID prefix and number, so iteration follows ID order.
+    nodes: BTreeMap<TaskKey, GraphNode>,
+    /// Task ID to node key.
+    ids: HashMap<String, TaskKey>,
+}
+
+impl DependencyGraph {
+    /// Build the graph by loading every task in the index.
+    ///
+    /// Archived tasks are added only when another node references them (as a
+    /// dependency or parent), so the archive is not scanned unless needed.
+    /// Unreadable task files are skipped (doctor reports them separately).
+    pub fn build(index: &TaskIndex) -> Result<Self> {
+        let mut graph = Self::default();
+        for info in index.all_tasks() {
+            graph.insert_task(index, info);
+        }
+
+        let mut pending: Vec<String> = graph.references().collect();
+        let mut seen = HashSet::new();
+        while let Some(id) = pending.pop() {
+            if graph.get(&id).is_some() || !seen.insert(id.clone()) {
+                continue;
+            }
+            if let Some(info) = index.find(&id)
+                && let Some(node) = graph.insert_task(index, info)
+            {
+                pending.extend(node.depends_on.iter().chain(&node.parent).cloned());
+            }
+        }
+        Ok(graph)
+    }
+
+    /// Load a task's frontmatter and insert it as a node.
+    fn insert_task(&mut self, index: &TaskIndex, info: &TaskInfo) -> Option<&GraphNode> {
+        let frontmatter = index.frontmatter(info).ok()?;
+        self.insert(GraphNode {
+            id: info.id.clone(),
+            title: frontmatter.title,
+            bucket: info.bucket.clone(),
+            priority: frontmatter.priority,
+            depends_on: frontmatter.depends_on,
+            parent: frontmatter.parent,
+        });
+        self.get(&info.id)
+    }
+
+    /// IDs referenced by any node as a dependency or parent.
+    fn references(&self) -> impl Iterator<Item = String> + '_ {
+        self.nodes()
+            .flat_map(|node| node.depends_on.iter().chain(&node.parent))
+            .cloned()
+    }
+
+    /// Build the graph for the workflow in `ctx`.
+    pub fn load(ctx: &WorkflowContext) -> Result<Self> {
+        Self::build(&TaskIndex::build(ctx)?)
+    }
+
+    /// Insert or replace a node.
+    pub fn insert(&mut self, node: GraphNode) {
+        let key = task_key(&node.id);
+        if let Some(old) = self.ids.insert(node.id.clone(), key.clone())
+            && old != key
+        {
+            self.nodes.remove(&old);
+        }
+        self.nodes.insert(key, node);
+    }
+
+    /// Look up a node by task ID.
+    pub fn get(&self, id: &str) -> Option<&GraphNode> {
+        self.ids.get(id).and_then(|n| self.nodes.get(n))
+    }
+
+    /// Iterate over all nodes in ID order (prefix, then number).
+    pub fn nodes(&self) -> impl Iterator<Item = &GraphNode> {
+        self.nodes.values()
+    }
+
+    /// All `(task, missing dependency)` pairs where the dependency does not exist.
+    pub fn dangling(&self) -> Vec<(String, String)> {
+        self.nodes()
+            .flat_map(|node| {
+                node.depends_on
+                    .iter()
+                    .filter(|dep| self.get(dep).is_none())
+                    .map(|dep| (node.id.clone(), dep.clone()))
+            })
+            .collect()
+    }
+
+    /// Shortest dependency path from `from` to `to`, following `depends_on`
+    /// edges. Returns the IDs along the path, including both ends.
+    pub fn find_path(&self, from: &str, to: &str) -> Option<Vec<String>> {
+        let mut parents: HashMap<&str, &str> = HashMap::new();
+        let mut queue = VecDeque::from([from]);
+        let mut seen = HashSet::from([from]);
+
+        while let Some(current) = queue.pop_front() {
+            if current == to {
+                let mut path = vec![to.to_string()];
+                let mut at = to;
+                while let Some(parent) = parents.get(at) {
+                    path.push(parent.to_string());
+                    at = parent;
+                }
+                path.reverse();
+                return Some(path);
+            }
+            let Some(node) = self.get(current) else {
+                continue;
+            };
+            for dep in &node.depends_on {
+                if seen.insert(dep.as_str()) {
+                    parents.insert(dep.as_str(), current);
+                    queue.push_back(dep.as_str());
+                }
+            }
+        }
+
+        None
+    }
+
+    /// The cycle that adding `task -> dep` would close, if any.
+    ///
+    /// Returned as a closed path starting and ending at `task`.
+    pub fn cycle_if_added(&self, task: &str, dep: &str) -> Option<Vec<String>> {
+        if task == dep {
+            return Some(vec![task.to_string(), task.to_string()]);
+        }
+        let mut path = self.find_path(dep, task)?;
+        path.insert(0, task.to_string());
+        Some(path)
+    }
+
+    /// Find dependency cycles.
+    ///
+    /// Each cycle is reported once, as a closed path starting and ending at
+    /// its lowest-numbered task.
+    pub fn find_cycles(&self) -> Vec<Vec<String>> {
+        let mut cycles: Vec<Vec<String>> = Vec::new();
+        let mut seen_members: HashSet<Vec<String>> = HashSet::new();
+
+        for node in self.nodes() {
+            for dep in &node.depends_on {
+                let Some(mut path) = self.find_path(dep, &node.id) else {
+                    continue;
+                };
+                path.insert(0, node.id.clone());
+                let cycle = self.rotate_to_lowest(path);
+
+                let mut members = cycle[..cycle.len() - 1].to_vec();
+                members.sort();
+                if seen_members.insert(members) {
+                    cycles.push(cycle);
+                }
+            }
+        }
+
+        cycles
+    }
+
+    /// Direct children of an epic, in ID order.
+    pub fn children(&self, id: &str) -> Vec<&GraphNode> {
+        self.nodes()
+            .filter(|node| node.parent.as_deref() == Some(id))
+            .collect()
+    }
+
+    /// Whether a task has children (is an epic).
+    pub fn is_epic(&self, id: &str) -> bool {
+        self.nodes().any(|node| node.parent.as_deref() == Some(id))
+    }
+
+    /// Rollup of an epic's direct children, or `None` if it has none.
+    pub fn rollup(&self, id: &str) -> Option<EpicRollup> {
+        let children = self.children(id);
+        if children.is_empty() {
+            return None;
+        }
+        let mut rollup = EpicRollup::default();
+        for child in children {
+            rollup.total += 1;
+            if child.bucket == "DONE" {
+                rollup.done += 1;
+            }
+            *rollup.by_bucket.entry(child.bucket.clone()).or_default() += 1;
+        }
+        Some(rollup)
+    }
+
+    /// The parent chain that setting `task`'s parent to `parent` would turn
+    /// into a loop, if any.
+    ///
+    /// Returned as a closed path starting and ending at `task`.
+    pub fn parent_cycle_if_set(&self, task: &str, parent: &str) -> Option<Vec<String>> {
+        let mut path = vec![task.to_string()];
+        let mut current = Some(parent.to_string());
+        while let Some(id) = current {
+            path.push(id.clone());
+            if id == task {
+                return Some(path);
+            }
+            if path.len() > self.nodes.len() + 1 {
+                return None;
+            }
+            current = self.get(&id).and_then(|node| node.parent.clone());
+        }
+        None
+    }
+
+    /// Whether a task has not been completed yet.
+    fn is_open(node: &GraphNode) -> bool {
+        node.bucket != "DONE"
+    }
+
+    /// Number of open tasks that transitively depend on each task.
+    ///
+    /// Counts distinct dependents (a task reachable along several paths is
+    /// counted once) and ignores tasks already in DONE.
+    pub fn downstream_counts(&self) -> HashMap<String, usize> {
+        let mut dependents: HashMap<&str, Vec<&str>> = HashMap::new();
+        for node in self.nodes() {
+            for dep in &node.depends_on {
+                dependents.entry(dep.as_str()).or_default().push(&node.id);
+            }
+        }
+
+        let mut counts = HashMap::new();
+        for node in self.nodes() {
+            let mut seen: HashSet<&str> = HashSet::from([node.id.as_str()]);
+            let mut stack: Vec<&str> = vec![&node.id];
+            let mut count = 0;
+            while let Some(current) = stack.pop() {
+                for dependent in dependents.get(current).into_iter().flatten() {
+                    if !seen.insert(dependent) {
+                        continue;
+                    }
+                    if self.get(dependent).is_some_and(Self::is_open) {
+                        count += 1;
+                    }
+                    stack.push(dependent);
+                }
+            }
+            counts.insert(node.id.clone(), count);
+        }
+        counts
+    }
+
+    /// Longest chain of open tasks linked by `depends_on`, in execution order
+    /// (first prerequisite first).
+    ///
+    /// Completed and missing dependencies do not extend a chain. Ties are
+    /// broken towards lower task numbers. Edges that would close a cycle are
+    /// ignored.
+    pub fn critical_path(&self) -> Vec<String> {
+        let mut memo: HashMap<&str, Vec<String>> = HashMap::new();
+        let mut best: Vec<String> = Vec::new();
+        for node in self.nodes().filter(|n| Self::is_open(n)) {
+            let chain = self.longest_chain(node, &mut memo, &mut HashSet::new());
+            if chain.len() > best.len() {
+                best = chain;
+            }
+        }
+        best.reverse();
+        best
+    }
+
+    /// Longest chain of open tasks starting at `node` and following its
+    /// dependencies (dependent first).
+    fn longest_chain<'a>(
+        &'a self,
+        node: &'a GraphNode,
+        memo: &mut HashMap<&'a str, Vec<String>>,
+        visiting: &mut HashSet<&'a str>,
+    ) -> Vec<String> {
+        if let Some(chain) = memo.get(node.id.as_str()) {
+            return chain.clone();
+        }
+        visiting.insert(&node.id);
+
+        let mut deps: Vec<&GraphNode> = node
+            .depends_on
+            .iter()
+            .filter_map(|dep| self.get(dep))
+            .filter(|dep| Self::is_open(dep) && !visiting.contains(dep.id.as_str()))
+            .collect();
+        deps.sort_by_key(|dep| task_key(&dep.id));
+
+        let mut longest: Vec<String> = Vec::new();
+        for dep in deps {
+            let chain = self.longest_chain(dep, memo, visiting);
+            if chain.len() > longest.len() {
+                longest = chain;
+            }
+        }
+        longest.insert(0, node.id.clone());
+
+        visiting.remove(node.id.as_str());
+        memo.insert(&node.id, longest.clone());
+        longest
+    }
+
+    /// READY tasks whose dependencies are all in DONE, in ID order.
+    ///
+    /// Epics are containers, not units of work, so they are never claimable.
+    pub fn claimable(&self) -> Vec<&GraphNode> {
+        self.nodes()
+            .filter(|node| node.bucket == "READY" && !self.is_epic(&node.id))
+            .filter(|node| {
+                node.depends_on
+                    .iter()
+                    .all(|dep| self.get(dep).is_some_and(|d| !Self::is_open(d)))
+            })
+            .collect()
+    }
+
+    /// Rotate a closed path so it starts (and ends) at its lowest-numbered task.
+    fn rotate_to_lowest(&self, mut cycle: Vec<String>) -> Vec<String> {
+        cycle.pop();
+        let start = cycle
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, id)| task_key(id))
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+        cycle.rotate_left(start);
+        cycle.push(cycle[0].clone());
+        cycle
+    }
+}
+
+/// Format a dependency path for messages (`TASK-001 -> TASK-002 -> TASK-001`).
+pub fn format_path(path: &[String]) -> String {
+    path.join(" -> ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(number: u32, deps: &[u32]) -> GraphNode {
+        GraphNode {
+            id: format!("TASK-{:03}", number),
+            title: format!("Task {}", number),
+            bucket: "READY".to_string(),
+            priority: "medium".to_string(),
+            depends_on: deps.iter().map(|d| format!("TASK-{:03}", d)).collect(),
+            parent: None,
+        }
+    }
+
+    fn child(number: u32, parent: u32, bucket: &str) -> GraphNode {
+        GraphNode {
+            bucket: bucket.to_string(),
+            parent: Some(format!("TASK-{:03}", parent)),
+            ..node(number, &[])
+        }
+    }
+
+    fn done(number: u32, deps: &[u32]) -> GraphNode {
+        GraphNode {
+            bucket: "DONE".to_string(),
+            ..node(number, deps)
+        }
+    }
+
+    fn graph(nodes: Vec<GraphNode>) -> DependencyGraph {
+        let mut graph = DependencyGraph::default();
+        for n in nodes {
+            graph.insert(n);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_dangling_and_paths() {
+        let g = graph(vec![node(1, &[]), node(2, &[1]), node(3, &[2, 9])]);
+
+        assert_eq!(
+            g.dangling(),
+            vec![("TASK-003".to_string(), "TASK-009".to_string())]
+        );
+        assert_eq!(
+            g.find_path("TASK-003", "TASK-001").unwrap(),
+            vec!["TASK-003", "TASK-002", "TASK-001"]
+        );
+        assert!(g.find_path("TASK-001", "TASK-003").is_none());
+        assert!(g.find_cycles().is_empty());
+    }
+
+    #[test]
+    fn test_cycle_if_added() {
+        let g = graph(vec![node(1, &[]), node(2, &[1]), node(3, &[2])]);
+
+        assert_eq!(
+            g.cycle_if_added("TASK-001", "TASK-003").unwrap(),
+            vec!["TASK-001", "TASK-003", "TASK-002", "TASK-001"]
+        );
+        assert_eq!(
+            g.cycle_if_added("TASK-002", "TASK-002").unwrap(),
+            vec!["TASK-002", "TASK-002"]
+        );
+        assert!(g.cycle_if_added("TASK-003", "TASK-001").is_none());
+    }
+
+    #[test]
+    fn test_find_cycles_reports_each_cycle_once() {
+        let g = graph(vec![
+            node(1, &[3]),
+            node(2, &[1]),
+            node(3, &[2]),
+            node(4, &[4]),
+            node(5, &[1]),
+        ]);
+
+        let cycles = g.find_cycles();
+        assert_eq!(cycles.len(), 2);
+        assert_eq!(
+            cycles[0],
+            vec!["TASK-001", "TASK-003", "TASK-002", "TASK-001"]
+        );
+        assert_eq!(cycles[1], vec!["TASK-004", "TASK-004"]);
+        assert_eq!(format_path(&cycles[1]), "TASK-004 -> TASK-004".to_string());
+    }
+
+    #[test]
+    fn test_downstream_counts_skip_done_and_count_once() {
+        // 1 <- 2 <- 4, 1 <- 3 <- 4, 4 <- 5 (done)
+        let g = graph(vec![
+            node(1, &[]),
+            node(2, &[1]),
+            node(3, &[1]),
+            node(4, &[2, 3]),
+            done(5, &[4]),
+        ]);
+
+        let counts = g.downstream_counts();
+        assert_eq!(counts["TASK-001"], 3);
+        assert_eq!(counts["TASK-002"], 1);
+        assert_eq!(counts["TASK-004"], 0);
+        assert_eq!(counts["TASK-005"], 0);
+    }
+
+    #[test]
+    fn test_critical_path_and_claimable() {
+        let g = graph(vec![
+            done(1, &[]),
+            node(2, &[1]),
+            node(3, &[2]),
+            node(4, &[3, 9]),
+            node(5, &[1]),
+            node(6, &[]),
+        ]);
+
+        assert_eq!(g.critical_path(), vec!["TASK-002", "TASK-003", "TASK-004"]);
+        let claimable: Vec<&str> = g.claimable().iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(claimable, vec!["TASK-002", "TASK-005", "TASK-006"]);
+
+        let cyclic = graph(vec![node(1, &[2]), node(2, &[1])]);
+        assert_eq!(cyclic.critical_path().len(), 2);
+        assert!(cyclic.claimable().is_empty());
+    }
+
+    #[test]
+    fn test_epic_children_and_rollup() {
+        let g = graph(vec![
+            node(1, &[]),
+            child(2, 1, "DONE"),
+            child(3, 1, "DOING"),
+            child(4, 1, "READY"),
+            child(5, 3, "READY"),
+        ]);
+
+        assert!(g.is_epic("TASK-001"));
+        assert!(!g.is_epic("TASK-004"));
+        let children: Vec<&str> = g
+            .children("TASK-001")
+            .iter()
+            .map(|n| n.id.as_str())
+            .collect();
+        assert_eq!(children, vec!["TASK-002", "TASK-003", "TASK-004"]);
+
+        let rollup = g.rollup("TASK-001").unwrap();
+        assert_eq!((rollup.done, rollup.total), (1, 3));
+        assert!(!rollup.is_complete());
+        assert_eq!(rollup.summary(), "1/3 done (DOING 1, READY 1)");
+        assert!(g.rollup("TASK-004").is_none());
+
+        // Epics are never claimable; their READY children are.
+        let claimable: Vec<&str> = g.claimable().iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(claimable, vec!["TASK-004", "TASK-005"]);
+
+        assert_eq!(
+            g.parent_cycle_if_set("TASK-001", "TASK-005").unwrap(),
+            vec!["TASK-001", "TASK-005", "TASK-003", "TASK-001"]
+        );
+        assert!(g.parent_cycle_if_set("TASK-004", "TASK-003").is_none());
+    }
+}
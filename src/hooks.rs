@@ -0,0 +1,448 @@
+//! Lifecycle hooks run around task transitions.
+//!
+//! `hooks:` in config.yaml maps hook names (`pre_claim`, `post_claim`, ...,
+//! `post_reject`) to commands. A hook command is shell-words parsed and run
+//! without a shell, in the task worktree when it exists (otherwise the
+//! repository root). It receives:
+//!
+//! - the task as JSON on stdin: every frontmatter field plus `bucket` and
+//!   `path`, as in `burl list --format json`
+//! - `BURL_HOOK`, `BURL_TASK_ID`, `BURL_WORKTREE`, and `BURL_BRANCH` in its
+//!   environment (empty when the task has no worktree/branch yet), along with
+//!   `worktree_setup.env`
+//!
+//! A `pre_*` hook runs before any workflow state changes; if it fails, the
+//! transition is aborted. A `post_*` hook runs once the transition is
+//! committed and `workflow.lock` released, so a slow hook (e.g. a dependency
+//! install after claim) doesn't hold up other commands. Its outcome is
+//! recorded as a `hook` event in a separate workflow commit, and a failure
+//! only prints a warning.
+//!
+//! A hook that runs longer than `hooks.timeout_seconds` (default 10 minutes)
+//! is killed along with every process it started, and counts as failed.
+
+use crate::config::Config;
+use crate::context::WorkflowContext;
+use crate::error::{BurlError, Result};
+use crate::events::{Event, EventAction, append_event};
+use crate::git::run_git;
+use crate::locks::wait_for_workflow_lock;
+use crate::task::TaskFrontmatter;
+use crate::validate::pipeline::run_with_timeout;
+use serde_json::{Map, Value, json};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Maximum number of output characters kept in hook errors and events.
+const HOOK_OUTPUT_MAX_CHARS: usize = 2000;
+
+/// The task a hook runs for.
+pub struct HookTask<'a> {
+    pub id: &'a str,
+    /// Bucket the task is in when the hook runs.
+    pub bucket: &'a str,
+    /// Task file path.
+    pub path: &'a Path,
+    pub frontmatter: &'a TaskFrontmatter,
+}
+
+impl<'a> HookTask<'a> {
+    pub fn new(
+        id: &'a str,
+        bucket: &'a str,
+        path: &'a Path,
+        frontmatter: &'a TaskFrontmatter,
+    ) -> Self {
+        Self {
+            id,
+            bucket,
+            path,
+            frontmatter,
+        }
+    }
+}
+
+/// Outcome of a hook command that ran.
+#[derive(Debug)]
+struct HookOutcome {
+    success: bool,
+    exit_code: Option<i32>,
+    /// Whether the hook was killed for exceeding `hooks.timeout_seconds`.
+    timed_out: bool,
+    /// Combined stdout/stderr (truncated), or the reason the command could
+    /// not be started.
+    output: String,
+}
+
+/// Run the `pre_<transition>` hook, if configured.
+///
+/// Returns an error (aborting the transition) if the hook fails.
+pub fn run_pre_hook(
+    ctx: &WorkflowContext,
+    config: &Config,
+    transition: &str,
+    task: &HookTask,
+) -> Result<()> {
+    let name = format!("pre_{}", transition);
+    let Some(command) = config.hooks.command(&name) else {
+        return Ok(());
+    };
+
+    let outcome = run_hook(ctx, config, &name, command, task);
+    if outcome.success {
+        return Ok(());
+    }
+
+    let status = outcome.status(config);
+    let mut msg = format!(
+        "{} hook {}; {} of {} aborted.\n\nCommand: {}\n",
+        name, status, transition, task.id, command
+    );
+    if !outcome.output.is_empty() {
+        msg.push_str(&format!("\nOutput:\n{}\n", outcome.output));
+    }
+    msg.push_str(&format!(
+        "\nFix the hook or the task, or remove `hooks.{}` from config.yaml.",
+        name
+    ));
+    Err(BurlError::UserError(msg))
+}
+
+/// Run the `post_<transition>` hook, if configured, and log a `hook` event.
+///
+/// Call this after the transition is committed and `workflow.lock` is
+/// released. Hook failures, and failures to record the event, are reported
+/// as warnings: the transition itself has already happened.
+pub fn run_post_hook(ctx: &WorkflowContext, config: &Config, transition: &str, task: &HookTask) {
+    let name = format!("post_{}", transition);
+    let Some(command) = config.hooks.command(&name) else {
+        return;
+    };
+
+    let outcome = run_hook(ctx, config, &name, command, task);
+    if !outcome.success {
+        eprintln!(
+            "Warning: {} hook {} for {}:\n{}",
+            name,
+            outcome.status(config),
+            task.id,
+            outcome.output
+        );
+    }
+
+    let event = Event::new(EventAction::Hook)
+        .with_task(task.id)
+        .with_details(json!({
+            "hook": name,
+            "command": command,
+            "success": outcome.success,
+            "exit_code": outcome.exit_code,
+            "timed_out": outcome.timed_out,
+            "output": outcome.output,
+        }));
+    if let Err(e) = record_hook_event(ctx, config, &name, task.id, &event) {
+        eprintln!(
+            "Warning: failed to record {} hook event for {}: {}",
+            name, task.id, e
+        );
+    }
+}
+
+/// Append a post hook's event and commit it on its own, re-taking
+/// `workflow.lock` for just that.
+fn record_hook_event(
+    ctx: &WorkflowContext,
+    config: &Config,
+    name: &str,
+    task_id: &str,
+    event: &Event,
+) -> Result<()> {
+    let _workflow_lock = wait_for_workflow_lock(ctx, "hook")?;
+    ctx.ensure_workflow_clean()?;
+    append_event(ctx, event)?;
+
+    if config.workflow_auto_commit {
+        run_git(&ctx.workflow_worktree, &["add", "."])
+            .map_err(|e| BurlError::GitError(format!("failed to stage hook event: {}", e)))?;
+        run_git(
+            &ctx.workflow_worktree,
+            &[
+                "commit",
+                "-m",
+                &format!("Record {} hook for {}", name, task_id),
+            ],
+        )
+        .map_err(|e| BurlError::GitError(format!("failed to commit hook event: {}", e)))?;
+
+        if config.workflow_auto_push {
+            run_git(
+                &ctx.workflow_worktree,
+                &["push", &config.remote, &config.workflow_branch],
+            )
+            .map_err(|e| BurlError::GitError(format!("failed to push workflow branch: {}", e)))?;
+        }
+    }
+
+    Ok(())
+}
+
+impl HookOutcome {
+    /// How a failed hook ended, e.g. "exited with code 3".
+    fn status(&self, config: &Config) -> String {
+        match self.exit_code {
+            _ if self.timed_out => format!(
+                "timed out after {}s (process group killed)",
+                config.hooks.timeout().as_secs()
+            ),
+            Some(code) => format!("exited with code {}", code),
+            None => "failed".to_string(),
+        }
+    }
+}
+
+/// Run one hook command and capture its outcome.
+fn run_hook(
+    ctx: &WorkflowContext,
+    config: &Config,
+    name: &str,
+    command: &str,
+    task: &HookTask,
+) -> HookOutcome {
+    let failed = |output: String| HookOutcome {
+        success: false,
+        exit_code: None,
+        timed_out: false,
+        output,
+    };
+
+    let args = match shell_words::split(command) {
+        Ok(args) if !args.is_empty() => args,
+        Ok(_) => return failed("command is empty".to_string()),
+        Err(e) => return failed(format!("failed to parse command: {}", e)),
+    };
+
+    let worktree = task
+        .frontmatter
+        .worktree
+        .as_deref()
+        .map(|w| resolve_path(ctx, w));
+    let cwd = worktree
+        .as_ref()
+        .filter(|w| w.is_dir())
+        .unwrap_or(&ctx.repo_root);
+
+    let stdin = match serde_json::to_string(&task_json(ctx, task)) {
+        Ok(json) => json,
+        Err(e) => return failed(format!("failed to serialize task: {}", e)),
+    };
+
+    let mut cmd = Command::new(&args[0]);
+    cmd.args(&args[1..])
+        .current_dir(cwd)
+        .envs(config.worktree_setup.resolved_env(&ctx.repo_root))
+        .env("BURL_HOOK", name)
+        .env("BURL_TASK_ID", task.id)
+        .env(
+            "BURL_WORKTREE",
+            worktree
+                .as_deref()
+                .map(|w| w.to_string_lossy().to_string())
+                .unwrap_or_default(),
+        )
+        .env(
+            "BURL_BRANCH",
+            task.frontmatter.branch.as_deref().unwrap_or_default(),
+        )
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let output = match run_with_timeout(&mut cmd, Some(stdin), Some(config.hooks.timeout())) {
+        Ok(output) => output,
+        Err(e) => {
+            return failed(format!(
+                "failed to execute command: {}\nFix: ensure the command is installed and in PATH.",
+                e
+            ));
+        }
+    };
+
+    let mut combined = String::from_utf8_lossy(&output.stdout).to_string();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if !stderr.is_empty() {
+        if !combined.is_empty() && !combined.ends_with('\n') {
+            combined.push('\n');
+        }
+        combined.push_str(&stderr);
+    }
+
+    HookOutcome {
+        success: output.status.is_some_and(|s| s.success()),
+        exit_code: output.status.and_then(|s| s.code()),
+        timed_out: output.status.is_none(),
+        output: truncate_tail(combined.trim_end(), HOOK_OUTPUT_MAX_CHARS),
+    }
+}
+
+/// The task as passed to hooks on stdin.
+fn task_json(ctx: &WorkflowContext, task: &HookTask) -> Value {
+    let mut record = match serde_json::to_value(task.frontmatter) {
+        Ok(Value::Object(map)) => map,
+        _ => Map::new(),
+    };
+    record.insert("bucket".to_string(), Value::String(task.bucket.to_string()));
+    let path = task.path.strip_prefix(&ctx.repo_root).unwrap_or(task.path);
+    record.insert(
+        "path".to_string(),
+        Value::String(path.to_string_lossy().replace('\\', "/")),
+    );
+    Value::Object(record)
+}
+
+/// Resolve a recorded worktree path against the repository root.
+fn resolve_path(ctx: &WorkflowContext, path: &str) -> PathBuf {
+    let path = PathBuf::from(path);
+    if path.is_absolute() {
+        path
+    } else {
+        ctx.repo_root.join(path)
+    }
+}
+
+/// Keep the last `max_chars` characters of `output` (errors usually come last).
+pub(crate) fn truncate_tail(output: &str, max_chars: usize) -> String {
+    let count = output.chars().count();
+    if count <= max_chars {
+        return output.to_string();
+    }
+    let tail: String = output.chars().skip(count - max_chars).collect();
+    format!("...\n{}", tail)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::init::cmd_init;
+    use crate::context::require_initialized_workflow;
+    use crate::events::read_events;
+    use crate::test_support::{DirGuard, create_test_repo};
+    use serial_test::serial;
+    use std::time::{Duration, Instant};
+
+    fn frontmatter() -> TaskFrontmatter {
+        TaskFrontmatter {
+            id: "TASK-001".to_string(),
+            title: "Hooked".to_string(),
+            branch: Some("task-001-hooked".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_hooks_receive_task_and_environment() {
+        let temp_dir = create_test_repo();
+        let _guard = DirGuard::new(temp_dir.path());
+        cmd_init().unwrap();
+        let ctx = require_initialized_workflow().unwrap();
+
+        let out = temp_dir.path().join("hook.out");
+        let mut config = Config::default();
+        config.hooks.pre_claim = Some(format!(
+            "sh -c 'cat > {0}; echo \" $BURL_HOOK $BURL_TASK_ID $BURL_BRANCH\" >> {0}'",
+            out.display()
+        ));
+        config.hooks.post_claim = Some("sh -c 'echo install failed >&2; exit 3'".to_string());
+
+        let fm = frontmatter();
+        let path = ctx.bucket_path("READY").join("TASK-001-hooked.md");
+        let task = HookTask::new("TASK-001", "READY", &path, &fm);
+
+        run_pre_hook(&ctx, &config, "claim", &task).unwrap();
+        let written = std::fs::read_to_string(&out).unwrap();
+        let (json, env) = written.split_once(" pre_claim").unwrap();
+        let json: Value = serde_json::from_str(json).unwrap();
+        assert_eq!(json["id"], "TASK-001");
+        assert_eq!(json["bucket"], "READY");
+        assert_eq!(json["path"], ".burl/.workflow/READY/TASK-001-hooked.md");
+        assert_eq!(env.trim(), "TASK-001 task-001-hooked");
+
+        // A failing post hook is non-fatal, and logged in its own commit.
+        run_post_hook(&ctx, &config, "claim", &task);
+        let event = read_events(&ctx).unwrap().pop().unwrap();
+        assert_eq!(event.action, EventAction::Hook);
+        assert_eq!(event.details["hook"], "post_claim");
+        assert_eq!(event.details["success"], false);
+        assert_eq!(event.details["exit_code"], 3);
+        assert_eq!(event.details["timed_out"], false);
+        assert_eq!(event.details["output"], "install failed");
+        ctx.ensure_workflow_clean().unwrap();
+        let subject = run_git(&ctx.workflow_worktree, &["log", "-1", "--format=%s"]).unwrap();
+        assert_eq!(subject.stdout, "Record post_claim hook for TASK-001");
+
+        // Unconfigured hooks do nothing.
+        run_pre_hook(&ctx, &config, "submit", &task).unwrap();
+        run_post_hook(&ctx, &config, "submit", &task);
+        assert_eq!(read_events(&ctx).unwrap().len(), 2);
+    }
+
+    #[test]
+    #[serial]
+    fn test_hook_timeout_kills_process_group() {
+        let temp_dir = create_test_repo();
+        let _guard = DirGuard::new(temp_dir.path());
+        cmd_init().unwrap();
+        let ctx = require_initialized_workflow().unwrap();
+
+        // The backgrounded sleep would keep the output pipes open (and the
+        // hook running) if only the direct child were killed.
+        let mut config = Config::default();
+        config.hooks.timeout_seconds = Some(1);
+        config.hooks.pre_claim = Some("sh -c 'sleep 30 & sleep 30'".to_string());
+        config.hooks.post_claim = config.hooks.pre_claim.clone();
+
+        let fm = frontmatter();
+        let path = ctx.bucket_path("READY").join("TASK-001-hooked.md");
+        let task = HookTask::new("TASK-001", "READY", &path, &fm);
+
+        let start = Instant::now();
+        let err = run_pre_hook(&ctx, &config, "claim", &task).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("pre_claim hook timed out after 1s")
+        );
+
+        run_post_hook(&ctx, &config, "claim", &task);
+        let event = read_events(&ctx).unwrap().pop().unwrap();
+        assert_eq!(event.details["success"], false);
+        assert_eq!(event.details["timed_out"], true);
+        assert!(start.elapsed() < Duration::from_secs(20));
+    }
+
+    #[test]
+    #[serial]
+    fn test_failing_pre_hook_returns_error() {
+        let temp_dir = create_test_repo();
+        let _guard = DirGuard::new(temp_dir.path());
+        cmd_init().unwrap();
+        let ctx = require_initialized_workflow().unwrap();
+
+        let mut config = Config::default();
+        config.hooks.pre_submit = Some("sh -c 'echo not ready; exit 1'".to_string());
+
+        let fm = frontmatter();
+        let path = ctx.bucket_path("DOING").join("TASK-001-hooked.md");
+        let task = HookTask::new("TASK-001", "DOING", &path, &fm);
+        let err = run_pre_hook(&ctx, &config, "submit", &task).unwrap_err();
+        assert_eq!(err.exit_code(), crate::exit_codes::USER_ERROR);
+        let msg = err.to_string();
+        assert!(msg.contains("pre_submit hook exited with code 1"));
+        assert!(msg.contains("submit of TASK-001 aborted"));
+        assert!(msg.contains("not ready"));
+    }
+
+    #[test]
+    fn test_truncate_tail_keeps_end() {
+        assert_eq!(truncate_tail("abc", 5), "abc");
+        assert_eq!(truncate_tail("abcdef", 2), "...\nef");
+    }
+}
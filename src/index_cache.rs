@@ -0,0 +1,239 @@
+//! On-disk cache of parsed task frontmatter.
+//!
+//! Large workflows spend most of their time re-reading and re-parsing task
+//! files. `TaskIndex` keeps the parsed frontmatter of every task it loads in
+//! `.burl/.workflow/locks/index.cache.json` (untracked, machine-local), keyed
+//! by the file path relative to `.workflow/`. An entry is reused only while
+//! the file's modification time (in nanoseconds) and size are unchanged, so
+//! any edit, move, or `git checkout` of a task file invalidates just that
+//! entry.
+//!
+//! A missing, corrupt, or outdated cache file is treated as empty. The cache
+//! is only written when a command calls `TaskIndex::save_cache`.
+
+use crate::error::{BurlError, Result};
+use crate::fs::atomic_write_file;
+use crate::task::{TaskFile, TaskFrontmatter};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const CACHE_VERSION: u32 = 1;
+
+/// Files modified more recently than this are not cached: another write with
+/// the same size inside the filesystem's timestamp granularity would go
+/// unnoticed.
+const RACY_WINDOW: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheFile {
+    version: u32,
+    entries: HashMap<String, CacheEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    modified_ns: u64,
+    size: u64,
+    frontmatter: TaskFrontmatter,
+}
+
+/// Frontmatter cache for the task files under one workflow state directory.
+#[derive(Debug)]
+pub struct TaskCache {
+    /// Cache file location.
+    path: PathBuf,
+    /// Directory that entry keys are relative to (`.workflow/`).
+    root: PathBuf,
+    entries: HashMap<String, CacheEntry>,
+    dirty: bool,
+}
+
+impl TaskCache {
+    /// Load the cache file, starting empty if it is missing or unreadable.
+    pub fn load(path: PathBuf, root: PathBuf) -> Self {
+        let entries = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<CacheFile>(&content).ok())
+            .filter(|file| file.version == CACHE_VERSION)
+            .map(|file| file.entries)
+            .unwrap_or_default();
+        Self {
+            path,
+            root,
+            entries,
+            dirty: false,
+        }
+    }
+
+    /// Frontmatter of the task file at `path`, parsing it only if the cached
+    /// entry is missing or stale.
+    pub fn frontmatter(&mut self, path: &Path) -> Result<TaskFrontmatter> {
+        let key = self.key(path);
+        let stamp = file_stamp(path);
+
+        if let (Some(key), Some((modified_ns, size))) = (&key, stamp)
+            && let Some(entry) = self.entries.get(key)
+            && entry.modified_ns == modified_ns
+            && entry.size == size
+        {
+            return Ok(entry.frontmatter.clone());
+        }
+
+        let frontmatter = TaskFile::load(path)?.frontmatter;
+
+        if let Some(key) = key {
+            match stamp {
+                Some((modified_ns, size)) if is_settled(modified_ns) => {
+                    self.entries.insert(
+                        key,
+                        CacheEntry {
+                            modified_ns,
+                            size,
+                            frontmatter: frontmatter.clone(),
+                        },
+                    );
+                    self.dirty = true;
+                }
+                _ => {
+                    if self.entries.remove(&key).is_some() {
+                        self.dirty = true;
+                    }
+                }
+            }
+        }
+
+        Ok(frontmatter)
+    }
+
+    /// Drop entries for files that no longer exist according to `keep`,
+    /// which receives each entry's path.
+    pub fn retain(&mut self, keep: impl Fn(&Path) -> bool) {
+        let before = self.entries.len();
+        let root = &self.root;
+        self.entries.retain(|key, _| keep(&root.join(key)));
+        if self.entries.len() != before {
+            self.dirty = true;
+        }
+    }
+
+    /// Write the cache back to disk if it changed.
+    pub fn save(&mut self) -> Result<()> {
+        if !self.dirty {
+            return Ok(());
+        }
+        let file = CacheFile {
+            version: CACHE_VERSION,
+            entries: std::mem::take(&mut self.entries),
+        };
+        let json = serde_json::to_string(&file);
+        self.entries = file.entries;
+        let json = json
+            .map_err(|e| BurlError::UserError(format!("failed to serialize index cache: {}", e)))?;
+        atomic_write_file(&self.path, &json)?;
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Cache key for `path`: its path relative to the root, with `/`.
+    fn key(&self, path: &Path) -> Option<String> {
+        let relative = path.strip_prefix(&self.root).ok()?;
+        let parts: Option<Vec<&str>> = relative.iter().map(|part| part.to_str()).collect();
+        Some(parts?.join("/"))
+    }
+}
+
+/// Modification time (ns since the epoch) and size of a file.
+fn file_stamp(path: &Path) -> Option<(u64, u64)> {
+    let metadata = std::fs::metadata(path).ok()?;
+    let modified = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+    Some((u64::try_from(modified.as_nanos()).ok()?, metadata.len()))
+}
+
+/// Whether a file last modified at `modified_ns` is outside the racy window.
+fn is_settled(modified_ns: u64) -> bool {
+    let Ok(now) = SystemTime::now().duration_since(UNIX_EPOCH) else {
+        return false;
+    };
+    now.saturating_sub(Duration::from_nanos(modified_ns)) >= RACY_WINDOW
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use tempfile::TempDir;
+
+    fn write_task(path: &Path, title: &str, age: Duration) {
+        std::fs::write(
+            path,
+            format!(
+                "---\nid: TASK-001\ntitle: {}\nestimate: 3\n---\n\n## Objective\n",
+                title
+            ),
+        )
+        .unwrap();
+        File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(SystemTime::now() - age)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_cache_reuses_and_invalidates_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        let cache_path = root.join("locks").join("index.cache.json");
+        std::fs::create_dir_all(root.join("READY")).unwrap();
+        let task = root.join("READY").join("TASK-001-a.md");
+        write_task(&task, "First", Duration::from_secs(60));
+
+        let mut cache = TaskCache::load(cache_path.clone(), root.clone());
+        assert_eq!(cache.frontmatter(&task).unwrap().title, "First");
+        cache.save().unwrap();
+
+        // Reloaded from disk, including unknown fields.
+        let mut cache = TaskCache::load(cache_path.clone(), root.clone());
+        let entry = &cache.entries["READY/TASK-001-a.md"];
+        assert_eq!(entry.frontmatter.title, "First");
+        assert_eq!(
+            entry.frontmatter.extra["estimate"],
+            serde_yaml::Value::from(3)
+        );
+        assert!(!cache.dirty);
+
+        // A cached entry wins while the stamp is unchanged...
+        cache
+            .entries
+            .get_mut("READY/TASK-001-a.md")
+            .unwrap()
+            .frontmatter
+            .title = "Cached".to_string();
+        assert_eq!(cache.frontmatter(&task).unwrap().title, "Cached");
+
+        // ...and is replaced once the file changes.
+        write_task(&task, "Second", Duration::from_secs(30));
+        assert_eq!(cache.frontmatter(&task).unwrap().title, "Second");
+
+        cache.retain(|path| path != task);
+        assert!(cache.entries.is_empty());
+
+        // Freshly written files are parsed but not cached.
+        write_task(&task, "Third", Duration::ZERO);
+        assert_eq!(cache.frontmatter(&task).unwrap().title, "Third");
+        assert!(cache.entries.is_empty());
+    }
+
+    #[test]
+    fn test_cache_ignores_corrupt_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache_path = temp_dir.path().join("index.cache.json");
+
+        std::fs::write(&cache_path, "not json").unwrap();
+        let cache = TaskCache::load(cache_path, temp_dir.path().to_path_buf());
+        assert!(cache.entries.is_empty());
+    }
+}
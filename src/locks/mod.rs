@@ -38,5 +38,6 @@ pub use guard::LockGuard;
 pub use metadata::LockMetadata;
 pub use operations::{
     acquire_claim_lock, acquire_task_lock, acquire_workflow_lock, clear_lock, list_locks,
+    wait_for_workflow_lock,
 };
 pub use types::{LockInfo, LockType};
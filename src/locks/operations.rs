@@ -9,6 +9,10 @@ use crate::error::{BurlError, Result};
 use std::fs::{self, OpenOptions};
 use std::io::Write;
 use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// How long [`wait_for_workflow_lock`] waits for another command to finish.
+const WORKFLOW_LOCK_WAIT: Duration = Duration::from_secs(30);
 
 /// Acquire a lock file using create_new semantics.
 ///
@@ -102,6 +106,23 @@ pub fn acquire_workflow_lock(ctx: &WorkflowContext, action: &str) -> Result<Lock
     acquire_lock(&ctx.workflow_lock_path(), &metadata)
 }
 
+/// Acquire the global workflow lock, retrying while another command holds it.
+///
+/// For short follow-up writes (e.g. recording a post hook's outcome) made
+/// after a command has already released the lock; gives up with the
+/// `LockError` after [`WORKFLOW_LOCK_WAIT`].
+pub fn wait_for_workflow_lock(ctx: &WorkflowContext, action: &str) -> Result<LockGuard> {
+    let start = Instant::now();
+    loop {
+        match acquire_workflow_lock(ctx, action) {
+            Err(BurlError::LockError(_)) if start.elapsed() < WORKFLOW_LOCK_WAIT => {
+                std::thread::sleep(Duration::from_millis(100));
+            }
+            result => return result,
+        }
+    }
+}
+
 /// Acquire a per-task lock.
 ///
 /// This lock must be held when transitioning a specific task.
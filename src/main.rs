@@ -10,27 +10,54 @@ mod commands;
 pub mod config;
 pub mod context;
 pub mod diff;
+pub mod duration;
 pub mod error;
 pub mod events;
 pub mod exit_codes;
 pub mod fs;
 pub mod git;
 pub mod git_worktree;
+pub mod graph;
+pub mod hooks;
+pub mod index_cache;
 pub mod locks;
+pub mod output;
+pub mod query;
 pub mod task;
 pub mod task_git;
 #[cfg(test)]
 mod test_support;
 pub mod validate;
+pub mod validation_logs;
 pub mod workflow;
+pub mod worktree_setup;
 
 use cli::Cli;
 use std::process::ExitCode;
 
 fn main() -> ExitCode {
     let cli = Cli::parse_args();
+    output::set_format(cli.output);
 
-    match commands::dispatch(cli.command) {
+    let command_name = cli.command.name();
+    let result = if output::is_json() && !cli.command.supports_json_output() {
+        Err(error::BurlError::UserError(format!(
+            "`--output json` is not supported by `burl {}`.",
+            command_name
+        )))
+    } else {
+        commands::dispatch(cli.command)
+    };
+
+    // In JSON mode, stdout carries exactly one result object
+    if output::is_json() {
+        println!(
+            "{}",
+            output::render_final(command_name, result.as_ref().map(|_| ()))
+        );
+    }
+
+    match result {
         Ok(()) => ExitCode::from(exit_codes::SUCCESS as u8),
         Err(err) => {
             // Print user-actionable error message to stderr
@@ -0,0 +1,279 @@
+//! Machine-readable command results for `--output json`.
+//!
+//! In JSON mode, lifecycle commands record a [`CommandResult`] while they run
+//! and `main` prints exactly one JSON object to stdout when the command
+//! finishes (successfully or not). Human-readable text written through
+//! [`textln!`] is redirected to stderr so stdout stays parseable.
+//!
+//! The result schema is versioned by [`SCHEMA_VERSION`]. Every key is always
+//! present (absent values are `null` or empty arrays) so consumers can rely on
+//! a fixed shape; incompatible changes bump the version.
+
+use crate::cli::OutputFormat;
+use crate::error::BurlError;
+use crate::validate::{ScopeViolation, StubViolation, ValidationStepResult};
+use serde::Serialize;
+use serde_json::{Value, json};
+use std::fmt;
+use std::sync::{Mutex, OnceLock};
+
+/// Version of the JSON result schema.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Output format selected for this process (set once from `main`).
+static FORMAT: OnceLock<OutputFormat> = OnceLock::new();
+
+/// Result recorded by the running command (JSON mode only).
+static RESULT: Mutex<Option<CommandResult>> = Mutex::new(None);
+
+/// Print a line of human-readable output.
+///
+/// Behaves like `println!` in text mode; in JSON mode the line goes to stderr
+/// so that stdout carries only the JSON result.
+macro_rules! textln {
+    () => {
+        $crate::output::write_text_line(format_args!(""))
+    };
+    ($($arg:tt)*) => {
+        $crate::output::write_text_line(format_args!($($arg)*))
+    };
+}
+pub(crate) use textln;
+
+/// Set the process-wide output format. Later calls are ignored.
+pub fn set_format(format: OutputFormat) {
+    let _ = FORMAT.set(format);
+}
+
+/// Whether `--output json` is active.
+pub fn is_json() -> bool {
+    FORMAT.get() == Some(&OutputFormat::Json)
+}
+
+/// Write a line of human-readable output (see [`textln!`]).
+pub fn write_text_line(args: fmt::Arguments) {
+    if is_json() {
+        eprintln!("{}", args);
+    } else {
+        println!("{}", args);
+    }
+}
+
+/// Error information included in a failed result.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ErrorInfo {
+    /// Stable error category (see `BurlError::category`).
+    pub category: String,
+    /// Process exit code for this error.
+    pub exit_code: i32,
+    /// Human-readable error message.
+    pub message: String,
+}
+
+impl From<&BurlError> for ErrorInfo {
+    fn from(err: &BurlError) -> Self {
+        Self {
+            category: err.category().to_string(),
+            exit_code: err.exit_code(),
+            message: err.to_string(),
+        }
+    }
+}
+
+/// The JSON result object emitted by a command in `--output json` mode.
+#[derive(Debug, Clone, Serialize)]
+pub struct CommandResult {
+    /// Schema version ([`SCHEMA_VERSION`]).
+    pub schema_version: u32,
+    /// Command name (e.g. `claim`).
+    pub command: String,
+    /// Whether the command succeeded.
+    pub ok: bool,
+    /// Task the command acted on.
+    pub task_id: Option<String>,
+    /// Bucket the task was in when the command started.
+    pub from_bucket: Option<String>,
+    /// Bucket the task is in after the command (`null` if the command failed
+    /// before changing anything).
+    pub to_bucket: Option<String>,
+    /// Task branch.
+    pub branch: Option<String>,
+    /// Task worktree path.
+    pub worktree: Option<String>,
+    /// Validation step results (validate/approve).
+    pub validation: Vec<ValidationStepResult>,
+    /// Scope violations found by submit/validate/approve.
+    pub scope_violations: Vec<ScopeViolation>,
+    /// Stub violations found by submit/validate/approve.
+    pub stub_violations: Vec<StubViolation>,
+    /// Command-specific details (object).
+    pub details: Value,
+    /// Error information when `ok` is false.
+    pub error: Option<ErrorInfo>,
+}
+
+impl CommandResult {
+    /// Create an empty result for the given command.
+    pub fn new(command: &str) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            command: command.to_string(),
+            ok: true,
+            task_id: None,
+            from_bucket: None,
+            to_bucket: None,
+            branch: None,
+            worktree: None,
+            validation: Vec::new(),
+            scope_violations: Vec::new(),
+            stub_violations: Vec::new(),
+            details: json!({}),
+            error: None,
+        }
+    }
+
+    /// Set the task ID.
+    pub fn with_task(mut self, task_id: &str) -> Self {
+        self.task_id = Some(task_id.to_string());
+        self
+    }
+
+    /// Set the bucket the task was in when the command started.
+    pub fn with_from_bucket(mut self, bucket: &str) -> Self {
+        self.from_bucket = Some(bucket.to_string());
+        self
+    }
+
+    /// Set the bucket the task is in after the command.
+    pub fn with_to_bucket(mut self, bucket: &str) -> Self {
+        self.to_bucket = Some(bucket.to_string());
+        self
+    }
+
+    /// Set the task branch and worktree.
+    pub fn with_git(mut self, branch: Option<&str>, worktree: Option<&str>) -> Self {
+        self.branch = branch.map(str::to_string);
+        self.worktree = worktree.map(str::to_string);
+        self
+    }
+
+    /// Set command-specific details.
+    pub fn with_details(mut self, details: Value) -> Self {
+        self.details = details;
+        self
+    }
+
+    /// Mark the result with the command's final outcome.
+    pub fn finish(mut self, outcome: Result<(), &BurlError>) -> Self {
+        match outcome {
+            Ok(()) => {
+                self.ok = true;
+                self.error = None;
+            }
+            Err(err) => {
+                self.ok = false;
+                self.error = Some(ErrorInfo::from(err));
+            }
+        }
+        self
+    }
+}
+
+/// Record the result for the running command (no-op in text mode).
+///
+/// Replaces any previously recorded result.
+pub fn record(result: CommandResult) {
+    if !is_json() {
+        return;
+    }
+    if let Ok(mut slot) = RESULT.lock() {
+        *slot = Some(result);
+    }
+}
+
+/// Amend the recorded result (no-op in text mode or if nothing is recorded).
+pub fn update(f: impl FnOnce(&mut CommandResult)) {
+    if !is_json() {
+        return;
+    }
+    if let Ok(mut slot) = RESULT.lock()
+        && let Some(result) = slot.as_mut()
+    {
+        f(result);
+    }
+}
+
+/// Take the recorded result (or an empty one) and render the final JSON object.
+pub fn render_final(command: &str, outcome: Result<(), &BurlError>) -> String {
+    let recorded = RESULT.lock().ok().and_then(|mut slot| slot.take());
+    let result = recorded
+        .unwrap_or_else(|| CommandResult::new(command))
+        .finish(outcome);
+
+    serde_json::to_string(&result).unwrap_or_else(|e| {
+        json!({
+            "schema_version": SCHEMA_VERSION,
+            "command": command,
+            "ok": false,
+            "error": {
+                "category": "internal",
+                "exit_code": crate::exit_codes::USER_ERROR,
+                "message": format!("failed to serialize result: {}", e),
+            },
+        })
+        .to_string()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validate::{ScopeViolation, ValidationStepResult};
+
+    #[test]
+    fn test_result_has_stable_shape() {
+        let result = CommandResult::new("claim")
+            .with_task("TASK-001")
+            .with_from_bucket("READY")
+            .with_to_bucket("DOING")
+            .with_git(Some("task-001-x"), Some(".worktrees/task-001-x"))
+            .finish(Ok(()));
+        let value = serde_json::to_value(&result).unwrap();
+
+        assert_eq!(value["schema_version"], SCHEMA_VERSION);
+        assert_eq!(value["command"], "claim");
+        assert_eq!(value["ok"], true);
+        assert_eq!(value["task_id"], "TASK-001");
+        assert_eq!(value["from_bucket"], "READY");
+        assert_eq!(value["to_bucket"], "DOING");
+        assert_eq!(value["branch"], "task-001-x");
+        assert_eq!(value["validation"], json!([]));
+        assert_eq!(value["scope_violations"], json!([]));
+        assert_eq!(value["stub_violations"], json!([]));
+        assert_eq!(value["details"], json!({}));
+        assert!(value["error"].is_null());
+    }
+
+    #[test]
+    fn test_result_serializes_error_and_violations() {
+        let err = BurlError::ValidationError("scope violation".to_string());
+        let mut result = CommandResult::new("submit").with_task("TASK-002");
+        result
+            .scope_violations
+            .push(ScopeViolation::out_of_scope("src/other.rs"));
+        result
+            .validation
+            .push(ValidationStepResult::fail("scope", "out of scope"));
+        let value = serde_json::to_value(result.finish(Err(&err))).unwrap();
+
+        assert_eq!(value["ok"], false);
+        assert_eq!(value["error"]["category"], "validation_error");
+        assert_eq!(value["error"]["exit_code"], 2);
+        assert_eq!(value["scope_violations"][0]["file_path"], "src/other.rs");
+        assert_eq!(
+            value["scope_violations"][0]["violation_type"],
+            "out_of_scope"
+        );
+        assert_eq!(value["validation"][0]["status"], "fail");
+    }
+}
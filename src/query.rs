@@ -0,0 +1,566 @@
+//! Task query language shared by `search`, `list`, `monitor`, and `watch`.
+//!
+//! A query is a whitespace-separated list of terms; a task matches when every
+//! term matches. A leading `-` negates a term.
+//!
+//! ```text
+//! tag:net priority:high bucket:QA "rollback"
+//! -tag:wip assigned:me started<2026-09-01
+//! section.context:postgres field.review.owner:alice
+//! ```
+//!
+//! - Bare words and `"quoted phrases"` search the title and markdown body
+//!   (case-insensitive).
+//! - `key:value` (or `key=value`) matches a field. Known keys: `id`, `bucket`,
+//!   `title` (substring), `priority`, `tag`, `agent`, `assigned`
+//!   (`user`, `user@host`, or `me`), `profile`, `parent`, `depends`,
+//!   `affects` (substring of `affects`/`affects_globs`), `branch`, `attempts`.
+//! - `created`, `started`, `submitted`, `completed`, `blocked` (or their
+//!   `*_at` names) take a date (`YYYY-MM-DD`) or RFC 3339 timestamp and also
+//!   support `>`, `>=`, `<`, `<=`. `:` on a date matches that whole day.
+//! - `section.<name>:text` searches one body section (`section.acceptance_criteria`).
+//! - `field.<name>:value` matches any frontmatter field by its YAML name,
+//!   including unknown `extra` fields; dots descend into nested maps. Lists
+//!   match if any element does; comparisons work on numbers and dates.
+//!
+//! Values containing spaces can be quoted: `title:"player jump"`.
+
+use crate::agent::prompt::extract_sections;
+use crate::error::{BurlError, Result};
+use crate::events::get_actor_string;
+use crate::task::TaskFile;
+use crate::workflow::TaskInfo;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde_json::Value;
+use std::cmp::Ordering;
+
+/// A parsed task query.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TaskQuery {
+    clauses: Vec<Clause>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Clause {
+    negate: bool,
+    term: Term,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Term {
+    /// Full-text search of title and body (lowercased).
+    Text(String),
+    /// Comparison of a task field against a value.
+    Field { key: Key, op: Op, value: String },
+    /// Date comparison on a lifecycle timestamp.
+    Date {
+        field: DateField,
+        op: Op,
+        value: DateValue,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Key {
+    Id,
+    Bucket,
+    Title,
+    Priority,
+    Tag,
+    Agent,
+    Assigned,
+    Profile,
+    Parent,
+    Depends,
+    Affects,
+    Branch,
+    Attempts,
+    Section(String),
+    Field(Vec<String>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DateField {
+    Created,
+    Started,
+    Submitted,
+    Completed,
+    Blocked,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Eq,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+impl Op {
+    fn accepts(self, ordering: Ordering) -> bool {
+        match self {
+            Op::Eq => ordering == Ordering::Equal,
+            Op::Gt => ordering == Ordering::Greater,
+            Op::Ge => ordering != Ordering::Less,
+            Op::Lt => ordering == Ordering::Less,
+            Op::Le => ordering != Ordering::Greater,
+        }
+    }
+}
+
+/// A date operand: a whole day or an exact instant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DateValue {
+    Day(NaiveDate),
+    Instant(DateTime<Utc>),
+}
+
+impl DateValue {
+    fn parse(text: &str) -> Option<Self> {
+        if let Ok(day) = NaiveDate::parse_from_str(text, "%Y-%m-%d") {
+            return Some(DateValue::Day(day));
+        }
+        DateTime::parse_from_rfc3339(text)
+            .ok()
+            .map(|ts| DateValue::Instant(ts.with_timezone(&Utc)))
+    }
+
+    /// Order `ts` relative to this value (a day compares at day granularity).
+    fn compare(self, ts: DateTime<Utc>) -> Ordering {
+        match self {
+            DateValue::Day(day) => ts.date_naive().cmp(&day),
+            DateValue::Instant(instant) => ts.cmp(&instant),
+        }
+    }
+}
+
+impl TaskQuery {
+    /// Parse a query string. An empty string matches every task.
+    ///
+    /// `bucket:` terms must name one of `buckets` (the configured
+    /// `states.buckets`).
+    pub fn parse(input: &str, buckets: &[String]) -> Result<Self> {
+        let mut clauses = Vec::new();
+        let mut chars = input.chars().peekable();
+
+        loop {
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+            let Some(&first) = chars.peek() else {
+                break;
+            };
+
+            let mut negate = false;
+            if first == '-' {
+                chars.next();
+                negate = true;
+            }
+
+            if chars.peek() == Some(&'"') {
+                let phrase = read_value(&mut chars, input)?;
+                clauses.push(Clause {
+                    negate,
+                    term: text_term(&phrase, input)?,
+                });
+                continue;
+            }
+
+            let mut name = String::new();
+            while let Some(&c) = chars.peek() {
+                if c.is_whitespace() || matches!(c, ':' | '=' | '<' | '>' | '"') {
+                    break;
+                }
+                name.push(c);
+                chars.next();
+            }
+
+            let op = match chars.peek() {
+                Some(':') | Some('=') => Some(Op::Eq),
+                Some('>') => Some(Op::Gt),
+                Some('<') => Some(Op::Lt),
+                _ => None,
+            };
+            let Some(mut op) = op.filter(|_| !name.is_empty()) else {
+                // A bare word (possibly followed by a quoted part).
+                if chars.peek() == Some(&'"') {
+                    name.push_str(&read_value(&mut chars, input)?);
+                }
+                clauses.push(Clause {
+                    negate,
+                    term: text_term(&name, input)?,
+                });
+                continue;
+            };
+            chars.next();
+            if matches!(op, Op::Gt | Op::Lt) && chars.next_if_eq(&'=').is_some() {
+                op = if op == Op::Gt { Op::Ge } else { Op::Le };
+            }
+
+            let value = read_value(&mut chars, input)?;
+            clauses.push(Clause {
+                negate,
+                term: field_term(&name, op, value, buckets)?,
+            });
+        }
+
+        Ok(Self { clauses })
+    }
+
+    /// Whether the query has no terms (matches everything).
+    pub fn is_empty(&self) -> bool {
+        self.clauses.is_empty()
+    }
+
+    /// Whether a loaded task matches every term of the query.
+    pub fn matches(&self, info: &TaskInfo, task: &TaskFile) -> bool {
+        self.clauses
+            .iter()
+            .all(|clause| clause.term.matches(info, task) != clause.negate)
+    }
+
+    /// Keep the tasks that match, loading each task file.
+    ///
+    /// Tasks whose file cannot be loaded never match.
+    pub fn filter<'a>(&self, infos: Vec<&'a TaskInfo>) -> Vec<&'a TaskInfo> {
+        if self.is_empty() {
+            return infos;
+        }
+        infos
+            .into_iter()
+            .filter(|info| TaskFile::load(&info.path).is_ok_and(|task| self.matches(info, &task)))
+            .collect()
+    }
+}
+
+/// Read a (possibly quoted) value up to the next whitespace.
+fn read_value(chars: &mut std::iter::Peekable<std::str::Chars>, input: &str) -> Result<String> {
+    let mut value = String::new();
+    if chars.next_if_eq(&'"').is_some() {
+        loop {
+            match chars.next() {
+                Some('"') => break,
+                Some(c) => value.push(c),
+                None => {
+                    return Err(invalid(input, "unterminated quote"));
+                }
+            }
+        }
+    } else {
+        while let Some(c) = chars.next_if(|c| !c.is_whitespace()) {
+            value.push(c);
+        }
+    }
+    Ok(value)
+}
+
+fn text_term(text: &str, input: &str) -> Result<Term> {
+    if text.is_empty() {
+        return Err(invalid(input, "empty search term"));
+    }
+    Ok(Term::Text(text.to_lowercase()))
+}
+
+fn field_term(name: &str, op: Op, value: String, buckets: &[String]) -> Result<Term> {
+    let lower = name.to_lowercase();
+    let date_field = match lower.as_str() {
+        "created" => Some(DateField::Created),
+        "started" | "started_at" => Some(DateField::Started),
+        "submitted" | "submitted_at" => Some(DateField::Submitted),
+        "completed" | "completed_at" => Some(DateField::Completed),
+        "blocked" | "blocked_at" => Some(DateField::Blocked),
+        _ => None,
+    };
+    if let Some(field) = date_field {
+        let date = DateValue::parse(&value).ok_or_else(|| {
+            BurlError::UserError(format!(
+                "invalid date '{}' for '{}': use YYYY-MM-DD or an RFC 3339 timestamp",
+                value, name
+            ))
+        })?;
+        return Ok(Term::Date {
+            field,
+            op,
+            value: date,
+        });
+    }
+
+    let key = if let Some(section) = lower.strip_prefix("section.") {
+        Key::Section(section.replace(['_', '-'], " "))
+    } else if let Some(path) = name.strip_prefix("field.") {
+        Key::Field(path.split('.').map(str::to_string).collect())
+    } else {
+        match lower.as_str() {
+            "id" => Key::Id,
+            "bucket" => Key::Bucket,
+            "title" => Key::Title,
+            "priority" => Key::Priority,
+            "tag" | "tags" => Key::Tag,
+            "agent" => Key::Agent,
+            "assigned" | "assigned_to" => Key::Assigned,
+            "profile" | "validation_profile" => Key::Profile,
+            "parent" => Key::Parent,
+            "depends" | "depends_on" => Key::Depends,
+            "affects" => Key::Affects,
+            "branch" => Key::Branch,
+            "attempts" | "qa_attempts" => Key::Attempts,
+            _ => {
+                return Err(BurlError::UserError(format!(
+                    "unknown query field '{}'.\n\n\
+                     Known fields: id, bucket, title, priority, tag, agent, assigned, profile, \
+                     parent, depends, affects, branch, attempts, created, started, submitted, \
+                     completed, blocked, section.<name>, field.<name>",
+                    name
+                )));
+            }
+        }
+    };
+
+    let value = match key {
+        Key::Bucket => {
+            let upper = value.to_uppercase();
+            if !buckets.contains(&upper) {
+                return Err(BurlError::UserError(format!(
+                    "invalid bucket '{}' in query: must be one of {}",
+                    value,
+                    buckets.join(", ")
+                )));
+            }
+            upper
+        }
+        Key::Priority => {
+            let lower = value.to_lowercase();
+            if !matches!(lower.as_str(), "high" | "medium" | "low") {
+                return Err(BurlError::UserError(format!(
+                    "invalid priority '{}' in query: must be 'high', 'medium', or 'low'",
+                    value
+                )));
+            }
+            lower
+        }
+        Key::Attempts => {
+            value.parse::<u32>().map_err(|_| {
+                BurlError::UserError(format!("invalid attempts count '{}' in query", value))
+            })?;
+            value
+        }
+        Key::Assigned if value == "me" => get_actor_string(),
+        _ => value,
+    };
+
+    if op != Op::Eq && !matches!(key, Key::Attempts | Key::Field(_)) {
+        return Err(BurlError::UserError(format!(
+            "'{}' does not support <, >, <=, >= (only dates, attempts and field.<name> do)",
+            name
+        )));
+    }
+
+    Ok(Term::Field { key, op, value })
+}
+
+fn invalid(input: &str, reason: &str) -> BurlError {
+    BurlError::UserError(format!("invalid query '{}': {}", input, reason))
+}
+
+impl Term {
+    fn matches(&self, info: &TaskInfo, task: &TaskFile) -> bool {
+        let fm = &task.frontmatter;
+        match self {
+            Term::Text(text) => {
+                fm.title.to_lowercase().contains(text) || task.body.to_lowercase().contains(text)
+            }
+            Term::Date { field, op, value } => {
+                let ts = match field {
+                    DateField::Created => fm.created,
+                    DateField::Started => fm.started_at,
+                    DateField::Submitted => fm.submitted_at,
+                    DateField::Completed => fm.completed_at,
+                    DateField::Blocked => fm.blocked_at,
+                };
+                ts.is_some_and(|ts| op.accepts(value.compare(ts)))
+            }
+            Term::Field { key, op, value } => {
+                let eq = |actual: &str| actual.eq_ignore_ascii_case(value);
+                let contains = |actual: &str| actual.to_lowercase().contains(&value.to_lowercase());
+                match key {
+                    Key::Id => eq(&info.id),
+                    Key::Bucket => info.bucket == *value,
+                    Key::Title => contains(&fm.title),
+                    Key::Priority => fm.priority == *value,
+                    Key::Tag => fm.tags.iter().any(|tag| eq(tag)),
+                    Key::Agent => fm.agent.as_deref().is_some_and(eq),
+                    Key::Assigned => fm
+                        .assigned_to
+                        .as_deref()
+                        .is_some_and(|a| assignee_matches(a, value)),
+                    Key::Profile => fm.validation_profile.as_deref().is_some_and(eq),
+                    Key::Parent => fm.parent.as_deref().is_some_and(eq),
+                    Key::Depends => fm.depends_on.iter().any(|dep| eq(dep)),
+                    Key::Affects => fm
+                        .affects
+                        .iter()
+                        .chain(&fm.affects_globs)
+                        .any(|entry| contains(entry)),
+                    Key::Branch => fm.branch.as_deref().is_some_and(eq),
+                    Key::Attempts => value
+                        .parse::<u32>()
+                        .is_ok_and(|n| op.accepts(fm.qa_attempts.cmp(&n))),
+                    Key::Section(name) => extract_sections(&task.body)
+                        .get(name)
+                        .is_some_and(|content| contains(content)),
+                    Key::Field(path) => {
+                        let Ok(record) = serde_json::to_value(fm) else {
+                            return false;
+                        };
+                        path.iter()
+                            .try_fold(&record, |node, segment| node.get(segment))
+                            .is_some_and(|node| value_matches(node, *op, value))
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Compare a frontmatter value (from `field.<name>`) against a query value.
+fn value_matches(node: &Value, op: Op, expected: &str) -> bool {
+    let scalar = match node {
+        Value::Array(items) => return items.iter().any(|item| value_matches(item, op, expected)),
+        Value::Null | Value::Object(_) => return false,
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    };
+
+    if let (Ok(actual), Ok(wanted)) = (scalar.parse::<f64>(), expected.parse::<f64>()) {
+        return actual
+            .partial_cmp(&wanted)
+            .is_some_and(|ordering| op.accepts(ordering));
+    }
+    if op != Op::Eq
+        && let Some(wanted) = DateValue::parse(expected)
+    {
+        return DateTime::parse_from_rfc3339(&scalar)
+            .map(|ts| ts.with_timezone(&Utc))
+            .or_else(|_| {
+                NaiveDate::parse_from_str(&scalar, "%Y-%m-%d")
+                    .map(|day| day.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc())
+            })
+            .is_ok_and(|ts| op.accepts(wanted.compare(ts)));
+    }
+    match op {
+        Op::Eq => scalar.eq_ignore_ascii_case(expected),
+        _ => op.accepts(scalar.as_str().cmp(expected)),
+    }
+}
+
+/// Match an `assigned_to` value against a filter.
+///
+/// A filter without `@` matches on the user part only, so `alice` matches
+/// `alice@any-host`.
+pub(crate) fn assignee_matches(assigned_to: &str, filter: &str) -> bool {
+    if filter.contains('@') {
+        assigned_to == filter
+    } else {
+        assigned_to.split('@').next() == Some(filter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn sample() -> (TaskInfo, TaskFile) {
+        let task = TaskFile::parse(
+            r#"---
+id: TASK-007
+title: Network rollback
+priority: high
+created: 2026-08-30T10:00:00Z
+completed_at: 2026-09-02T12:00:00Z
+assigned_to: alice@box
+qa_attempts: 2
+affects_globs:
+  - src/net/**
+tags: [net, backend]
+review:
+  owner: bob
+  points: 5
+---
+
+## Objective
+Roll back failed migrations.
+
+## Context
+Uses postgres advisory locks.
+"#,
+        )
+        .unwrap();
+        let info = TaskInfo {
+            id: "TASK-007".to_string(),
+            bucket: "QA".to_string(),
+            path: PathBuf::from("TASK-007.md"),
+            number: 7,
+            archived: false,
+        };
+        (info, task)
+    }
+
+    fn buckets() -> Vec<String> {
+        crate::config::StatesConfig::default().buckets
+    }
+
+    fn matches(query: &str) -> bool {
+        let (info, task) = sample();
+        TaskQuery::parse(query, &buckets())
+            .unwrap()
+            .matches(&info, &task)
+    }
+
+    #[test]
+    fn test_fields_text_and_negation() {
+        assert!(matches(r#"tag:net priority:high bucket:qa "ROLLBACK""#));
+        assert!(matches(
+            "title:network affects:net assigned:alice attempts>=2"
+        ));
+        assert!(matches("-tag:wip migrations"));
+        assert!(!matches("tag:net -bucket:QA"));
+        assert!(!matches("attempts<2"));
+        assert!(matches("section.context:postgres"));
+        assert!(!matches("section.objective:postgres"));
+        assert!(matches(""));
+    }
+
+    #[test]
+    fn test_dates_and_extra_fields() {
+        assert!(matches("completed>2026-09-01 created<=2026-08-30"));
+        assert!(matches("completed:2026-09-02"));
+        assert!(!matches("completed>2026-09-02"));
+        assert!(!matches("started<2030-01-01"));
+        assert!(matches("completed<2026-09-02T13:00:00Z"));
+        assert!(matches("field.review.owner:BOB field.review.points>4"));
+        assert!(matches("field.tags:backend field.qa_attempts=2"));
+        assert!(!matches("field.review.missing:x"));
+    }
+
+    #[test]
+    fn test_assignee_matches_user_part() {
+        assert!(assignee_matches("alice@host", "alice"));
+        assert!(assignee_matches("alice@host", "alice@host"));
+        assert!(!assignee_matches("alice@host", "alice@other"));
+        assert!(!assignee_matches("alicia@host", "alice"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for (query, expected) in [
+            ("color:red", "unknown query field 'color'"),
+            ("bucket:LATER", "invalid bucket 'LATER'"),
+            ("completed>yesterday", "invalid date 'yesterday'"),
+            ("tag>net", "does not support"),
+            (r#"title:"open"#, "unterminated quote"),
+        ] {
+            let err = TaskQuery::parse(query, &buckets()).unwrap_err();
+            assert!(err.to_string().contains(expected), "{}: {}", query, err);
+        }
+    }
+}
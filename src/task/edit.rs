@@ -0,0 +1,165 @@
+//! Typed frontmatter edits used by `burl edit`.
+//!
+//! Each edit targets exactly one frontmatter field so callers can apply bucket
+//! policy per field before mutating the task.
+
+use super::{TaskFile, TaskFrontmatter};
+use crate::error::{BurlError, Result};
+use serde_json::{Map, Value, json};
+
+/// A single typed mutation of task frontmatter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FrontmatterEdit {
+    /// Replace the task title.
+    Title(String),
+    /// Replace the priority (already normalized).
+    Priority(String),
+    /// Add an explicit path to `affects`.
+    AddAffects(String),
+    /// Remove an explicit path from `affects`.
+    RemoveAffects(String),
+    /// Add a glob to `affects_globs`.
+    AddAffectsGlob(String),
+    /// Remove a glob from `affects_globs`.
+    RemoveAffectsGlob(String),
+    /// Add a path/glob to `must_not_touch`.
+    AddMustNotTouch(String),
+    /// Remove a path/glob from `must_not_touch`.
+    RemoveMustNotTouch(String),
+    /// Add a task ID to `depends_on`.
+    AddDependency(String),
+    /// Remove a task ID from `depends_on`.
+    RemoveDependency(String),
+    /// Set (`Some`) or clear (`None`) the parent epic.
+    Parent(Option<String>),
+    /// Add a tag.
+    AddTag(String),
+    /// Remove a tag.
+    RemoveTag(String),
+    /// Set (`Some`) or clear (`None`) the agent profile.
+    Agent(Option<String>),
+    /// Set (`Some`) or clear (`None`) the validation profile.
+    ValidationProfile(Option<String>),
+    /// Replace the recorded base SHA.
+    BaseSha(String),
+}
+
+impl FrontmatterEdit {
+    /// The frontmatter field this edit touches.
+    pub fn field(&self) -> &'static str {
+        match self {
+            FrontmatterEdit::Title(_) => "title",
+            FrontmatterEdit::Priority(_) => "priority",
+            FrontmatterEdit::AddAffects(_) | FrontmatterEdit::RemoveAffects(_) => "affects",
+            FrontmatterEdit::AddAffectsGlob(_) | FrontmatterEdit::RemoveAffectsGlob(_) => {
+                "affects_globs"
+            }
+            FrontmatterEdit::AddMustNotTouch(_) | FrontmatterEdit::RemoveMustNotTouch(_) => {
+                "must_not_touch"
+            }
+            FrontmatterEdit::AddDependency(_) | FrontmatterEdit::RemoveDependency(_) => {
+                "depends_on"
+            }
+            FrontmatterEdit::Parent(_) => "parent",
+            FrontmatterEdit::AddTag(_) | FrontmatterEdit::RemoveTag(_) => "tags",
+            FrontmatterEdit::Agent(_) => "agent",
+            FrontmatterEdit::ValidationProfile(_) => "validation_profile",
+            FrontmatterEdit::BaseSha(_) => "base_sha",
+        }
+    }
+}
+
+impl TaskFile {
+    /// Apply a single frontmatter edit.
+    ///
+    /// List additions are de-duplicated and list removals of absent values are
+    /// no-ops. Returns `true` if the frontmatter changed.
+    pub fn apply_edit(&mut self, edit: &FrontmatterEdit) -> bool {
+        let fm = &mut self.frontmatter;
+        match edit {
+            FrontmatterEdit::Title(title) => replace_value(&mut fm.title, title),
+            FrontmatterEdit::Priority(priority) => replace_value(&mut fm.priority, priority),
+            FrontmatterEdit::AddAffects(v) => push_unique(&mut fm.affects, v),
+            FrontmatterEdit::RemoveAffects(v) => remove_all(&mut fm.affects, v),
+            FrontmatterEdit::AddAffectsGlob(v) => push_unique(&mut fm.affects_globs, v),
+            FrontmatterEdit::RemoveAffectsGlob(v) => remove_all(&mut fm.affects_globs, v),
+            FrontmatterEdit::AddMustNotTouch(v) => push_unique(&mut fm.must_not_touch, v),
+            FrontmatterEdit::RemoveMustNotTouch(v) => remove_all(&mut fm.must_not_touch, v),
+            FrontmatterEdit::AddDependency(v) => push_unique(&mut fm.depends_on, v),
+            FrontmatterEdit::RemoveDependency(v) => remove_all(&mut fm.depends_on, v),
+            FrontmatterEdit::Parent(v) => replace_option(&mut fm.parent, v),
+            FrontmatterEdit::AddTag(v) => push_unique(&mut fm.tags, v),
+            FrontmatterEdit::RemoveTag(v) => remove_all(&mut fm.tags, v),
+            FrontmatterEdit::Agent(v) => replace_option(&mut fm.agent, v),
+            FrontmatterEdit::ValidationProfile(v) => replace_option(&mut fm.validation_profile, v),
+            FrontmatterEdit::BaseSha(v) => replace_option(&mut fm.base_sha, &Some(v.clone())),
+        }
+    }
+}
+
+fn replace_value(slot: &mut String, value: &str) -> bool {
+    if slot == value {
+        return false;
+    }
+    *slot = value.to_string();
+    true
+}
+
+fn replace_option(slot: &mut Option<String>, value: &Option<String>) -> bool {
+    if slot == value {
+        return false;
+    }
+    *slot = value.clone();
+    true
+}
+
+fn push_unique(list: &mut Vec<String>, value: &str) -> bool {
+    if list.iter().any(|v| v == value) {
+        return false;
+    }
+    list.push(value.to_string());
+    true
+}
+
+fn remove_all(list: &mut Vec<String>, value: &str) -> bool {
+    let before = list.len();
+    list.retain(|v| v != value);
+    list.len() != before
+}
+
+/// Compute a field-level diff between two frontmatter snapshots.
+///
+/// Returns a JSON object keyed by field name, where each entry is
+/// `{"before": ..., "after": ...}`. Absent fields are reported as `null`.
+pub fn frontmatter_diff(before: &TaskFrontmatter, after: &TaskFrontmatter) -> Result<Value> {
+    let before = frontmatter_to_json(before)?;
+    let after = frontmatter_to_json(after)?;
+
+    let mut keys: Vec<&String> = before.keys().chain(after.keys()).collect();
+    keys.sort();
+    keys.dedup();
+
+    let mut diff = Map::new();
+    for key in keys {
+        let old = before.get(key).cloned().unwrap_or(Value::Null);
+        let new = after.get(key).cloned().unwrap_or(Value::Null);
+        if old != new {
+            diff.insert(key.clone(), json!({ "before": old, "after": new }));
+        }
+    }
+
+    Ok(Value::Object(diff))
+}
+
+fn frontmatter_to_json(frontmatter: &TaskFrontmatter) -> Result<Map<String, Value>> {
+    match serde_json::to_value(frontmatter) {
+        Ok(Value::Object(map)) => Ok(map),
+        Ok(_) => Err(BurlError::UserError(
+            "failed to serialize task frontmatter: expected an object".to_string(),
+        )),
+        Err(e) => Err(BurlError::UserError(format!(
+            "failed to serialize task frontmatter: {}",
+            e
+        ))),
+    }
+}
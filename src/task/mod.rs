@@ -27,13 +27,17 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
+mod edit;
 mod io;
 mod mutations;
+mod template;
 #[cfg(test)]
 mod tests;
 
 // Re-export methods are implemented directly on TaskFile via impl blocks
 // in the io and mutations modules, so no explicit re-exports needed
+pub use edit::{FrontmatterEdit, frontmatter_diff};
+pub use template::{TaskTemplate, TemplateDefaults, list_templates};
 
 /// A parsed task file with frontmatter and markdown body.
 #[derive(Debug, Clone)]
@@ -82,6 +86,17 @@ pub struct TaskFrontmatter {
     #[serde(default)]
     pub qa_attempts: u32,
 
+    // =========================================================================
+    // Blocking
+    // =========================================================================
+    /// Why the task is blocked (set while the task is in BLOCKED).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocked_reason: Option<String>,
+
+    /// When the task was moved to BLOCKED.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocked_at: Option<DateTime<Utc>>,
+
     // =========================================================================
     // Lifecycle timestamps
     // =========================================================================
@@ -134,6 +149,17 @@ pub struct TaskFrontmatter {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub depends_on: Vec<String>,
 
+    /// Epic (parent task) this task belongs to.
+    ///
+    /// A task with children is an epic: it is never claimed itself and is
+    /// completed automatically once all of its children reach DONE.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+
+    /// Tasks that replaced this one when it was split (`burl split`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub superseded_by: Vec<String>,
+
     // =========================================================================
     // Tags
     // =========================================================================
@@ -174,6 +200,8 @@ impl Default for TaskFrontmatter {
             created: None,
             assigned_to: None,
             qa_attempts: 0,
+            blocked_reason: None,
+            blocked_at: None,
             started_at: None,
             submitted_at: None,
             completed_at: None,
@@ -184,6 +212,8 @@ impl Default for TaskFrontmatter {
             affects_globs: Vec::new(),
             must_not_touch: Vec::new(),
             depends_on: Vec::new(),
+            parent: None,
+            superseded_by: Vec::new(),
             tags: Vec::new(),
             agent: None,
             validation_profile: None,
@@ -19,6 +19,13 @@ impl TaskFile {
         self.frontmatter.base_sha = Some(base_sha.to_string());
     }
 
+    /// Clear git-related fields (branch, worktree, base_sha).
+    pub fn clear_git_info(&mut self) {
+        self.frontmatter.branch = None;
+        self.frontmatter.worktree = None;
+        self.frontmatter.base_sha = None;
+    }
+
     /// Set the submitted_at timestamp.
     pub fn set_submitted(&mut self, time: DateTime<Utc>) {
         self.frontmatter.submitted_at = Some(time);
@@ -39,16 +46,28 @@ impl TaskFile {
     /// If the section exists, content is appended below it.
     /// If not, a new section is created at the end of the body.
     pub fn append_to_qa_report(&mut self, content: &str) {
-        const QA_REPORT_HEADING: &str = "## QA Report";
+        self.append_to_section("## QA Report", content);
+    }
 
+    /// Append an entry to the History section.
+    ///
+    /// Used for lifecycle notes that aren't QA results (e.g. block/unblock).
+    /// If the section does not exist, it is created at the end of the body.
+    pub fn append_to_history(&mut self, content: &str) {
+        self.append_to_section("## History", content);
+    }
+
+    /// Append content at the end of the section with the given heading,
+    /// creating the section at the end of the body if it doesn't exist.
+    fn append_to_section(&mut self, heading: &str, content: &str) {
         // Ensure body ends with newline for clean appending
         if !self.body.is_empty() && !self.body.ends_with('\n') {
             self.body.push('\n');
         }
 
-        if let Some(pos) = self.body.find(QA_REPORT_HEADING) {
-            // Find the end of the QA Report section (next ## heading or end of body)
-            let after_heading = pos + QA_REPORT_HEADING.len();
+        if let Some(pos) = self.body.find(heading) {
+            // Find the end of the section (next ## heading or end of body)
+            let after_heading = pos + heading.len();
             let section_end = self.body[after_heading..]
                 .find("\n## ")
                 .map(|p| after_heading + p)
@@ -67,17 +86,35 @@ impl TaskFile {
             self.body
                 .insert_str(insert_pos, &format!("{}{}\n", prefix, content));
         } else {
-            // Create new QA Report section at the end
-            self.body
-                .push_str(&format!("\n{}\n{}\n", QA_REPORT_HEADING, content));
+            // Create new section at the end
+            self.body.push_str(&format!("\n{}\n{}\n", heading, content));
         }
     }
 
+    /// Mark the task as blocked with a reason and timestamp.
+    pub fn set_blocked(&mut self, reason: &str, time: DateTime<Utc>) {
+        self.frontmatter.blocked_reason = Some(reason.to_string());
+        self.frontmatter.blocked_at = Some(time);
+    }
+
+    /// Clear the blocked_reason/blocked_at fields.
+    pub fn clear_blocked(&mut self) {
+        self.frontmatter.blocked_reason = None;
+        self.frontmatter.blocked_at = None;
+    }
+
     /// Clear the assigned_to field (for unassignment).
     pub fn clear_assigned(&mut self) {
         self.frontmatter.assigned_to = None;
     }
 
+    /// Clear assigned_to/started_at for a task going back to READY. The git
+    /// fields are kept so the next claim can reuse the branch.
+    pub fn clear_claim(&mut self) {
+        self.clear_assigned();
+        self.frontmatter.started_at = None;
+    }
+
     /// Set the agent profile for this task.
     pub fn set_agent(&mut self, agent_name: &str) {
         self.frontmatter.agent = Some(agent_name.to_string());
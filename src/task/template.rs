@@ -0,0 +1,150 @@
+//! Named task templates for `burl add --template`.
+//!
+//! A template is a markdown file `.burl/.workflow/task-templates/<name>.md`.
+//! Its optional YAML frontmatter holds default task fields; its body replaces
+//! the standard task skeleton. The body may use `{title}`, `{id}`,
+//! `{priority}` and `{date}` placeholders (see
+//! [`render_template`](crate::agent::prompt::render_template); write `{{`/`}}`
+//! for literal braces).
+//!
+//! ```text
+//! ---
+//! tags: [bug]
+//! validation_profile: quick
+//! must_not_touch: [migrations/**]
+//! ---
+//!
+//! ## Objective
+//! Fix: {title}
+//! ```
+
+use super::TaskFile;
+use crate::agent::prompt::render_template;
+use crate::error::{BurlError, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Default task fields declared in a template's frontmatter.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TemplateDefaults {
+    pub tags: Vec<String>,
+    pub validation_profile: Option<String>,
+    pub agent: Option<String>,
+    pub affects: Vec<String>,
+    pub affects_globs: Vec<String>,
+    pub must_not_touch: Vec<String>,
+}
+
+/// A parsed task template.
+#[derive(Debug, Clone)]
+pub struct TaskTemplate {
+    /// Template name (file stem).
+    pub name: String,
+    pub defaults: TemplateDefaults,
+    /// Unrendered body.
+    pub body: String,
+}
+
+impl TaskTemplate {
+    /// Load the template `name` from the templates directory.
+    pub fn load(dir: &Path, name: &str) -> Result<Self> {
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(BurlError::UserError(format!(
+                "invalid template name '{}': use letters, digits, '-' and '_' only.",
+                name
+            )));
+        }
+
+        let path = dir.join(format!("{}.md", name));
+        if !path.is_file() {
+            let available = list_templates(dir);
+            return Err(BurlError::UserError(format!(
+                "task template '{}' not found in {}.\n\nAvailable templates: {}",
+                name,
+                dir.display(),
+                if available.is_empty() {
+                    "(none)".to_string()
+                } else {
+                    available.join(", ")
+                }
+            )));
+        }
+
+        let content = std::fs::read_to_string(&path).map_err(|e| {
+            BurlError::UserError(format!(
+                "failed to read task template '{}': {}",
+                path.display(),
+                e
+            ))
+        })?;
+        Self::parse(name, &content)
+    }
+
+    /// Parse template content: optional frontmatter followed by the body.
+    pub fn parse(name: &str, content: &str) -> Result<Self> {
+        let normalized = content.replace("\r\n", "\n");
+        let (defaults, body) = if let Some(rest) = normalized.strip_prefix("---\n---") {
+            // Empty frontmatter block.
+            let body = rest.strip_prefix('\n').unwrap_or(rest);
+            (TemplateDefaults::default(), body.to_string())
+        } else if normalized.starts_with("---") {
+            let (yaml, body_start) = TaskFile::extract_frontmatter(&normalized, content)?;
+            let defaults = serde_yaml::from_str::<Option<TemplateDefaults>>(&yaml)
+                .map_err(|e| {
+                    BurlError::UserError(format!(
+                        "failed to parse frontmatter of task template '{}': {}",
+                        name, e
+                    ))
+                })?
+                .unwrap_or_default();
+            (defaults, content[body_start..].to_string())
+        } else {
+            (TemplateDefaults::default(), content.to_string())
+        };
+
+        Ok(Self {
+            name: name.to_string(),
+            defaults,
+            body,
+        })
+    }
+
+    /// Render the body, substituting `{variable}` placeholders.
+    pub fn render_body(&self, variables: &HashMap<String, String>) -> Result<String> {
+        render_template(&self.body, variables).map_err(|e| {
+            let mut placeholders: Vec<String> = variables
+                .keys()
+                .map(|name| format!("{{{}}}", name))
+                .collect();
+            placeholders.sort();
+            BurlError::UserError(format!(
+                "failed to render task template '{}': {}\n\n\
+                 Available placeholders: {}. Write {{{{ and }}}} for literal braces.",
+                self.name,
+                e,
+                placeholders.join(", ")
+            ))
+        })
+    }
+}
+
+/// Names of the templates in `dir`, sorted. A missing directory has none.
+pub fn list_templates(dir: &Path) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "md"))
+        .filter_map(|path| Some(path.file_stem()?.to_string_lossy().to_string()))
+        .collect();
+    names.sort();
+    names
+}
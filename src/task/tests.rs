@@ -288,6 +288,43 @@ Other content.
     assert!(task.body.contains("## Other Section"));
 }
 
+#[test]
+fn test_append_to_history_keeps_qa_report_intact() {
+    let mut task = TaskFile::parse(FULL_TASK).unwrap();
+
+    task.append_to_history("### Blocked: waiting on API");
+    assert!(task.body.contains("## History"));
+    assert!(task.body.contains("### Blocked: waiting on API"));
+
+    let qa_pos = task.body.find("## QA Report").unwrap();
+    let history_pos = task.body.find("## History").unwrap();
+    assert!(history_pos > qa_pos);
+}
+
+#[test]
+fn test_set_and_clear_blocked() {
+    let mut task = TaskFile::parse(MINIMAL_TASK).unwrap();
+    let now = Utc::now();
+
+    task.set_blocked("waiting on API keys", now);
+    assert_eq!(
+        task.frontmatter.blocked_reason.as_deref(),
+        Some("waiting on API keys")
+    );
+    assert_eq!(task.frontmatter.blocked_at, Some(now));
+
+    let reparsed = TaskFile::parse(&task.to_string().unwrap()).unwrap();
+    assert_eq!(
+        reparsed.frontmatter.blocked_reason.as_deref(),
+        Some("waiting on API keys")
+    );
+
+    task.clear_blocked();
+    assert!(task.frontmatter.blocked_reason.is_none());
+    assert!(task.frontmatter.blocked_at.is_none());
+    assert!(!task.to_string().unwrap().contains("blocked_reason"));
+}
+
 #[test]
 fn test_clear_assigned() {
     let mut task = TaskFile::parse(FULL_TASK).unwrap();
@@ -439,3 +476,97 @@ fn test_load_nonexistent_file() {
             .contains("failed to read task file")
     );
 }
+
+// =============================================================================
+// Frontmatter edit tests
+// =============================================================================
+
+#[test]
+fn test_apply_edit_list_add_is_deduplicated() {
+    let mut task = TaskFile::parse(FULL_TASK).unwrap();
+
+    assert!(task.apply_edit(&FrontmatterEdit::AddTag("net".to_string())));
+    assert!(!task.apply_edit(&FrontmatterEdit::AddTag("net".to_string())));
+    assert!(!task.apply_edit(&FrontmatterEdit::AddTag("feature".to_string())));
+    assert_eq!(task.frontmatter.tags, vec!["feature", "player", "net"]);
+}
+
+#[test]
+fn test_apply_edit_list_remove() {
+    let mut task = TaskFile::parse(FULL_TASK).unwrap();
+
+    assert!(task.apply_edit(&FrontmatterEdit::RemoveAffectsGlob(
+        "src/player/**".to_string()
+    )));
+    assert!(task.frontmatter.affects_globs.is_empty());
+    assert!(!task.apply_edit(&FrontmatterEdit::RemoveAffectsGlob(
+        "src/player/**".to_string()
+    )));
+}
+
+#[test]
+fn test_apply_edit_scalar_fields() {
+    let mut task = TaskFile::parse(MINIMAL_TASK).unwrap();
+
+    assert!(!task.apply_edit(&FrontmatterEdit::Priority("medium".to_string())));
+    assert!(task.apply_edit(&FrontmatterEdit::Priority("high".to_string())));
+    assert!(task.apply_edit(&FrontmatterEdit::Agent(Some("codex".to_string()))));
+    assert_eq!(task.frontmatter.agent, Some("codex".to_string()));
+    assert!(task.apply_edit(&FrontmatterEdit::Agent(None)));
+    assert!(task.frontmatter.agent.is_none());
+    assert!(!task.apply_edit(&FrontmatterEdit::ValidationProfile(None)));
+}
+
+#[test]
+fn test_frontmatter_diff_reports_changed_fields_only() {
+    let before = TaskFile::parse(FULL_TASK).unwrap();
+    let mut after = before.clone();
+    after.apply_edit(&FrontmatterEdit::Priority("low".to_string()));
+    after.apply_edit(&FrontmatterEdit::RemoveTag("player".to_string()));
+    after.apply_edit(&FrontmatterEdit::Agent(Some("codex".to_string())));
+
+    let diff = frontmatter_diff(&before.frontmatter, &after.frontmatter).unwrap();
+    let diff = diff.as_object().unwrap();
+
+    assert_eq!(diff.len(), 3);
+    assert_eq!(diff["priority"]["before"], "high");
+    assert_eq!(diff["priority"]["after"], "low");
+    assert_eq!(diff["tags"]["after"], serde_json::json!(["feature"]));
+    assert!(diff["agent"]["before"].is_null());
+    assert_eq!(diff["agent"]["after"], "codex");
+}
+
+#[test]
+fn test_task_template_parse_and_render() {
+    let template = TaskTemplate::parse(
+        "bugfix",
+        "---\ntags: [bug]\nvalidation_profile: quick\nmust_not_touch: [migrations/**]\n---\n\n## Objective\nFix: {title} ({id})\n\n```rust\nfn f() {{}}\n```\n",
+    )
+    .unwrap();
+    assert_eq!(template.defaults.tags, vec!["bug"]);
+    assert_eq!(
+        template.defaults.validation_profile.as_deref(),
+        Some("quick")
+    );
+
+    let vars = std::collections::HashMap::from([
+        ("title".to_string(), "Crash on start".to_string()),
+        ("id".to_string(), "TASK-007".to_string()),
+    ]);
+    let body = template.render_body(&vars).unwrap();
+    assert!(body.starts_with("\n## Objective\nFix: Crash on start (TASK-007)\n"));
+    assert!(body.contains("fn f() {}"));
+
+    let err = TaskTemplate::parse("x", "Owner: {owner}\n")
+        .unwrap()
+        .render_body(&vars)
+        .unwrap_err();
+    assert!(err.to_string().contains("undefined variable 'owner'"));
+
+    let err = TaskTemplate::parse("x", "---\npriority: high\n---\nbody\n").unwrap_err();
+    assert!(err.to_string().contains("unknown field `priority`"));
+
+    let plain = TaskTemplate::parse("plain", "---\n---\n## Objective\n").unwrap();
+    assert_eq!(plain.defaults, TemplateDefaults::default());
+    assert_eq!(plain.body, "## Objective\n");
+}
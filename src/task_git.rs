@@ -5,12 +5,14 @@
 //! using it for git operations or filesystem access.
 //!
 //! Invariants (V1):
-//! - Task branches are named `task-<numeric>[-<slug>]` and must match the task ID.
+//! - Task branches are named `<prefix>-<numeric>[-<slug>]` (the lowercased task ID,
+//!   e.g. `task-001-foo`, `bug-042`) and must match the task ID.
 //! - Task worktrees live directly under `{repo_root}/.worktrees/` and the directory
 //!   name must match the task branch name.
 
 use crate::context::WorkflowContext;
 use crate::error::{BurlError, Result};
+use crate::workflow::split_task_id;
 use std::path::{Component, Path, PathBuf};
 
 #[derive(Debug, Clone)]
@@ -27,22 +29,32 @@ pub fn validate_task_branch(task_id: &str, branch: &str) -> Result<()> {
         ));
     }
 
+    let Some((id_prefix, _)) = split_task_id(task_id) else {
+        return Err(BurlError::UserError(format!(
+            "invalid task id '{}' for task branch '{}'.",
+            task_id, branch
+        )));
+    };
+    let prefix = format!("{}-", id_prefix.to_ascii_lowercase());
+    let expected_format = format!(
+        "Expected format: {}<NNN>[-slug] (e.g., {}001-player-jump).",
+        prefix, prefix
+    );
+
     // Enforce the convention and a restrictive charset to avoid surprising git
     // argument parsing (e.g. branch names that look like flags) and prevent
     // using unrelated branches.
     if branch != branch.to_ascii_lowercase() {
         return Err(BurlError::UserError(format!(
-            "invalid task branch '{}': must be lowercase.\n\n\
-             Expected format: task-<NNN>[-slug] (e.g., task-001-player-jump).",
-            branch
+            "invalid task branch '{}': must be lowercase.\n\n{}",
+            branch, expected_format
         )));
     }
 
-    if !branch.starts_with("task-") {
+    if !branch.starts_with(&prefix) {
         return Err(BurlError::UserError(format!(
-            "invalid task branch '{}': must start with 'task-'.\n\n\
-             Expected format: task-<NNN>[-slug] (e.g., task-001-player-jump).",
-            branch
+            "invalid task branch '{}': must start with '{}' for task '{}'.\n\n{}",
+            branch, prefix, task_id, expected_format
         )));
     }
 
@@ -53,12 +65,9 @@ pub fn validate_task_branch(task_id: &str, branch: &str) -> Result<()> {
         )));
     }
 
-    let numeric_expected = task_id
-        .strip_prefix("TASK-")
-        .unwrap_or(task_id)
-        .to_ascii_lowercase();
+    let numeric_expected = task_id[prefix.len()..].to_ascii_lowercase();
 
-    let rest = &branch["task-".len()..];
+    let rest = &branch[prefix.len()..];
     let (numeric_actual, slug) = match rest.split_once('-') {
         Some((n, s)) => (n, Some(s)),
         None => (rest, None),
@@ -66,17 +75,16 @@ pub fn validate_task_branch(task_id: &str, branch: &str) -> Result<()> {
 
     if numeric_actual.is_empty() || !numeric_actual.chars().all(|c| c.is_ascii_digit()) {
         return Err(BurlError::UserError(format!(
-            "invalid task branch '{}': numeric portion must be digits.\n\n\
-             Expected format: task-<NNN>[-slug] (e.g., task-001-player-jump).",
-            branch
+            "invalid task branch '{}': numeric portion must be digits.\n\n{}",
+            branch, expected_format
         )));
     }
 
     if numeric_actual != numeric_expected {
         return Err(BurlError::UserError(format!(
             "invalid task branch '{}': does not match task id '{}'.\n\n\
-             Expected prefix: task-{}",
-            branch, task_id, numeric_expected
+             Expected prefix: {}{}",
+            branch, task_id, prefix, numeric_expected
         )));
     }
 
@@ -291,6 +299,14 @@ mod tests {
         assert!(err.to_string().contains("does not match task id"));
     }
 
+    #[test]
+    fn validate_task_branch_uses_task_id_prefix() {
+        assert!(validate_task_branch("BUG-042", "bug-042-login-crash").is_ok());
+        let err = validate_task_branch("BUG-042", "task-042-login-crash").unwrap_err();
+        assert!(err.to_string().contains("must start with 'bug-'"));
+        assert!(validate_task_branch("TASK-001", "bug-001").is_err());
+    }
+
     #[test]
     fn validate_task_branch_rejects_uppercase() {
         let err = validate_task_branch("TASK-001", "task-001-Foo").unwrap_err();
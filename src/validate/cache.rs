@@ -0,0 +1,252 @@
+//! On-disk cache of passing validation steps.
+//!
+//! `burl validate` followed by `burl approve` would otherwise run the whole
+//! profile twice on an identical tree. Every step that passes is recorded in
+//! `.burl/.workflow/locks/validation.cache.json` (untracked, machine-local),
+//! keyed by the profile name, the step command (with its `cwd`), the
+//! environment it runs with (`worktree_setup.env` overlaid with the step's own
+//! `env`), and the git tree id of the task worktree's HEAD. A task without a
+//! validation profile runs the legacy `build_command`, cached under
+//! [`BUILD_COMMAND_PROFILE`]. A later run of the same step
+//! on the same tree is reported as `cached-pass` instead of being rerun.
+//!
+//! Keying on the tree rather than the commit means a rebase that picks up
+//! upstream changes yields a new tree, so `burl approve` reruns every step
+//! against it. The cache is only used while the worktree has no uncommitted
+//! or untracked changes, since those are not part of the tree id.
+//!
+//! This cache is best-effort: a missing, corrupt, or outdated cache file is
+//! treated as empty, and failures to write it are ignored. `--no-cache`
+//! bypasses it entirely.
+
+use crate::config::ValidationCommandStep;
+use crate::fs::atomic_write_file;
+use crate::git::{has_worktree_changes, run_git};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+const CACHE_VERSION: u32 = 2;
+
+/// Profile key under which the legacy `build_command` step is cached.
+pub const BUILD_COMMAND_PROFILE: &str = "build_command";
+
+/// Oldest entries beyond this many are dropped on save.
+const MAX_ENTRIES: usize = 1000;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheFile {
+    version: u32,
+    entries: Vec<CacheEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct CacheEntry {
+    profile: String,
+    command: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cwd: Option<String>,
+    /// `worktree_setup.env` overlaid with the step's own `env`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    env: BTreeMap<String, String>,
+    tree: String,
+    passed_at: DateTime<Utc>,
+}
+
+impl CacheEntry {
+    /// Whether this entry records the same step run on the same tree.
+    fn same_run(&self, other: &CacheEntry) -> bool {
+        self.profile == other.profile
+            && self.command == other.command
+            && self.cwd == other.cwd
+            && self.env == other.env
+            && self.tree == other.tree
+    }
+}
+
+/// Cached step results for one profile run on one tree.
+#[derive(Debug)]
+pub struct StepCache {
+    /// Cache file location.
+    path: PathBuf,
+    profile: String,
+    /// Resolved `worktree_setup.env` layered under every step's env.
+    shared_env: BTreeMap<String, String>,
+    /// Tree id of the worktree's HEAD.
+    tree: String,
+    entries: Vec<CacheEntry>,
+    /// Passes recorded during this run, written back by [`StepCache::save`].
+    recorded: Vec<CacheEntry>,
+}
+
+impl StepCache {
+    /// Open the cache for running `profile` in `worktree` with the shared
+    /// step environment `shared_env`.
+    ///
+    /// Returns `None` if the worktree's HEAD tree cannot be determined or the
+    /// worktree has uncommitted or untracked changes.
+    pub fn open(
+        path: PathBuf,
+        profile: &str,
+        worktree: &Path,
+        shared_env: &BTreeMap<String, String>,
+    ) -> Option<Self> {
+        if has_worktree_changes(worktree).unwrap_or(true) {
+            return None;
+        }
+        let tree = run_git(worktree, &["rev-parse", "HEAD^{tree}"])
+            .ok()?
+            .stdout
+            .trim()
+            .to_string();
+        if tree.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            entries: load_entries(&path),
+            path,
+            profile: profile.to_string(),
+            shared_env: shared_env.clone(),
+            tree,
+            recorded: Vec::new(),
+        })
+    }
+
+    /// Whether `step` already passed on this tree.
+    pub fn has_passed(&self, step: &ValidationCommandStep) -> bool {
+        let entry = self.entry(step);
+        self.entries.iter().any(|e| e.same_run(&entry))
+    }
+
+    /// Record that `step` passed on this tree.
+    pub fn record_pass(&mut self, step: &ValidationCommandStep) {
+        let entry = self.entry(step);
+        if !self.has_passed(step) {
+            self.recorded.push(entry.clone());
+            self.entries.push(entry);
+        }
+    }
+
+    /// Merge the passes recorded during this run into the cache file.
+    ///
+    /// The file is re-read first so entries written by concurrent runs are
+    /// kept.
+    pub fn save(&self) {
+        if self.recorded.is_empty() {
+            return;
+        }
+        let mut entries = load_entries(&self.path);
+        for entry in &self.recorded {
+            if !entries.iter().any(|e| e.same_run(entry)) {
+                entries.push(entry.clone());
+            }
+        }
+        if entries.len() > MAX_ENTRIES {
+            entries.drain(..entries.len() - MAX_ENTRIES);
+        }
+
+        let file = CacheFile {
+            version: CACHE_VERSION,
+            entries,
+        };
+        if let Ok(json) = serde_json::to_string(&file) {
+            let _ = atomic_write_file(&self.path, &json);
+        }
+    }
+
+    fn entry(&self, step: &ValidationCommandStep) -> CacheEntry {
+        let mut env = self.shared_env.clone();
+        env.extend(step.env.clone());
+        CacheEntry {
+            profile: self.profile.clone(),
+            command: step.command.trim().to_string(),
+            cwd: step.cwd.clone(),
+            env,
+            tree: self.tree.clone(),
+            passed_at: Utc::now(),
+        }
+    }
+}
+
+/// Entries of the cache file at `path`, or none if it is missing or unreadable.
+fn load_entries(path: &Path) -> Vec<CacheEntry> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str::<CacheFile>(&content).ok())
+        .filter(|file| file.version == CACHE_VERSION)
+        .map(|file| file.entries)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::create_test_repo;
+
+    fn step(command: &str) -> ValidationCommandStep {
+        ValidationCommandStep {
+            name: "test".to_string(),
+            command: command.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_step_cache_keys_on_profile_command_and_tree() {
+        let temp_dir = create_test_repo();
+        let repo = temp_dir.path();
+        let cache_path = repo.join(".git/validation.cache.json");
+        let env = BTreeMap::new();
+
+        let mut cache = StepCache::open(cache_path.clone(), "ci", repo, &env).unwrap();
+        assert!(!cache.has_passed(&step("cargo test")));
+        cache.record_pass(&step("cargo test"));
+        cache.save();
+
+        let cache = StepCache::open(cache_path.clone(), "ci", repo, &env).unwrap();
+        assert!(cache.has_passed(&step("cargo test")));
+        assert!(!cache.has_passed(&step("cargo test --release")));
+        let mut in_subdir = step("cargo test");
+        in_subdir.cwd = Some("crates/core".to_string());
+        assert!(!cache.has_passed(&in_subdir));
+        let other_profile = StepCache::open(cache_path.clone(), "nightly", repo, &env).unwrap();
+        assert!(!other_profile.has_passed(&step("cargo test")));
+
+        // Uncommitted changes aren't part of the tree id.
+        std::fs::write(repo.join("README.md"), "changed\n").unwrap();
+        assert!(StepCache::open(cache_path.clone(), "ci", repo, &env).is_none());
+
+        // A new tree misses the cache.
+        run_git(repo, &["commit", "-am", "Change README"]).unwrap();
+        let cache = StepCache::open(cache_path, "ci", repo, &env).unwrap();
+        assert!(!cache.has_passed(&step("cargo test")));
+    }
+
+    #[test]
+    fn test_step_cache_keys_on_shared_env() {
+        let temp_dir = create_test_repo();
+        let repo = temp_dir.path();
+        let cache_path = repo.join(".git/validation.cache.json");
+        let env: BTreeMap<String, String> =
+            [("CARGO_TARGET_DIR".to_string(), "/shared/a".to_string())].into();
+
+        let mut cache = StepCache::open(cache_path.clone(), "ci", repo, &env).unwrap();
+        cache.record_pass(&step("cargo test"));
+        cache.save();
+        let cache = StepCache::open(cache_path.clone(), "ci", repo, &env).unwrap();
+        assert!(cache.has_passed(&step("cargo test")));
+
+        // A different worktree_setup.env misses the cache...
+        let other: BTreeMap<String, String> =
+            [("CARGO_TARGET_DIR".to_string(), "/shared/b".to_string())].into();
+        let cache = StepCache::open(cache_path, "ci", repo, &other).unwrap();
+        assert!(!cache.has_passed(&step("cargo test")));
+
+        // ...unless the step overrides the variable back to the same value.
+        let mut pinned = step("cargo test");
+        pinned.env = env.clone();
+        assert!(cache.has_passed(&pinned));
+    }
+}
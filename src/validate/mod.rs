@@ -4,14 +4,19 @@
 //! - Scope enforcement: ensures changes are within allowed paths
 //! - Stub detection: detects incomplete code patterns in added lines
 //! - Build validation: runs build/test commands (future)
+//! - Reports: JUnit XML and SARIF output for CI dashboards and code scanning
 
+pub mod cache;
 pub mod pipeline;
+pub mod reports;
 pub mod scope;
 pub mod stubs;
 
+pub use cache::{BUILD_COMMAND_PROFILE, StepCache};
 pub use pipeline::{
     ValidationStepResult, ValidationStepStatus, run_command_steps, should_run_step,
 };
+pub use reports::{junit_report, sarif_report};
 pub use scope::{ScopeValidationResult, ScopeViolation, ScopeViolationType, validate_scope};
 pub use stubs::{
     CompiledStubPatterns, StubValidationResult, StubViolation, validate_stubs,
@@ -1,12 +1,19 @@
 //! Validation command pipeline.
 //!
 //! Supports ordered command steps with optional conditions based on the set of
-//! changed files in the task diff.
+//! changed files in the task diff. Steps declaring `parallel_group` or `needs`
+//! run concurrently (up to a cap); results are always reported in step order.
 
+use super::cache::StepCache;
 use crate::config::ValidationCommandStep;
 use globset::{Glob, GlobSet, GlobSetBuilder};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
 use std::path::Path;
-use std::process::Command;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
 
 /// Maximum number of lines to include in QA Report summaries.
 pub const QA_REPORT_MAX_LINES: usize = 50;
@@ -15,19 +22,48 @@ pub const QA_REPORT_MAX_LINES: usize = 50;
 pub const QA_REPORT_MAX_CHARS: usize = 4096;
 
 /// Status of a validation step.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ValidationStepStatus {
     Pass,
     Fail,
     Skip,
+    /// Failed, but the step has `allow_failure` set.
+    Warn,
+    /// Passed earlier on the same tree (see [`super::cache`]); not rerun.
+    #[serde(rename = "cached-pass")]
+    CachedPass,
+}
+
+impl ValidationStepStatus {
+    /// Label used in text output and QA reports.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pass => "PASS",
+            Self::Fail => "FAIL",
+            Self::Skip => "SKIP",
+            Self::Warn => "WARN",
+            Self::CachedPass => "CACHED-PASS",
+        }
+    }
+
+    /// Whether the step passed, now or on an earlier run.
+    pub fn is_pass(&self) -> bool {
+        matches!(self, Self::Pass | Self::CachedPass)
+    }
 }
 
 /// Result of a single validation step.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ValidationStepResult {
     pub name: String,
     pub status: ValidationStepStatus,
     pub message: Option<String>,
+    /// The step hit its `timeout_seconds` and its process group was killed.
+    pub timed_out: bool,
+    /// Full command output, saved by [`crate::validation_logs`].
+    #[serde(skip)]
+    pub log: Option<String>,
 }
 
 impl ValidationStepResult {
@@ -36,6 +72,8 @@ impl ValidationStepResult {
             name: name.into(),
             status: ValidationStepStatus::Pass,
             message: None,
+            timed_out: false,
+            log: None,
         }
     }
 
@@ -44,6 +82,18 @@ impl ValidationStepResult {
             name: name.into(),
             status: ValidationStepStatus::Fail,
             message: Some(message.into()),
+            timed_out: false,
+            log: None,
+        }
+    }
+
+    pub fn cached_pass(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status: ValidationStepStatus::CachedPass,
+            message: None,
+            timed_out: false,
+            log: None,
         }
     }
 
@@ -52,14 +102,27 @@ impl ValidationStepResult {
             name: name.into(),
             status: ValidationStepStatus::Skip,
             message: Some(message.into()),
+            timed_out: false,
+            log: None,
         }
     }
 
     pub fn is_success(&self) -> bool {
-        matches!(
-            self.status,
-            ValidationStepStatus::Pass | ValidationStepStatus::Skip
-        )
+        self.status.is_pass()
+            || matches!(
+                self.status,
+                ValidationStepStatus::Skip | ValidationStepStatus::Warn
+            )
+    }
+
+    /// Status label for text output and QA reports (`TIMEOUT` for a failed
+    /// step that timed out).
+    pub fn label(&self) -> &'static str {
+        if self.timed_out && self.status == ValidationStepStatus::Fail {
+            "TIMEOUT"
+        } else {
+            self.status.as_str()
+        }
     }
 }
 
@@ -102,31 +165,201 @@ pub fn should_run_step(step: &ValidationCommandStep, changed_files: &[String]) -
     false
 }
 
-/// Run an ordered list of command steps in the given worktree.
+/// Compute the steps each step waits for.
+///
+/// - A step with `needs` waits for exactly the named steps.
+/// - A step in a `parallel_group` waits for every earlier step outside its group.
+/// - Any other step waits for every earlier step.
+///
+/// Returns an error for unknown `needs` entries and dependency cycles.
+pub fn step_dependencies(steps: &[ValidationCommandStep]) -> Result<Vec<Vec<usize>>, String> {
+    let mut deps = Vec::with_capacity(steps.len());
+    for (idx, step) in steps.iter().enumerate() {
+        if step.needs.is_empty() {
+            deps.push(
+                (0..idx)
+                    .filter(|&earlier| {
+                        step.parallel_group.is_none()
+                            || steps[earlier].parallel_group != step.parallel_group
+                    })
+                    .collect(),
+            );
+            continue;
+        }
+
+        let mut needed = Vec::new();
+        for need in &step.needs {
+            match steps.iter().position(|s| &s.name == need) {
+                Some(pos) if pos != idx => needed.push(pos),
+                Some(_) => return Err(format!("step '{}' needs itself", step.name)),
+                None => {
+                    return Err(format!(
+                        "step '{}' needs unknown step '{}'",
+                        step.name, need
+                    ));
+                }
+            }
+        }
+        deps.push(needed);
+    }
+
+    // Every step must be reachable in a topological order.
+    let mut done = vec![false; steps.len()];
+    let mut remaining = steps.len();
+    while remaining > 0 {
+        let ready: Vec<usize> = (0..steps.len())
+            .filter(|&idx| !done[idx] && deps[idx].iter().all(|&d| done[d]))
+            .collect();
+        if ready.is_empty() {
+            let stuck: Vec<&str> = (0..steps.len())
+                .filter(|&idx| !done[idx])
+                .map(|idx| steps[idx].name.as_str())
+                .collect();
+            return Err(format!(
+                "steps have a dependency cycle: {}",
+                stuck.join(", ")
+            ));
+        }
+        for idx in ready {
+            done[idx] = true;
+            remaining -= 1;
+        }
+    }
+
+    Ok(deps)
+}
+
+/// Run command steps in the given worktree, at most `max_parallel` at a time.
+///
+/// Steps start as soon as the steps they wait for (see [`step_dependencies`])
+/// have finished; a step whose `needs` did not pass is skipped. Results are
+/// returned in step order regardless of completion order. `env` is added to
+/// each command's environment (the config `worktree_setup.env`).
+///
+/// With a `cache`, steps that already passed on the worktree's tree are
+/// reported as cached passes without running, and new passes are recorded in
+/// it (the caller saves it).
 pub fn run_command_steps(
     steps: &[ValidationCommandStep],
     changed_files: &[String],
     worktree_path: &Path,
+    env: &BTreeMap<String, String>,
+    max_parallel: usize,
+    mut cache: Option<&mut StepCache>,
 ) -> Vec<ValidationStepResult> {
-    let mut results = Vec::new();
-
-    for step in steps {
-        if !should_run_step(step, changed_files) {
-            results.push(ValidationStepResult::skip(
-                &step.name,
-                "skipped (no matching changed files)",
-            ));
-            continue;
+    let deps = match step_dependencies(steps) {
+        Ok(deps) => deps,
+        Err(e) => {
+            return vec![ValidationStepResult::fail(
+                "validation",
+                format!("invalid validation steps: {}", e),
+            )];
         }
+    };
 
-        results.push(run_command_step(&step.name, &step.command, worktree_path));
-    }
+    let max_parallel = max_parallel.max(1);
+    let mut results: Vec<Option<ValidationStepResult>> = vec![None; steps.len()];
+    let mut started = vec![false; steps.len()];
+    let (tx, rx) = mpsc::channel();
+
+    std::thread::scope(|scope| {
+        let mut running = 0;
+        loop {
+            for (idx, step) in steps.iter().enumerate() {
+                if started[idx] || !deps[idx].iter().all(|&d| results[d].is_some()) {
+                    continue;
+                }
+
+                let failed_need = step
+                    .needs
+                    .iter()
+                    .zip(&deps[idx])
+                    .find(|&(_, &d)| results[d].as_ref().is_some_and(|r| !r.status.is_pass()));
+                if let Some((need, _)) = failed_need {
+                    started[idx] = true;
+                    results[idx] = Some(ValidationStepResult::skip(
+                        &step.name,
+                        format!("skipped (needed step '{}' did not pass)", need),
+                    ));
+                    continue;
+                }
+
+                if !should_run_step(step, changed_files) {
+                    started[idx] = true;
+                    results[idx] = Some(ValidationStepResult::skip(
+                        &step.name,
+                        "skipped (no matching changed files)",
+                    ));
+                    continue;
+                }
+
+                if cache.as_ref().is_some_and(|c| c.has_passed(step)) {
+                    started[idx] = true;
+                    results[idx] = Some(ValidationStepResult::cached_pass(&step.name));
+                    continue;
+                }
+
+                if running >= max_parallel {
+                    continue;
+                }
+                started[idx] = true;
+                running += 1;
+                let tx = tx.clone();
+                scope.spawn(move || {
+                    let result = run_command_step(step, worktree_path, env);
+                    let _ = tx.send((idx, result));
+                });
+            }
+
+            if running == 0 {
+                // Skips above may have unblocked further steps.
+                if started.iter().all(|&s| s) {
+                    break;
+                }
+                continue;
+            }
+
+            let Ok((idx, result)) = rx.recv() else { break };
+            if let Some(cache) = cache.as_deref_mut()
+                && result.status == ValidationStepStatus::Pass
+            {
+                cache.record_pass(&steps[idx]);
+            }
+            results[idx] = Some(result);
+            running -= 1;
+        }
+    });
 
     results
+        .into_iter()
+        .zip(steps)
+        .map(|(result, step)| {
+            result.unwrap_or_else(|| ValidationStepResult::fail(&step.name, "step did not run"))
+        })
+        .collect()
+}
+
+/// Run one step, honoring its `cwd`, `env`, `timeout_seconds`, and
+/// `allow_failure` settings.
+fn run_command_step(
+    step: &ValidationCommandStep,
+    worktree_path: &Path,
+    env: &BTreeMap<String, String>,
+) -> ValidationStepResult {
+    let mut result = run_step_command(step, worktree_path, env);
+    if step.allow_failure && result.status == ValidationStepStatus::Fail {
+        result.status = ValidationStepStatus::Warn;
+    }
+    result
 }
 
-fn run_command_step(name: &str, command: &str, worktree_path: &Path) -> ValidationStepResult {
-    let command = command.trim();
+fn run_step_command(
+    step: &ValidationCommandStep,
+    worktree_path: &Path,
+    env: &BTreeMap<String, String>,
+) -> ValidationStepResult {
+    let name = step.name.as_str();
+    let command = step.command.trim();
     if command.is_empty() {
         return ValidationStepResult::fail(name, "command is empty");
     }
@@ -151,14 +384,34 @@ fn run_command_step(name: &str, command: &str, worktree_path: &Path) -> Validati
         );
     }
 
+    let cwd = match &step.cwd {
+        Some(cwd) => worktree_path.join(cwd),
+        None => worktree_path.to_path_buf(),
+    };
+    if !cwd.is_dir() {
+        return ValidationStepResult::fail(
+            name,
+            format!(
+                "working directory '{}' does not exist.\nCommand: {}\nFix: check the step's `cwd` (relative to the task worktree).",
+                cwd.display(),
+                command
+            ),
+        );
+    }
+
     let program = &args[0];
     let cmd_args = &args[1..];
 
-    let output = match Command::new(program)
-        .args(cmd_args)
-        .current_dir(worktree_path)
-        .output()
-    {
+    let mut cmd = Command::new(program);
+    cmd.args(cmd_args)
+        .current_dir(&cwd)
+        .envs(env)
+        .envs(&step.env)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let timeout = step.timeout_seconds.map(Duration::from_secs);
+    let output = match run_with_timeout(&mut cmd, None, timeout) {
         Ok(output) => output,
         Err(e) => {
             return ValidationStepResult::fail(
@@ -171,13 +424,30 @@ fn run_command_step(name: &str, command: &str, worktree_path: &Path) -> Validati
         }
     };
 
-    if output.status.success() {
-        return ValidationStepResult::pass(name);
-    }
-
     let stdout = String::from_utf8_lossy(&output.stdout).to_string();
     let stderr = String::from_utf8_lossy(&output.stderr).to_string();
-    let exit_code = output.status.code().unwrap_or(-1);
+
+    let outcome = match (output.status, timeout) {
+        (None, Some(timeout)) => format!(
+            "timed out after {}s (process group killed)",
+            timeout.as_secs()
+        ),
+        (status, _) => format!("exit code {}", status.and_then(|s| s.code()).unwrap_or(-1)),
+    };
+    let log = format!(
+        "$ {}\ncwd: {}\nresult: {}\n\n--- stdout ---\n{}\n--- stderr ---\n{}",
+        command,
+        cwd.display(),
+        outcome,
+        stdout,
+        stderr
+    );
+
+    if output.status.is_some_and(|s| s.success()) {
+        let mut result = ValidationStepResult::pass(name);
+        result.log = Some(log);
+        return result;
+    }
 
     let combined = if !stderr.is_empty() {
         format!("{}\n{}", stdout, stderr)
@@ -185,10 +455,10 @@ fn run_command_step(name: &str, command: &str, worktree_path: &Path) -> Validati
         stdout
     };
 
-    let mut msg = format!(
-        "Command failed with exit code {}\nCommand: {}\n",
-        exit_code, command
-    );
+    let mut msg = match output.status {
+        None => format!("Command {}\nCommand: {}\n", outcome, command),
+        Some(_) => format!("Command failed with {}\nCommand: {}\n", outcome, command),
+    };
     let truncated = truncate_output(&combined, QA_REPORT_MAX_LINES, QA_REPORT_MAX_CHARS);
     if !truncated.is_empty() {
         msg.push_str("\nOutput (truncated):\n```\n");
@@ -196,7 +466,104 @@ fn run_command_step(name: &str, command: &str, worktree_path: &Path) -> Validati
         msg.push_str("\n```\n");
     }
 
-    ValidationStepResult::fail(name, msg)
+    let mut result = ValidationStepResult::fail(name, msg);
+    result.timed_out = output.status.is_none();
+    result.log = Some(log);
+    result
+}
+
+/// Captured output of a step (or hook) command.
+pub(crate) struct StepOutput {
+    /// Exit status, or `None` if the command timed out and was killed.
+    pub status: Option<ExitStatus>,
+    pub stdout: Vec<u8>,
+    pub stderr: Vec<u8>,
+}
+
+/// Spawn `cmd` in its own process group, feed it `input` on stdin (which the
+/// caller must have piped), and wait for it, killing the whole group after
+/// `timeout`.
+pub(crate) fn run_with_timeout(
+    cmd: &mut Command,
+    input: Option<String>,
+    timeout: Option<Duration>,
+) -> std::io::Result<StepOutput> {
+    // Run the command in its own process group so a timeout can kill
+    // everything it spawned, not just the direct child.
+    #[cfg(unix)]
+    std::os::unix::process::CommandExt::process_group(cmd, 0);
+    let mut child = cmd.spawn()?;
+
+    // Write stdin from a separate thread so a command that produces lots of
+    // output before reading its input cannot deadlock. Commands that ignore
+    // stdin may close it early; the resulting write error is harmless.
+    let writer = input.zip(child.stdin.take()).map(|(input, mut pipe)| {
+        std::thread::spawn(move || {
+            let _ = pipe.write_all(input.as_bytes());
+        })
+    });
+
+    // Drain both pipes while waiting so a chatty command can't block on a
+    // full pipe.
+    let stdout = child.stdout.take().map(spawn_reader);
+    let stderr = child.stderr.take().map(spawn_reader);
+
+    let start = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break Some(status);
+        }
+        if timeout.is_some_and(|t| start.elapsed() >= t) {
+            kill_process_group(&mut child);
+            break None;
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    };
+
+    if let Some(writer) = writer {
+        let _ = writer.join();
+    }
+    let join = |reader: Option<std::thread::JoinHandle<Vec<u8>>>| {
+        reader.and_then(|r| r.join().ok()).unwrap_or_default()
+    };
+    Ok(StepOutput {
+        status,
+        stdout: join(stdout),
+        stderr: join(stderr),
+    })
+}
+
+fn spawn_reader<R: Read + Send + 'static>(mut pipe: R) -> std::thread::JoinHandle<Vec<u8>> {
+    std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        let _ = pipe.read_to_end(&mut buf);
+        buf
+    })
+}
+
+/// Kill a timed-out step and everything it started.
+#[cfg(unix)]
+fn kill_process_group(child: &mut Child) {
+    // The child leads its own process group (see `run_with_timeout`).
+    let _ = Command::new("kill")
+        .args(["-KILL", "--", &format!("-{}", child.id())])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status();
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+/// Kill a timed-out step and everything it started.
+#[cfg(windows)]
+fn kill_process_group(child: &mut Child) {
+    let _ = Command::new("taskkill")
+        .args(["/T", "/F", "/PID", &child.id().to_string()])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status();
+    let _ = child.kill();
+    let _ = child.wait();
 }
 
 fn truncate_output(output: &str, max_lines: usize, max_chars: usize) -> String {
@@ -285,11 +652,160 @@ mod tests {
     fn test_run_command_step_pass_and_fail() {
         let temp = TempDir::new().unwrap();
 
-        let pass = run_command_step("pass", "git --version", temp.path());
+        let env = BTreeMap::new();
+        let pass = run_command_step(&step("pass", "git --version"), temp.path(), &env);
         assert_eq!(pass.status, ValidationStepStatus::Pass);
 
-        let fail = run_command_step("fail", "git definitely-not-a-command", temp.path());
+        let fail = run_command_step(
+            &step("fail", "git definitely-not-a-command"),
+            temp.path(),
+            &env,
+        );
         assert_eq!(fail.status, ValidationStepStatus::Fail);
+        assert!(!fail.timed_out);
         assert!(fail.message.unwrap_or_default().contains("exit code"));
     }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_run_command_step_cwd_env_and_allow_failure() {
+        let temp = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp.path().join("web")).unwrap();
+
+        let mut check = step(
+            "web",
+            "sh -c 'test \"$(basename \"$PWD\")\" = web && test \"$SHARED-$LOCAL\" = base-step'",
+        );
+        check.cwd = Some("web".to_string());
+        check.env = [("LOCAL".to_string(), "step".to_string())].into();
+        let env = [
+            ("SHARED".to_string(), "base".to_string()),
+            ("LOCAL".to_string(), "base".to_string()),
+        ]
+        .into();
+        assert_eq!(
+            run_command_step(&check, temp.path(), &env).status,
+            ValidationStepStatus::Pass
+        );
+
+        check.cwd = Some("missing".to_string());
+        let missing = run_command_step(&check, temp.path(), &env);
+        assert_eq!(missing.status, ValidationStepStatus::Fail);
+        assert!(missing.message.unwrap().contains("does not exist"));
+
+        let mut flaky = step("flaky", "sh -c 'exit 1'");
+        flaky.allow_failure = true;
+        let warn = run_command_step(&flaky, temp.path(), &env);
+        assert_eq!(warn.status, ValidationStepStatus::Warn);
+        assert_eq!(warn.label(), "WARN");
+        assert!(warn.is_success());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_run_command_step_timeout_kills_process_group() {
+        let temp = TempDir::new().unwrap();
+        // The background sleep would keep the output pipes open (and the step
+        // hanging) if only the direct child were killed.
+        let mut hung = step(
+            "hung",
+            "sh -c 'echo started; (sleep 30; touch survived) & sleep 30'",
+        );
+        hung.timeout_seconds = Some(1);
+
+        let start = Instant::now();
+        let result = run_command_step(&hung, temp.path(), &BTreeMap::new());
+        assert!(start.elapsed() < Duration::from_secs(10));
+        assert_eq!(result.status, ValidationStepStatus::Fail);
+        assert!(result.timed_out);
+        assert_eq!(result.label(), "TIMEOUT");
+        let message = result.message.unwrap();
+        assert!(message.contains("timed out after 1s"));
+        assert!(message.contains("started"));
+        assert!(!temp.path().join("survived").exists());
+    }
+
+    fn step(name: &str, command: &str) -> ValidationCommandStep {
+        ValidationCommandStep {
+            name: name.to_string(),
+            command: command.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_step_dependencies() {
+        let mut steps = vec![
+            step("build", "true"),
+            step("lint", "true"),
+            step("test", "true"),
+            step("report", "true"),
+        ];
+        assert_eq!(
+            step_dependencies(&steps).unwrap(),
+            vec![vec![], vec![0], vec![0, 1], vec![0, 1, 2]]
+        );
+
+        steps[1].parallel_group = Some("checks".to_string());
+        steps[2].parallel_group = Some("checks".to_string());
+        steps[3].needs = vec!["test".to_string()];
+        assert_eq!(
+            step_dependencies(&steps).unwrap(),
+            vec![vec![], vec![0], vec![0], vec![2]]
+        );
+
+        steps[0].needs = vec!["report".to_string()];
+        assert!(step_dependencies(&steps).unwrap_err().contains("cycle"));
+        steps[0].needs = vec!["missing".to_string()];
+        assert!(
+            step_dependencies(&steps)
+                .unwrap_err()
+                .contains("unknown step 'missing'")
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_run_command_steps_in_parallel() {
+        let temp = TempDir::new().unwrap();
+        // Each grouped step only passes if the other one is running at the same time.
+        let wait_for = |own: &str, other: &str| {
+            format!(
+                "sh -c 'touch {}; for i in $(seq 100); do [ -f {} ] && exit 0; sleep 0.05; done; exit 1'",
+                own, other
+            )
+        };
+        let mut steps = vec![
+            step("a", &wait_for("a", "b")),
+            step("b", &wait_for("b", "a")),
+            step("fails", "sh -c 'exit 1'"),
+            step("after-fails", "true"),
+            step("last", "true"),
+        ];
+        steps[0].parallel_group = Some("checks".to_string());
+        steps[1].parallel_group = Some("checks".to_string());
+        steps[3].needs = vec!["fails".to_string()];
+
+        let results = run_command_steps(&steps, &[], temp.path(), &BTreeMap::new(), 4, None);
+        let names: Vec<&str> = results.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["a", "b", "fails", "after-fails", "last"]);
+        let statuses: Vec<ValidationStepStatus> = results.iter().map(|r| r.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ValidationStepStatus::Pass,
+                ValidationStepStatus::Pass,
+                ValidationStepStatus::Fail,
+                ValidationStepStatus::Skip,
+                ValidationStepStatus::Pass,
+            ]
+        );
+        assert!(
+            results[3]
+                .message
+                .as_deref()
+                .unwrap()
+                .contains("needed step 'fails'")
+        );
+    }
 }
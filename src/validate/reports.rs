@@ -0,0 +1,301 @@
+//! Machine-readable validation reports.
+//!
+//! `burl validate --report-junit <path>` writes every gate (scope, stubs, and
+//! each profile step) as a JUnit test case, for CI dashboards.
+//! `--report-sarif <path>` writes every scope and stub violation as a SARIF
+//! 2.1.0 result, for code-scanning UIs. Stub results point at the added line
+//! that matched; scope results point at the whole file.
+
+use super::pipeline::{ValidationStepResult, ValidationStepStatus};
+use super::scope::{ScopeViolation, ScopeViolationType};
+use super::stubs::StubViolation;
+use serde_json::{Value, json};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// SARIF rules: (id, name, description).
+const RULES: [(&str, &str, &str); 3] = [
+    (
+        "burl/scope/forbidden",
+        "ForbiddenPath",
+        "Changed file matches a must_not_touch pattern (scope rule S1).",
+    ),
+    (
+        "burl/scope/out-of-scope",
+        "OutOfScope",
+        "Changed file is not covered by affects/affects_globs (scope rule S2).",
+    ),
+    (
+        "burl/stubs",
+        "StubPattern",
+        "Added line matches a stub pattern.",
+    ),
+];
+
+/// JUnit XML for one validation run of `task_id`.
+///
+/// Failed steps become `<failure>` (type `timeout` for timed-out steps),
+/// skipped steps `<skipped>`; warnings and cached passes pass, with the
+/// details in `<system-out>`.
+pub fn junit_report(task_id: &str, results: &[ValidationStepResult]) -> String {
+    let failures = results.iter().filter(|r| !r.is_success()).count();
+    let skipped = results
+        .iter()
+        .filter(|r| r.status == ValidationStepStatus::Skip)
+        .count();
+    let counts = format!(
+        "tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"{}\"",
+        results.len(),
+        failures,
+        skipped
+    );
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!("<testsuites name=\"burl validate\" {}>\n", counts));
+    xml.push_str(&format!(
+        "  <testsuite name=\"{}\" {}>\n",
+        xml_escape(task_id),
+        counts
+    ));
+
+    for result in results {
+        let open = format!(
+            "    <testcase classname=\"{}\" name=\"{}\"",
+            xml_escape(task_id),
+            xml_escape(&result.name)
+        );
+        let message = result.message.as_deref().unwrap_or_default();
+        let summary = xml_escape(message.lines().next().unwrap_or_default());
+
+        match result.status {
+            ValidationStepStatus::Pass if message.is_empty() => {
+                xml.push_str(&open);
+                xml.push_str("/>\n");
+                continue;
+            }
+            ValidationStepStatus::Fail => {
+                let kind = if result.timed_out {
+                    "timeout"
+                } else {
+                    "failure"
+                };
+                xml.push_str(&format!(
+                    "{}>\n      <failure type=\"{}\" message=\"{}\">{}</failure>\n",
+                    open,
+                    kind,
+                    summary,
+                    xml_escape(message)
+                ));
+            }
+            ValidationStepStatus::Skip => {
+                xml.push_str(&format!(
+                    "{}>\n      <skipped message=\"{}\"/>\n",
+                    open, summary
+                ));
+            }
+            ValidationStepStatus::CachedPass => {
+                xml.push_str(&format!(
+                    "{}>\n      <system-out>cached pass: passed earlier on the same tree</system-out>\n",
+                    open
+                ));
+            }
+            ValidationStepStatus::Pass | ValidationStepStatus::Warn => {
+                xml.push_str(&format!(
+                    "{}>\n      <system-out>{}: {}</system-out>\n",
+                    open,
+                    result.label(),
+                    xml_escape(message)
+                ));
+            }
+        }
+        xml.push_str("    </testcase>\n");
+    }
+
+    xml.push_str("  </testsuite>\n</testsuites>\n");
+    xml
+}
+
+/// SARIF 2.1.0 log with one result per scope and stub violation.
+pub fn sarif_report(
+    task_id: &str,
+    scope_violations: &[ScopeViolation],
+    stub_violations: &[StubViolation],
+) -> Value {
+    let mut results: Vec<Value> = Vec::new();
+
+    for violation in scope_violations {
+        let (rule, text) = match violation.violation_type {
+            ScopeViolationType::Forbidden => (
+                RULES[0].0,
+                format!(
+                    "{} matches must_not_touch pattern '{}'.",
+                    violation.file_path,
+                    violation.matched_pattern.as_deref().unwrap_or("<unknown>")
+                ),
+            ),
+            ScopeViolationType::OutOfScope => (
+                RULES[1].0,
+                format!(
+                    "{} is outside the task scope (affects/affects_globs).",
+                    violation.file_path
+                ),
+            ),
+        };
+        results.push(json!({
+            "ruleId": rule,
+            "level": "error",
+            "message": { "text": text },
+            "locations": [{
+                "physicalLocation": {
+                    "artifactLocation": { "uri": violation.file_path },
+                },
+            }],
+        }));
+    }
+
+    for violation in stub_violations {
+        results.push(json!({
+            "ruleId": RULES[2].0,
+            "level": "error",
+            "message": {
+                "text": format!(
+                    "Added line matches stub pattern '{}': {}",
+                    violation.matched_pattern,
+                    violation.content.trim()
+                ),
+            },
+            "locations": [{
+                "physicalLocation": {
+                    "artifactLocation": { "uri": violation.file_path },
+                    "region": { "startLine": violation.line_number },
+                },
+            }],
+        }));
+    }
+
+    let rules: Vec<Value> = RULES
+        .iter()
+        .map(|(id, name, description)| {
+            json!({
+                "id": id,
+                "name": name,
+                "shortDescription": { "text": description },
+            })
+        })
+        .collect();
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "burl",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules,
+                },
+            },
+            "automationDetails": { "id": format!("burl-validate/{}/", task_id) },
+            "results": results,
+        }],
+    })
+}
+
+/// Escape text for XML attributes and content, dropping characters XML 1.0
+/// cannot represent (e.g. terminal escape codes in command output).
+fn xml_escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            '\t' | '\n' | '\r' => out.push(c),
+            c if c < ' ' => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_junit_report() {
+        let mut timed_out = ValidationStepResult::fail("e2e", "Command timed out after 5s");
+        timed_out.timed_out = true;
+        let results = vec![
+            ValidationStepResult::pass("scope"),
+            ValidationStepResult::fail("stubs", "Stub patterns found\n  src/a.rs:3 <todo!()>"),
+            ValidationStepResult::skip("docs", "skipped (no matching changed files)"),
+            ValidationStepResult::cached_pass("test"),
+            timed_out,
+        ];
+
+        let xml = junit_report("TASK-001", &results);
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n"));
+        assert!(xml.contains(
+            "<testsuite name=\"TASK-001\" tests=\"5\" failures=\"2\" errors=\"0\" skipped=\"1\">"
+        ));
+        assert!(xml.contains("<testcase classname=\"TASK-001\" name=\"scope\"/>"));
+        assert!(xml.contains(
+            "<failure type=\"failure\" message=\"Stub patterns found\">Stub patterns found\n  src/a.rs:3 &lt;todo!()&gt;</failure>"
+        ));
+        assert!(xml.contains("<skipped message=\"skipped (no matching changed files)\"/>"));
+        assert!(xml.contains("<system-out>cached pass"));
+        assert!(xml.contains("<failure type=\"timeout\""));
+    }
+
+    #[test]
+    fn test_sarif_report() {
+        let sarif = sarif_report(
+            "TASK-001",
+            &[
+                ScopeViolation::forbidden("src/enemy/ai.rs", "src/enemy/**"),
+                ScopeViolation::out_of_scope("README.md"),
+            ],
+            &[StubViolation::new(
+                "src/player/jump.rs",
+                67,
+                "    unimplemented!()",
+                "unimplemented!",
+            )],
+        );
+
+        assert_eq!(sarif["version"], "2.1.0");
+        let run = &sarif["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], "burl");
+        assert_eq!(run["tool"]["driver"]["rules"].as_array().unwrap().len(), 3);
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0]["ruleId"], "burl/scope/forbidden");
+        assert_eq!(
+            results[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "src/enemy/ai.rs"
+        );
+        assert_eq!(results[1]["ruleId"], "burl/scope/out-of-scope");
+        assert_eq!(results[2]["ruleId"], "burl/stubs");
+        let location = &results[2]["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/player/jump.rs");
+        assert_eq!(location["region"]["startLine"], 67);
+        assert!(
+            results[2]["message"]["text"]
+                .as_str()
+                .unwrap()
+                .ends_with(": unimplemented!()")
+        );
+    }
+
+    #[test]
+    fn test_xml_escape() {
+        assert_eq!(
+            xml_escape("a < b && \"c\"\u{1b}[0m"),
+            "a &lt; b &amp;&amp; &quot;c&quot;[0m"
+        );
+    }
+}
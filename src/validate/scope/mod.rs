@@ -9,9 +9,11 @@
 use crate::error::{BurlError, Result};
 use crate::task::TaskFrontmatter;
 use globset::{Glob, GlobSet, GlobSetBuilder};
+use serde::Serialize;
 
 /// Type of scope violation.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ScopeViolationType {
     /// File matches a `must_not_touch` pattern (Rule S1).
     Forbidden,
@@ -20,7 +22,7 @@ pub enum ScopeViolationType {
 }
 
 /// A single scope violation.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct ScopeViolation {
     /// The file path that violated scope rules (repo-relative, forward slashes).
     pub file_path: String,
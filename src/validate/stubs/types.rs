@@ -1,7 +1,9 @@
 //! Core types for stub validation results and violations.
 
+use serde::Serialize;
+
 /// A single stub violation found in an added line.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct StubViolation {
     /// Repository-relative file path (forward slashes).
     pub file_path: String,
@@ -0,0 +1,226 @@
+//! Full validation output, kept per run.
+//!
+//! QA report entries only keep a truncated tail of each failing step's output.
+//! Every validation run (`burl validate`, `burl approve`) also saves each
+//! command step's complete stdout/stderr to
+//! `.burl/.workflow/validation-logs/<TASK-ID>/<attempt>/<NN>-<step>.log`,
+//! where attempts are numbered 1, 2, ... per task and `NN` numbers the logged
+//! steps of a run in order. The directory is untracked, like
+//! `agent-logs/`, and `burl logs <TASK-ID> --validation` prints it.
+
+use crate::context::WorkflowContext;
+use crate::error::{BurlError, Result};
+use crate::fs::atomic_write_file;
+use crate::validate::ValidationStepResult;
+use std::path::{Path, PathBuf};
+
+/// Save the logs of `results` as the task's next attempt.
+///
+/// Returns the attempt number, or `None` if no step produced output (e.g.
+/// only scope and stub checks ran).
+pub fn save_validation_logs(
+    ctx: &WorkflowContext,
+    task_id: &str,
+    results: &[ValidationStepResult],
+) -> Result<Option<u32>> {
+    if results.iter().all(|r| r.log.is_none()) {
+        return Ok(None);
+    }
+
+    ensure_untracked(&ctx.validation_logs_dir())?;
+    let attempt = attempts(ctx, task_id).last().map_or(1, |last| last + 1);
+    let dir = attempt_dir(ctx, task_id, attempt);
+    create_dir(&dir)?;
+
+    let logs = results
+        .iter()
+        .filter_map(|r| Some((&r.name, r.log.as_ref()?)));
+    for (idx, (name, log)) in logs.enumerate() {
+        atomic_write_file(dir.join(log_file_name(idx + 1, name)), log)?;
+    }
+
+    Ok(Some(attempt))
+}
+
+/// Save the logs of `results`, printing a warning instead of failing, and
+/// return the QA report line pointing at them (empty if nothing was saved).
+pub fn save_and_link(
+    ctx: &WorkflowContext,
+    task_id: &str,
+    results: &[ValidationStepResult],
+) -> String {
+    match save_validation_logs(ctx, task_id, results) {
+        Ok(Some(attempt)) => qa_report_line(ctx, task_id, attempt),
+        Ok(None) => String::new(),
+        Err(e) => {
+            eprintln!(
+                "Warning: failed to save validation logs for {}: {}",
+                task_id, e
+            );
+            String::new()
+        }
+    }
+}
+
+/// QA report line pointing at an attempt's logs.
+pub fn qa_report_line(ctx: &WorkflowContext, task_id: &str, attempt: u32) -> String {
+    let dir = attempt_dir(ctx, task_id, attempt);
+    let dir = dir.strip_prefix(&ctx.repo_root).unwrap_or(&dir);
+    format!(
+        "\n**Full logs:** `{}/` (`burl logs {} --validation --attempt {}`)\n",
+        dir.to_string_lossy().replace('\\', "/"),
+        task_id,
+        attempt
+    )
+}
+
+/// Attempt numbers with saved logs for a task, in ascending order.
+pub fn attempts(ctx: &WorkflowContext, task_id: &str) -> Vec<u32> {
+    let Ok(entries) = std::fs::read_dir(ctx.task_validation_logs_dir(task_id)) else {
+        return Vec::new();
+    };
+    let mut attempts: Vec<u32> = entries
+        .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok())
+        .collect();
+    attempts.sort_unstable();
+    attempts
+}
+
+/// Directory holding one attempt's logs.
+pub fn attempt_dir(ctx: &WorkflowContext, task_id: &str, attempt: u32) -> PathBuf {
+    ctx.task_validation_logs_dir(task_id)
+        .join(attempt.to_string())
+}
+
+/// Log file name for the `index`th logged step of a run (`2`, `build/test`
+/// -> `02-build-test.log`).
+///
+/// The index keeps names unique when two step names sanitize alike (`build/test`
+/// and `build-test`) and lists an attempt's logs in step order.
+pub fn log_file_name(index: usize, step: &str) -> String {
+    format!("{:02}-{}.log", index, sanitize_step_name(step))
+}
+
+/// A step name with characters unsafe in file names replaced by `-`.
+pub fn sanitize_step_name(step: &str) -> String {
+    let name: String = step
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    name.trim_start_matches('.').to_string()
+}
+
+/// The sanitized step name of a log file (`02-build-test.log` -> `build-test`).
+pub fn log_step_name(path: &Path) -> Option<&str> {
+    let stem = path.file_stem()?.to_str()?;
+    let (index, step) = stem.split_once('-')?;
+    (!index.is_empty() && index.bytes().all(|b| b.is_ascii_digit())).then_some(step)
+}
+
+/// Create the logs directory with a `.gitignore` that ignores everything in
+/// it, so logs stay out of workflow commits even if the workflow's own
+/// `.gitignore` predates `validation-logs/`.
+fn ensure_untracked(dir: &Path) -> Result<()> {
+    create_dir(dir)?;
+    let gitignore = dir.join(".gitignore");
+    if !gitignore.exists() {
+        atomic_write_file(&gitignore, "*\n")?;
+    }
+    Ok(())
+}
+
+fn create_dir(dir: &Path) -> Result<()> {
+    std::fs::create_dir_all(dir).map_err(|e| {
+        BurlError::UserError(format!(
+            "failed to create validation logs directory '{}': {}",
+            dir.display(),
+            e
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::init::cmd_init;
+    use crate::context::require_initialized_workflow;
+    use crate::git::has_worktree_changes;
+    use crate::test_support::{DirGuard, create_test_repo};
+    use serial_test::serial;
+
+    #[test]
+    #[serial]
+    fn test_save_validation_logs_numbers_attempts() {
+        let temp_dir = create_test_repo();
+        let _guard = DirGuard::new(temp_dir.path());
+        cmd_init().unwrap();
+        let ctx = require_initialized_workflow().unwrap();
+
+        let mut build = ValidationStepResult::fail("build/test", "Command failed");
+        build.log = Some("full output\n".to_string());
+        let results = vec![ValidationStepResult::pass("scope"), build];
+
+        assert_eq!(
+            save_validation_logs(&ctx, "TASK-001", &results[..1]).unwrap(),
+            None
+        );
+        assert_eq!(
+            save_validation_logs(&ctx, "TASK-001", &results).unwrap(),
+            Some(1)
+        );
+        assert_eq!(
+            save_validation_logs(&ctx, "TASK-001", &results).unwrap(),
+            Some(2)
+        );
+        assert_eq!(attempts(&ctx, "TASK-001"), vec![1, 2]);
+        assert_eq!(
+            std::fs::read_to_string(attempt_dir(&ctx, "TASK-001", 2).join("01-build-test.log"))
+                .unwrap(),
+            "full output\n"
+        );
+        assert!(!has_worktree_changes(&ctx.workflow_worktree).unwrap());
+
+        assert_eq!(
+            qa_report_line(&ctx, "TASK-001", 2),
+            "\n**Full logs:** `.burl/.workflow/validation-logs/TASK-001/2/` (`burl logs TASK-001 --validation --attempt 2`)\n"
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_save_validation_logs_keeps_colliding_step_names_apart() {
+        let temp_dir = create_test_repo();
+        let _guard = DirGuard::new(temp_dir.path());
+        cmd_init().unwrap();
+        let ctx = require_initialized_workflow().unwrap();
+
+        let mut slash = ValidationStepResult::pass("build/test");
+        slash.log = Some("slash\n".to_string());
+        let mut dash = ValidationStepResult::pass("build-test");
+        dash.log = Some("dash\n".to_string());
+        save_validation_logs(&ctx, "TASK-001", &[slash, dash]).unwrap();
+
+        let dir = attempt_dir(&ctx, "TASK-001", 1);
+        let read = |name: &str| std::fs::read_to_string(dir.join(name)).unwrap();
+        assert_eq!(read("01-build-test.log"), "slash\n");
+        assert_eq!(read("02-build-test.log"), "dash\n");
+    }
+
+    #[test]
+    fn test_log_file_name() {
+        assert_eq!(log_file_name(1, "cargo test"), "01-cargo-test.log");
+        assert_eq!(log_file_name(2, "build/test"), "02-build-test.log");
+        assert_eq!(log_file_name(12, "../x"), "12--x.log");
+        assert_eq!(
+            log_step_name(Path::new("02-build-test.log")),
+            Some("build-test")
+        );
+        assert_eq!(log_step_name(Path::new("build-test.log")), None);
+    }
+}
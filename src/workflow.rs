@@ -1,30 +1,46 @@
 //! Workflow operations and task index for burl.
 //!
 //! This module provides:
-//! - Task index: enumerate buckets and map task IDs to file paths
+//! - Task index: enumerate buckets and map task IDs to file paths, falling
+//!   back to the `ARCHIVE/YYYY-MM/` directories on demand
+//! - Cached task frontmatter (see [`crate::index_cache`])
 //! - Bucket operations: list tasks, find tasks, move tasks between buckets
 //! - Task ID validation and generation
 //! - Title slugification for task filenames
 
+use crate::config::Config;
 use crate::context::WorkflowContext;
 use crate::error::{BurlError, Result};
+use crate::index_cache::TaskCache;
+use crate::task::{TaskFile, TaskFrontmatter};
 use regex::Regex;
-use std::collections::HashMap;
+use std::cell::{OnceCell, RefCell};
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
 
-/// All workflow buckets in order.
+/// Built-in workflow buckets in order. Workflows may add more through
+/// `states.buckets` in config.yaml (see [`TaskIndex::buckets`]).
 pub const BUCKETS: &[&str] = &["READY", "DOING", "QA", "DONE", "BLOCKED"];
 
-/// Regex pattern for valid task IDs.
+/// Directory (next to the buckets) holding archived DONE tasks in `YYYY-MM/`
+/// subdirectories.
+pub const ARCHIVE_DIR: &str = "ARCHIVE";
+
+/// Regex pattern for valid task IDs: an uppercase prefix (see the
+/// `id_prefixes` config) and a number of at least 3 digits.
 static TASK_ID_REGEX: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^TASK-\d{3,}$").expect("Invalid task ID regex"));
+    LazyLock::new(|| Regex::new(r"^[A-Z][A-Z0-9]*-\d{3,}$").expect("Invalid task ID regex"));
+
+/// Ordering key for task IDs: prefix, then number, so `BUG-002` sorts before
+/// `TASK-001` and `TASK-009` before `TASK-010`.
+pub type TaskKey = (String, u32);
 
 /// Information about a task in the workflow.
 #[derive(Debug, Clone)]
 pub struct TaskInfo {
-    /// The task ID (e.g., "TASK-001").
+    /// The task ID (e.g., "TASK-001", "BUG-042").
     pub id: String,
 
     /// The bucket the task is in (e.g., "READY", "DOING").
@@ -35,153 +51,338 @@ pub struct TaskInfo {
 
     /// The numeric part of the task ID.
     pub number: u32,
+
+    /// Whether the task lives in the archive. Archived tasks report the
+    /// `DONE` bucket.
+    pub archived: bool,
 }
 
-/// Index of all tasks in the workflow.
+/// Tasks found in one or more task directories.
 #[derive(Debug, Default)]
-pub struct TaskIndex {
+struct TaskSet {
     /// Map of task ID to task info.
     tasks: HashMap<String, TaskInfo>,
 
-    /// Maximum task number seen (for generating new IDs).
-    max_number: u32,
+    /// Maximum task number seen per ID prefix (for generating new IDs).
+    max_numbers: HashMap<String, u32>,
+}
+
+impl TaskSet {
+    /// Add every task file in `dir` to the set.
+    fn scan(&mut self, dir: &Path, bucket: &str, archived: bool) -> Result<()> {
+        let entries = fs::read_dir(dir).map_err(|e| {
+            BurlError::UserError(format!(
+                "failed to read bucket directory '{}': {}",
+                dir.display(),
+                e
+            ))
+        })?;
+
+        for entry in entries {
+            let entry = entry.map_err(|e| {
+                BurlError::UserError(format!("failed to read directory entry: {}", e))
+            })?;
+
+            let path = entry.path();
+
+            // Skip non-markdown files and non-task files
+            if path.extension().and_then(|e| e.to_str()) != Some("md") {
+                continue;
+            }
+
+            // Extract task ID from filename
+            if let Some(task_id) = extract_task_id_from_filename(&path)
+                && let Some((prefix, number)) = split_task_id(&task_id)
+            {
+                let max_number = self.max_numbers.entry(prefix.to_string()).or_default();
+                *max_number = (*max_number).max(number);
+
+                self.tasks.insert(
+                    task_id.clone(),
+                    TaskInfo {
+                        id: task_id,
+                        bucket: bucket.to_string(),
+                        path,
+                        number,
+                        archived,
+                    },
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Index of all tasks in the workflow.
+///
+/// Only the buckets are scanned up front. The archive is scanned the first
+/// time it is needed: to find a task that is not in any bucket, or to reserve
+/// archived task numbers in [`next_number`](Self::next_number).
+///
+/// Task frontmatter read through [`frontmatter`](Self::frontmatter) is kept in
+/// the on-disk cache; commands that read many tasks write it back with
+/// [`save_cache`](Self::save_cache).
+#[derive(Debug, Default)]
+pub struct TaskIndex {
+    /// Configured buckets in display order (`states.buckets`).
+    buckets: Vec<String>,
+
+    /// Tasks in the buckets.
+    active: TaskSet,
+
+    /// Archive directory, if this index was built from a workflow.
+    archive_dir: Option<PathBuf>,
+
+    /// Archived tasks, loaded on first use, with the first error hit while
+    /// reading the archive.
+    archive: OnceCell<(TaskSet, Option<String>)>,
+
+    /// Cache file and the workflow state directory its keys are relative to,
+    /// if this index was built from a workflow.
+    cache_paths: Option<(PathBuf, PathBuf)>,
+
+    /// Frontmatter cache, loaded on first use.
+    cache: OnceCell<RefCell<TaskCache>>,
 }
 
 impl TaskIndex {
     /// Build a task index by scanning all buckets.
     ///
-    /// This function scans all bucket directories for task files matching
-    /// the pattern `TASK-{id}-{slug}.md`.
+    /// This function scans the bucket directories configured in
+    /// `states.buckets` (the built-in buckets by default) for task files
+    /// matching the pattern `{PREFIX}-{id}-{slug}.md`.
     pub fn build(ctx: &WorkflowContext) -> Result<Self> {
-        let mut index = TaskIndex::default();
-
-        for bucket in BUCKETS {
+        let mut index = TaskIndex {
+            buckets: Config::load(ctx.config_path())
+                .unwrap_or_default()
+                .states
+                .buckets,
+            archive_dir: Some(ctx.archive_dir()),
+            cache_paths: Some((ctx.index_cache_path(), ctx.workflow_state_dir.clone())),
+            ..Default::default()
+        };
+
+        for bucket in &index.buckets {
             let bucket_path = ctx.bucket_path(bucket);
             if !bucket_path.exists() {
                 continue;
             }
+            index.active.scan(&bucket_path, bucket, false)?;
+        }
 
-            let entries = fs::read_dir(&bucket_path).map_err(|e| {
-                BurlError::UserError(format!(
-                    "failed to read bucket directory '{}': {}",
-                    bucket_path.display(),
-                    e
-                ))
-            })?;
-
-            for entry in entries {
-                let entry = entry.map_err(|e| {
-                    BurlError::UserError(format!("failed to read directory entry: {}", e))
-                })?;
-
-                let path = entry.path();
+        Ok(index)
+    }
 
-                // Skip non-markdown files and non-task files
-                if path.extension().and_then(|e| e.to_str()) != Some("md") {
-                    continue;
-                }
+    /// Configured buckets in display order.
+    pub fn buckets(&self) -> &[String] {
+        &self.buckets
+    }
 
-                // Extract task ID from filename
-                if let Some(task_id) = extract_task_id_from_filename(&path)
-                    && let Some(number) = extract_task_number(&task_id)
-                {
-                    index.tasks.insert(
-                        task_id.clone(),
-                        TaskInfo {
-                            id: task_id,
-                            bucket: bucket.to_string(),
-                            path,
-                            number,
-                        },
+    /// Archived tasks, scanning the archive on first use.
+    ///
+    /// A missing archive is empty. Unreadable archive directories are
+    /// skipped, and the first error is kept for [`try_archive`](Self::try_archive).
+    fn archive_scan(&self) -> &(TaskSet, Option<String>) {
+        self.archive.get_or_init(|| {
+            let mut set = TaskSet::default();
+            let Some(dir) = self.archive_dir.as_deref().filter(|dir| dir.exists()) else {
+                return (set, None);
+            };
+            let months = match fs::read_dir(dir) {
+                Ok(months) => months,
+                Err(e) => {
+                    let error = format!(
+                        "failed to read archive directory '{}': {}",
+                        dir.display(),
+                        e
                     );
-
-                    if number > index.max_number {
-                        index.max_number = number;
-                    }
+                    return (set, Some(error));
+                }
+            };
+
+            let mut error = None;
+            for month in months {
+                let result = month
+                    .map_err(|e| {
+                        BurlError::UserError(format!("failed to read directory entry: {}", e))
+                    })
+                    .and_then(|month| {
+                        let path = month.path();
+                        if path.is_dir() {
+                            set.scan(&path, "DONE", true)
+                        } else {
+                            Ok(())
+                        }
+                    });
+                if let Err(e) = result {
+                    error.get_or_insert(e.to_string());
                 }
             }
-        }
+            (set, error)
+        })
+    }
 
-        Ok(index)
+    /// Archived tasks, skipping unreadable archive directories.
+    ///
+    /// Fine for listings; anything that must see every archived task uses
+    /// [`try_archive`](Self::try_archive).
+    fn archive(&self) -> &TaskSet {
+        &self.archive_scan().0
     }
 
-    /// Get the next available task number.
-    pub fn next_number(&self) -> u32 {
-        self.max_number + 1
+    /// Archived tasks, failing if any part of the archive could not be read.
+    fn try_archive(&self) -> Result<&TaskSet> {
+        match self.archive_scan() {
+            (set, None) => Ok(set),
+            (_, Some(error)) => Err(BurlError::UserError(format!(
+                "{}\n\nArchived task IDs must be known before a new ID is allocated. \
+                 Fix the archive directory permissions and retry.",
+                error
+            ))),
+        }
     }
 
-    /// Find a task by ID.
+    /// Get the next available task number for IDs with `prefix`.
+    ///
+    /// Each prefix is numbered separately. Numbers of archived tasks stay
+    /// reserved, so this fails if the archive cannot be read.
+    pub fn next_number(&self, prefix: &str) -> Result<u32> {
+        let max_in = |set: &TaskSet| set.max_numbers.get(prefix).copied().unwrap_or(0);
+        Ok(max_in(&self.active).max(max_in(self.try_archive()?)) + 1)
+    }
+
+    /// Find a task by ID, looking in the archive if it is in no bucket.
     pub fn find(&self, task_id: &str) -> Option<&TaskInfo> {
         // Normalize to uppercase
         let normalized = task_id.to_uppercase();
-        self.tasks.get(&normalized)
+        self.active
+            .tasks
+            .get(&normalized)
+            .or_else(|| self.archive().tasks.get(&normalized))
     }
 
     /// Get all tasks in a specific bucket.
+    ///
+    /// Archived tasks are not included.
     pub fn tasks_in_bucket(&self, bucket: &str) -> Vec<&TaskInfo> {
-        self.tasks.values().filter(|t| t.bucket == bucket).collect()
+        self.active
+            .tasks
+            .values()
+            .filter(|t| t.bucket == bucket)
+            .collect()
     }
 
     /// Get task counts per bucket.
     pub fn bucket_counts(&self) -> HashMap<String, usize> {
         let mut counts = HashMap::new();
-        for bucket in BUCKETS {
-            counts.insert(bucket.to_string(), 0);
+        for bucket in &self.buckets {
+            counts.insert(bucket.clone(), 0);
         }
-        for task in self.tasks.values() {
+        for task in self.active.tasks.values() {
             *counts.entry(task.bucket.clone()).or_insert(0) += 1;
         }
         counts
     }
 
-    /// Get all tasks.
+    /// Get all tasks in the buckets (archived tasks are not included).
     pub fn all_tasks(&self) -> impl Iterator<Item = &TaskInfo> {
-        self.tasks.values()
+        self.active.tasks.values()
+    }
+
+    /// Get all archived tasks.
+    pub fn archived_tasks(&self) -> impl Iterator<Item = &TaskInfo> {
+        self.archive().tasks.values()
+    }
+
+    /// Parsed frontmatter of a task.
+    ///
+    /// Served from the on-disk cache while the task file is unchanged; use
+    /// [`TaskFile::load`] when the body is needed too.
+    pub fn frontmatter(&self, info: &TaskInfo) -> Result<TaskFrontmatter> {
+        let Some((cache_path, state_dir)) = &self.cache_paths else {
+            return Ok(TaskFile::load(&info.path)?.frontmatter);
+        };
+        self.cache
+            .get_or_init(|| RefCell::new(TaskCache::load(cache_path.clone(), state_dir.clone())))
+            .borrow_mut()
+            .frontmatter(&info.path)
+    }
+
+    /// Prune cache entries for files this index no longer sees and write the
+    /// frontmatter cache back, if [`frontmatter`](Self::frontmatter) changed it.
+    pub fn save_cache(&self) -> Result<()> {
+        let Some(cache) = self.cache.get() else {
+            return Ok(());
+        };
+        let mut known: HashSet<&Path> = self
+            .active
+            .tasks
+            .values()
+            .map(|t| t.path.as_path())
+            .collect();
+        let archive = self.archive.get().map(|(set, _)| set);
+        if let Some(archive) = archive {
+            known.extend(archive.tasks.values().map(|t| t.path.as_path()));
+        }
+        let archive_dir = self.archive_dir.as_deref();
+
+        let mut cache = cache.borrow_mut();
+        cache.retain(|path| {
+            known.contains(path)
+                || (archive.is_none() && archive_dir.is_some_and(|dir| path.starts_with(dir)))
+        });
+        cache.save()
     }
 }
 
 /// Extract the task ID from a task filename.
 ///
-/// Expected format: `TASK-{id}-{slug}.md`
+/// Expected format: `{PREFIX}-{number}-{slug}.md`
 ///
 /// Returns the task ID (e.g., "TASK-001") or None if the filename
 /// doesn't match the expected pattern.
 fn extract_task_id_from_filename(path: &Path) -> Option<String> {
     let filename = path.file_stem()?.to_str()?;
 
-    // Match TASK-NNN at the start
-    if let Some(rest) = filename.strip_prefix("TASK-") {
-        // Find the second hyphen (after the number)
-        if let Some(end) = rest.find('-') {
-            let number_part = &rest[..end];
-            // Verify it's numeric
-            if number_part.chars().all(|c| c.is_ascii_digit()) && !number_part.is_empty() {
-                return Some(format!("TASK-{}", number_part));
-            }
-        } else {
-            // No slug, just TASK-NNN
-            if rest.chars().all(|c| c.is_ascii_digit()) && !rest.is_empty() {
-                return Some(format!("TASK-{}", rest));
-            }
-        }
-    }
+    // Match PREFIX-NNN at the start
+    let (prefix, rest) = filename.split_once('-')?;
+    let number_part = rest.split('-').next().unwrap_or(rest);
+    let task_id = format!("{}-{}", prefix, number_part);
+    split_task_id(&task_id)?;
+    Some(task_id)
+}
 
-    None
+/// Split a task ID into its prefix and number (`BUG-042` -> `("BUG", 42)`).
+///
+/// Returns None unless the ID is an uppercase prefix, a hyphen, and digits.
+pub fn split_task_id(task_id: &str) -> Option<(&str, u32)> {
+    let (prefix, number_part) = task_id.split_once('-')?;
+    let mut prefix_chars = prefix.chars();
+    if !prefix_chars.next()?.is_ascii_uppercase()
+        || !prefix_chars.all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+        || number_part.is_empty()
+        || !number_part.chars().all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    Some((prefix, number_part.parse().ok()?))
 }
 
-/// Extract the numeric part from a task ID.
-fn extract_task_number(task_id: &str) -> Option<u32> {
-    if let Some(rest) = task_id.strip_prefix("TASK-") {
-        rest.parse().ok()
-    } else {
-        None
+/// Ordering key for a task ID (see [`TaskKey`]). IDs that do not parse sort
+/// after all others.
+pub fn task_key(task_id: &str) -> TaskKey {
+    match split_task_id(task_id) {
+        Some((prefix, number)) => (prefix.to_string(), number),
+        None => (task_id.to_string(), u32::MAX),
     }
 }
 
 /// Validate a task ID format.
 ///
-/// Valid task IDs match the pattern `TASK-NNN` where NNN is at least 3 digits.
-/// Also rejects any path traversal attempts.
+/// Valid task IDs match the pattern `PREFIX-NNN`, where PREFIX is uppercase
+/// letters and digits starting with a letter (e.g. `TASK`, `BUG`) and NNN is at
+/// least 3 digits. Also rejects any path traversal attempts.
 ///
 /// # Arguments
 ///
@@ -196,7 +397,7 @@ pub fn validate_task_id(task_id: &str) -> Result<String> {
     if task_id.contains('/') || task_id.contains('\\') || task_id.contains("..") {
         return Err(BurlError::UserError(format!(
             "invalid task ID '{}': contains path traversal characters.\n\
-             Task IDs must be in the format TASK-NNN (e.g., TASK-001).",
+             Task IDs must be in the format PREFIX-NNN (e.g., TASK-001).",
             task_id
         )));
     }
@@ -207,7 +408,7 @@ pub fn validate_task_id(task_id: &str) -> Result<String> {
     // Validate format
     if !TASK_ID_REGEX.is_match(&normalized) {
         return Err(BurlError::UserError(format!(
-            "invalid task ID '{}': must be in the format TASK-NNN (e.g., TASK-001).\n\
+            "invalid task ID '{}': must be in the format PREFIX-NNN (e.g., TASK-001).\n\
              The number must be at least 3 digits.",
             task_id
         )));
@@ -216,17 +417,19 @@ pub fn validate_task_id(task_id: &str) -> Result<String> {
     Ok(normalized)
 }
 
-/// Generate a task ID from a number.
+/// Generate a task ID from a prefix and number.
 ///
 /// # Arguments
 ///
+/// * `prefix` - The ID prefix (e.g., "TASK", "BUG")
 /// * `number` - The task number
+/// * `width` - Minimum number of digits (zero-padded, at least 3)
 ///
 /// # Returns
 ///
-/// The task ID in the format `TASK-NNN` (zero-padded to at least 3 digits).
-pub fn generate_task_id(number: u32) -> String {
-    format!("TASK-{:03}", number)
+/// The task ID in the format `PREFIX-NNN`.
+pub fn generate_task_id(prefix: &str, number: u32, width: usize) -> String {
+    format!("{}-{:0width$}", prefix, number, width = width.max(3))
 }
 
 /// Slugify a title for use in a task filename.
@@ -327,6 +530,8 @@ mod tests {
         assert_eq!(validate_task_id("TASK-123").unwrap(), "TASK-123");
         assert_eq!(validate_task_id("TASK-0001").unwrap(), "TASK-0001"); // 4 digits ok
         assert_eq!(validate_task_id("TASK-99999").unwrap(), "TASK-99999");
+        assert_eq!(validate_task_id("bug-042").unwrap(), "BUG-042"); // other prefixes
+        assert_eq!(validate_task_id("NET2-007").unwrap(), "NET2-007");
     }
 
     #[test]
@@ -338,6 +543,8 @@ mod tests {
         assert!(validate_task_id("001").is_err()); // no prefix
         assert!(validate_task_id("TASK-").is_err()); // no number
         assert!(validate_task_id("").is_err()); // empty
+        assert!(validate_task_id("2FA-001").is_err()); // prefix must start with a letter
+        assert!(validate_task_id("MY_BUG-001").is_err()); // letters and digits only
     }
 
     #[test]
@@ -351,10 +558,11 @@ mod tests {
 
     #[test]
     fn test_generate_task_id() {
-        assert_eq!(generate_task_id(1), "TASK-001");
-        assert_eq!(generate_task_id(12), "TASK-012");
-        assert_eq!(generate_task_id(123), "TASK-123");
-        assert_eq!(generate_task_id(1234), "TASK-1234");
+        assert_eq!(generate_task_id("TASK", 1, 3), "TASK-001");
+        assert_eq!(generate_task_id("TASK", 12, 3), "TASK-012");
+        assert_eq!(generate_task_id("TASK", 123, 3), "TASK-123");
+        assert_eq!(generate_task_id("TASK", 1234, 3), "TASK-1234");
+        assert_eq!(generate_task_id("BUG", 42, 4), "BUG-0042");
     }
 
     #[test]
@@ -435,6 +643,12 @@ mod tests {
             Some("TASK-0001".to_string())
         );
 
+        let path = PathBuf::from("BUG-042-login-crash.md");
+        assert_eq!(
+            extract_task_id_from_filename(&path),
+            Some("BUG-042".to_string())
+        );
+
         let path = PathBuf::from("not-a-task.md");
         assert_eq!(extract_task_id_from_filename(&path), None);
 
@@ -443,10 +657,53 @@ mod tests {
     }
 
     #[test]
-    fn test_extract_task_number() {
-        assert_eq!(extract_task_number("TASK-001"), Some(1));
-        assert_eq!(extract_task_number("TASK-123"), Some(123));
-        assert_eq!(extract_task_number("TASK-0001"), Some(1));
-        assert_eq!(extract_task_number("INVALID"), None);
+    fn test_split_task_id() {
+        assert_eq!(split_task_id("TASK-001"), Some(("TASK", 1)));
+        assert_eq!(split_task_id("TASK-123"), Some(("TASK", 123)));
+        assert_eq!(split_task_id("TASK-0001"), Some(("TASK", 1)));
+        assert_eq!(split_task_id("NET2-007"), Some(("NET2", 7)));
+        assert_eq!(split_task_id("INVALID"), None);
+        assert_eq!(split_task_id("bug-001"), None);
+        assert_eq!(split_task_id("2FA-001"), None);
+    }
+
+    #[test]
+    fn test_task_key_orders_by_prefix_then_number() {
+        let mut ids = vec!["TASK-010", "BUG-002", "TASK-009", "BUG-010"];
+        ids.sort_by_key(|id| task_key(id));
+        assert_eq!(ids, vec!["BUG-002", "BUG-010", "TASK-009", "TASK-010"]);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_task_index_writes_cache_only_on_save() {
+        use crate::test_support::{DirGuard, create_test_repo};
+        use std::time::{Duration, SystemTime};
+
+        let temp_dir = create_test_repo();
+        let _guard = DirGuard::new(temp_dir.path());
+        crate::commands::init::cmd_init().unwrap();
+        let ctx = crate::context::require_initialized_workflow().unwrap();
+
+        // Old enough to be cached (outside the racy window).
+        let task = ctx.bucket_path("READY").join("TASK-001-cached.md");
+        std::fs::write(&task, "---\nid: TASK-001\ntitle: Cached\n---\n").unwrap();
+        fs::File::options()
+            .write(true)
+            .open(&task)
+            .unwrap()
+            .set_modified(SystemTime::now() - Duration::from_secs(60))
+            .unwrap();
+
+        let index = TaskIndex::build(&ctx).unwrap();
+        let info = index.find("TASK-001").unwrap();
+        assert_eq!(index.frontmatter(info).unwrap().title, "Cached");
+        drop(index);
+        assert!(!ctx.index_cache_path().exists());
+
+        let index = TaskIndex::build(&ctx).unwrap();
+        index.frontmatter(index.find("TASK-001").unwrap()).unwrap();
+        index.save_cache().unwrap();
+        assert!(ctx.index_cache_path().exists());
     }
 }
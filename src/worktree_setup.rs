@@ -0,0 +1,395 @@
+//! Provisioning for freshly created task worktrees.
+//!
+//! `worktree_setup:` in config.yaml lists untracked files to copy or symlink
+//! from the main worktree (e.g. `.env`, `node_modules`), commands to run in the
+//! new worktree (e.g. `npm ci`), and environment variables shared by setup
+//! commands, hooks, agents, and validation steps (e.g. a common
+//! `CARGO_TARGET_DIR`).
+//!
+//! Setup is best-effort: every step is attempted and its outcome reported, so
+//! `burl claim` can surface failures without losing the claim. Setup commands
+//! stop at the first failure since later ones usually depend on earlier ones.
+//! They get no stdin, so a prompt fails instead of waiting, and a command
+//! running past `timeout_seconds` is killed with everything it started.
+
+use crate::config::WorktreeSetupConfig;
+use crate::context::WorkflowContext;
+use crate::hooks::truncate_tail;
+use crate::validate::pipeline::run_with_timeout;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+/// Maximum number of output characters kept for a failed setup command.
+const SETUP_OUTPUT_MAX_CHARS: usize = 2000;
+
+/// Outcome of one setup step.
+#[derive(Debug, Clone, Serialize)]
+pub struct SetupStepResult {
+    /// Step description, e.g. "copy .env" or "run npm ci".
+    pub step: String,
+    pub success: bool,
+    /// Why the step failed or was skipped.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl SetupStepResult {
+    fn ok(step: String) -> Self {
+        Self {
+            step,
+            success: true,
+            message: None,
+        }
+    }
+
+    fn failed(step: String, message: impl Into<String>) -> Self {
+        Self {
+            step,
+            success: false,
+            message: Some(message.into()),
+        }
+    }
+}
+
+/// Outcome of provisioning one worktree.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SetupReport {
+    pub steps: Vec<SetupStepResult>,
+}
+
+impl SetupReport {
+    /// The steps that failed.
+    pub fn failures(&self) -> impl Iterator<Item = &SetupStepResult> {
+        self.steps.iter().filter(|s| !s.success)
+    }
+
+    /// Whether every step succeeded.
+    pub fn is_success(&self) -> bool {
+        self.failures().next().is_none()
+    }
+
+    /// Markdown list of failed steps, for the task body.
+    pub fn format_failures(&self) -> String {
+        let mut out = String::new();
+        for failure in self.failures() {
+            out.push_str(&format!("- **{}**", failure.step));
+            if let Some(message) = &failure.message {
+                if message.contains('\n') {
+                    out.push_str(&format!("\n\n```\n{}\n```\n", message));
+                } else {
+                    out.push_str(&format!(": {}", message));
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// Copy/symlink configured files into `worktree` and run the setup commands.
+pub fn provision_worktree(
+    ctx: &WorkflowContext,
+    setup: &WorktreeSetupConfig,
+    worktree: &Path,
+) -> SetupReport {
+    let mut report = SetupReport::default();
+
+    for path in &setup.copy {
+        let step = format!("copy {}", path);
+        let source = ctx.repo_root.join(path);
+        let dest = worktree.join(path);
+        report
+            .steps
+            .push(match link_or_copy(&source, &dest, copy_path) {
+                Ok(()) => SetupStepResult::ok(step),
+                Err(e) => SetupStepResult::failed(step, e),
+            });
+    }
+
+    for path in &setup.symlink {
+        let step = format!("symlink {}", path);
+        let source = ctx.repo_root.join(path);
+        let dest = worktree.join(path);
+        report
+            .steps
+            .push(match link_or_copy(&source, &dest, symlink_path) {
+                Ok(()) => SetupStepResult::ok(step),
+                Err(e) => SetupStepResult::failed(step, e),
+            });
+    }
+
+    let env = setup.resolved_env(&ctx.repo_root);
+    let mut failed = false;
+    for command in &setup.commands {
+        let step = format!("run {}", command);
+        if failed {
+            report.steps.push(SetupStepResult::failed(
+                step,
+                "not run (an earlier setup command failed)",
+            ));
+            continue;
+        }
+        let result = run_setup_command(step, command, worktree, &env, setup.timeout());
+        failed = !result.success;
+        report.steps.push(result);
+    }
+
+    report
+}
+
+/// Place `source` at `dest` using `place`, unless `dest` already exists.
+fn link_or_copy(
+    source: &Path,
+    dest: &Path,
+    place: fn(&Path, &Path) -> std::io::Result<()>,
+) -> Result<(), String> {
+    if !source.exists() {
+        return Err(format!("'{}' does not exist", source.display()));
+    }
+    if dest.symlink_metadata().is_ok() {
+        // Tracked (or already provisioned) paths are left alone.
+        return Ok(());
+    }
+    if let Some(parent) = dest.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("failed to create '{}': {}", parent.display(), e))?;
+    }
+    place(source, dest).map_err(|e| e.to_string())
+}
+
+/// Copy a file or directory tree.
+///
+/// Symlinks are recreated with the same target rather than followed, so a
+/// link cycle (or a link to somewhere huge) cannot blow up the copy.
+fn copy_path(source: &Path, dest: &Path) -> std::io::Result<()> {
+    let file_type = source.symlink_metadata()?.file_type();
+    if file_type.is_symlink() {
+        return copy_symlink(source, dest);
+    }
+    if !file_type.is_dir() {
+        return std::fs::copy(source, dest).map(|_| ());
+    }
+    std::fs::create_dir_all(dest)?;
+    for entry in std::fs::read_dir(source)? {
+        let entry = entry?;
+        copy_path(&entry.path(), &dest.join(entry.file_name()))?;
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn copy_symlink(source: &Path, dest: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(std::fs::read_link(source)?, dest)
+}
+
+#[cfg(windows)]
+fn copy_symlink(source: &Path, dest: &Path) -> std::io::Result<()> {
+    let target = std::fs::read_link(source)?;
+    if source.is_dir() {
+        std::os::windows::fs::symlink_dir(target, dest)
+    } else {
+        std::os::windows::fs::symlink_file(target, dest)
+    }
+}
+
+#[cfg(unix)]
+fn symlink_path(source: &Path, dest: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(source, dest)
+}
+
+#[cfg(windows)]
+fn symlink_path(source: &Path, dest: &Path) -> std::io::Result<()> {
+    if source.is_dir() {
+        std::os::windows::fs::symlink_dir(source, dest)
+    } else {
+        std::os::windows::fs::symlink_file(source, dest)
+    }
+}
+
+fn run_setup_command(
+    step: String,
+    command: &str,
+    worktree: &Path,
+    env: &BTreeMap<String, String>,
+    timeout: Duration,
+) -> SetupStepResult {
+    let args = match shell_words::split(command) {
+        Ok(args) if !args.is_empty() => args,
+        Ok(_) => return SetupStepResult::failed(step, "command is empty"),
+        Err(e) => {
+            return SetupStepResult::failed(step, format!("failed to parse command: {}", e));
+        }
+    };
+
+    let mut cmd = Command::new(&args[0]);
+    cmd.args(&args[1..])
+        .current_dir(worktree)
+        .envs(env)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let output = match run_with_timeout(&mut cmd, None, Some(timeout)) {
+        Ok(output) => output,
+        Err(e) => {
+            return SetupStepResult::failed(
+                step,
+                format!(
+                    "failed to execute command: {}\nFix: ensure the command is installed and in PATH.",
+                    e
+                ),
+            );
+        }
+    };
+
+    let mut message = match output.status {
+        Some(status) if status.success() => return SetupStepResult::ok(step),
+        Some(status) => match status.code() {
+            Some(code) => format!("exited with code {}", code),
+            None => "terminated by a signal".to_string(),
+        },
+        None => format!(
+            "timed out after {}s (process group killed)",
+            timeout.as_secs()
+        ),
+    };
+    let mut combined = String::from_utf8_lossy(&output.stdout).to_string();
+    combined.push_str(&String::from_utf8_lossy(&output.stderr));
+    let combined = combined.trim();
+    if !combined.is_empty() {
+        message.push('\n');
+        message.push_str(&truncate_tail(combined, SETUP_OUTPUT_MAX_CHARS));
+    }
+    SetupStepResult::failed(step, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::init::cmd_init;
+    use crate::context::require_initialized_workflow;
+    use crate::test_support::{DirGuard, create_test_repo};
+    use serial_test::serial;
+
+    #[test]
+    #[serial]
+    fn test_provision_worktree_copies_links_and_runs_commands() {
+        let temp_dir = create_test_repo();
+        let _guard = DirGuard::new(temp_dir.path());
+        cmd_init().unwrap();
+        let ctx = require_initialized_workflow().unwrap();
+
+        std::fs::write(temp_dir.path().join(".env"), "SECRET=1\n").unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("cache/deps")).unwrap();
+        std::fs::write(temp_dir.path().join("cache/deps/lib.txt"), "lib\n").unwrap();
+        let worktree = tempfile::TempDir::new().unwrap();
+
+        let setup = WorktreeSetupConfig {
+            copy: vec![".env".to_string(), "missing.txt".to_string()],
+            symlink: vec!["cache".to_string()],
+            commands: vec![
+                "sh -c 'echo $SHARED_DIR > setup.out'".to_string(),
+                "sh -c 'echo boom >&2; exit 2'".to_string(),
+                "touch never".to_string(),
+            ],
+            env: [("SHARED_DIR".to_string(), "{repo_root}/shared".to_string())].into(),
+            ..Default::default()
+        };
+
+        let report = provision_worktree(&ctx, &setup, worktree.path());
+        let wt = worktree.path();
+        assert_eq!(
+            std::fs::read_to_string(wt.join(".env")).unwrap(),
+            "SECRET=1\n"
+        );
+        assert!(wt.join("cache").symlink_metadata().unwrap().is_symlink());
+        assert!(wt.join("cache/deps/lib.txt").exists());
+        assert_eq!(
+            std::fs::read_to_string(wt.join("setup.out"))
+                .unwrap()
+                .trim(),
+            format!("{}/shared", ctx.repo_root.display())
+        );
+        assert!(!wt.join("never").exists());
+
+        assert!(!report.is_success());
+        let failed: Vec<&str> = report.failures().map(|f| f.step.as_str()).collect();
+        assert_eq!(
+            failed,
+            vec![
+                "copy missing.txt",
+                "run sh -c 'echo boom >&2; exit 2'",
+                "run touch never"
+            ]
+        );
+        let body = report.format_failures();
+        assert!(body.contains("exited with code 2\nboom"));
+        assert!(body.contains("**run touch never**: not run"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_setup_command_times_out_and_gets_no_stdin() {
+        let temp_dir = create_test_repo();
+        let _guard = DirGuard::new(temp_dir.path());
+        cmd_init().unwrap();
+        let ctx = require_initialized_workflow().unwrap();
+        let worktree = tempfile::TempDir::new().unwrap();
+
+        // `read` sees end of input instead of waiting on the terminal; the
+        // backgrounded sleep must be killed along with the hung command.
+        let setup = WorktreeSetupConfig {
+            commands: vec![
+                "sh -c 'read answer; echo \"[$answer]\" > answer.txt'".to_string(),
+                "sh -c 'sleep 30 & sleep 30'".to_string(),
+            ],
+            timeout_seconds: Some(1),
+            ..Default::default()
+        };
+
+        let start = std::time::Instant::now();
+        let report = provision_worktree(&ctx, &setup, worktree.path());
+        assert!(start.elapsed() < Duration::from_secs(20));
+        assert_eq!(
+            std::fs::read_to_string(worktree.path().join("answer.txt")).unwrap(),
+            "[]\n"
+        );
+        let failed: Vec<&SetupStepResult> = report.failures().collect();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].step, "run sh -c 'sleep 30 & sleep 30'");
+        assert_eq!(
+            failed[0].message.as_deref(),
+            Some("timed out after 1s (process group killed)")
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_copy_path_recreates_symlinks() {
+        let source = tempfile::TempDir::new().unwrap();
+        let src = source.path().join("config");
+        std::fs::create_dir_all(src.join("nested")).unwrap();
+        std::fs::write(src.join("nested/app.toml"), "debug = true\n").unwrap();
+        // A cycle back to the tree root, and a link to a file in it.
+        std::os::unix::fs::symlink("..", src.join("nested/loop")).unwrap();
+        std::os::unix::fs::symlink("nested/app.toml", src.join("app.toml")).unwrap();
+
+        let worktree = tempfile::TempDir::new().unwrap();
+        let dest = worktree.path().join("config");
+        copy_path(&src, &dest).unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(dest.join("nested/app.toml")).unwrap(),
+            "debug = true\n"
+        );
+        let cycle = dest.join("nested/loop");
+        assert!(cycle.symlink_metadata().unwrap().is_symlink());
+        assert_eq!(std::fs::read_link(&cycle).unwrap(), Path::new(".."));
+        assert_eq!(
+            std::fs::read_link(dest.join("app.toml")).unwrap(),
+            Path::new("nested/app.toml")
+        );
+    }
+}
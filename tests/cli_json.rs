@@ -0,0 +1,82 @@
+//! End-to-end check of `--output json`: runs the built `burl` binary and
+//! asserts the stdout/stderr split that scripts rely on.
+
+use std::path::Path;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+fn git(repo_dir: &Path, args: &[&str]) {
+    let output = Command::new("git")
+        .current_dir(repo_dir)
+        .args(args)
+        .output()
+        .unwrap_or_else(|e| panic!("failed to execute git {}: {}", args.join(" "), e));
+    assert!(
+        output.status.success(),
+        "git {} failed:\n{}",
+        args.join(" "),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+fn burl(repo_dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_burl"))
+        .current_dir(repo_dir)
+        .args(args)
+        .output()
+        .unwrap_or_else(|e| panic!("failed to execute burl {}: {}", args.join(" "), e))
+}
+
+/// A repo on `main` with one commit and an `origin` remote pointing at itself.
+fn create_test_repo_with_remote() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path();
+
+    git(path, &["init"]);
+    git(path, &["symbolic-ref", "HEAD", "refs/heads/main"]);
+    git(path, &["config", "user.email", "test@example.com"]);
+    git(path, &["config", "user.name", "Test User"]);
+    std::fs::write(path.join("README.md"), "# Test\n").unwrap();
+    git(path, &["add", "."]);
+    git(path, &["commit", "-m", "Initial commit"]);
+    let path_str = path.to_string_lossy().to_string();
+    git(path, &["remote", "add", "origin", &path_str]);
+
+    temp_dir
+}
+
+#[test]
+fn test_claim_json_output_is_a_single_object_on_stdout() {
+    let temp_dir = create_test_repo_with_remote();
+    let root = temp_dir.path();
+
+    assert!(burl(root, &["init"]).status.success());
+    assert!(burl(root, &["add", "JSON task"]).status.success());
+
+    let output = burl(root, &["--output", "json", "claim", "TASK-001"]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(output.status.success(), "claim failed:\n{}", stderr);
+
+    // stdout holds exactly one JSON object and nothing else.
+    let mut stream = serde_json::Deserializer::from_str(&stdout).into_iter::<serde_json::Value>();
+    let result = stream.next().unwrap().unwrap();
+    assert!(
+        stream.next().is_none(),
+        "extra output on stdout:\n{}",
+        stdout
+    );
+    assert!(result.is_object());
+    assert_eq!(result["schema_version"], 1);
+    assert_eq!(result["command"], "claim");
+    assert_eq!(result["task_id"], "TASK-001");
+
+    // The worktree path printed for humans goes to stderr instead.
+    let worktree = result["worktree"].as_str().unwrap();
+    assert!(worktree.ends_with("task-001-json-task"));
+    assert!(
+        stderr.lines().any(|line| line == worktree),
+        "stderr:\n{}",
+        stderr
+    );
+}